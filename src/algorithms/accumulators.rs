@@ -0,0 +1,357 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::shape::Aabb2D;
+
+/// Running axis aligned bounding box.
+///
+/// Accumulators built over separate chunks of a data set (files, network
+/// batches, threads) can be combined with [`merge`](Self::merge); the result is
+/// the same as one accumulator fed every point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundsAccumulator<T: Float + Debug> {
+    min: Point2D<T>,
+    max: Point2D<T>,
+    count: usize,
+}
+
+impl<T: Float + Debug> Default for BoundsAccumulator<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Float + Debug> BoundsAccumulator<T> {
+    /// Creates an accumulator that has seen no points.
+    pub fn new() -> Self {
+        BoundsAccumulator {
+            min: Point2D::new(T::max_value(), T::max_value()),
+            max: Point2D::new(T::min_value(), T::min_value()),
+            count: 0,
+        }
+    }
+
+    /// Adds a point.
+    pub fn push(&mut self, point: Point2D<T>) {
+        if point.x < self.min.x { self.min.x = point.x; }
+        if point.y < self.min.y { self.min.y = point.y; }
+        if point.x > self.max.x { self.max.x = point.x; }
+        if point.y > self.max.y { self.max.y = point.y; }
+        self.count += 1;
+    }
+
+    /// Adds all points seen by `other`.
+    pub fn merge(&mut self, other: &Self) {
+        if other.count > 0 {
+            let count = self.count + other.count;
+            self.push(other.min);
+            self.push(other.max);
+            self.count = count;
+        }
+    }
+
+    /// Number of points seen so far.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The bounding box, or `None` if no point was added.
+    ///
+    /// Same result as [`bounding_box_2d`](super::point_algorithms::bounding_box_2d).
+    pub fn bounds(&self) -> Option<Aabb2D<T>> {
+        (self.count > 0).then_some(Aabb2D::new(self.min, self.max))
+    }
+}
+
+/// Running centroid (mean position).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CentroidAccumulator<T: Float + Debug> {
+    sum: Point2D<T>,
+    count: usize,
+}
+
+impl<T: Float + Debug> Default for CentroidAccumulator<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Float + Debug> CentroidAccumulator<T> {
+    /// Creates an accumulator that has seen no points.
+    pub fn new() -> Self {
+        CentroidAccumulator { sum: Point2D::origin(), count: 0 }
+    }
+
+    /// Adds a point.
+    pub fn push(&mut self, point: Point2D<T>) {
+        self.sum = self.sum + point;
+        self.count += 1;
+    }
+
+    /// Adds all points seen by `other`.
+    pub fn merge(&mut self, other: &Self) {
+        self.sum = self.sum + other.sum;
+        self.count += other.count;
+    }
+
+    /// Number of points seen so far.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The centroid, or `None` if no point was added.
+    pub fn centroid(&self) -> Option<Point2D<T>> {
+        (self.count > 0).then(|| self.sum / T::from(self.count).unwrap())
+    }
+}
+
+/// Running center of mass of points carrying individual weights (masses).
+///
+/// Unlike the other accumulators it is fed `(point, weight)` pairs, e.g.
+/// particles of a particle filter or sensor readings with confidences.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WeightedCentroidAccumulator<T: Float + Debug> {
+    weighted_sum: Point2D<T>,
+    total_weight: T,
+}
+
+impl<T: Float + Debug> Default for WeightedCentroidAccumulator<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Float + Debug> WeightedCentroidAccumulator<T> {
+    /// Creates an accumulator that has seen no points.
+    pub fn new() -> Self {
+        WeightedCentroidAccumulator { weighted_sum: Point2D::origin(), total_weight: T::zero() }
+    }
+
+    /// Adds a point with mass `weight`.
+    pub fn push(&mut self, point: Point2D<T>, weight: T) {
+        self.weighted_sum = self.weighted_sum + point * weight;
+        self.total_weight = self.total_weight + weight;
+    }
+
+    /// Adds all points seen by `other`.
+    pub fn merge(&mut self, other: &Self) {
+        self.weighted_sum = self.weighted_sum + other.weighted_sum;
+        self.total_weight = self.total_weight + other.total_weight;
+    }
+
+    /// Sum of the weights seen so far.
+    pub fn total_weight(&self) -> T {
+        self.total_weight
+    }
+
+    /// The center of mass, or `None` if the weights sum to zero.
+    pub fn centroid(&self) -> Option<Point2D<T>> {
+        (self.total_weight != T::zero()).then(|| self.weighted_sum / self.total_weight)
+    }
+}
+
+impl<T: Float + Debug> Extend<(Point2D<T>, T)> for WeightedCentroidAccumulator<T> {
+    fn extend<I: IntoIterator<Item = (Point2D<T>, T)>>(&mut self, points: I) {
+        for (point, weight) in points {
+            self.push(point, weight);
+        }
+    }
+}
+
+impl<T: Float + Debug> FromIterator<(Point2D<T>, T)> for WeightedCentroidAccumulator<T> {
+    fn from_iter<I: IntoIterator<Item = (Point2D<T>, T)>>(points: I) -> Self {
+        let mut accumulator = Self::new();
+        accumulator.extend(points);
+        accumulator
+    }
+}
+
+/// Running mean, variance and covariance of the x and y coordinates.
+///
+/// # Algorithm Explanation
+/// Summing squares and dividing at the end cancels catastrophically for data
+/// far from the origin (e.g. projected coordinates in the millions). Instead:
+/// - `push` uses Welford's update of the mean and the sums of squared deviations.
+/// - `merge` uses Chan et al.'s pairwise combination of two such states.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CovarianceAccumulator<T: Float + Debug> {
+    mean: Point2D<T>,
+    // sums of (x - mean.x)², (y - mean.y)² and (x - mean.x)(y - mean.y)
+    m_xx: T,
+    m_yy: T,
+    m_xy: T,
+    count: usize,
+}
+
+impl<T: Float + Debug> Default for CovarianceAccumulator<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Float + Debug> CovarianceAccumulator<T> {
+    /// Creates an accumulator that has seen no points.
+    pub fn new() -> Self {
+        CovarianceAccumulator { mean: Point2D::origin(), m_xx: T::zero(), m_yy: T::zero(), m_xy: T::zero(), count: 0 }
+    }
+
+    /// Adds a point.
+    pub fn push(&mut self, point: Point2D<T>) {
+        self.count += 1;
+        let before = point - self.mean;
+        self.mean = self.mean + before / T::from(self.count).unwrap();
+        let after = point - self.mean;
+        self.m_xx = self.m_xx + before.x * after.x;
+        self.m_yy = self.m_yy + before.y * after.y;
+        self.m_xy = self.m_xy + before.x * after.y;
+    }
+
+    /// Adds all points seen by `other`.
+    pub fn merge(&mut self, other: &Self) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = *other;
+            return;
+        }
+        let (n_a, n_b) = (T::from(self.count).unwrap(), T::from(other.count).unwrap());
+        let n = n_a + n_b;
+        let delta = other.mean - self.mean;
+        let weight = n_a * n_b / n;
+
+        self.mean = self.mean + delta * (n_b / n);
+        self.m_xx = self.m_xx + other.m_xx + delta.x * delta.x * weight;
+        self.m_yy = self.m_yy + other.m_yy + delta.y * delta.y * weight;
+        self.m_xy = self.m_xy + other.m_xy + delta.x * delta.y * weight;
+        self.count += other.count;
+    }
+
+    /// Number of points seen so far.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The mean position, or `None` if no point was added.
+    pub fn mean(&self) -> Option<Point2D<T>> {
+        (self.count > 0).then_some(self.mean)
+    }
+
+    /// Population variance of x and y, or `None` if no point was added.
+    pub fn variance(&self) -> Option<Point2D<T>> {
+        let n = T::from(self.count).unwrap();
+        (self.count > 0).then(|| Point2D::new(self.m_xx / n, self.m_yy / n))
+    }
+
+    /// Population covariance matrix `[[var_x, cov_xy], [cov_xy, var_y]]`, or `None`
+    /// if no point was added.
+    pub fn covariance(&self) -> Option<[[T; 2]; 2]> {
+        let n = T::from(self.count).unwrap();
+        (self.count > 0).then(|| {
+            let cov = self.m_xy / n;
+            [[self.m_xx / n, cov], [cov, self.m_yy / n]]
+        })
+    }
+}
+
+macro_rules! impl_extend {
+    ($($accumulator:ident),*) => {
+        $(
+            impl<T: Float + Debug> Extend<Point2D<T>> for $accumulator<T> {
+                fn extend<I: IntoIterator<Item = Point2D<T>>>(&mut self, points: I) {
+                    for point in points {
+                        self.push(point);
+                    }
+                }
+            }
+
+            impl<T: Float + Debug> FromIterator<Point2D<T>> for $accumulator<T> {
+                fn from_iter<I: IntoIterator<Item = Point2D<T>>>(points: I) -> Self {
+                    let mut accumulator = Self::new();
+                    accumulator.extend(points);
+                    accumulator
+                }
+            }
+        )*
+    };
+}
+
+impl_extend!(BoundsAccumulator, CentroidAccumulator, CovarianceAccumulator);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::point_algorithms::{bounding_box_2d, centroid_2d};
+    use approx::assert_relative_eq;
+    use alloc::vec::Vec;
+
+    fn cloud() -> Vec<Point2D<f64>> {
+        (0..100)
+            .map(|i| {
+                let t = i as f64;
+                Point2D::new(500_000.0 + (t * 0.7).sin() * 30.0, 4_000_000.0 + t * 0.5 + (t * 1.9).cos())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_bounds_and_centroid_merge_like_one_pass() {
+        let points = cloud();
+        let mut bounds: BoundsAccumulator<f64> = points[..30].iter().cloned().collect();
+        bounds.merge(&points[30..].iter().cloned().collect());
+        bounds.merge(&BoundsAccumulator::new());
+        bounds.merge(&[points[0]].into_iter().collect());
+        assert_eq!(bounds.bounds(), bounding_box_2d(&points));
+        assert_eq!(bounds.count(), 101);
+
+        let mut centroid: CentroidAccumulator<f64> = points[..30].iter().cloned().collect();
+        centroid.merge(&points[30..].iter().cloned().collect());
+        let expected = centroid_2d(&points);
+        assert_relative_eq!(centroid.centroid().unwrap().x, expected.x, max_relative = 1e-12);
+        assert_relative_eq!(centroid.centroid().unwrap().y, expected.y, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn test_weighted_centroid_accumulator() {
+        let points = cloud();
+        // integer weights act like repeated points
+        let weights: Vec<f64> = (0..points.len()).map(|i| (i % 3) as f64).collect();
+        let mut weighted: WeightedCentroidAccumulator<f64> = points.iter().cloned().zip(weights.iter().cloned()).take(40).collect();
+        weighted.merge(&points.iter().cloned().zip(weights.iter().cloned()).skip(40).collect());
+        let repeated: CentroidAccumulator<f64> = points.iter().zip(&weights).flat_map(|(&p, &w)| core::iter::repeat_n(p, w as usize)).collect();
+        assert_eq!(weighted.total_weight(), repeated.count() as f64);
+        assert_relative_eq!(weighted.centroid().unwrap().x, repeated.centroid().unwrap().x, max_relative = 1e-12);
+        assert_relative_eq!(weighted.centroid().unwrap().y, repeated.centroid().unwrap().y, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn test_covariance_accumulator_against_two_pass() {
+        let points = cloud();
+        let n = points.len() as f64;
+        let mean = centroid_2d(&points);
+        let var_x = points.iter().map(|p| (p.x - mean.x).powi(2)).sum::<f64>() / n;
+        let var_y = points.iter().map(|p| (p.y - mean.y).powi(2)).sum::<f64>() / n;
+        let cov = points.iter().map(|p| (p.x - mean.x) * (p.y - mean.y)).sum::<f64>() / n;
+
+        let mut merged = CovarianceAccumulator::new();
+        for chunk in points.chunks(17) {
+            merged.merge(&chunk.iter().cloned().collect());
+        }
+        let single: CovarianceAccumulator<f64> = points.iter().cloned().collect();
+        for acc in [merged, single] {
+            let [[xx, xy], [yx, yy]] = acc.covariance().unwrap();
+            assert_relative_eq!(xx, var_x, max_relative = 1e-9);
+            assert_relative_eq!(yy, var_y, max_relative = 1e-9);
+            assert_relative_eq!(xy, cov, max_relative = 1e-9);
+            assert_eq!(xy, yx);
+        }
+    }
+
+    #[test]
+    fn test_empty_accumulators() {
+        assert_eq!(BoundsAccumulator::<f64>::new().bounds(), None);
+        assert_eq!(CentroidAccumulator::<f64>::new().centroid(), None);
+        assert_eq!(WeightedCentroidAccumulator::<f64>::new().centroid(), None);
+        assert_eq!(CovarianceAccumulator::<f64>::new().variance(), None);
+    }
+}
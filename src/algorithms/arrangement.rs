@@ -0,0 +1,259 @@
+use num::Float;
+use core::cmp::Ordering;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::line::Line2D;
+use super::dcel::Dcel;
+use super::noding::{node_segments, snap_tolerance};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Where a query point lies in an [`Arrangement2D`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointLocation {
+    /// On the vertex with this index.
+    Vertex(usize),
+    /// On the interior of the edge of this half-edge (either twin).
+    Edge(usize),
+    /// Strictly inside the face with this index.
+    Face(usize),
+}
+
+/// Planar subdivision induced by a set of segments, with point location.
+///
+/// Inserted segments are split wherever they touch or cross each other and the
+/// pieces are linked into a [`Dcel`]. Point location then uses a slab
+/// decomposition: the vertex x-coordinates cut the plane into vertical slabs, each
+/// holding the edges that span it sorted bottom to top, so a query is two binary
+/// searches. The face containing a point is the face below the first edge above it.
+///
+/// Every insertion rebuilds the subdivision; add segments in batches with
+/// [`from_segments`](Self::from_segments) or [`Extend`] where possible.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::arrangement::{Arrangement2D, PointLocation};
+/// use geomengine::algorithms::dcel::Dcel;
+/// use geomengine::line::Line2D;
+/// use geomengine::point::Point2D;
+///
+/// // three strokes of a '#', then the fourth closing its middle cell
+/// let mut arrangement = Arrangement2D::from_segments(&[
+///     Line2D::new(Point2D::new(1.0, 0.0), Point2D::new(1.0, 3.0)),
+///     Line2D::new(Point2D::new(2.0, 0.0), Point2D::new(2.0, 3.0)),
+///     Line2D::new(Point2D::new(0.0, 1.0), Point2D::new(3.0, 1.0)),
+/// ]);
+/// assert_eq!(arrangement.dcel().face_count(), 1);
+///
+/// arrangement.insert(Line2D::new(Point2D::new(0.0, 2.0), Point2D::new(3.0, 2.0)));
+/// assert_eq!(arrangement.dcel().face_count(), 2);
+///
+/// let PointLocation::Face(middle) = arrangement.locate(&Point2D::new(1.5, 1.5)) else { panic!() };
+/// assert_ne!(middle, Dcel::<f64>::UNBOUNDED_FACE);
+/// assert_eq!(arrangement.locate(&Point2D::new(0.5, 0.5)), PointLocation::Face(Dcel::<f64>::UNBOUNDED_FACE));
+/// assert!(matches!(arrangement.locate(&Point2D::new(1.0, 1.0)), PointLocation::Vertex(_)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Arrangement2D<T: Float + Debug> {
+    segments: Vec<Line2D<T>>,
+    dcel: Dcel<T>,
+    tolerance: T,
+    /// Vertex indices sorted by x.
+    by_x: Vec<usize>,
+    /// Distinct vertex x-coordinates, the slab boundaries.
+    slab_xs: Vec<T>,
+    /// Per slab, the leftward half-edges spanning it from bottom to top.
+    slabs: Vec<Vec<usize>>,
+    /// Per slab boundary, the vertical half-edges lying on it.
+    verticals: Vec<Vec<usize>>,
+}
+
+impl<T: Float + Debug> Default for Arrangement2D<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Float + Debug> Arrangement2D<T> {
+    pub fn new() -> Self {
+        Self::from_segments(&[])
+    }
+
+    /// Builds the arrangement of `segments`, which may cross each other.
+    ///
+    /// # Time Complexity
+    /// * O(n log n + k) to split the segments at their k intersections, plus
+    ///   O(n²) time and space in the worst case for the slabs.
+    pub fn from_segments(segments: &[Line2D<T>]) -> Self {
+        let mut arrangement = Arrangement2D {
+            segments: segments.to_vec(),
+            dcel: Dcel::from_segments(&[]),
+            tolerance: T::zero(),
+            by_x: Vec::new(),
+            slab_xs: Vec::new(),
+            slabs: Vec::new(),
+            verticals: Vec::new(),
+        };
+        arrangement.rebuild();
+        arrangement
+    }
+
+    /// Adds a segment, splitting it and the edges it crosses at their intersections.
+    pub fn insert(&mut self, segment: Line2D<T>) {
+        self.segments.push(segment);
+        self.rebuild();
+    }
+
+    /// The inserted segments, before splitting.
+    pub fn segments(&self) -> &[Line2D<T>] {
+        &self.segments
+    }
+
+    pub fn dcel(&self) -> &Dcel<T> {
+        &self.dcel
+    }
+
+    /// Finds the vertex, edge or face containing `point`.
+    ///
+    /// Points closer than a small scale-relative tolerance to a vertex or edge are
+    /// reported on it.
+    ///
+    /// # Time Complexity
+    /// * O(log n), plus the vertices and vertical edges within the tolerance of
+    ///   the query's x-coordinate.
+    pub fn locate(&self, point: &Point2D<T>) -> PointLocation {
+        let tolerance = self.tolerance;
+        let vertices = self.dcel.vertices();
+        let first = self.by_x.partition_point(|&v| vertices[v].x < point.x - tolerance);
+        let near_x = self.by_x[first..].iter().take_while(|&&v| vertices[v].x <= point.x + tolerance);
+        if let Some(&v) = near_x.into_iter().find(|&&v| vertices[v].distance(point) <= tolerance) {
+            return PointLocation::Vertex(v);
+        }
+
+        let on_edge = |h: usize| self.dcel.segment(h).closest_point_to(point).distance(point) <= tolerance;
+        let boundary = self.slab_xs.partition_point(|&x| x < point.x - tolerance);
+        for (verticals, &x) in self.verticals[boundary..].iter().zip(&self.slab_xs[boundary..]) {
+            if x > point.x + tolerance {
+                break;
+            }
+            if let Some(&h) = verticals.iter().find(|&&h| on_edge(h)) {
+                return PointLocation::Edge(h);
+            }
+        }
+
+        let unbounded = PointLocation::Face(Dcel::<T>::UNBOUNDED_FACE);
+        let (Some(&left), Some(&right)) = (self.slab_xs.first(), self.slab_xs.last()) else {
+            return unbounded;
+        };
+        if point.x < left || point.x > right || self.slabs.is_empty() {
+            return unbounded;
+        }
+        let slab = self.slab_xs.partition_point(|&x| x <= point.x).saturating_sub(1).min(self.slabs.len() - 1);
+        let edges = &self.slabs[slab];
+        let above = edges.partition_point(|&h| y_at(&self.dcel.segment(h), point.x) <= point.y);
+        for &h in edges[above.saturating_sub(1)..].iter().take(2) {
+            if on_edge(h) {
+                return PointLocation::Edge(h);
+            }
+        }
+        // stored half-edges point left, so their face lies below them
+        edges.get(above).map_or(unbounded, |&h| PointLocation::Face(self.dcel.face(h)))
+    }
+
+    fn rebuild(&mut self) {
+        self.tolerance = snap_tolerance(self.segments.iter().flat_map(|s| [s.p1, s.p2]));
+        self.dcel = Dcel::with_tolerance(&node_segments(&self.segments, self.tolerance), self.tolerance);
+
+        let dcel = &self.dcel;
+        let vertices = dcel.vertices();
+        self.by_x = (0..vertices.len()).collect();
+        self.by_x.sort_by(|&a, &b| vertices[a].x.partial_cmp(&vertices[b].x).unwrap_or(Ordering::Equal));
+        self.slab_xs = self.by_x.iter().map(|&v| vertices[v].x).collect();
+        self.slab_xs.dedup();
+
+        let boundary_of = |x: T| self.slab_xs.partition_point(|&s| s < x);
+        self.verticals = vec![Vec::new(); self.slab_xs.len()];
+        self.slabs = vec![Vec::new(); self.slab_xs.len().saturating_sub(1)];
+        for h in (0..dcel.half_edge_count()).step_by(2) {
+            let (a, b) = (vertices[dcel.origin(h)], vertices[dcel.destination(h)]);
+            if a.x == b.x {
+                self.verticals[boundary_of(a.x)].push(h);
+                continue;
+            }
+            let leftward = if b.x < a.x { h } else { dcel.twin(h) };
+            for slab in &mut self.slabs[boundary_of(a.x.min(b.x))..boundary_of(a.x.max(b.x))] {
+                slab.push(leftward);
+            }
+        }
+
+        let two = T::one() + T::one();
+        for (slab, bounds) in self.slabs.iter_mut().zip(self.slab_xs.windows(2)) {
+            let middle = (bounds[0] + bounds[1]) / two;
+            slab.sort_by(|&g, &h| {
+                y_at(&dcel.segment(g), middle).partial_cmp(&y_at(&dcel.segment(h), middle)).unwrap_or(Ordering::Equal)
+            });
+        }
+    }
+}
+
+impl<T: Float + Debug> Extend<Line2D<T>> for Arrangement2D<T> {
+    fn extend<I: IntoIterator<Item = Line2D<T>>>(&mut self, segments: I) {
+        self.segments.extend(segments);
+        self.rebuild();
+    }
+}
+
+/// Height of the non-vertical `segment`'s supporting line at `x`.
+fn y_at<T: Float + Debug>(segment: &Line2D<T>, x: T) -> T {
+    let t = (x - segment.p1.x) / (segment.p2.x - segment.p1.x);
+    segment.p1.y + (segment.p2.y - segment.p1.y) * t
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shape::Area;
+
+    fn segment(x1: f64, y1: f64, x2: f64, y2: f64) -> Line2D<f64> {
+        Line2D::new(Point2D::new(x1, y1), Point2D::new(x2, y2))
+    }
+
+    #[test]
+    fn test_arrangement_splits_crossing_segments() {
+        // a triangle cut by a line crossing two of its sides
+        let mut arrangement = Arrangement2D::from_segments(&[
+            segment(0.0, 0.0, 4.0, 0.0),
+            segment(4.0, 0.0, 0.0, 4.0),
+            segment(0.0, 4.0, 0.0, 0.0),
+        ]);
+        arrangement.extend([segment(-1.0, 1.0, 5.0, 1.0)]);
+        let dcel = arrangement.dcel();
+        assert_eq!(dcel.vertex_count(), 7);
+        assert_eq!(dcel.face_count(), 3);
+
+        let PointLocation::Face(bottom) = arrangement.locate(&Point2D::new(1.0, 0.5)) else { panic!("not in a face") };
+        let PointLocation::Face(top) = arrangement.locate(&Point2D::new(1.0, 2.0)) else { panic!("not in a face") };
+        assert_eq!(dcel.face_polygon(bottom).unwrap().area(), 3.5);
+        assert_eq!(dcel.face_polygon(top).unwrap().area(), 4.5);
+        assert_eq!(arrangement.locate(&Point2D::new(4.0, 4.0)), PointLocation::Face(Dcel::<f64>::UNBOUNDED_FACE));
+    }
+
+    #[test]
+    fn test_locate_on_edges_and_vertices() {
+        let mut arrangement = Arrangement2D::new();
+        assert_eq!(arrangement.locate(&Point2D::new(0.0, 0.0)), PointLocation::Face(Dcel::<f64>::UNBOUNDED_FACE));
+        for s in [segment(0.0, 0.0, 2.0, 0.0), segment(2.0, 0.0, 2.0, 2.0), segment(2.0, 2.0, 0.0, 2.0), segment(0.0, 2.0, 0.0, 0.0)] {
+            arrangement.insert(s);
+        }
+        let dcel = arrangement.dcel();
+
+        let PointLocation::Edge(h) = arrangement.locate(&Point2D::new(1.0, 2.0)) else { panic!("not on an edge") };
+        assert_eq!(dcel.segment(h).p1.y, 2.0);
+        let PointLocation::Edge(h) = arrangement.locate(&Point2D::new(2.0, 0.5)) else { panic!("not on an edge") };
+        assert_eq!(dcel.segment(h).p1.x, 2.0);
+        let PointLocation::Vertex(v) = arrangement.locate(&Point2D::new(2.0, 2.0)) else { panic!("not on a vertex") };
+        assert_eq!(dcel.vertex(v), Point2D::new(2.0, 2.0));
+        assert_eq!(arrangement.locate(&Point2D::new(1e-3, 1.0)), PointLocation::Face(1));
+        assert_eq!(arrangement.locate(&Point2D::new(3.0, 1.0)), PointLocation::Face(Dcel::<f64>::UNBOUNDED_FACE));
+    }
+}
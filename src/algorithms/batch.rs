@@ -0,0 +1,468 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::shape::Aabb2D;
+use crate::transform::Angle;
+use alloc::vec::Vec;
+
+/// Number of independent partial sums used by [`batch_centroid_2d`].
+///
+/// Both the scalar and the SIMD path spread point `i` into partial sum `i % 4`
+/// and combine them as `(s0 + s1) + (s2 + s3)`, so they round identically.
+const PARTIAL_SUMS: usize = 4;
+
+/// Floating point types with a batch implementation (`f32` and `f64`).
+///
+/// With the `simd` feature enabled on `x86_64`, the batch functions run on SSE2
+/// registers; everywhere else they use a scalar loop. Both paths perform the same
+/// floating point operations in the same order and return bit-identical results.
+pub trait BatchFloat: Float + Debug + private::Sealed {
+    #[doc(hidden)]
+    fn translate(points: &mut [Point2D<Self>], dx: Self, dy: Self);
+    #[doc(hidden)]
+    fn rotate(points: &mut [Point2D<Self>], cos: Self, sin: Self, center: Point2D<Self>);
+    #[doc(hidden)]
+    fn distances(points: &[Point2D<Self>], target: Point2D<Self>, out: &mut Vec<Self>);
+    #[doc(hidden)]
+    fn bounds(points: &[Point2D<Self>]) -> Aabb2D<Self>;
+    #[doc(hidden)]
+    fn partial_sums(points: &[Point2D<Self>]) -> [Point2D<Self>; PARTIAL_SUMS];
+}
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+/// Translates every point in place by `delta`.
+pub fn translate_points_2d<T: BatchFloat>(points: &mut [Point2D<T>], delta: (T, T)) {
+    T::translate(points, delta.0, delta.1);
+}
+
+/// Rotates every point in place by `angle` around `center` (defaulting to origin).
+///
+/// Produces exactly the same coordinates as calling [`Point2D::rotate`] on each point.
+pub fn rotate_points_2d<T: BatchFloat>(points: &mut [Point2D<T>], angle: Angle<T>, center: Option<Point2D<T>>) {
+    let (sin, cos) = angle.sin_cos();
+    T::rotate(points, cos, sin, center.unwrap_or_default());
+}
+
+/// Computes the distance from every point to `target`.
+pub fn distances_to_point_2d<T: BatchFloat>(points: &[Point2D<T>], target: &Point2D<T>) -> Vec<T> {
+    let mut out = Vec::with_capacity(points.len());
+    T::distances(points, *target, &mut out);
+    out
+}
+
+/// Computes the axis aligned bounding box of a set of points.
+///
+/// Same result as [`bounding_box_2d`](super::point_algorithms::bounding_box_2d).
+pub fn batch_bounding_box_2d<T: BatchFloat>(points: &[Point2D<T>]) -> Option<Aabb2D<T>> {
+    if points.is_empty() {
+        return None;
+    }
+    Some(T::bounds(points))
+}
+
+/// Computes the centroid of a set of points.
+///
+/// Sums into four interleaved partial sums, which is both what lets the SIMD path
+/// keep several registers busy and slightly more accurate than one running sum.
+pub fn batch_centroid_2d<T: BatchFloat>(points: &[Point2D<T>]) -> Point2D<T> {
+    let [s0, s1, s2, s3] = T::partial_sums(points);
+    ((s0 + s1) + (s2 + s3)) / T::from(points.len()).unwrap()
+}
+
+mod scalar {
+    use super::*;
+
+    pub fn translate<T: Float + Debug>(points: &mut [Point2D<T>], dx: T, dy: T) {
+        for p in points {
+            p.x = p.x + dx;
+            p.y = p.y + dy;
+        }
+    }
+
+    pub fn rotate<T: Float + Debug>(points: &mut [Point2D<T>], cos: T, sin: T, center: Point2D<T>) {
+        for p in points {
+            let (dx, dy) = (p.x - center.x, p.y - center.y);
+            *p = Point2D::new(center.x + dx * cos - dy * sin, center.y + dx * sin + dy * cos);
+        }
+    }
+
+    pub fn distances<T: Float + Debug>(points: &[Point2D<T>], target: Point2D<T>, out: &mut Vec<T>) {
+        out.extend(points.iter().map(|p| p.distance(&target)));
+    }
+
+    pub fn bounds<T: Float + Debug>(points: &[Point2D<T>]) -> Aabb2D<T> {
+        let mut min = Point2D::new(T::max_value(), T::max_value());
+        let mut max = Point2D::new(T::min_value(), T::min_value());
+        for p in points {
+            if p.x < min.x { min.x = p.x; }
+            if p.y < min.y { min.y = p.y; }
+            if p.x > max.x { max.x = p.x; }
+            if p.y > max.y { max.y = p.y; }
+        }
+        Aabb2D::new(min, max)
+    }
+
+    #[cfg_attr(all(feature = "simd", target_arch = "x86_64"), allow(dead_code))]
+    pub fn partial_sums<T: Float + Debug>(points: &[Point2D<T>]) -> [Point2D<T>; PARTIAL_SUMS] {
+        let mut sums = [Point2D::origin(); PARTIAL_SUMS];
+        for (i, &p) in points.iter().enumerate() {
+            sums[i % PARTIAL_SUMS] = sums[i % PARTIAL_SUMS] + p;
+        }
+        sums
+    }
+}
+
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+macro_rules! impl_batch_float_scalar {
+    ($t:ty) => {
+        impl BatchFloat for $t {
+            fn translate(points: &mut [Point2D<Self>], dx: Self, dy: Self) {
+                scalar::translate(points, dx, dy)
+            }
+            fn rotate(points: &mut [Point2D<Self>], cos: Self, sin: Self, center: Point2D<Self>) {
+                scalar::rotate(points, cos, sin, center)
+            }
+            fn distances(points: &[Point2D<Self>], target: Point2D<Self>, out: &mut Vec<Self>) {
+                scalar::distances(points, target, out)
+            }
+            fn bounds(points: &[Point2D<Self>]) -> Aabb2D<Self> {
+                scalar::bounds(points)
+            }
+            fn partial_sums(points: &[Point2D<Self>]) -> [Point2D<Self>; PARTIAL_SUMS] {
+                scalar::partial_sums(points)
+            }
+        }
+    };
+}
+
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+impl_batch_float_scalar!(f32);
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+impl_batch_float_scalar!(f64);
+
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod sse2 {
+    // SSE2 is part of the x86_64 baseline, so no runtime feature detection is needed.
+    // `Point2D` is `repr(C)`: one `Point2D<f64>` fills an `__m128d`, two
+    // `Point2D<f32>` fill an `__m128`.
+    use super::*;
+    use core::arch::x86_64::*;
+
+    macro_rules! impl_batch_float_sse2 {
+        ($t:ty, $translate:ident, $rotate:ident, $distances:ident, $bounds:ident, $partial_sums:ident) => {
+            // SAFETY (all calls): SSE2 is enabled on every x86_64 target.
+            impl BatchFloat for $t {
+                fn translate(points: &mut [Point2D<Self>], dx: Self, dy: Self) {
+                    unsafe { $translate(points, dx, dy) }
+                }
+                fn rotate(points: &mut [Point2D<Self>], cos: Self, sin: Self, center: Point2D<Self>) {
+                    unsafe { $rotate(points, cos, sin, center) }
+                }
+                fn distances(points: &[Point2D<Self>], target: Point2D<Self>, out: &mut Vec<Self>) {
+                    unsafe { $distances(points, target, out) }
+                }
+                fn bounds(points: &[Point2D<Self>]) -> Aabb2D<Self> {
+                    unsafe { $bounds(points) }
+                }
+                fn partial_sums(points: &[Point2D<Self>]) -> [Point2D<Self>; PARTIAL_SUMS] {
+                    unsafe { $partial_sums(points) }
+                }
+            }
+        };
+    }
+
+    impl_batch_float_sse2!(f64, translate_f64, rotate_f64, distances_f64, bounds_f64, partial_sums_f64);
+    impl_batch_float_sse2!(f32, translate_f32, rotate_f32, distances_f32, bounds_f32, partial_sums_f32);
+
+    #[target_feature(enable = "sse2")]
+    fn translate_f64(points: &mut [Point2D<f64>], dx: f64, dy: f64) {
+        let delta = _mm_set_pd(dy, dx);
+        for p in points.iter_mut() {
+            let ptr = p as *mut Point2D<f64> as *mut f64;
+            // SAFETY: `ptr` points to two consecutive, initialized f64 values.
+            unsafe { _mm_storeu_pd(ptr, _mm_add_pd(_mm_loadu_pd(ptr), delta)) };
+        }
+    }
+
+    #[target_feature(enable = "sse2")]
+    fn rotate_f64(points: &mut [Point2D<f64>], cos: f64, sin: f64, center: Point2D<f64>) {
+        // (cx + dx·cos) − dy·sin and (cy + dx·sin) + dy·cos, in the scalar order
+        let c = _mm_set_pd(center.y, center.x);
+        let by_dx = _mm_set_pd(sin, cos);
+        let by_dy = _mm_set_pd(cos, -sin);
+        for p in points.iter_mut() {
+            let ptr = p as *mut Point2D<f64> as *mut f64;
+            // SAFETY: as in `translate`.
+            let d = _mm_sub_pd(unsafe { _mm_loadu_pd(ptr) }, c);
+            let (dx, dy) = (_mm_unpacklo_pd(d, d), _mm_unpackhi_pd(d, d));
+            let rotated = _mm_add_pd(_mm_add_pd(c, _mm_mul_pd(dx, by_dx)), _mm_mul_pd(dy, by_dy));
+            unsafe { _mm_storeu_pd(ptr, rotated) };
+        }
+    }
+
+    #[target_feature(enable = "sse2")]
+    fn distances_f64(points: &[Point2D<f64>], target: Point2D<f64>, out: &mut Vec<f64>) {
+        let t = _mm_set_pd(target.y, target.x);
+        let mut pairs = points.chunks_exact(2);
+        for pair in &mut pairs {
+            let ptr = pair.as_ptr() as *const f64;
+            // SAFETY: the chunk holds two points, i.e. four consecutive f64 values.
+            let (a, b) = unsafe { (_mm_loadu_pd(ptr), _mm_loadu_pd(ptr.add(2))) };
+            let (a, b) = (_mm_sub_pd(a, t), _mm_sub_pd(b, t));
+            let (a, b) = (_mm_mul_pd(a, a), _mm_mul_pd(b, b));
+            let sum = _mm_add_pd(_mm_unpacklo_pd(a, b), _mm_unpackhi_pd(a, b));
+            let mut lanes = [0.0; 2];
+            unsafe { _mm_storeu_pd(lanes.as_mut_ptr(), _mm_sqrt_pd(sum)) };
+            out.extend_from_slice(&lanes);
+        }
+        scalar::distances(pairs.remainder(), target, out);
+    }
+
+    #[target_feature(enable = "sse2")]
+    fn bounds_f64(points: &[Point2D<f64>]) -> Aabb2D<f64> {
+        // the accumulator is the second operand: `min`/`max` return it when the
+        // point is NaN, which skips NaN coordinates like the scalar comparisons
+        let mut min = _mm_set1_pd(f64::MAX);
+        let mut max = _mm_set1_pd(f64::MIN);
+        for p in points {
+            // SAFETY: one point is two consecutive f64 values.
+            let v = unsafe { _mm_loadu_pd(p as *const Point2D<f64> as *const f64) };
+            min = _mm_min_pd(v, min);
+            max = _mm_max_pd(v, max);
+        }
+        let (mut lo, mut hi) = ([0.0; 2], [0.0; 2]);
+        unsafe {
+            _mm_storeu_pd(lo.as_mut_ptr(), min);
+            _mm_storeu_pd(hi.as_mut_ptr(), max);
+        }
+        Aabb2D::new(Point2D::new(lo[0], lo[1]), Point2D::new(hi[0], hi[1]))
+    }
+
+    #[target_feature(enable = "sse2")]
+    fn partial_sums_f64(points: &[Point2D<f64>]) -> [Point2D<f64>; PARTIAL_SUMS] {
+        let mut acc = [_mm_setzero_pd(); PARTIAL_SUMS];
+        let mut quads = points.chunks_exact(PARTIAL_SUMS);
+        for quad in &mut quads {
+            let ptr = quad.as_ptr() as *const f64;
+            for (k, sum) in acc.iter_mut().enumerate() {
+                // SAFETY: the chunk holds four points, i.e. eight consecutive f64 values.
+                *sum = _mm_add_pd(*sum, unsafe { _mm_loadu_pd(ptr.add(2 * k)) });
+            }
+        }
+        let mut sums = acc.map(|sum| {
+            let mut lanes = [0.0; 2];
+            unsafe { _mm_storeu_pd(lanes.as_mut_ptr(), sum) };
+            Point2D::new(lanes[0], lanes[1])
+        });
+        for (k, &p) in quads.remainder().iter().enumerate() {
+            sums[k] = sums[k] + p;
+        }
+        sums
+    }
+
+    #[target_feature(enable = "sse2")]
+    fn translate_f32(points: &mut [Point2D<f32>], dx: f32, dy: f32) {
+        let delta = _mm_set_ps(dy, dx, dy, dx);
+        let mut pairs = points.chunks_exact_mut(2);
+        for pair in &mut pairs {
+            let ptr = pair.as_mut_ptr() as *mut f32;
+            // SAFETY: the chunk holds two points, i.e. four consecutive f32 values.
+            unsafe { _mm_storeu_ps(ptr, _mm_add_ps(_mm_loadu_ps(ptr), delta)) };
+        }
+        scalar::translate(pairs.into_remainder(), dx, dy);
+    }
+
+    #[target_feature(enable = "sse2")]
+    fn rotate_f32(points: &mut [Point2D<f32>], cos: f32, sin: f32, center: Point2D<f32>) {
+        let c = _mm_set_ps(center.y, center.x, center.y, center.x);
+        let by_dx = _mm_set_ps(sin, cos, sin, cos);
+        let by_dy = _mm_set_ps(cos, -sin, cos, -sin);
+        let mut pairs = points.chunks_exact_mut(2);
+        for pair in &mut pairs {
+            let ptr = pair.as_mut_ptr() as *mut f32;
+            // SAFETY: as in `translate`.
+            let d = _mm_sub_ps(unsafe { _mm_loadu_ps(ptr) }, c);
+            let dx = _mm_shuffle_ps::<0b10_10_00_00>(d, d);
+            let dy = _mm_shuffle_ps::<0b11_11_01_01>(d, d);
+            let rotated = _mm_add_ps(_mm_add_ps(c, _mm_mul_ps(dx, by_dx)), _mm_mul_ps(dy, by_dy));
+            unsafe { _mm_storeu_ps(ptr, rotated) };
+        }
+        scalar::rotate(pairs.into_remainder(), cos, sin, center);
+    }
+
+    #[target_feature(enable = "sse2")]
+    fn distances_f32(points: &[Point2D<f32>], target: Point2D<f32>, out: &mut Vec<f32>) {
+        let t = _mm_set_ps(target.y, target.x, target.y, target.x);
+        let mut quads = points.chunks_exact(4);
+        for quad in &mut quads {
+            let ptr = quad.as_ptr() as *const f32;
+            // SAFETY: the chunk holds four points, i.e. eight consecutive f32 values.
+            let (a, b) = unsafe { (_mm_loadu_ps(ptr), _mm_loadu_ps(ptr.add(4))) };
+            let (a, b) = (_mm_sub_ps(a, t), _mm_sub_ps(b, t));
+            let (a, b) = (_mm_mul_ps(a, a), _mm_mul_ps(b, b));
+            // gather the x² and y² lanes of the four points and add them pairwise
+            let xs = _mm_shuffle_ps::<0b10_00_10_00>(a, b);
+            let ys = _mm_shuffle_ps::<0b11_01_11_01>(a, b);
+            let mut lanes = [0.0; 4];
+            unsafe { _mm_storeu_ps(lanes.as_mut_ptr(), _mm_sqrt_ps(_mm_add_ps(xs, ys))) };
+            out.extend_from_slice(&lanes);
+        }
+        scalar::distances(quads.remainder(), target, out);
+    }
+
+    #[target_feature(enable = "sse2")]
+    fn bounds_f32(points: &[Point2D<f32>]) -> Aabb2D<f32> {
+        let mut min = _mm_set1_ps(f32::MAX);
+        let mut max = _mm_set1_ps(f32::MIN);
+        let mut pairs = points.chunks_exact(2);
+        for pair in &mut pairs {
+            // SAFETY: the chunk holds two points, i.e. four consecutive f32 values.
+            let v = unsafe { _mm_loadu_ps(pair.as_ptr() as *const f32) };
+            min = _mm_min_ps(v, min);
+            max = _mm_max_ps(v, max);
+        }
+        let (mut lo, mut hi) = ([0.0; 4], [0.0; 4]);
+        unsafe {
+            _mm_storeu_ps(lo.as_mut_ptr(), min);
+            _mm_storeu_ps(hi.as_mut_ptr(), max);
+        }
+        let tail = scalar::bounds(pairs.remainder());
+        let min = scalar::bounds(&[Point2D::new(lo[0], lo[1]), Point2D::new(lo[2], lo[3]), tail.min]).min;
+        let max = scalar::bounds(&[Point2D::new(hi[0], hi[1]), Point2D::new(hi[2], hi[3]), tail.max]).max;
+        Aabb2D::new(min, max)
+    }
+
+    #[target_feature(enable = "sse2")]
+    fn partial_sums_f32(points: &[Point2D<f32>]) -> [Point2D<f32>; PARTIAL_SUMS] {
+        // lanes hold (x, y) of partial sums 0 and 1, and of 2 and 3
+        let mut low = _mm_setzero_ps();
+        let mut high = _mm_setzero_ps();
+        let mut quads = points.chunks_exact(PARTIAL_SUMS);
+        for quad in &mut quads {
+            let ptr = quad.as_ptr() as *const f32;
+            // SAFETY: the chunk holds four points, i.e. eight consecutive f32 values.
+            unsafe {
+                low = _mm_add_ps(low, _mm_loadu_ps(ptr));
+                high = _mm_add_ps(high, _mm_loadu_ps(ptr.add(4)));
+            }
+        }
+        let (mut l, mut h) = ([0.0; 4], [0.0; 4]);
+        unsafe {
+            _mm_storeu_ps(l.as_mut_ptr(), low);
+            _mm_storeu_ps(h.as_mut_ptr(), high);
+        }
+        let mut sums = [
+            Point2D::new(l[0], l[1]),
+            Point2D::new(l[2], l[3]),
+            Point2D::new(h[0], h[1]),
+            Point2D::new(h[2], h[3]),
+        ];
+        for (k, &p) in quads.remainder().iter().enumerate() {
+            sums[k] = sums[k] + p;
+        }
+        sums
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::point_algorithms::{bounding_box_2d, centroid_2d};
+
+    fn cloud(n: usize) -> Vec<Point2D<f64>> {
+        (0..n)
+            .map(|i| {
+                let t = i as f64;
+                Point2D::new((t * 0.37).sin() * 100.0 + t, (t * 1.3).cos() * 50.0 - t * 0.5)
+            })
+            .collect()
+    }
+
+    fn cloud_f32(n: usize) -> Vec<Point2D<f32>> {
+        cloud(n).into_iter().map(|p| Point2D::new(p.x as f32, p.y as f32)).collect()
+    }
+
+    #[test]
+    fn test_translate_points_2d_matches_point2d() {
+        for n in [0, 1, 2, 3, 7] {
+            let mut points = cloud(n);
+            let expected: Vec<_> = points.iter().map(|&p| p + Point2D::new(1.5, -2.25)).collect();
+            translate_points_2d(&mut points, (1.5, -2.25));
+            assert_eq!(points, expected);
+
+            let mut points = cloud_f32(n);
+            let expected: Vec<_> = points.iter().map(|&p| p + Point2D::new(1.5, -2.25)).collect();
+            translate_points_2d(&mut points, (1.5, -2.25));
+            assert_eq!(points, expected);
+        }
+    }
+
+    #[test]
+    fn test_rotate_points_2d_matches_point2d() {
+        let center = Point2D::new(3.0, -1.0);
+        for n in [0, 1, 2, 5, 8] {
+            let mut points = cloud(n);
+            let expected: Vec<_> = points.iter().map(|p| p.rotate(Angle::from_degrees(33.0), Some(center))).collect();
+            rotate_points_2d(&mut points, Angle::from_degrees(33.0), Some(center));
+            assert_eq!(points, expected);
+
+            let center = Point2D::new(3.0f32, -1.0);
+            let mut points = cloud_f32(n);
+            let expected: Vec<_> = points.iter().map(|p| p.rotate(Angle::from_degrees(33.0), Some(center))).collect();
+            rotate_points_2d(&mut points, Angle::from_degrees(33.0), Some(center));
+            assert_eq!(points, expected);
+        }
+    }
+
+    #[test]
+    fn test_distances_to_point_2d_matches_point2d() {
+        for n in [0, 1, 4, 9] {
+            let points = cloud(n);
+            let target = Point2D::new(-4.0, 2.0);
+            let expected: Vec<_> = points.iter().map(|p| p.distance(&target)).collect();
+            assert_eq!(distances_to_point_2d(&points, &target), expected);
+
+            let points = cloud_f32(n);
+            let target = Point2D::new(-4.0f32, 2.0);
+            let expected: Vec<_> = points.iter().map(|p| p.distance(&target)).collect();
+            assert_eq!(distances_to_point_2d(&points, &target), expected);
+        }
+    }
+
+    #[test]
+    fn test_batch_bounding_box_2d_matches_scalar() {
+        assert_eq!(batch_bounding_box_2d::<f64>(&[]), None);
+        for n in [1, 2, 3, 10] {
+            assert_eq!(batch_bounding_box_2d(&cloud(n)), bounding_box_2d(&cloud(n)));
+            assert_eq!(batch_bounding_box_2d(&cloud_f32(n)), bounding_box_2d(&cloud_f32(n)));
+        }
+    }
+
+    #[test]
+    fn test_batch_bounding_box_2d_skips_nan() {
+        let points = vec![Point2D::new(1.0, f64::NAN), Point2D::new(f64::NAN, 2.0), Point2D::new(3.0, -1.0)];
+        assert_eq!(
+            batch_bounding_box_2d(&points),
+            Some(Aabb2D::new(Point2D::new(1.0, -1.0), Point2D::new(3.0, 2.0)))
+        );
+    }
+
+    #[test]
+    fn test_batch_centroid_2d() {
+        for n in [1, 3, 4, 11] {
+            let points = cloud(n);
+            assert_eq!(<f64 as BatchFloat>::partial_sums(&points), scalar::partial_sums(&points));
+            let centroid = batch_centroid_2d(&points);
+            let expected = centroid_2d(&points);
+            assert!((centroid.x - expected.x).abs() < 1e-9 && (centroid.y - expected.y).abs() < 1e-9);
+
+            let points = cloud_f32(n);
+            assert_eq!(<f32 as BatchFloat>::partial_sums(&points), scalar::partial_sums(&points));
+        }
+    }
+}
@@ -0,0 +1,234 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::{Point2D, Vector2D};
+use crate::line::Polyline2D;
+use crate::polygon::{Polygon2D, PolygonWithHoles};
+use crate::shape::Geometry2D;
+use super::overlay::{OverlayMode, overlay_2d};
+use super::stroke::{LineCap, LineJoin, StrokeStyle, stroke_2d};
+use super::union::union_all_2d;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Vertices of the polygons standing in for circles, one per 22.5° as in the arcs of [`stroke_2d`].
+const CIRCLE_SEGMENTS: usize = 16;
+
+/// The area within `distance` of a geometry, or for a negative `distance`, the
+/// part of its area at least `-distance` away from its outline.
+///
+/// # Arguments
+/// * `geometry` - The shape to buffer. Polygons and circles are areas; points,
+///   lines and polylines have none, so a negative `distance` erases them. For
+///   polygons with holes see [`buffer_polygons_2d`].
+/// * `distance` - How far to grow, or with a negative sign, shrink.
+///
+/// # Returns
+/// * One `(exterior, holes)` pair per connected area, exteriors counterclockwise
+///   and holes clockwise, as [`union_all_2d`] returns them. Shrinking returns
+///   one pair per piece of each polygon that is left, so overlapping members of
+///   a `MultiPolygon` give overlapping pieces.
+///
+/// # Algorithm Explanation
+/// Every outline is stroked `2 * |distance|` wide with round caps and joins by
+/// [`stroke_2d`], and points become dots. Growing unions the stroke triangles
+/// with the areas; shrinking overlays every area with the strokes along its own
+/// rings and keeps what no triangle covers. Round parts are polygons inscribed
+/// in the exact arcs, so the buffer lies slightly inside the true one there.
+///
+/// # Time Complexity
+/// **O(n²)** in the number of output vertices, dominated by noding.
+///
+/// # Panics
+/// If `distance` is NaN or infinite.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::buffer::buffer_2d;
+/// use geomengine::line::Line2D;
+/// use geomengine::point::Point2D;
+/// use geomengine::shape::{Area, Geometry2D};
+///
+/// let road = Geometry2D::Line(Line2D::new(Point2D::new(0.0, 0.0), Point2D::new(10.0, 0.0)));
+/// let buffered = buffer_2d(&road, 1.0);
+/// assert_eq!(buffered.len(), 1);
+/// // a 10 x 2 rectangle with two half disks at the ends
+/// let area: f64 = buffered[0].0.area();
+/// assert!(area > 20.0 + 3.0 && area < 20.0 + std::f64::consts::PI);
+/// ```
+pub fn buffer_2d<T: Float + Debug>(geometry: &Geometry2D<T>, distance: T) -> Vec<(Polygon2D<T>, Vec<Polygon2D<T>>)> {
+    let mut areas = Vec::new();
+    let mut outlines = Vec::new();
+    match geometry {
+        Geometry2D::Point(point) => outlines.push(Polyline2D::new(vec![*point])),
+        Geometry2D::Line(line) => outlines.push(Polyline2D::new(vec![line.p1, line.p2])),
+        Geometry2D::Polyline(polyline) => outlines.push(polyline.clone()),
+        Geometry2D::Polygon(polygon) => areas.push(PolygonWithHoles::from(polygon.clone())),
+        Geometry2D::Circle(circle) => areas.push(PolygonWithHoles::from(regular_polygon(circle.center, circle.radius))),
+        Geometry2D::MultiPoint(points) => outlines.extend(points.iter().map(|&point| Polyline2D::new(vec![point]))),
+        Geometry2D::MultiPolyline(polylines) => outlines.extend(polylines.iter().cloned()),
+        Geometry2D::MultiPolygon(polygons) => areas.extend(polygons.iter().cloned().map(PolygonWithHoles::from)),
+    }
+    buffer(&areas, &outlines, distance)
+}
+
+/// [`buffer_2d`] for polygons with holes: growing narrows and may close the
+/// holes, and unions the polygons; shrinking widens the holes.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::buffer::buffer_polygons_2d;
+/// use geomengine::point::Point2D;
+/// use geomengine::polygon::{Polygon2D, PolygonWithHoles};
+///
+/// let square = |min: f64, size: f64| Polygon2D::new(vec![Point2D::new(min, min), Point2D::new(min + size, min), Point2D::new(min + size, min + size), Point2D::new(min, min + size)]);
+/// let frame = PolygonWithHoles::new(square(0.0, 10.0), vec![square(3.0, 4.0)]);
+/// assert_eq!(buffer_polygons_2d(&[frame.clone()], 1.0)[0].1.len(), 1);
+/// assert!(buffer_polygons_2d(&[frame], 2.5)[0].1.is_empty());
+/// ```
+pub fn buffer_polygons_2d<T: Float + Debug>(polygons: &[PolygonWithHoles<T>], distance: T) -> Vec<(Polygon2D<T>, Vec<Polygon2D<T>>)> {
+    buffer(polygons, &[], distance)
+}
+
+fn buffer<T: Float + Debug>(areas: &[PolygonWithHoles<T>], outlines: &[Polyline2D<T>], distance: T) -> Vec<(Polygon2D<T>, Vec<Polygon2D<T>>)> {
+    assert!(distance.is_finite(), "buffer distance must be finite");
+    let mut style = StrokeStyle::new(distance.abs() * (T::one() + T::one()));
+    style.cap = LineCap::Round;
+    style.join = LineJoin::Round;
+
+    if distance >= T::zero() {
+        // union cannot take holes, so polygons with holes go in as triangles
+        let mut pieces: Vec<Polygon2D<T>> = areas
+            .iter()
+            .flat_map(|area| match area.holes() {
+                [] => vec![area.exterior().clone()],
+                _ => area.triangulate().into_iter().map(Polygon2D::from).collect(),
+            })
+            .collect();
+        pieces.extend(stroke(outlines.iter().cloned().chain(areas.iter().flat_map(closed_rings)), &style));
+        return union_all_2d(&pieces);
+    }
+    // every area shrinks on its own: what is left of the exterior outside its
+    // holes and the strokes along its rings
+    areas
+        .iter()
+        .flat_map(|area| {
+            let mut cut = area.holes().to_vec();
+            cut.extend(stroke(closed_rings(area), &style));
+            overlay_2d(core::slice::from_ref(area.exterior()), &cut, OverlayMode::Identity)
+        })
+        .filter(|piece| piece.b.is_none())
+        .map(|piece| (piece.exterior, piece.holes))
+        .collect()
+}
+
+/// The triangles of the strokes along `outlines`.
+fn stroke<T: Float + Debug>(outlines: impl Iterator<Item = Polyline2D<T>>, style: &StrokeStyle<T>) -> Vec<Polygon2D<T>> {
+    outlines
+        .flat_map(|outline| {
+            let (vertices, triangles) = stroke_2d(&outline, style);
+            triangles.into_iter().map(move |[a, b, c]| Polygon2D::new(vec![vertices[a], vertices[b], vertices[c]]))
+        })
+        .collect()
+}
+
+/// The rings of `polygon` as polylines ending where they start.
+fn closed_rings<T: Float + Debug>(polygon: &PolygonWithHoles<T>) -> impl Iterator<Item = Polyline2D<T>> + '_ {
+    polygon.rings().map(|ring| {
+        let mut vertices = ring.vertices().to_vec();
+        vertices.extend(vertices.first().copied());
+        Polyline2D::new(vertices)
+    })
+}
+
+/// Counterclockwise polygon with [`CIRCLE_SEGMENTS`] vertices on the circle around `center`.
+fn regular_polygon<T: Float + Debug>(center: Point2D<T>, radius: T) -> Polygon2D<T> {
+    let step = T::from(core::f64::consts::TAU / CIRCLE_SEGMENTS as f64).unwrap();
+    Polygon2D::new(
+        (0..CIRCLE_SEGMENTS)
+            .map(|i| {
+                let (sin, cos) = (step * T::from(i).unwrap()).sin_cos();
+                center + Vector2D::new(cos, sin) * radius
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::line::Line2D;
+    use crate::shape::{Area, Circle2D, Contains};
+
+    fn square(size: f64) -> Polygon2D<f64> {
+        Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(size, 0.0), Point2D::new(size, size), Point2D::new(0.0, size)])
+    }
+
+    #[test]
+    fn test_buffer_2d_grows_polygons_with_round_corners() {
+        let buffered = buffer_2d(&Geometry2D::Polygon(square(4.0)), 1.0);
+        assert_eq!(buffered.len(), 1);
+        let (exterior, holes) = &buffered[0];
+        assert!(holes.is_empty());
+        // the square, four 4 x 1 sides and four quarter disks
+        let area = exterior.area();
+        assert!(area > 32.0 + 3.0 && area < 32.0 + core::f64::consts::PI, "{area}");
+        assert!(exterior.contains(&Point2D::new(-0.9, 2.0)));
+        assert!(!exterior.contains(&Point2D::new(-0.9, -0.9)));
+    }
+
+    #[test]
+    fn test_buffer_2d_shrinks_areas_and_erases_lines() {
+        let shrunk = buffer_2d(&Geometry2D::Polygon(square(4.0)), -1.0);
+        assert_eq!(shrunk.len(), 1);
+        assert!((shrunk[0].0.area() - 4.0).abs() < 1e-9);
+        assert!(shrunk[0].1.is_empty());
+
+        assert!(buffer_2d(&Geometry2D::Polygon(square(4.0)), -2.5).is_empty());
+        let line = Geometry2D::Line(Line2D::new(Point2D::new(0.0, 0.0), Point2D::new(4.0, 0.0)));
+        assert!(buffer_2d(&line, -1.0).is_empty());
+        assert!(buffer_2d(&line, 0.0).is_empty());
+    }
+
+    #[test]
+    fn test_buffer_2d_points_and_rings() {
+        let points = Geometry2D::MultiPoint(vec![Point2D::new(0.0, 0.0), Point2D::new(10.0, 0.0)]);
+        let dots = buffer_2d(&points, 1.0);
+        assert_eq!(dots.len(), 2);
+        let circle = regular_polygon(Point2D::new(0.0, 0.0), 1.0).area();
+        for (exterior, _) in &dots {
+            assert!((exterior.area() - circle).abs() < 1e-9);
+        }
+
+        // a closed path leaves a hole in the middle
+        let ring = Polyline2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(4.0, 0.0), Point2D::new(4.0, 4.0), Point2D::new(0.0, 4.0), Point2D::new(0.0, 0.0)]);
+        let buffered = buffer_2d(&Geometry2D::Polyline(ring), 1.0);
+        assert_eq!(buffered.len(), 1);
+        assert_eq!(buffered[0].1.len(), 1);
+        assert!((buffered[0].1[0].area() - 4.0).abs() < 1e-9);
+
+        let disk = buffer_2d(&Geometry2D::Circle(Circle2D::new(Point2D::new(0.0, 0.0), 2.0)), -1.0);
+        assert_eq!(disk.len(), 1);
+        assert!(disk[0].0.contains(&Point2D::new(0.5, 0.5)));
+        assert!(!disk[0].0.contains(&Point2D::new(1.2, 0.0)));
+    }
+
+    #[test]
+    fn test_buffer_polygons_2d_narrows_and_widens_holes() {
+        let hole = Polygon2D::new(square(4.0).vertices().iter().map(|&p| p + Vector2D::new(3.0, 3.0)).collect());
+        let frame = [PolygonWithHoles::new(square(10.0), vec![hole])];
+
+        let grown = buffer_polygons_2d(&frame, 1.0);
+        assert_eq!(grown.len(), 1);
+        assert_eq!(grown[0].1.len(), 1);
+        // the hole shrinks to 2 x 2, its corners stay square
+        assert!((grown[0].1[0].area() - 4.0).abs() < 1e-9);
+        assert!(buffer_polygons_2d(&frame, 2.5)[0].1.is_empty());
+
+        let shrunk = buffer_polygons_2d(&frame, -1.0);
+        assert_eq!(shrunk.len(), 1);
+        // 8 x 8 outside, the hole grows to 6 x 6 with rounded corners
+        let area = shrunk[0].0.area() - shrunk[0].1.iter().map(|h| h.area()).sum::<f64>();
+        assert!(area > 64.0 - 32.0 - core::f64::consts::PI && area < 64.0 - 32.0 - 3.0, "{area}");
+        assert!(buffer_polygons_2d(&frame, -2.2).is_empty());
+    }
+}
@@ -0,0 +1,277 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::line::{Line2D, Polyline2D};
+use crate::polygon::Polygon2D;
+use crate::shape::{Aabb2D, Distance};
+use super::noding::snap_tolerance;
+use alloc::vec::Vec;
+
+/// Which side of the clipping polygon [`clip_polyline_to_polygon`] keeps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClipSide {
+    /// The parts inside the polygon, including parts running along its boundary.
+    Inside,
+    /// The parts outside the polygon, the complement of [`ClipSide::Inside`].
+    Outside,
+}
+
+/// The part of `segment` inside `aabb` (boundary included).
+///
+/// # Returns
+/// * The clipped segment, running in the same direction, with endpoints inside
+///   the box kept exactly; or `None` if the segment misses the box. A segment
+///   only touching the box at one point gives a zero-length segment.
+///
+/// # Algorithm Explanation
+/// Liang–Barsky: with the segment written as `p1 + t (p2 - p1)` for `t` in
+/// `[0, 1]`, each of the four sides bounds `t` from one side, entering or
+/// leaving depending on the direction. The clipped range is the largest entry
+/// to the smallest exit, empty if they cross.
+///
+/// # Time Complexity
+/// **O(1)**.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::clip::clip_segment_to_aabb;
+/// use geomengine::line::Line2D;
+/// use geomengine::point::Point2D;
+/// use geomengine::shape::Aabb2D;
+///
+/// let viewport = Aabb2D::new(Point2D::new(0.0, 0.0), Point2D::new(10.0, 10.0));
+/// // grazing the top left corner
+/// let clipped = clip_segment_to_aabb(&Line2D::new(Point2D::new(-5.0, 5.0), Point2D::new(5.0, 15.0)), &viewport);
+/// assert_eq!(clipped, Some(Line2D::new(Point2D::new(0.0, 10.0), Point2D::new(0.0, 10.0))));
+/// let clipped = clip_segment_to_aabb(&Line2D::new(Point2D::new(5.0, 5.0), Point2D::new(15.0, 10.0)), &viewport);
+/// assert_eq!(clipped, Some(Line2D::new(Point2D::new(5.0, 5.0), Point2D::new(10.0, 7.5))));
+/// assert_eq!(clip_segment_to_aabb(&Line2D::new(Point2D::new(11.0, 0.0), Point2D::new(20.0, 5.0)), &viewport), None);
+/// ```
+pub fn clip_segment_to_aabb<T: Float + Debug>(segment: &Line2D<T>, aabb: &Aabb2D<T>) -> Option<Line2D<T>> {
+    let (start, end) = (segment.p1, segment.p2);
+    let direction = end - start;
+    let (mut enter, mut exit) = (T::zero(), T::one());
+    for (p, q) in [
+        (-direction.x, start.x - aabb.min.x),
+        (direction.x, aabb.max.x - start.x),
+        (-direction.y, start.y - aabb.min.y),
+        (direction.y, aabb.max.y - start.y),
+    ] {
+        if p == T::zero() {
+            // parallel to this side: entirely outside it or no bound at all
+            if q < T::zero() {
+                return None;
+            }
+        } else if p < T::zero() {
+            enter = enter.max(q / p);
+        } else {
+            exit = exit.min(q / p);
+        }
+    }
+    if enter > exit || enter.is_nan() || exit.is_nan() {
+        return None;
+    }
+    let at = |t: T| if t == T::zero() { start } else if t == T::one() { end } else { start + direction * t };
+    Some(Line2D::new(at(enter), at(exit)))
+}
+
+/// Cuts `path` at the boundary of `polygon` and keeps the parts on `side`,
+/// e.g. to crop a road network to a study area.
+///
+/// # Returns
+/// * The kept parts as polylines in path order, each running in the path's
+///   direction. Parts of one path segment joined across a touched vertex stay
+///   one polyline.
+///
+/// # Algorithm Explanation
+/// - Each path segment is cut wherever it crosses or touches a polygon edge,
+///   with coordinates closer than a small tolerance relative to the input's
+///   magnitude treated as touching.
+/// - Each piece lies entirely on one side, so its midpoint decides whether it
+///   is kept; pieces along the boundary count as inside.
+/// - Consecutive kept pieces are chained into polylines.
+///
+/// # Time Complexity
+/// **O(n m)** for a path of `n` segments and a polygon of `m` edges.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::clip::{clip_polyline_to_polygon, ClipSide};
+/// use geomengine::line::Polyline2D;
+/// use geomengine::point::Point2D;
+/// use geomengine::polygon::Polygon2D;
+///
+/// let area = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(4.0, 0.0), Point2D::new(4.0, 4.0), Point2D::new(0.0, 4.0)]);
+/// let road = Polyline2D::new(vec![Point2D::new(-2.0, 1.0), Point2D::new(2.0, 1.0), Point2D::new(2.0, 6.0)]);
+/// let inside = clip_polyline_to_polygon(&road, &area, ClipSide::Inside);
+/// assert_eq!(inside, [Polyline2D::new(vec![Point2D::new(0.0, 1.0), Point2D::new(2.0, 1.0), Point2D::new(2.0, 4.0)])]);
+/// let outside = clip_polyline_to_polygon(&road, &area, ClipSide::Outside);
+/// assert_eq!(outside.len(), 2);
+/// assert_eq!(outside[1].vertices(), &[Point2D::new(2.0, 4.0), Point2D::new(2.0, 6.0)]);
+/// ```
+pub fn clip_polyline_to_polygon<T: Float + Debug>(path: &Polyline2D<T>, polygon: &Polygon2D<T>, side: ClipSide) -> Vec<Polyline2D<T>> {
+    let tolerance = snap_tolerance(path.vertices().iter().chain(polygon.vertices()).copied());
+    let edges: Vec<Line2D<T>> = polygon.edges().collect();
+    let keep_inside = side == ClipSide::Inside;
+    let mut clipped = Vec::new();
+    let mut current: Vec<Point2D<T>> = Vec::new();
+    for segment in path.edges() {
+        let length = segment.length();
+        if length <= T::zero() {
+            continue;
+        }
+        let cuts = cut_parameters(&segment, &edges, tolerance / length);
+        let direction = segment.p2 - segment.p1;
+        let at = |t: T| if t == T::zero() { segment.p1 } else if t == T::one() { segment.p2 } else { segment.p1 + direction * t };
+        for window in cuts.windows(2) {
+            let (from, to) = (at(window[0]), at(window[1]));
+            let middle = at((window[0] + window[1]) / (T::one() + T::one()));
+            let inside = edges.iter().any(|edge| middle.distance_to(edge) <= tolerance) || polygon.contains_point(&middle);
+            if inside != keep_inside {
+                flush(&mut current, &mut clipped);
+            } else {
+                if current.last() != Some(&from) {
+                    flush(&mut current, &mut clipped);
+                    current.push(from);
+                }
+                current.push(to);
+            }
+        }
+    }
+    flush(&mut current, &mut clipped);
+    clipped
+}
+
+/// Sorted parameters along `segment`, 0 and 1 included, where it meets `edges`.
+/// Cuts closer than `tolerance` (as a fraction of the segment) to each other or
+/// to an end are merged.
+fn cut_parameters<T: Float + Debug>(segment: &Line2D<T>, edges: &[Line2D<T>], tolerance: T) -> Vec<T> {
+    let direction = segment.p2 - segment.p1;
+    let squared_length = direction.dot_product(&direction);
+    let mut cuts = Vec::new();
+    for edge in edges {
+        let edge_direction = edge.p2 - edge.p1;
+        let denominator = direction.cross_product(&edge_direction);
+        if denominator != T::zero() {
+            let offset = edge.p1 - segment.p1;
+            let t = offset.cross_product(&edge_direction) / denominator;
+            let u = offset.cross_product(&direction) / denominator;
+            if u >= -tolerance && u <= T::one() + tolerance {
+                cuts.push(t);
+            }
+        }
+        // edge ends touching the segment, which also covers overlapping parallel edges
+        for end in [edge.p1, edge.p2] {
+            if segment.distance_to(&end) <= tolerance * squared_length.sqrt() {
+                cuts.push((end - segment.p1).dot_product(&direction) / squared_length);
+            }
+        }
+    }
+    let mut cuts: Vec<T> = cuts.into_iter().filter(|&t| t > tolerance && t < T::one() - tolerance).collect();
+    cuts.push(T::zero());
+    cuts.push(T::one());
+    cuts.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    cuts.dedup_by(|later, earlier| *later - *earlier <= tolerance && *later != T::one());
+    // a cut merged into the end is dropped rather than moving the end
+    if cuts.len() > 2 && T::one() - cuts[cuts.len() - 2] <= tolerance {
+        cuts.remove(cuts.len() - 2);
+    }
+    cuts
+}
+
+/// Moves the polyline being built, if it has a segment, into `clipped`.
+fn flush<T: Float + Debug>(current: &mut Vec<Point2D<T>>, clipped: &mut Vec<Polyline2D<T>>) {
+    if current.len() >= 2 {
+        clipped.push(Polyline2D::new(core::mem::take(current)));
+    } else {
+        current.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(x1: f64, y1: f64, x2: f64, y2: f64) -> Line2D<f64> {
+        Line2D::new(Point2D::new(x1, y1), Point2D::new(x2, y2))
+    }
+
+    #[test]
+    fn test_clip_segment_to_aabb() {
+        let aabb = Aabb2D::new(Point2D::new(-1.0, -2.0), Point2D::new(3.0, 2.0));
+        // inside, crossing, and missing in both directions
+        assert_eq!(clip_segment_to_aabb(&segment(0.0, 0.0, 1.0, 1.0), &aabb), Some(segment(0.0, 0.0, 1.0, 1.0)));
+        assert_eq!(clip_segment_to_aabb(&segment(-5.0, 0.0, 5.0, 0.0), &aabb), Some(segment(-1.0, 0.0, 3.0, 0.0)));
+        assert_eq!(clip_segment_to_aabb(&segment(5.0, 0.0, -5.0, 0.0), &aabb), Some(segment(3.0, 0.0, -1.0, 0.0)));
+        assert_eq!(clip_segment_to_aabb(&segment(1.0, -4.0, 1.0, 4.0), &aabb), Some(segment(1.0, -2.0, 1.0, 2.0)));
+        assert_eq!(clip_segment_to_aabb(&segment(-3.0, 0.0, 0.0, 4.0), &aabb), None);
+        assert_eq!(clip_segment_to_aabb(&segment(4.0, -5.0, 4.0, 5.0), &aabb), None);
+        assert_eq!(clip_segment_to_aabb(&segment(-5.0, 0.0, -2.0, 0.0), &aabb), None);
+        // along an edge, and degenerate segments
+        assert_eq!(clip_segment_to_aabb(&segment(-4.0, 2.0, 0.0, 2.0), &aabb), Some(segment(-1.0, 2.0, 0.0, 2.0)));
+        assert_eq!(clip_segment_to_aabb(&segment(1.0, 1.0, 1.0, 1.0), &aabb), Some(segment(1.0, 1.0, 1.0, 1.0)));
+        assert_eq!(clip_segment_to_aabb(&segment(9.0, 1.0, 9.0, 1.0), &aabb), None);
+    }
+
+    #[test]
+    fn test_clipped_points_lie_on_segment_and_in_box() {
+        let aabb = Aabb2D::new(Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0));
+        for i in 0..40 {
+            let angle = i as f64 * 0.37;
+            let (sin, cos) = angle.sin_cos();
+            let line = segment(0.3 - 2.0 * cos, 0.6 - 2.0 * sin, 0.3 + 1.5 * cos, 0.6 + 1.7 * sin);
+            let clipped = clip_segment_to_aabb(&line, &aabb).unwrap();
+            for p in [clipped.p1, clipped.p2] {
+                assert!(p.x > -1e-12 && p.x < 1.0 + 1e-12 && p.y > -1e-12 && p.y < 1.0 + 1e-12);
+                assert!((line.p2 - line.p1).cross_product(&(p - line.p1)).abs() < 1e-9);
+            }
+        }
+    }
+
+    fn path(points: &[(f64, f64)]) -> Polyline2D<f64> {
+        Polyline2D::new(points.iter().map(|&(x, y)| Point2D::new(x, y)).collect())
+    }
+
+    #[test]
+    fn test_clip_polyline_to_concave_polygon() {
+        // a U opening upwards: the path dips into the left arm, crosses the gap and the right arm
+        let u_shape = Polygon2D::new(vec![
+            Point2D::new(0.0, 0.0),
+            Point2D::new(6.0, 0.0),
+            Point2D::new(6.0, 6.0),
+            Point2D::new(4.0, 6.0),
+            Point2D::new(4.0, 2.0),
+            Point2D::new(2.0, 2.0),
+            Point2D::new(2.0, 6.0),
+            Point2D::new(0.0, 6.0),
+        ]);
+        let line = path(&[(-1.0, 4.0), (7.0, 4.0)]);
+        assert_eq!(
+            clip_polyline_to_polygon(&line, &u_shape, ClipSide::Inside),
+            [path(&[(0.0, 4.0), (2.0, 4.0)]), path(&[(4.0, 4.0), (6.0, 4.0)])]
+        );
+        assert_eq!(
+            clip_polyline_to_polygon(&line, &u_shape, ClipSide::Outside),
+            [path(&[(-1.0, 4.0), (0.0, 4.0)]), path(&[(2.0, 4.0), (4.0, 4.0)]), path(&[(6.0, 4.0), (7.0, 4.0)])]
+        );
+
+        // along the inner bottom edge, which is boundary and therefore inside
+        let along = path(&[(3.0, 5.0), (3.0, 2.0), (5.0, 2.0)]);
+        assert_eq!(clip_polyline_to_polygon(&along, &u_shape, ClipSide::Inside), [path(&[(3.0, 2.0), (4.0, 2.0), (5.0, 2.0)])]);
+        assert_eq!(clip_polyline_to_polygon(&along, &u_shape, ClipSide::Outside), [path(&[(3.0, 5.0), (3.0, 2.0)])]);
+    }
+
+    #[test]
+    fn test_clip_polyline_touching_and_missing() {
+        let square = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(4.0, 0.0), Point2D::new(4.0, 4.0), Point2D::new(0.0, 4.0)]);
+        // grazing a corner keeps nothing inside and the whole path, split there, outside
+        let grazing = path(&[(3.0, 5.0), (5.0, 3.0)]);
+        assert!(clip_polyline_to_polygon(&grazing, &square, ClipSide::Inside).is_empty());
+        assert_eq!(clip_polyline_to_polygon(&grazing, &square, ClipSide::Outside), [path(&[(3.0, 5.0), (4.0, 4.0), (5.0, 3.0)])]);
+        let far = path(&[(10.0, 10.0), (12.0, 10.0)]);
+        assert!(clip_polyline_to_polygon(&far, &square, ClipSide::Inside).is_empty());
+        assert_eq!(clip_polyline_to_polygon(&far, &square, ClipSide::Outside), [far]);
+        let within = path(&[(1.0, 1.0), (2.0, 3.0), (3.0, 1.0)]);
+        assert_eq!(clip_polyline_to_polygon(&within, &square, ClipSide::Inside), [within]);
+    }
+}
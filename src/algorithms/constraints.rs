@@ -0,0 +1,381 @@
+use num::Float;
+use core::fmt::{Debug, Display, self};
+use crate::point::Point2D;
+use crate::line::Line2D;
+use crate::shape::Circle2D;
+use crate::transform::Angle;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Iterations [`Sketch::solve`] takes before giving up.
+const MAX_ITERATIONS: usize = 200;
+
+/// A point of a [`Sketch`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PointId(usize);
+
+/// A line of a [`Sketch`], running between two of its points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineId(usize);
+
+/// A circle of a [`Sketch`], around one of its points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CircleId(usize);
+
+/// A geometric relation [`Sketch::solve`] makes hold.
+///
+/// Line constraints are about the infinite lines through the two points, so
+/// parallel lines may point in opposite directions.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Constraint<T: Float + Debug> {
+    /// The points are at the same position.
+    Coincident(PointId, PointId),
+    /// The point is at the given position.
+    Fixed(PointId, Point2D<T>),
+    /// The points are the given distance apart.
+    Distance(PointId, PointId, T),
+    Parallel(LineId, LineId),
+    Perpendicular(LineId, LineId),
+    /// The second line is turned counterclockwise from the first by the angle
+    /// (or by the angle plus a half turn).
+    Angle(LineId, LineId, Angle<T>),
+    /// The line touches the circle.
+    Tangent(LineId, CircleId),
+    /// The circle has the given radius.
+    Radius(CircleId, T),
+}
+
+/// Why [`Sketch::solve`] failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolveError {
+    /// The constraints still do not hold after this many iterations; usually
+    /// they contradict each other, e.g. two different distances between the same points.
+    NotConverged { iterations: usize },
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::NotConverged { iterations } => write!(f, "constraints not satisfied after {iterations} iterations"),
+        }
+    }
+}
+
+impl core::error::Error for SolveError {}
+
+/// A parametric sketch: points, lines and circles tied together by [`Constraint`]s.
+///
+/// Geometries are added at approximate positions, and [`solve`](Self::solve)
+/// moves them as little as it can until every constraint holds, as in the
+/// sketcher of a parametric CAD tool.
+///
+/// # Algorithm Explanation
+/// - Every constraint is an equation in the coordinates and radii that is zero
+///   when it holds, scaled so that angles are measured by their sine.
+/// - Levenberg–Marquardt minimizes the sum of their squares: each step solves
+///   the Gauss–Newton equations damped by a factor that grows when a step fails
+///   and shrinks when it succeeds. The damping also picks a small step when the
+///   sketch is under-constrained, so free geometry stays close to where it was.
+/// - Derivatives are taken by finite differences.
+///
+/// # Time Complexity
+/// **O(m n² + n³)** per iteration for `n` coordinates and radii and `m` constraints.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::constraints::{Constraint, Sketch};
+/// use geomengine::point::Point2D;
+///
+/// // a roughly drawn right triangle with legs 3 and 4
+/// let mut sketch = Sketch::new();
+/// let a = sketch.add_point(Point2D::new(0.0, 0.0));
+/// let b = sketch.add_point(Point2D::new(3.8, 0.3));
+/// let c = sketch.add_point(Point2D::new(0.2, 2.9));
+/// let (ab, ac) = (sketch.add_line(a, b), sketch.add_line(a, c));
+/// sketch.constrain(Constraint::Fixed(a, Point2D::new(0.0, 0.0)));
+/// sketch.constrain(Constraint::Distance(a, b, 4.0));
+/// sketch.constrain(Constraint::Distance(a, c, 3.0));
+/// sketch.constrain(Constraint::Perpendicular(ab, ac));
+/// sketch.solve().unwrap();
+/// let hypotenuse: f64 = sketch.point(b).distance(&sketch.point(c));
+/// assert!((hypotenuse - 5.0).abs() < 1e-6);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sketch<T: Float + Debug> {
+    // x and y of every point and the radius of every circle
+    parameters: Vec<T>,
+    lines: Vec<(PointId, PointId)>,
+    circles: Vec<(PointId, usize)>,
+    constraints: Vec<Constraint<T>>,
+}
+
+impl<T: Float + Debug> Default for Sketch<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Float + Debug> Sketch<T> {
+    pub fn new() -> Self {
+        Sketch { parameters: Vec::new(), lines: Vec::new(), circles: Vec::new(), constraints: Vec::new() }
+    }
+
+    /// Adds a point at an approximate `position`.
+    pub fn add_point(&mut self, position: Point2D<T>) -> PointId {
+        self.parameters.extend([position.x, position.y]);
+        PointId(self.parameters.len() - 2)
+    }
+    pub fn add_line(&mut self, start: PointId, end: PointId) -> LineId {
+        self.lines.push((start, end));
+        LineId(self.lines.len() - 1)
+    }
+    /// Adds a circle around `center` with an approximate `radius`.
+    pub fn add_circle(&mut self, center: PointId, radius: T) -> CircleId {
+        self.parameters.push(radius);
+        self.circles.push((center, self.parameters.len() - 1));
+        CircleId(self.circles.len() - 1)
+    }
+    pub fn constrain(&mut self, constraint: Constraint<T>) {
+        self.constraints.push(constraint);
+    }
+
+    pub fn point(&self, id: PointId) -> Point2D<T> {
+        point_in(&self.parameters, id)
+    }
+    pub fn line(&self, id: LineId) -> Line2D<T> {
+        let (start, end) = self.lines[id.0];
+        Line2D::new(self.point(start), self.point(end))
+    }
+    pub fn circle(&self, id: CircleId) -> Circle2D<T> {
+        let (center, radius) = self.circles[id.0];
+        Circle2D::new(self.point(center), self.parameters[radius])
+    }
+
+    /// Moves the geometry until every constraint holds.
+    ///
+    /// # Returns
+    /// * The number of iterations taken, or [`SolveError::NotConverged`], in which
+    ///   case the geometry is left at the best positions found.
+    ///
+    /// # Panics
+    /// If a constraint refers to a geometry of another sketch that this one does not have.
+    pub fn solve(&mut self) -> Result<usize, SolveError> {
+        let scale = self.parameters.iter().fold(T::one(), |scale, p| scale.max(p.abs()));
+        let tolerance = T::epsilon().sqrt() * scale;
+        let (min_damping, max_damping) = (T::from(1e-12).unwrap(), T::from(1e12).unwrap());
+        let ten = T::from(10.0).unwrap();
+        let mut damping = T::from(1e-3).unwrap();
+        let mut residuals = self.residuals(&self.parameters);
+        for iteration in 0..MAX_ITERATIONS {
+            if residuals.iter().all(|r| r.abs() <= tolerance) {
+                return Ok(iteration);
+            }
+            let jacobian = self.jacobian(&residuals);
+            let n = self.parameters.len();
+            // normal equations JᵀJ δ = -Jᵀr
+            let mut normal = vec![vec![T::zero(); n]; n];
+            let mut gradient = vec![T::zero(); n];
+            for (row, &r) in jacobian.iter().zip(&residuals) {
+                for i in 0..n {
+                    gradient[i] = gradient[i] - row[i] * r;
+                    for j in 0..n {
+                        normal[i][j] = normal[i][j] + row[i] * row[j];
+                    }
+                }
+            }
+
+            let cost = sum_of_squares(&residuals);
+            loop {
+                let mut damped = normal.clone();
+                for (i, row) in damped.iter_mut().enumerate() {
+                    row[i] = row[i] + damping * (T::one() + normal[i][i]);
+                }
+                let step = solve_linear(damped, gradient.clone());
+                let candidate: Option<Vec<T>> = step.map(|step| self.parameters.iter().zip(step).map(|(&p, s)| p + s).collect());
+                if let Some(candidate) = candidate {
+                    let candidate_residuals = self.residuals(&candidate);
+                    if sum_of_squares(&candidate_residuals) < cost {
+                        self.parameters = candidate;
+                        residuals = candidate_residuals;
+                        damping = (damping / ten).max(min_damping);
+                        break;
+                    }
+                }
+                damping = damping * ten;
+                if damping > max_damping {
+                    return Err(SolveError::NotConverged { iterations: iteration + 1 });
+                }
+            }
+        }
+        if residuals.iter().all(|r| r.abs() <= tolerance) {
+            Ok(MAX_ITERATIONS)
+        } else {
+            Err(SolveError::NotConverged { iterations: MAX_ITERATIONS })
+        }
+    }
+
+    /// How far each constraint is from holding, zero when it does.
+    fn residuals(&self, parameters: &[T]) -> Vec<T> {
+        let point = |id: PointId| point_in(parameters, id);
+        let direction = |id: LineId| {
+            let (start, end) = self.lines[id.0];
+            point(end) - point(start)
+        };
+        // sine and cosine of the angle from one line to the other
+        let sin_cos = |first: LineId, second: LineId| {
+            let (u, v) = (direction(first), direction(second));
+            let length = (u.dot_product(&u) * v.dot_product(&v)).sqrt();
+            (u.cross_product(&v) / length, u.dot_product(&v) / length)
+        };
+
+        let mut residuals = Vec::with_capacity(self.constraints.len() * 2);
+        for constraint in &self.constraints {
+            match *constraint {
+                Constraint::Coincident(a, b) => {
+                    let offset = point(b) - point(a);
+                    residuals.extend([offset.x, offset.y]);
+                }
+                Constraint::Fixed(a, position) => {
+                    let offset = point(a) - position;
+                    residuals.extend([offset.x, offset.y]);
+                }
+                Constraint::Distance(a, b, distance) => residuals.push(point(a).distance(&point(b)) - distance),
+                Constraint::Parallel(first, second) => residuals.push(sin_cos(first, second).0),
+                Constraint::Perpendicular(first, second) => residuals.push(sin_cos(first, second).1),
+                Constraint::Angle(first, second, angle) => {
+                    // sin(φ - θ), zero for φ = θ and φ = θ + π
+                    let (sin, cos) = sin_cos(first, second);
+                    let (sin_target, cos_target) = angle.sin_cos();
+                    residuals.push(sin * cos_target - cos * sin_target);
+                }
+                Constraint::Tangent(line, circle) => {
+                    let (start, _) = self.lines[line.0];
+                    let (center, radius) = self.circles[circle.0];
+                    let u = direction(line);
+                    let offset = point(center) - point(start);
+                    residuals.push(u.cross_product(&offset).abs() / u.dot_product(&u).sqrt() - parameters[radius]);
+                }
+                Constraint::Radius(circle, radius) => residuals.push(parameters[self.circles[circle.0].1] - radius),
+            }
+        }
+        residuals
+    }
+
+    /// Forward difference derivatives of the residuals, one row per residual.
+    fn jacobian(&self, residuals: &[T]) -> Vec<Vec<T>> {
+        let n = self.parameters.len();
+        let mut jacobian = vec![vec![T::zero(); n]; residuals.len()];
+        let mut shifted = self.parameters.clone();
+        for j in 0..n {
+            let step = T::epsilon().sqrt() * self.parameters[j].abs().max(T::one());
+            shifted[j] = self.parameters[j] + step;
+            for (row, (moved, &r)) in jacobian.iter_mut().zip(self.residuals(&shifted).into_iter().zip(residuals)) {
+                row[j] = (moved - r) / step;
+            }
+            shifted[j] = self.parameters[j];
+        }
+        jacobian
+    }
+}
+
+fn point_in<T: Float + Debug>(parameters: &[T], id: PointId) -> Point2D<T> {
+    Point2D::new(parameters[id.0], parameters[id.0 + 1])
+}
+
+fn sum_of_squares<T: Float>(values: &[T]) -> T {
+    values.iter().fold(T::zero(), |sum, &v| sum + v * v)
+}
+
+/// Solves `a x = b` by Gaussian elimination with partial pivoting, `None` if
+/// `a` is singular or the result is not finite.
+fn solve_linear<T: Float>(mut a: Vec<Vec<T>>, mut b: Vec<T>) -> Option<Vec<T>> {
+    let n = b.len();
+    for column in 0..n {
+        let pivot = (column..n).max_by(|&i, &j| a[i][column].abs().partial_cmp(&a[j][column].abs()).unwrap())?;
+        if a[pivot][column] == T::zero() || a[pivot][column].is_nan() {
+            return None;
+        }
+        a.swap(column, pivot);
+        b.swap(column, pivot);
+        let (upper, lower) = a.split_at_mut(column + 1);
+        let pivot_row = &upper[column];
+        for (offset, row) in lower.iter_mut().enumerate() {
+            let factor = row[column] / pivot_row[column];
+            for (value, &pivot_value) in row[column..].iter_mut().zip(&pivot_row[column..]) {
+                *value = *value - factor * pivot_value;
+            }
+            b[column + 1 + offset] = b[column + 1 + offset] - factor * b[column];
+        }
+    }
+    let mut x = vec![T::zero(); n];
+    for row in (0..n).rev() {
+        let known = (row + 1..n).fold(T::zero(), |sum, k| sum + a[row][k] * x[k]);
+        x[row] = (b[row] - known) / a[row][row];
+    }
+    x.iter().all(|v| v.is_finite()).then_some(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shape::Distance;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_sketch_solves_rectangle() {
+        let mut sketch = Sketch::new();
+        let corners: Vec<PointId> = [(0.0, 0.0), (4.0, 0.0), (4.5, 2.5), (-0.3, 3.2)]
+            .into_iter()
+            .map(|(x, y)| sketch.add_point(Point2D::new(x, y)))
+            .collect();
+        let sides: Vec<LineId> = (0..4).map(|i| sketch.add_line(corners[i], corners[(i + 1) % 4])).collect();
+        sketch.constrain(Constraint::Fixed(corners[0], Point2D::new(0.0, 0.0)));
+        sketch.constrain(Constraint::Fixed(corners[1], Point2D::new(4.0, 0.0)));
+        for i in 0..3 {
+            sketch.constrain(Constraint::Perpendicular(sides[i], sides[i + 1]));
+        }
+        sketch.constrain(Constraint::Distance(corners[1], corners[2], 3.0));
+        // redundant, but consistent with the others
+        sketch.constrain(Constraint::Parallel(sides[0], sides[2]));
+        assert!(sketch.solve().is_ok());
+        for (id, (x, y)) in corners.iter().zip([(0.0, 0.0), (4.0, 0.0), (4.0, 3.0), (0.0, 3.0)]) {
+            assert_relative_eq!(sketch.point(*id).x, x, epsilon = 1e-6);
+            assert_relative_eq!(sketch.point(*id).y, y, epsilon = 1e-6);
+        }
+        // already solved: nothing left to do
+        assert_eq!(sketch.solve(), Ok(0));
+    }
+
+    #[test]
+    fn test_sketch_tangent_angle_and_conflicts() {
+        let mut sketch: Sketch<f64> = Sketch::new();
+        let center = sketch.add_point(Point2D::new(0.0, 0.0));
+        let circle = sketch.add_circle(center, 1.5);
+        let (origin, x_axis_end) = (sketch.add_point(Point2D::new(-3.0, -2.0)), sketch.add_point(Point2D::new(3.0, -2.0)));
+        let (start, end) = (sketch.add_point(Point2D::new(-3.0, 0.0)), sketch.add_point(Point2D::new(3.0, 2.5)));
+        let reference = sketch.add_line(origin, x_axis_end);
+        let line = sketch.add_line(start, end);
+        for (point, position) in [(center, (0.0, 0.0)), (origin, (-3.0, -2.0)), (x_axis_end, (3.0, -2.0)), (start, (-3.0, 0.0))] {
+            sketch.constrain(Constraint::Fixed(point, Point2D::new(position.0, position.1)));
+        }
+        sketch.constrain(Constraint::Radius(circle, 1.0));
+        // from (-3, 0) the tangents to the unit circle leave at asin(1/3) to the x axis
+        let tangent_angle = (1.0f64 / 3.0).asin();
+        sketch.constrain(Constraint::Angle(reference, line, Angle::from_radians(tangent_angle)));
+        sketch.constrain(Constraint::Tangent(line, circle));
+        assert!(sketch.solve().is_ok());
+        let (segment, solved) = (sketch.line(line), sketch.circle(circle));
+        assert_relative_eq!(solved.radius, 1.0, epsilon = 1e-6);
+        let direction = segment.p2 - segment.p1;
+        assert_relative_eq!(direction.y.atan2(direction.x).to_degrees().rem_euclid(180.0), tangent_angle.to_degrees(), epsilon = 1e-5);
+        // the infinite line touches the circle
+        let far = Line2D::new(segment.p1 - direction * 10.0, segment.p2 + direction * 10.0);
+        assert_relative_eq!(far.distance_to(&solved.center), 1.0, epsilon = 1e-6);
+
+        let mut conflicting = Sketch::new();
+        let (a, b) = (conflicting.add_point(Point2D::new(0.0, 0.0)), conflicting.add_point(Point2D::new(1.0, 0.0)));
+        conflicting.constrain(Constraint::Distance(a, b, 1.0));
+        conflicting.constrain(Constraint::Distance(a, b, 2.0));
+        assert!(matches!(conflicting.solve(), Err(SolveError::NotConverged { .. })));
+    }
+}
@@ -0,0 +1,175 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::line::Polyline2D;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Extracts the isolines of a sampled scalar field with marching squares.
+///
+/// # Arguments
+/// * `grid` - Samples row by row, starting with the bottom row; sample
+///   `(column, row)` sits at the point `(column, row)`.
+/// * `width`, `height` - The number of columns and rows.
+/// * `iso_value` - The level to contour. Samples at or above it count as inside.
+///
+/// # Returns
+/// * One polyline per contour, in grid coordinates. Closed contours repeat
+///   their first point at the end; open ones run from border to border. Every
+///   contour keeps the inside on its left, so closed ones around a peak run
+///   counterclockwise.
+///
+/// # Algorithm Explanation
+/// - Each cell of four samples is classified by which corners are inside; its
+///   edges with one inside and one outside end are crossed by the contour, at
+///   the point found by linear interpolation between the two samples.
+/// - Walking a cell's edges counterclockwise, the contour enters where the walk
+///   leaves the inside and exits where it comes back. In the two saddle cases,
+///   the average of the corners decides whether the inside connects through the
+///   cell's center, and thereby which entry goes with which exit.
+/// - Crossing points are shared by the two cells of an edge, so each segment's
+///   exit is the next one's entry: chains starting at the border are followed
+///   first, and whatever is left forms closed loops.
+///
+/// # Time Complexity
+/// **O(w h)** for a `w` by `h` grid.
+///
+/// # Panics
+/// If `grid` does not hold `width * height` samples.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::contour::marching_squares;
+///
+/// // a single peak in the middle of a 3x3 grid
+/// let grid = [0.0f64, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0];
+/// let contours = marching_squares(&grid, 3, 3, 0.5);
+/// assert_eq!(contours.len(), 1);
+/// // a diamond through the midpoints around the peak, closed
+/// assert_eq!(contours[0].len(), 5);
+/// assert!(contours[0].vertices().iter().all(|p| (p.x - 1.0).abs() + (p.y - 1.0).abs() == 0.5));
+/// ```
+pub fn marching_squares<T: Float + Debug>(grid: &[T], width: usize, height: usize, iso_value: T) -> Vec<Polyline2D<T>> {
+    assert_eq!(grid.len(), width * height, "grid must hold width * height samples");
+    let sample = |column: usize, row: usize| grid[row * width + column];
+    let inside = |column: usize, row: usize| sample(column, row) >= iso_value;
+
+    // edge keys: 2 * (row * width + column) for the horizontal edge to the right of
+    // a sample, plus one for the vertical edge above it
+    let crossing = |key: usize| {
+        let (column, row) = ((key / 2) % width, (key / 2) / width);
+        let horizontal = key.is_multiple_of(2);
+        let (end_column, end_row) = if horizontal { (column + 1, row) } else { (column, row + 1) };
+        let (a, b) = (sample(column, row), sample(end_column, end_row));
+        let t = (iso_value - a) / (b - a);
+        let (x, y) = (T::from(column).unwrap(), T::from(row).unwrap());
+        if horizontal { Point2D::new(x + t, y) } else { Point2D::new(x, y + t) }
+    };
+
+    let mut segments = Vec::new();
+    for row in 0..height.saturating_sub(1) {
+        for column in 0..width.saturating_sub(1) {
+            // counterclockwise from the bottom left
+            let corners = [(column, row), (column + 1, row), (column + 1, row + 1), (column, row + 1)];
+            let edges = [
+                2 * (row * width + column),
+                2 * (row * width + column + 1) + 1,
+                2 * ((row + 1) * width + column),
+                2 * (row * width + column) + 1,
+            ];
+            let state = corners.map(|(c, r)| inside(c, r));
+            let entries: Vec<usize> = (0..4).filter(|&k| state[k] && !state[(k + 1) % 4]).collect();
+            let exits: Vec<usize> = (0..4).filter(|&k| !state[k] && state[(k + 1) % 4]).collect();
+            match (entries.as_slice(), exits.as_slice()) {
+                (&[entry], &[exit]) => segments.push((edges[entry], edges[exit])),
+                (&[_, _], &[_, _]) => {
+                    let four = T::from(4).unwrap();
+                    let center = corners.iter().fold(T::zero(), |sum, &(c, r)| sum + sample(c, r)) / four;
+                    // joined through the center, each contour cuts off an outside corner
+                    let turn = if center >= iso_value { 1 } else { 3 };
+                    for &entry in &entries {
+                        segments.push((edges[entry], edges[(entry + turn) % 4]));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mut starting_at = vec![None; 2 * width * height];
+    let mut is_exit = vec![false; 2 * width * height];
+    for (i, &(entry, exit)) in segments.iter().enumerate() {
+        starting_at[entry] = Some(i);
+        is_exit[exit] = true;
+    }
+    let mut used = vec![false; segments.len()];
+    let mut contours = Vec::new();
+    let follow = |first: usize, used: &mut [bool]| {
+        let mut points = vec![crossing(segments[first].0)];
+        let mut current = Some(first);
+        while let Some(i) = current.filter(|&i| !used[i]) {
+            used[i] = true;
+            points.push(crossing(segments[i].1));
+            current = starting_at[segments[i].1];
+        }
+        points.dedup();
+        Polyline2D::new(points)
+    };
+    // open contours start on the border, where no segment leads in
+    for i in 0..segments.len() {
+        if !is_exit[segments[i].0] {
+            contours.push(follow(i, &mut used));
+        }
+    }
+    for i in 0..segments.len() {
+        if !used[i] {
+            contours.push(follow(i, &mut used));
+        }
+    }
+    contours
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::sdf::sdf_from_polygon;
+    use crate::polygon::{Polygon2D, ring};
+    use crate::shape::Aabb2D;
+
+    #[test]
+    fn test_marching_squares_ramp_and_saddle() {
+        // a ramp rising to the right: one open contour at x = 1.5, running down so the inside is on its left
+        let ramp: Vec<f64> = (0..12).map(|i| (i % 4) as f64).collect();
+        let contours = marching_squares(&ramp, 4, 3, 1.5);
+        assert_eq!(contours.len(), 1);
+        assert_eq!(contours[0].vertices(), &[Point2D::new(1.5, 2.0), Point2D::new(1.5, 1.0), Point2D::new(1.5, 0.0)]);
+
+        // a saddle: high on one diagonal, low on the other
+        let saddle = [1.0, 0.0, 0.0, 1.0];
+        // with the center (0.5) inside, the contours cut off the low corners
+        let low_corners = marching_squares(&saddle, 2, 2, 0.4);
+        assert_eq!(low_corners.len(), 2);
+        assert!(low_corners.iter().any(|c| c.vertices().contains(&Point2D::new(0.6, 0.0)) && c.vertices().contains(&Point2D::new(1.0, 0.4))));
+        let high_corners = marching_squares(&saddle, 2, 2, 0.6);
+        assert!(high_corners.iter().any(|c| c.vertices().contains(&Point2D::new(0.4, 0.0)) && c.vertices().contains(&Point2D::new(0.0, 0.4))));
+
+        assert!(marching_squares(&[2.0; 9], 3, 3, 1.0).is_empty());
+    }
+
+    #[test]
+    fn test_marching_squares_recovers_rasterized_polygon() {
+        // contouring the negated distance field at zero gives back the square
+        let square = Polygon2D::new(vec![Point2D::new(2.0, 2.0), Point2D::new(6.0, 2.0), Point2D::new(6.0, 6.0), Point2D::new(2.0, 6.0)]);
+        let field = sdf_from_polygon(&square, &Aabb2D::new(Point2D::new(0.0, 0.0), Point2D::new(8.0, 8.0)), 0.5);
+        let depth: Vec<f64> = field.values().iter().map(|d| -d).collect();
+        let contours = marching_squares(&depth, field.width(), field.height(), 0.0);
+        assert_eq!(contours.len(), 1);
+
+        let contour = contours[0].vertices();
+        assert_eq!(contour.first(), contour.last());
+        // in grid coordinates the square spans cells 3.5 to 11.5
+        let area = ring::signed_area(&contour[1..]) * 0.25;
+        assert!((area - 16.0).abs() < 0.5);
+        assert!(contour.iter().all(|p| (3.0..=12.0).contains(&p.x) && (3.0..=12.0).contains(&p.y)));
+    }
+}
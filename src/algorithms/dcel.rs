@@ -0,0 +1,283 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::line::Line2D;
+use crate::polygon::Polygon2D;
+use super::noding::snap_tolerance;
+use super::planar_graph::PlanarGraph;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Face of a [`Dcel`]: one half-edge of its outer boundary (none for the
+/// unbounded face) and one of each boundary cycle of the holes inside it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Face {
+    outer: Option<usize>,
+    inner: Vec<usize>,
+}
+
+/// Doubly-connected edge list over a planar subdivision.
+///
+/// Vertices, half-edges and faces are addressed by index. Half-edge `h` runs from
+/// [`origin(h)`](Self::origin) to [`destination(h)`](Self::destination) with the
+/// face [`face(h)`](Self::face) on its left; [`twin(h)`](Self::twin) runs the other
+/// way. [`next`](Self::next) and [`prev`](Self::prev) walk the boundary cycle of
+/// that face, counterclockwise around bounded faces and clockwise around holes.
+/// Face [`UNBOUNDED_FACE`](Self::UNBOUNDED_FACE) is the area outside everything.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::dcel::Dcel;
+/// use geomengine::line::Line2D;
+/// use geomengine::point::Point2D;
+///
+/// // a square split by a diagonal
+/// let p = [Point2D::new(0.0, 0.0), Point2D::new(1.0, 0.0), Point2D::new(1.0, 1.0), Point2D::new(0.0, 1.0)];
+/// let mut segments: Vec<_> = (0..4).map(|i| Line2D::new(p[i], p[(i + 1) % 4])).collect();
+/// segments.push(Line2D::new(p[0], p[2]));
+///
+/// let dcel = Dcel::from_segments(&segments);
+/// assert_eq!((dcel.vertex_count(), dcel.half_edge_count(), dcel.face_count()), (4, 10, 3));
+///
+/// let h = dcel.outer_component(1).unwrap();
+/// assert_eq!(dcel.cycle(h).count(), 3);
+/// assert_eq!(dcel.next(dcel.prev(h)), h);
+/// assert_eq!(dcel.twin(dcel.twin(h)), h);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Dcel<T: Float + Debug> {
+    vertices: Vec<Point2D<T>>,
+    incident: Vec<usize>,
+    origins: Vec<usize>,
+    next: Vec<usize>,
+    prev: Vec<usize>,
+    faces_of: Vec<usize>,
+    faces: Vec<Face>,
+}
+
+impl<T: Float + Debug> Dcel<T> {
+    pub const UNBOUNDED_FACE: usize = 0;
+
+    /// Builds the subdivision induced by segments that only meet at their endpoints.
+    ///
+    /// # Arguments
+    /// * `segments` - Non-crossing segments. Endpoints closer than a small
+    ///   scale-relative tolerance are merged, and duplicate and zero-length
+    ///   segments dropped along with any vertex left without edges. Crossing segments are not split; node them first with
+    ///   [`node_segments_2d`](super::noding::node_segments_2d).
+    ///
+    /// # Returns
+    /// * The DCEL; every segment becomes a pair of twin half-edges. Components
+    ///   nested inside a face are attached to it as holes, and dangling edges lie
+    ///   with both sides in the same face.
+    ///
+    /// # Time Complexity
+    /// * O(n log n) to link the half-edges, plus O(c * f) to find the faces
+    ///   enclosing the c hole cycles among f faces.
+    pub fn from_segments(segments: &[Line2D<T>]) -> Self {
+        Self::with_tolerance(segments, snap_tolerance(segments.iter().flat_map(|s| [s.p1, s.p2])))
+    }
+
+    /// [`from_segments`](Self::from_segments) merging endpoints closer than `tolerance`.
+    pub(crate) fn with_tolerance(segments: &[Line2D<T>], tolerance: T) -> Self {
+        let graph = PlanarGraph::new(segments, tolerance);
+        let planar_faces = graph.faces();
+
+        let mut prev = vec![0; graph.half_edge_count()];
+        for (h, &n) in graph.next.iter().enumerate() {
+            prev[n] = h;
+        }
+        let mut incident = vec![usize::MAX; graph.vertices.len()];
+        for (h, &origin) in graph.origins.iter().enumerate().rev() {
+            incident[origin] = h;
+        }
+        // endpoints of dropped zero-length or duplicate segments can be left without edges
+        let mut renumbered = vec![usize::MAX; graph.vertices.len()];
+        let mut vertices = Vec::with_capacity(graph.vertices.len());
+        for (v, &point) in graph.vertices.iter().enumerate() {
+            if incident[v] != usize::MAX {
+                renumbered[v] = vertices.len();
+                vertices.push(point);
+            }
+        }
+        incident.retain(|&h| h != usize::MAX);
+        let origins = graph.origins.iter().map(|&v| renumbered[v]).collect();
+
+        let mut faces = vec![Face { outer: None, inner: Vec::new() }];
+        for (face, &shell) in planar_faces.shells.iter().enumerate() {
+            faces.push(Face {
+                outer: Some(planar_faces.cycles[shell][0]),
+                inner: planar_faces.holes[face].iter().map(|&hole| planar_faces.cycles[hole][0]).collect(),
+            });
+        }
+        // bounded faces are shifted by one to make room for the unbounded face
+        let faces_of: Vec<usize> = planar_faces.owners.iter().map(|owner| owner.map_or(Self::UNBOUNDED_FACE, |f| f + 1)).collect();
+        for cycle in &planar_faces.cycles {
+            if faces_of[cycle[0]] == Self::UNBOUNDED_FACE {
+                faces[Self::UNBOUNDED_FACE].inner.push(cycle[0]);
+            }
+        }
+
+        Dcel { vertices, incident, origins, next: graph.next, prev, faces_of, faces }
+    }
+
+    pub fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+    pub fn half_edge_count(&self) -> usize {
+        self.origins.len()
+    }
+    /// Number of faces, including the unbounded one.
+    pub fn face_count(&self) -> usize {
+        self.faces.len()
+    }
+
+    pub fn vertex(&self, v: usize) -> Point2D<T> {
+        self.vertices[v]
+    }
+    pub fn vertices(&self) -> &[Point2D<T>] {
+        &self.vertices
+    }
+    /// One half-edge leaving vertex `v`.
+    pub fn incident_edge(&self, v: usize) -> usize {
+        self.incident[v]
+    }
+
+    pub fn origin(&self, h: usize) -> usize {
+        self.origins[h]
+    }
+    pub fn destination(&self, h: usize) -> usize {
+        self.origins[self.twin(h)]
+    }
+    pub fn twin(&self, h: usize) -> usize {
+        h ^ 1
+    }
+    pub fn next(&self, h: usize) -> usize {
+        self.next[h]
+    }
+    pub fn prev(&self, h: usize) -> usize {
+        self.prev[h]
+    }
+    /// Face to the left of half-edge `h`.
+    pub fn face(&self, h: usize) -> usize {
+        self.faces_of[h]
+    }
+    /// The segment traced by half-edge `h`.
+    pub fn segment(&self, h: usize) -> Line2D<T> {
+        Line2D::new(self.vertices[self.origin(h)], self.vertices[self.destination(h)])
+    }
+
+    /// A half-edge on the outer boundary of face `f`, `None` for the unbounded face.
+    pub fn outer_component(&self, f: usize) -> Option<usize> {
+        self.faces[f].outer
+    }
+    /// One half-edge on each hole boundary inside face `f`.
+    pub fn inner_components(&self, f: usize) -> &[usize] {
+        &self.faces[f].inner
+    }
+
+    /// The half-edges of the boundary cycle through `h`, starting at `h`.
+    pub fn cycle(&self, h: usize) -> impl Iterator<Item = usize> + '_ {
+        let mut current = Some(h);
+        core::iter::from_fn(move || {
+            let edge = current?;
+            let following = self.next(edge);
+            current = (following != h).then_some(following);
+            Some(edge)
+        })
+    }
+
+    /// The half-edges leaving vertex `v`, in clockwise order.
+    pub fn outgoing(&self, v: usize) -> impl Iterator<Item = usize> + '_ {
+        let start = self.incident[v];
+        let mut current = Some(start);
+        core::iter::from_fn(move || {
+            let edge = current?;
+            let following = self.next(self.twin(edge));
+            current = (following != start).then_some(following);
+            Some(edge)
+        })
+    }
+
+    /// The outer boundary of bounded face `f` as a counterclockwise polygon.
+    pub fn face_polygon(&self, f: usize) -> Option<Polygon2D<T>> {
+        let outer = self.outer_component(f)?;
+        Some(Polygon2D::new(self.cycle(outer).map(|h| self.vertices[self.origin(h)]).collect()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shape::Area;
+
+    fn ring(points: &[(f64, f64)]) -> Vec<Line2D<f64>> {
+        (0..points.len())
+            .map(|i| {
+                let (a, b) = (points[i], points[(i + 1) % points.len()]);
+                Line2D::new(Point2D::new(a.0, a.1), Point2D::new(b.0, b.1))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_dcel_navigation_invariants() {
+        // two cells sharing the edge x = 1
+        let mut segments = ring(&[(0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (2.0, 2.0), (1.0, 2.0), (0.0, 2.0)]);
+        segments.push(Line2D::new(Point2D::new(1.0, 0.0), Point2D::new(1.0, 2.0)));
+        let dcel = Dcel::from_segments(&segments);
+
+        assert_eq!(dcel.face_count(), 3);
+        for h in 0..dcel.half_edge_count() {
+            assert_eq!(dcel.prev(dcel.next(h)), h);
+            assert_eq!(dcel.origin(dcel.next(h)), dcel.destination(h));
+            assert_eq!(dcel.face(dcel.next(h)), dcel.face(h));
+            assert_ne!(dcel.face(h), dcel.face(dcel.twin(h)));
+        }
+        let middle = (0..dcel.vertex_count()).find(|&v| dcel.vertex(v) == Point2D::new(1.0, 0.0)).unwrap();
+        assert_eq!(dcel.outgoing(middle).count(), 3);
+        assert!(dcel.outgoing(middle).all(|h| dcel.origin(h) == middle));
+
+        for f in 1..dcel.face_count() {
+            assert_eq!(dcel.face_polygon(f).unwrap().area(), 2.0);
+        }
+        assert_eq!(dcel.inner_components(Dcel::<f64>::UNBOUNDED_FACE).len(), 1);
+    }
+
+    #[test]
+    fn test_dcel_holes_and_dangling_edges() {
+        let mut segments = ring(&[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)]);
+        segments.extend(ring(&[(4.0, 4.0), (6.0, 4.0), (6.0, 6.0), (4.0, 6.0)]));
+        // a spike into the outer face
+        segments.push(Line2D::new(Point2D::new(10.0, 10.0), Point2D::new(12.0, 12.0)));
+        let dcel = Dcel::from_segments(&segments);
+
+        assert_eq!(dcel.face_count(), 3);
+        let outer = (1..3).find(|&f| !dcel.inner_components(f).is_empty()).unwrap();
+        let hole = dcel.inner_components(outer)[0];
+        assert_eq!(dcel.cycle(hole).count(), 4);
+        assert!(dcel.cycle(hole).all(|h| dcel.face(h) == outer));
+
+        let spike = (0..dcel.half_edge_count()).find(|&h| dcel.vertex(dcel.destination(h)) == Point2D::new(12.0, 12.0)).unwrap();
+        assert_eq!(dcel.face(spike), Dcel::<f64>::UNBOUNDED_FACE);
+        assert_eq!(dcel.face(dcel.twin(spike)), Dcel::<f64>::UNBOUNDED_FACE);
+        assert_eq!(dcel.next(spike), dcel.twin(spike));
+    }
+
+    #[test]
+    fn test_dcel_drops_orphan_vertices() {
+        let p = Point2D::new(3.0, 3.0);
+        let dcel = Dcel::from_segments(&[Line2D::new(p, p)]);
+        assert_eq!(dcel.vertex_count(), 0);
+        assert_eq!(dcel.half_edge_count(), 0);
+
+        let mut segments = vec![Line2D::new(p, p)];
+        segments.extend(ring(&[(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)]));
+        let dcel = Dcel::from_segments(&segments);
+        assert_eq!(dcel.vertex_count(), 3);
+        for v in 0..dcel.vertex_count() {
+            assert_eq!(dcel.origin(dcel.incident_edge(v)), v);
+            assert_eq!(dcel.outgoing(v).count(), 2);
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use super::point_algorithms::bounding_box_2d;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// How far the corners of the enclosing triangle lie outside the points, in
+/// multiples of their extent.
+const SUPER_TRIANGLE_SCALE: f64 = 1000.0;
+
+/// Delaunay triangulation of a point set.
+///
+/// # Returns
+/// * Counterclockwise triangles as indices into `points`, none of whose
+///   circumcircles holds another point. Duplicate points are left out, and
+///   fewer than three non-collinear points give no triangles.
+///
+/// # Algorithm Explanation
+/// Bowyer-Watson: the points are inserted one by one into a triangulation of a
+/// triangle enclosing all of them. The triangles whose circumcircle holds the
+/// new point form a star-shaped cavity around it, which is replaced by a fan of
+/// triangles from the point to the cavity's boundary. Triangles touching the
+/// enclosing triangle are dropped at the end.
+///
+/// # Time Complexity
+/// **O(n²)**, as every insertion scans all triangles for the cavity.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::delaunay::delaunay_2d;
+/// use geomengine::point::Point2D;
+///
+/// let points = [Point2D::new(0.0, 0.0), Point2D::new(2.0, 0.0), Point2D::new(2.0, 2.0), Point2D::new(0.0, 2.0), Point2D::new(1.0, 0.5)];
+/// assert_eq!(delaunay_2d(&points).len(), 4);
+/// ```
+pub fn delaunay_2d<T: Float + Debug>(points: &[Point2D<T>]) -> Vec<[usize; 3]> {
+    let triangulation = BowyerWatson::new(points);
+    triangulation.triangles.iter().filter(|t| t.iter().all(|&v| !triangulation.is_super(v))).copied().collect()
+}
+
+/// Delaunay triangulation that keeps the enclosing triangle, so every point
+/// inside it lies in some triangle.
+#[derive(Debug, Clone)]
+pub(crate) struct BowyerWatson<T: Float + Debug> {
+    /// The input points followed by the three corners of the enclosing triangle.
+    pub(crate) points: Vec<Point2D<T>>,
+    /// Counterclockwise triangles.
+    pub(crate) triangles: Vec<[usize; 3]>,
+}
+
+impl<T: Float + Debug> BowyerWatson<T> {
+    pub(crate) fn new(points: &[Point2D<T>]) -> Self {
+        let n = points.len();
+        let origin = Point2D::new(T::zero(), T::zero());
+        let (center, extent) = bounding_box_2d(points).map_or((origin, T::zero()), |b| (b.center(), b.width().max(b.height())));
+        let reach = T::from(SUPER_TRIANGLE_SCALE).unwrap() * if extent > T::zero() { extent } else { T::one() };
+        let mut all = points.to_vec();
+        all.push(Point2D::new(center.x - reach, center.y - reach));
+        all.push(Point2D::new(center.x + reach, center.y - reach));
+        all.push(Point2D::new(center.x, center.y + reach));
+
+        let mut triangulation = BowyerWatson { points: all, triangles: vec![[n, n + 1, n + 2]] };
+        for (i, &point) in points.iter().enumerate() {
+            let cavity = triangulation.cavity(point);
+            if cavity.is_empty() {
+                // a duplicate of an earlier point
+                continue;
+            }
+            let boundary = triangulation.cavity_boundary(&cavity);
+            let mut t = 0;
+            triangulation.triangles.retain(|_| {
+                t += 1;
+                cavity.binary_search(&(t - 1)).is_err()
+            });
+            triangulation.triangles.extend(boundary.into_iter().map(|(a, b)| [a, b, i]));
+        }
+        triangulation
+    }
+
+    /// Is `v` a corner of the enclosing triangle?
+    pub(crate) fn is_super(&self, v: usize) -> bool {
+        v >= self.points.len() - 3
+    }
+
+    /// Triangles whose circumcircle strictly holds `point`, in increasing order.
+    pub(crate) fn cavity(&self, point: Point2D<T>) -> Vec<usize> {
+        (0..self.triangles.len())
+            .filter(|&t| {
+                let [a, b, c] = self.triangles[t].map(|v| self.points[v]);
+                in_circumcircle(a, b, c, point)
+            })
+            .collect()
+    }
+
+    /// Counterclockwise edges of the cavity's outline: triangle edges not shared with another cavity triangle.
+    pub(crate) fn cavity_boundary(&self, cavity: &[usize]) -> Vec<(usize, usize)> {
+        let edges = |t: usize| {
+            let [a, b, c] = self.triangles[t];
+            [(a, b), (b, c), (c, a)]
+        };
+        cavity
+            .iter()
+            .flat_map(|&t| edges(t))
+            .filter(|&(a, b)| !cavity.iter().any(|&u| edges(u).contains(&(b, a))))
+            .collect()
+    }
+}
+
+/// Is `d` strictly inside the circumcircle of the counterclockwise triangle `a`, `b`, `c`?
+fn in_circumcircle<T: Float + Debug>(a: Point2D<T>, b: Point2D<T>, c: Point2D<T>, d: Point2D<T>) -> bool {
+    let (a, b, c) = (a - d, b - d, c - d);
+    let det = a.dot_product(&a) * b.cross_product(&c) - b.dot_product(&b) * a.cross_product(&c) + c.dot_product(&c) * a.cross_product(&b);
+    det > T::zero()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::sampling::{XorShift, sample_aabb};
+    use crate::polygon::ring;
+    use crate::shape::Aabb2D;
+
+    #[test]
+    fn test_delaunay_circumcircles_are_empty() {
+        let bounds = Aabb2D::new(Point2D::new(-3.0, 0.0), Point2D::new(7.0, 5.0));
+        let mut rng = XorShift::new(9);
+        let points: Vec<Point2D<f64>> = (0..150).map(|_| sample_aabb(&bounds, &mut rng)).collect();
+        let triangles = delaunay_2d(&points);
+        // 2n - 2 - h triangles for h hull points, less any hull slivers lost to the enclosing triangle
+        assert!(triangles.len() > 250);
+        for t in &triangles {
+            let [a, b, c] = t.map(|v| points[v]);
+            assert!(ring::signed_area(&[a, b, c]) > 0.0);
+            assert!(points.iter().all(|&p| !in_circumcircle(a, b, c, p)));
+        }
+    }
+
+    #[test]
+    fn test_delaunay_degenerate_inputs() {
+        assert!(delaunay_2d::<f64>(&[]).is_empty());
+        let line = [Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0), Point2D::new(2.0, 2.0)];
+        assert!(delaunay_2d(&line).is_empty());
+        let square = [Point2D::new(0.0, 0.0), Point2D::new(1.0, 0.0), Point2D::new(1.0, 1.0), Point2D::new(0.0, 1.0), Point2D::new(1.0, 0.0)];
+        assert_eq!(delaunay_2d(&square).len(), 2);
+    }
+}
@@ -0,0 +1,223 @@
+use num::Float;
+use core::cmp::Ordering;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+/// Convex hull that is maintained while points arrive one at a time.
+///
+/// # Algorithm Explanation
+/// The hull is kept as a lower and an upper chain, each a map from x to the
+/// chain vertex at that x. Inserting a point:
+/// 1. Looks up its neighbours on the chain; if it lies on or above the lower
+///    chain (below the upper one) it is inside and nothing changes.
+/// 2. Otherwise it is added, and neighbours on either side are removed while
+///    they no longer make a left turn.
+///
+/// Every point is added and removed at most once, so an insertion costs
+/// **O(log n)** amortized, where `n` is the number of hull vertices.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::incremental_hull::IncrementalHull2D;
+/// use geomengine::point::Point2D;
+///
+/// let mut hull = IncrementalHull2D::new();
+/// for (x, y) in [(0.0, 0.0), (4.0, 0.0), (2.0, 1.0), (2.0, 3.0)] {
+///     hull.insert(Point2D::new(x, y));
+/// }
+/// assert!(!hull.insert(Point2D::new(2.0, 2.0))); // inside
+/// assert_eq!(hull.hull().len(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct IncrementalHull2D<T: Float + Debug> {
+    lower: Chain<T>,
+    // the upper chain is stored as the lower chain of the points mirrored at y = 0
+    upper: Chain<T>,
+}
+
+impl<T: Float + Debug> Default for IncrementalHull2D<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Float + Debug> IncrementalHull2D<T> {
+    /// Creates an empty hull.
+    pub fn new() -> Self {
+        IncrementalHull2D { lower: Chain::default(), upper: Chain::default() }
+    }
+
+    /// Adds a point, returning whether it changed the hull.
+    ///
+    /// Points with a NaN coordinate are ignored.
+    pub fn insert(&mut self, point: Point2D<T>) -> bool {
+        if point.x.is_nan() || point.y.is_nan() {
+            return false;
+        }
+        let lower = self.lower.insert(point);
+        let upper = self.upper.insert(Point2D::new(point.x, -point.y));
+        lower || upper
+    }
+
+    /// Returns `true` if no point has been inserted yet.
+    pub fn is_empty(&self) -> bool {
+        self.lower.0.is_empty()
+    }
+
+    /// The current hull vertices, in the same counterclockwise order as
+    /// [`convex_hull_2d`](super::point_algorithms::convex_hull_2d) returns them.
+    pub fn hull(&self) -> Vec<Point2D<T>> {
+        let lower: Vec<Point2D<T>> = self.lower.0.iter().map(|(x, &y)| Point2D::new(x.0, y)).collect();
+        let upper: Vec<Point2D<T>> = self.upper.0.iter().map(|(x, &y)| Point2D::new(x.0, -y)).collect();
+
+        let mut hull = lower.clone();
+        for (i, p) in upper.iter().enumerate().rev() {
+            let duplicate = (i == upper.len() - 1 && Some(p) == lower.last()) || (i == 0 && Some(p) == lower.first());
+            if !duplicate {
+                hull.push(*p);
+            }
+        }
+        hull
+    }
+}
+
+impl<T: Float + Debug> Extend<Point2D<T>> for IncrementalHull2D<T> {
+    fn extend<I: IntoIterator<Item = Point2D<T>>>(&mut self, points: I) {
+        for point in points {
+            self.insert(point);
+        }
+    }
+}
+
+/// Lower convex chain: strictly left-turning vertices ordered by x, at most one per x.
+#[derive(Debug, Clone)]
+struct Chain<T: Float + Debug>(BTreeMap<Key<T>, T>);
+
+impl<T: Float + Debug> Default for Chain<T> {
+    fn default() -> Self {
+        Chain(BTreeMap::new())
+    }
+}
+
+impl<T: Float + Debug> Chain<T> {
+    fn insert(&mut self, q: Point2D<T>) -> bool {
+        let key = Key(q.x);
+        if let Some(&y) = self.0.get(&key) {
+            if y <= q.y {
+                return false;
+            }
+            self.0.remove(&key);
+        } else if let (Some(p), Some(s)) = (self.before(q.x), self.after(q.x))
+            && turn(p, s, q) >= T::zero()
+        {
+            return false;
+        }
+
+        while let Some(p1) = self.before(q.x) {
+            match self.before(p1.x) {
+                Some(p2) if turn(p2, p1, q) <= T::zero() => self.0.remove(&Key(p1.x)),
+                _ => break,
+            };
+        }
+        while let Some(s1) = self.after(q.x) {
+            match self.after(s1.x) {
+                Some(s2) if turn(q, s1, s2) <= T::zero() => self.0.remove(&Key(s1.x)),
+                _ => break,
+            };
+        }
+        self.0.insert(key, q.y);
+        true
+    }
+
+    /// Chain vertex with the largest x smaller than `x`.
+    fn before(&self, x: T) -> Option<Point2D<T>> {
+        self.0.range(..Key(x)).next_back().map(|(k, &y)| Point2D::new(k.0, y))
+    }
+
+    /// Chain vertex with the smallest x larger than `x`.
+    fn after(&self, x: T) -> Option<Point2D<T>> {
+        use core::ops::Bound::{Excluded, Unbounded};
+        self.0.range((Excluded(Key(x)), Unbounded)).next().map(|(k, &y)| Point2D::new(k.0, y))
+    }
+}
+
+/// Positive if `o -> a -> b` turns left.
+fn turn<T: Float + Debug>(o: Point2D<T>, a: Point2D<T>, b: Point2D<T>) -> T {
+    (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x)
+}
+
+/// Totally ordered map key for non-NaN coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Key<T>(T);
+
+impl<T: Float> Eq for Key<T> {}
+
+impl<T: Float> PartialOrd for Key<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Float> Ord for Key<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.partial_cmp(&other.0).unwrap_or(Ordering::Equal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::point_algorithms::convex_hull_2d;
+
+    #[test]
+    fn test_incremental_hull_matches_convex_hull_2d() {
+        let mut hull = IncrementalHull2D::new();
+        let mut points = Vec::new();
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        for _ in 0..500 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let p = Point2D::new((state % 1000) as f64, ((state >> 20) % 1000) as f64);
+            points.push(p);
+            hull.insert(p);
+            if points.len() >= 3 {
+                assert_eq!(hull.hull(), convex_hull_2d(&points));
+            }
+        }
+    }
+
+    #[test]
+    fn test_incremental_hull_degenerate_inputs() {
+        let mut hull = IncrementalHull2D::new();
+        assert!(hull.is_empty());
+        assert!(hull.hull().is_empty());
+
+        assert!(hull.insert(Point2D::new(1.0, 1.0)));
+        assert!(!hull.insert(Point2D::new(1.0, 1.0)));
+        assert_eq!(hull.hull(), vec![Point2D::new(1.0, 1.0)]);
+
+        // collinear points keep only the two extremes
+        hull.extend([Point2D::new(2.0, 2.0), Point2D::new(3.0, 3.0)]);
+        assert_eq!(hull.hull(), vec![Point2D::new(1.0, 1.0), Point2D::new(3.0, 3.0)]);
+        assert!(!hull.insert(Point2D::new(f64::NAN, 0.0)));
+    }
+
+    #[test]
+    fn test_incremental_hull_vertical_edges() {
+        let mut hull = IncrementalHull2D::new();
+        hull.extend([
+            Point2D::new(0.0, 0.0),
+            Point2D::new(0.0, 2.0),
+            Point2D::new(2.0, 0.0),
+            Point2D::new(2.0, 2.0),
+            Point2D::new(1.0, 1.0),
+        ]);
+        assert_eq!(
+            hull.hull(),
+            vec![Point2D::new(0.0, 0.0), Point2D::new(2.0, 0.0), Point2D::new(2.0, 2.0), Point2D::new(0.0, 2.0)]
+        );
+    }
+}
@@ -0,0 +1,269 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::polygon::ring;
+use crate::shape::{Aabb2D, Triangle2D};
+use super::delaunay::BowyerWatson;
+use super::point_algorithms::bounding_box_2d;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Estimates a scalar field from scattered samples.
+///
+/// Only [`interpolate`](Self::interpolate) has to be implemented; grids are
+/// evaluated through it.
+pub trait Interpolator<T: Float + Debug> {
+    /// Estimated value at `point`, or `None` where the interpolator has no answer.
+    fn interpolate(&self, point: Point2D<T>) -> Option<T>;
+
+    /// Values at `columns` by `rows` evenly spaced nodes spanning `bounds`,
+    /// corners included, row by row from `bounds.min`.
+    fn interpolate_grid(&self, bounds: &Aabb2D<T>, columns: usize, rows: usize) -> Vec<Option<T>> {
+        let step = |extent: T, count: usize| if count > 1 { extent / T::from(count - 1).unwrap() } else { T::zero() };
+        let (dx, dy) = (step(bounds.width(), columns), step(bounds.height(), rows));
+        let mut values = Vec::with_capacity(columns * rows);
+        for row in 0..rows {
+            let y = bounds.min.y + dy * T::from(row).unwrap();
+            for column in 0..columns {
+                values.push(self.interpolate(Point2D::new(bounds.min.x + dx * T::from(column).unwrap(), y)));
+            }
+        }
+        values
+    }
+}
+
+/// Inverse distance weighting (Shepard's method): the average of all samples
+/// weighted by `1 / distance^power`.
+///
+/// # Time Complexity
+/// **O(n)** per query.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::interpolation::{InverseDistanceWeighting, Interpolator};
+/// use geomengine::point::Point2D;
+///
+/// let samples = [Point2D::new(0.0, 0.0), Point2D::new(4.0, 0.0)];
+/// let idw = InverseDistanceWeighting::new(&samples, &[1.0, 3.0], 2.0);
+/// assert_eq!(idw.interpolate(Point2D::new(2.0, 0.0)), Some(2.0));
+/// assert_eq!(idw.interpolate(Point2D::new(1.0, 0.0)), Some(1.2));
+/// assert_eq!(idw.interpolate(Point2D::new(4.0, 0.0)), Some(3.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct InverseDistanceWeighting<T: Float + Debug> {
+    samples: Vec<Point2D<T>>,
+    values: Vec<T>,
+    power: T,
+}
+
+impl<T: Float + Debug> InverseDistanceWeighting<T> {
+    /// # Panics
+    /// If `samples` and `values` differ in length.
+    pub fn new(samples: &[Point2D<T>], values: &[T], power: T) -> Self {
+        assert_eq!(samples.len(), values.len(), "every sample needs exactly one value");
+        InverseDistanceWeighting { samples: samples.to_vec(), values: values.to_vec(), power }
+    }
+}
+
+impl<T: Float + Debug> Interpolator<T> for InverseDistanceWeighting<T> {
+    /// The weighted average, the value of a sample hit exactly, or `None` without samples.
+    fn interpolate(&self, point: Point2D<T>) -> Option<T> {
+        let (mut weighted, mut total) = (T::zero(), T::zero());
+        for (sample, &value) in self.samples.iter().zip(&self.values) {
+            let offset = *sample - point;
+            let distance_sq = offset.dot_product(&offset);
+            if distance_sq == T::zero() {
+                return Some(value);
+            }
+            // (d²)^(p/2) avoids the square root
+            let weight = distance_sq.powf(self.power / (T::one() + T::one())).recip();
+            weighted = weighted + weight * value;
+            total = total + weight;
+        }
+        (total > T::zero()).then(|| weighted / total)
+    }
+}
+
+/// Natural neighbour (Sibson) interpolation over the Delaunay triangulation of the samples.
+///
+/// # Algorithm Explanation
+/// - Inserting the query point into the Voronoi diagram of the samples gives it
+///   a cell carved out of the cells of its natural neighbours: the corners of
+///   the Delaunay triangles whose circumcircle holds it.
+/// - The weight of each neighbour is the share of the new cell taken from its
+///   old cell. The new cell is cut from the box around its corners, the
+///   circumcenters of the query point with each edge of the cavity, by the
+///   bisectors to the neighbours; each share is cut out of it the same way.
+/// - The result is smooth away from the samples, exact at them and reproduces
+///   linear functions. Outside the convex hull of the samples the new cell is
+///   unbounded and there is no estimate.
+/// - The triangulation is built inside a finite super triangle, 1000 times the
+///   extent of the samples. Near the hull, chiefly along long thin hull edges,
+///   the query's circumcircles can still reach a super triangle corner; such
+///   points also get no estimate, though they lie inside the hull.
+///
+/// # Time Complexity
+/// - Construction: O(n²) for the triangulation.
+/// - Query: O(n) to find the cavity.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::interpolation::{Interpolator, NaturalNeighbor};
+/// use geomengine::point::Point2D;
+///
+/// let samples = [Point2D::new(0.0, 0.0), Point2D::new(4.0, 0.0), Point2D::new(4.0, 4.0), Point2D::new(0.0, 4.0)];
+/// let heights = [0.0f64, 4.0, 8.0, 4.0];
+/// let nn = NaturalNeighbor::new(&samples, &heights);
+/// let value = nn.interpolate(Point2D::new(1.0, 2.0)).unwrap();
+/// assert!((value - 3.0).abs() < 1e-9);
+/// assert_eq!(nn.interpolate(Point2D::new(5.0, 2.0)), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct NaturalNeighbor<T: Float + Debug> {
+    triangulation: BowyerWatson<T>,
+    values: Vec<T>,
+}
+
+impl<T: Float + Debug> NaturalNeighbor<T> {
+    /// Triangulates the samples; of duplicate samples only the first one counts.
+    ///
+    /// # Panics
+    /// If `samples` and `values` differ in length.
+    pub fn new(samples: &[Point2D<T>], values: &[T]) -> Self {
+        assert_eq!(samples.len(), values.len(), "every sample needs exactly one value");
+        NaturalNeighbor { triangulation: BowyerWatson::new(samples), values: values.to_vec() }
+    }
+
+    /// Sibson weights of the natural neighbours of `point` as `(sample, weight)`
+    /// pairs summing to one, or `None` outside the convex hull of the samples
+    /// and for some points just inside it (see the type's documentation).
+    pub fn weights(&self, point: Point2D<T>) -> Option<Vec<(usize, T)>> {
+        let bw = &self.triangulation;
+        let cavity = bw.cavity(point);
+        if cavity.is_empty() {
+            // only a sample itself is inside no circumcircle
+            return bw.points[..self.values.len()].iter().position(|&p| p == point).map(|i| vec![(i, T::one())]);
+        }
+        let mut neighbors: Vec<usize> = cavity.iter().flat_map(|&t| bw.triangles[t]).collect();
+        neighbors.sort_unstable();
+        neighbors.dedup();
+        if neighbors.iter().any(|&v| bw.is_super(v)) {
+            return None;
+        }
+
+        let corners = bw
+            .cavity_boundary(&cavity)
+            .into_iter()
+            .map(|(a, b)| Triangle2D::new(point, bw.points[a], bw.points[b]).circumcenter())
+            .collect::<Option<Vec<_>>>()?;
+        let mut cell = bounding_box_2d(&corners)?.corners().to_vec();
+        for &v in &neighbors {
+            cell = clip_closer(&cell, point, bw.points[v]);
+        }
+
+        let mut weights: Vec<(usize, T)> = neighbors
+            .iter()
+            .map(|&v| {
+                let share = neighbors.iter().filter(|&&u| u != v).fold(cell.clone(), |share, &u| clip_closer(&share, bw.points[v], bw.points[u]));
+                (v, ring::signed_area(&share).abs())
+            })
+            .collect();
+        let total = weights.iter().fold(T::zero(), |total, &(_, w)| total + w);
+        if total <= T::zero() {
+            return None;
+        }
+        for (_, w) in &mut weights {
+            *w = *w / total;
+        }
+        Some(weights)
+    }
+}
+
+impl<T: Float + Debug> Interpolator<T> for NaturalNeighbor<T> {
+    /// The Sibson-weighted average of the natural neighbours, or `None` where
+    /// [`weights`](NaturalNeighbor::weights) gives none.
+    fn interpolate(&self, point: Point2D<T>) -> Option<T> {
+        let weights = self.weights(point)?;
+        Some(weights.iter().fold(T::zero(), |sum, &(v, w)| sum + w * self.values[v]))
+    }
+}
+
+/// Part of the convex polygon `ring` at least as close to `a` as to `b`.
+fn clip_closer<T: Float + Debug>(ring: &[Point2D<T>], a: Point2D<T>, b: Point2D<T>) -> Vec<Point2D<T>> {
+    let normal = b - a;
+    let middle = (a + b) / (T::one() + T::one());
+    let side = |p: Point2D<T>| (p - middle).dot_product(&normal);
+    let mut clipped = Vec::with_capacity(ring.len() + 1);
+    for (i, &p) in ring.iter().enumerate() {
+        let q = ring[(i + 1) % ring.len()];
+        let (sp, sq) = (side(p), side(q));
+        if sp <= T::zero() {
+            clipped.push(p);
+        }
+        if (sp <= T::zero()) != (sq <= T::zero()) {
+            clipped.push(p + (q - p) * (sp / (sp - sq)));
+        }
+    }
+    clipped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::sampling::{Rng, XorShift, sample_aabb};
+    use approx::assert_relative_eq;
+
+    fn plane(p: Point2D<f64>) -> f64 {
+        2.0 * p.x - 3.0 * p.y + 1.0
+    }
+
+    #[test]
+    fn test_natural_neighbor_reproduces_linear_fields() {
+        let mut rng = XorShift::new(21);
+        let samples: Vec<Point2D<f64>> = sample_grid_jittered(&mut rng);
+        let values: Vec<f64> = samples.iter().map(|&p| plane(p)).collect();
+        let nn = NaturalNeighbor::new(&samples, &values);
+
+        let inner = Aabb2D::new(Point2D::new(2.0, 2.0), Point2D::new(8.0, 8.0));
+        for _ in 0..300 {
+            let point = sample_aabb(&inner, &mut rng);
+            let weights = nn.weights(point).unwrap();
+            assert_relative_eq!(weights.iter().map(|w| w.1).sum::<f64>(), 1.0, epsilon = 1e-9);
+            assert!(weights.iter().all(|w| w.1 >= 0.0));
+            assert_relative_eq!(nn.interpolate(point).unwrap(), plane(point), epsilon = 1e-8);
+        }
+        assert_eq!(nn.interpolate(samples[17]), Some(values[17]));
+        assert_eq!(nn.interpolate(Point2D::new(-5.0, 5.0)), None);
+    }
+
+    fn sample_grid_jittered(rng: &mut XorShift) -> Vec<Point2D<f64>> {
+        (0..121)
+            .map(|i| Point2D::new((i % 11) as f64 + 0.3 * rng.unit::<f64>(), (i / 11) as f64 + 0.3 * rng.unit::<f64>()))
+            .collect()
+    }
+
+    #[test]
+    fn test_inverse_distance_weighting() {
+        let samples = [Point2D::new(0.0, 0.0), Point2D::new(1.0, 0.0), Point2D::new(0.0, 1.0)];
+        let idw = InverseDistanceWeighting::new(&samples, &[3.0, 6.0, 9.0], 1.0);
+        assert_eq!(idw.interpolate(Point2D::new(0.0, 1.0)), Some(9.0));
+        // equidistant from the last two samples, twice as far from the first
+        let value = idw.interpolate(Point2D::new(1.0, 1.0)).unwrap();
+        let w = 1.0 / 2f64.sqrt();
+        assert_relative_eq!(value, (3.0 * w + 6.0 + 9.0) / (w + 2.0), epsilon = 1e-12);
+        assert_eq!(InverseDistanceWeighting::<f64>::new(&[], &[], 2.0).interpolate(Point2D::new(0.0, 0.0)), None);
+    }
+
+    #[test]
+    fn test_interpolate_grid() {
+        let samples = [Point2D::new(0.0, 0.0), Point2D::new(2.0, 0.0), Point2D::new(2.0, 2.0), Point2D::new(0.0, 2.0)];
+        let values: Vec<f64> = samples.iter().map(|&p| plane(p)).collect();
+        let nn = NaturalNeighbor::new(&samples, &values);
+        let grid = nn.interpolate_grid(&Aabb2D::new(Point2D::new(0.0, 0.0), Point2D::new(2.0, 2.0)), 3, 2);
+        assert_eq!(grid.len(), 6);
+        assert_eq!(grid[0], Some(plane(samples[0])));
+        assert_eq!(grid[5], Some(plane(samples[2])));
+        // the middle of the bottom edge lies on the hull, where the cell is unbounded
+        assert_eq!(grid[1], None);
+    }
+}
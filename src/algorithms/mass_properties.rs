@@ -0,0 +1,207 @@
+use num::{Float, Zero};
+use core::fmt::Debug;
+use crate::point::{Point2D, Vector2D};
+use crate::polygon::Polygon2D;
+
+/// Mass, centroid and second moments of a shape of uniform density.
+///
+/// Moments are taken about axes through the centroid parallel to the coordinate
+/// axes: `ixx` is `∫ y² dm`, `iyy` is `∫ x² dm` and `ixy` the product moment `∫ x y dm`,
+/// with `x` and `y` measured from the centroid. For polygons these are the area
+/// moments used for beam sections.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MassProperties2D<T: Float + Debug> {
+    /// Area of a polygon, number of points of a point set.
+    pub mass: T,
+    pub centroid: Point2D<T>,
+    pub ixx: T,
+    pub iyy: T,
+    pub ixy: T,
+}
+
+impl<T: Float + Debug> MassProperties2D<T> {
+    /// Moment about the axis through the centroid perpendicular to the plane, `ixx + iyy`.
+    pub fn polar_moment(&self) -> T {
+        self.ixx + self.iyy
+    }
+
+    /// Radii of gyration `(√(ixx / mass), √(iyy / mass))` about the centroidal x and y axes.
+    pub fn radius_of_gyration(&self) -> (T, T) {
+        ((self.ixx / self.mass).sqrt(), (self.iyy / self.mass).sqrt())
+    }
+
+    /// The largest and smallest moment about any axis through the centroid.
+    pub fn principal_moments(&self) -> [T; 2] {
+        let two = T::one() + T::one();
+        let (mean, radius) = ((self.ixx + self.iyy) / two, ((self.ixx - self.iyy) / two).hypot(self.ixy));
+        [mean + radius, mean - radius]
+    }
+
+    /// Angle in radians from the x axis, in `(-π/2, π/2]`, of the axis with the
+    /// largest moment; the smallest is about the perpendicular axis.
+    pub fn principal_angle(&self) -> T {
+        let two = T::one() + T::one();
+        let angle = (-(self.ixy + self.ixy)).atan2(self.ixx - self.iyy) / two;
+        let half_pi = T::from(core::f64::consts::FRAC_PI_2).unwrap();
+        if angle <= -half_pi { angle + half_pi + half_pi } else { angle }
+    }
+}
+
+/// [`MassProperties2D`] of the area of `exterior` minus `holes`.
+///
+/// # Returns
+/// * The properties, or `None` if the remaining area is not positive. Either
+///   winding is accepted for every ring.
+///
+/// # Algorithm Explanation
+/// Green's theorem turns each area integral into a sum over the edges: with
+/// `cᵢ = xᵢ yᵢ₊₁ - xᵢ₊₁ yᵢ`, the area is `Σ cᵢ / 2` and, for example,
+/// `∫ x² dA = Σ (xᵢ² + xᵢ xᵢ₊₁ + xᵢ₊₁²) cᵢ / 12`. The sums are taken relative
+/// to the first exterior vertex, so large coordinates do not cancel, and moved
+/// to the centroid with the parallel axis theorem.
+///
+/// # Time Complexity
+/// **O(n)** in the total number of vertices.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::mass_properties::polygon_mass_properties;
+/// use geomengine::point::Point2D;
+/// use geomengine::polygon::Polygon2D;
+///
+/// // a 6 × 2 beam section: b h³ / 12 about the horizontal axis
+/// let section = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(6.0, 0.0), Point2D::new(6.0, 2.0), Point2D::new(0.0, 2.0)]);
+/// let properties = polygon_mass_properties(&section, &[]).unwrap();
+/// assert_eq!(properties.mass, 12.0);
+/// assert_eq!(properties.centroid, Point2D::new(3.0, 1.0));
+/// assert_eq!(properties.ixx, 4.0);
+/// assert_eq!(properties.iyy, 36.0);
+/// assert_eq!(properties.ixy, 0.0);
+/// ```
+pub fn polygon_mass_properties<T: Float + Debug>(exterior: &Polygon2D<T>, holes: &[Polygon2D<T>]) -> Option<MassProperties2D<T>> {
+    let origin = *exterior.vertices().first()?;
+    let mut total = ring_integrals(exterior.vertices(), origin);
+    for hole in holes {
+        let hole = ring_integrals(hole.vertices(), origin);
+        total.iter_mut().zip(hole).for_each(|(sum, part)| *sum = *sum - part);
+    }
+    let [area, x, y, xx, yy, xy] = total;
+    if area <= T::zero() || area.is_nan() {
+        return None;
+    }
+    let centroid = Point2D::new(x / area, y / area);
+    Some(MassProperties2D {
+        mass: area,
+        centroid: origin + centroid,
+        ixx: yy - area * centroid.y * centroid.y,
+        iyy: xx - area * centroid.x * centroid.x,
+        ixy: xy - area * centroid.x * centroid.y,
+    })
+}
+
+/// [`MassProperties2D`] of `points`, each of unit mass.
+///
+/// # Returns
+/// * The properties, or `None` if there are no points.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::mass_properties::point_mass_properties;
+/// use geomengine::point::Point2D;
+///
+/// let points = [Point2D::new(-1.0, -1.0), Point2D::new(1.0, 1.0), Point2D::new(3.0, 3.0)];
+/// let properties = point_mass_properties(&points).unwrap();
+/// assert_eq!(properties.centroid, Point2D::new(1.0, 1.0));
+/// // all mass on the diagonal: nothing about it, everything about the axis across it
+/// assert_eq!(properties.principal_moments(), [16.0, 0.0]);
+/// assert!((properties.principal_angle() + std::f64::consts::FRAC_PI_4).abs() < 1e-12);
+/// ```
+pub fn point_mass_properties<T: Float + Debug>(points: &[Point2D<T>]) -> Option<MassProperties2D<T>> {
+    let origin = *points.first()?;
+    let mass = T::from(points.len()).unwrap();
+    let mean = points.iter().fold(Vector2D::zero(), |sum, &p| sum + (p - origin)) / mass;
+    let (mut ixx, mut iyy, mut ixy) = (T::zero(), T::zero(), T::zero());
+    for &p in points {
+        let d = p - origin - mean;
+        ixx = ixx + d.y * d.y;
+        iyy = iyy + d.x * d.x;
+        ixy = ixy + d.x * d.y;
+    }
+    Some(MassProperties2D { mass, centroid: origin + mean, ixx, iyy, ixy })
+}
+
+/// `[∫ dA, ∫ x dA, ∫ y dA, ∫ x² dA, ∫ y² dA, ∫ x y dA]` over the area of `ring`,
+/// with coordinates relative to `origin`, positive whatever the winding.
+fn ring_integrals<T: Float + Debug>(ring: &[Point2D<T>], origin: Point2D<T>) -> [T; 6] {
+    let mut sums = [T::zero(); 6];
+    for i in 0..ring.len() {
+        let (a, b) = (ring[i] - origin, ring[(i + 1) % ring.len()] - origin);
+        let c = a.cross_product(&b);
+        let two = T::one() + T::one();
+        let terms = [
+            T::one(),
+            a.x + b.x,
+            a.y + b.y,
+            a.x * a.x + a.x * b.x + b.x * b.x,
+            a.y * a.y + a.y * b.y + b.y * b.y,
+            a.x * b.y + two * a.x * a.y + two * b.x * b.y + b.x * a.y,
+        ];
+        sums.iter_mut().zip(terms).for_each(|(sum, term)| *sum = *sum + term * c);
+    }
+    let divisors = [2.0, 6.0, 6.0, 12.0, 12.0, 24.0];
+    let sign = if sums[0] < T::zero() { -T::one() } else { T::one() };
+    sums.iter_mut().zip(divisors).for_each(|(sum, divisor)| *sum = *sum * sign / T::from(divisor).unwrap());
+    sums
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transform::{Angle, Transform2D, Transformable};
+    use approx::assert_relative_eq;
+    use alloc::vec::Vec;
+
+    fn rectangle(width: f64, height: f64) -> Polygon2D<f64> {
+        let (w, h) = (width / 2.0, height / 2.0);
+        Polygon2D::new(vec![Point2D::new(-w, -h), Point2D::new(w, -h), Point2D::new(w, h), Point2D::new(-w, h)])
+    }
+
+    #[test]
+    fn test_polygon_mass_properties_rotated_and_with_holes() {
+        // turned by 30° and moved far away, clockwise: principal moments are those of the rectangle
+        let placed = rectangle(6.0, 2.0)
+            .apply(&Transform2D::rotation(Angle::from_degrees(30.0)).then(&Transform2D::translation(1e5, 2e5)));
+        let clockwise = Polygon2D::new(placed.vertices().iter().rev().copied().collect());
+        let properties = polygon_mass_properties(&clockwise, &[]).unwrap();
+        assert_relative_eq!(properties.mass, 12.0, epsilon = 1e-6);
+        assert_relative_eq!(properties.centroid.x, 1e5, epsilon = 1e-6);
+        let [largest, smallest] = properties.principal_moments();
+        assert_relative_eq!(largest, 36.0, epsilon = 1e-6);
+        assert_relative_eq!(smallest, 4.0, epsilon = 1e-6);
+        // the long side lies at 30°, so the largest moment is about the axis across it
+        assert_relative_eq!(properties.principal_angle().to_degrees(), -60.0, epsilon = 1e-6);
+        assert_relative_eq!(properties.polar_moment(), 40.0, epsilon = 1e-6);
+
+        // a hollow square tube: outer minus inner, either winding
+        let hole = Polygon2D::new(rectangle(2.0, 2.0).vertices().iter().rev().copied().collect());
+        let tube = polygon_mass_properties(&rectangle(4.0, 4.0), &[hole]).unwrap();
+        assert_relative_eq!(tube.mass, 12.0);
+        assert_relative_eq!(tube.ixx, (256.0 - 16.0) / 12.0, epsilon = 1e-12);
+        assert_relative_eq!(tube.radius_of_gyration().0, (20.0f64 / 12.0).sqrt(), epsilon = 1e-12);
+        assert_eq!(polygon_mass_properties(&rectangle(2.0, 2.0), &[rectangle(2.0, 2.0)]), None);
+        assert_eq!(polygon_mass_properties(&Polygon2D::<f64>::new(Vec::new()), &[]), None);
+    }
+
+    #[test]
+    fn test_point_mass_properties_matches_definition() {
+        let points: Vec<Point2D<f64>> = (0..10).map(|i| Point2D::new(i as f64, (i * i % 7) as f64)).collect();
+        let properties = point_mass_properties(&points).unwrap();
+        let mean = points.iter().fold(Point2D::origin(), |sum, &p| sum + p) / 10.0;
+        let ixy: f64 = points.iter().map(|p| (p.x - mean.x) * (p.y - mean.y)).sum();
+        assert_relative_eq!(properties.ixy, ixy, epsilon = 1e-9);
+        assert_relative_eq!(properties.iyy, 82.5, epsilon = 1e-9);
+        let [largest, smallest] = properties.principal_moments();
+        assert!(largest >= properties.ixx.max(properties.iyy) && smallest <= properties.ixx.min(properties.iyy));
+        assert_eq!(point_mass_properties::<f64>(&[]), None);
+    }
+}
@@ -1 +1,42 @@
 pub mod point_algorithms;
+pub mod batch;
+#[cfg(feature = "parallel")]
+pub mod parallel;
+pub mod incremental_hull;
+pub mod accumulators;
+pub mod scratch;
+pub mod overlay;
+pub mod union;
+pub mod noding;
+pub mod polygonize;
+pub mod snapping;
+pub mod relate;
+pub mod dcel;
+pub mod arrangement;
+pub mod topology;
+pub mod triangulate;
+pub mod raster;
+pub mod sdf;
+pub mod contour;
+pub mod tessellate;
+pub mod stroke;
+pub mod buffer;
+pub mod simplify;
+pub mod sampling;
+pub mod similarity;
+pub mod resample;
+pub mod path_stats;
+pub mod morph;
+pub mod symmetry;
+pub mod pca;
+pub mod clip;
+pub mod self_intersection;
+pub mod validation;
+pub mod constraints;
+pub mod mass_properties;
+pub mod packing;
+pub mod delaunay;
+pub mod interpolation;
+mod planar_graph;
+
+pub(crate) use planar_graph::merge_points;
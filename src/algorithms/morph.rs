@@ -0,0 +1,198 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::polygon::{Polygon2D, ring};
+use alloc::vec::Vec;
+
+/// Samples compared per candidate start when matching the rings up.
+const ALIGNMENT_SAMPLES: usize = 64;
+
+/// Tweens between two polygons, precomputed once for many frames.
+///
+/// # Algorithm Explanation
+/// - Both rings are put in the same orientation and parameterized by the
+///   fraction of their perimeter travelled from a start vertex.
+/// - The start on `b` is the vertex that, with both shapes centered on their
+///   mean sample, minimizes the summed squared distances between 64 samples at
+///   matching fractions, so the shape turns as little as possible on the way.
+/// - The vertices of both rings, at their fractions, are merged into one list
+///   and each ring is evaluated at all of them. Both then have the same vertex
+///   count without losing any corner, and corresponding vertices are
+///   interpolated linearly.
+///
+/// # Time Complexity
+/// **O(m (n + m))** to build for rings of `n` and `m` vertices, **O(n + m)** per frame.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::morph::PolygonMorph;
+/// use geomengine::point::Point2D;
+/// use geomengine::polygon::Polygon2D;
+/// use geomengine::shape::Area;
+///
+/// let square = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(2.0, 0.0), Point2D::new(2.0, 2.0), Point2D::new(0.0, 2.0)]);
+/// let diamond = Polygon2D::new(vec![Point2D::new(1.0, -1.0), Point2D::new(3.0, 1.0), Point2D::new(1.0, 3.0), Point2D::new(-1.0, 1.0)]);
+/// let morph = PolygonMorph::new(&square, &diamond).unwrap();
+/// assert_eq!(morph.at(0.0).area(), 4.0);
+/// assert_eq!(morph.at(1.0).area(), 8.0);
+/// // the corners line up a quarter of the perimeter apart on both, so no vertices are added
+/// let halfway = morph.at(0.5);
+/// assert_eq!(halfway.len(), 4);
+/// assert!(halfway.area() > 4.0 && halfway.area() < 8.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PolygonMorph<T: Float + Debug> {
+    from: Vec<Point2D<T>>,
+    to: Vec<Point2D<T>>,
+}
+
+impl<T: Float + Debug> PolygonMorph<T> {
+    /// Matches up the rings of `a` and `b`, or returns `None` if either has
+    /// fewer than three vertices or no perimeter.
+    pub fn new(a: &Polygon2D<T>, b: &Polygon2D<T>) -> Option<Self> {
+        let a = Ring::new(a.vertices().to_vec())?;
+        let mut b_vertices = b.vertices().to_vec();
+        if (ring::signed_area(a.vertices.as_slice()) < T::zero()) != (ring::signed_area(&b_vertices) < T::zero()) {
+            b_vertices.reverse();
+        }
+        let b = Ring::new(b_vertices)?;
+
+        let samples: Vec<T> = (0..ALIGNMENT_SAMPLES).map(|k| T::from(k).unwrap() / T::from(ALIGNMENT_SAMPLES).unwrap()).collect();
+        let a_samples = centered(samples.iter().map(|&s| a.at(s)).collect());
+        let shift = (0..b.vertices.len())
+            .map(|start| {
+                let b_samples = centered(samples.iter().map(|&s| b.at(b.fractions[start] + s)).collect());
+                let cost = a_samples.iter().zip(&b_samples).fold(T::zero(), |sum, (p, q)| {
+                    let d = *p - *q;
+                    sum + d.dot_product(&d)
+                });
+                (cost, b.fractions[start])
+            })
+            .min_by(|x, y| x.0.partial_cmp(&y.0).unwrap())
+            .map(|(_, shift)| shift)?;
+
+        let mut fractions: Vec<T> = a.fractions.iter().copied().chain(b.fractions.iter().map(|&f| wrap(f - shift))).collect();
+        fractions.sort_by(|x, y| x.partial_cmp(y).unwrap());
+        let tolerance = T::epsilon().sqrt();
+        fractions.dedup_by(|x, y| *x - *y <= tolerance);
+        if fractions.len() > 1 && T::one() - fractions[fractions.len() - 1] <= tolerance {
+            fractions.pop();
+        }
+        Some(PolygonMorph {
+            from: fractions.iter().map(|&f| a.at(f)).collect(),
+            to: fractions.iter().map(|&f| b.at(f + shift)).collect(),
+        })
+    }
+
+    /// The shape at time `t`: `a` at 0, `b` at 1, extrapolated outside that range.
+    pub fn at(&self, t: T) -> Polygon2D<T> {
+        Polygon2D::new(self.from.iter().zip(&self.to).map(|(&p, &q)| p + (q - p) * t).collect())
+    }
+}
+
+/// Interpolates between `a` and `b` at time `t` (0 gives `a`, 1 gives `b`);
+/// see [`PolygonMorph`] for how the rings are matched up. `None` if either has
+/// fewer than three vertices or no perimeter.
+pub fn morph<T: Float + Debug>(a: &Polygon2D<T>, b: &Polygon2D<T>, t: T) -> Option<Polygon2D<T>> {
+    PolygonMorph::new(a, b).map(|morph| morph.at(t))
+}
+
+/// A closed ring parameterized by the fraction of its perimeter.
+struct Ring<T: Float + Debug> {
+    vertices: Vec<Point2D<T>>,
+    // the fraction of the perimeter at each vertex, starting at 0
+    fractions: Vec<T>,
+}
+
+impl<T: Float + Debug> Ring<T> {
+    fn new(vertices: Vec<Point2D<T>>) -> Option<Self> {
+        if vertices.len() < 3 {
+            return None;
+        }
+        let mut fractions = Vec::with_capacity(vertices.len());
+        let mut travelled = T::zero();
+        for i in 0..vertices.len() {
+            fractions.push(travelled);
+            travelled = travelled + vertices[i].distance(&vertices[(i + 1) % vertices.len()]);
+        }
+        if travelled <= T::zero() {
+            return None;
+        }
+        fractions.iter_mut().for_each(|f| *f = *f / travelled);
+        Some(Ring { vertices, fractions })
+    }
+
+    /// The point at `fraction` of the perimeter, wrapping around.
+    fn at(&self, fraction: T) -> Point2D<T> {
+        let fraction = wrap(fraction);
+        let i = self.fractions.partition_point(|&f| f <= fraction) - 1;
+        let (start, end) = (self.fractions[i], self.fractions.get(i + 1).copied().unwrap_or(T::one()));
+        let (p, q) = (self.vertices[i], self.vertices[(i + 1) % self.vertices.len()]);
+        if end <= start { p } else { p + (q - p) * ((fraction - start) / (end - start)) }
+    }
+}
+
+/// `fraction` brought into `0..1`.
+fn wrap<T: Float>(fraction: T) -> T {
+    let wrapped = fraction - fraction.floor();
+    if wrapped >= T::one() { T::zero() } else { wrapped }
+}
+
+fn centered<T: Float + Debug>(mut points: Vec<Point2D<T>>) -> Vec<Point2D<T>> {
+    let count = T::from(points.len()).unwrap();
+    let mean = points.iter().fold(Point2D::new(T::zero(), T::zero()), |sum, &p| sum + p) / count;
+    points.iter_mut().for_each(|p| *p = Point2D::from(*p - mean));
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shape::Area;
+    use approx::assert_relative_eq;
+
+    fn polygon(points: &[(f64, f64)]) -> Polygon2D<f64> {
+        Polygon2D::new(points.iter().map(|&(x, y)| Point2D::new(x, y)).collect())
+    }
+
+    #[test]
+    fn test_morph_endpoints_keep_shapes() {
+        let triangle = polygon(&[(0.0, 0.0), (4.0, 0.0), (0.0, 3.0)]);
+        let hexagon: Vec<(f64, f64)> = (0..6).map(|i| {
+            let angle = i as f64 * core::f64::consts::FRAC_PI_3;
+            (10.0 + 2.0 * angle.cos(), 2.0 * angle.sin())
+        }).collect();
+        // given clockwise, matched up counterclockwise
+        let hexagon = polygon(&hexagon.into_iter().rev().collect::<Vec<_>>());
+        let tween = PolygonMorph::new(&triangle, &hexagon).unwrap();
+        let (start, end) = (tween.at(0.0), tween.at(1.0));
+        assert_eq!(start.len(), end.len());
+        assert_relative_eq!(start.area(), 6.0, epsilon = 1e-9);
+        assert_relative_eq!(end.area(), hexagon.area(), epsilon = 1e-9);
+        // every original corner survives
+        for corner in triangle.vertices() {
+            assert!(start.vertices().iter().any(|p| p.distance(corner) < 1e-9));
+        }
+        for corner in hexagon.vertices() {
+            assert!(end.vertices().iter().any(|p| p.distance(corner) < 1e-9));
+        }
+        assert!(ring::signed_area(tween.at(0.5).vertices()) > 0.0);
+        assert_eq!(morph(&triangle, &polygon(&[(0.0, 0.0), (1.0, 1.0)]), 0.5), None);
+    }
+
+    #[test]
+    fn test_morph_minimizes_rotation() {
+        // the same square listed from a different start vertex: no vertex should travel
+        let a = polygon(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]);
+        let b = polygon(&[(1.0, 1.0), (0.0, 1.0), (0.0, 0.0), (1.0, 0.0)]);
+        let tween = PolygonMorph::new(&a, &b).unwrap();
+        for (p, q) in tween.from.iter().zip(&tween.to) {
+            assert!(p.distance(q) < 1e-12);
+        }
+        // moved square: every vertex travels by the same offset
+        let moved = polygon(&[(5.0, 6.0), (6.0, 6.0), (6.0, 7.0), (5.0, 7.0)]);
+        let halfway = morph(&b, &moved, 0.5).unwrap();
+        assert_eq!(halfway.len(), 4);
+        assert!(halfway.vertices().contains(&Point2D::new(2.5, 3.0)));
+    }
+}
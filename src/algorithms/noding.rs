@@ -0,0 +1,217 @@
+use num::Float;
+use core::cmp::Ordering;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::line::Line2D;
+use alloc::vec::Vec;
+
+/// Snapping tolerance for a set of coordinates, relative to their magnitude.
+///
+/// Intersection points are computed in floating point, so two segments crossing
+/// at "the same" location produce slightly different coordinates; everything
+/// closer than this tolerance is treated as one vertex.
+pub(crate) fn snap_tolerance<T: Float + Debug>(points: impl IntoIterator<Item = Point2D<T>>) -> T {
+    let scale = points
+        .into_iter()
+        .fold(T::zero(), |acc, p| acc.max(p.x.abs()).max(p.y.abs()));
+    if scale == T::zero() {
+        T::epsilon()
+    } else {
+        scale * T::epsilon().sqrt()
+    }
+}
+
+/// Splits every segment at all points where it touches or crosses another one.
+///
+/// # Arguments
+/// * `segments` - Raw linework, e.g. digitized boundaries that cross each other.
+///
+/// # Returns
+/// * Pieces that only meet at their endpoints. Each input segment is replaced by
+///   its pieces in order; zero-length segments are dropped.
+///
+/// Coordinates closer than a small tolerance relative to the input's magnitude are
+/// treated as touching. A crossing is computed once and shared by both segments,
+/// and an endpoint touching another segment becomes that segment's split vertex,
+/// so pieces meeting at a node have bit-identical endpoints.
+///
+/// # Time Complexity
+/// A sweep over the segments sorted by their left end only pairs segments whose
+/// x-ranges overlap: **O(n log n + k)** for k overlapping pairs, **O(n²)** at worst.
+pub fn node_segments_2d<T: Float + Debug>(segments: &[Line2D<T>]) -> Vec<Line2D<T>> {
+    let tolerance = snap_tolerance(segments.iter().flat_map(|s| [s.p1, s.p2]));
+    node_segments(segments, tolerance)
+}
+
+/// [`node_segments_2d`] with an explicit snapping tolerance.
+pub(crate) fn node_segments<T: Float + Debug>(segments: &[Line2D<T>], tolerance: T) -> Vec<Line2D<T>> {
+    let mut noded = Vec::with_capacity(segments.len());
+    node_segments_with(segments, tolerance, &mut NodingBuffers::default(), &mut noded);
+    noded
+}
+
+/// Intermediate buffers of the noding step, kept between calls to avoid reallocating them.
+#[derive(Debug, Clone)]
+pub(crate) struct NodingBuffers<T: Float + Debug> {
+    segments: Vec<Line2D<T>>,
+    splits: Vec<Vec<(T, Point2D<T>)>>,
+    order: Vec<usize>,
+}
+
+impl<T: Float + Debug> Default for NodingBuffers<T> {
+    fn default() -> Self {
+        NodingBuffers { segments: Vec::new(), splits: Vec::new(), order: Vec::new() }
+    }
+}
+
+/// [`node_segments`] writing into `noded` (which is cleared first) and reusing `buffers`.
+pub(crate) fn node_segments_with<T: Float + Debug>(segments: &[Line2D<T>], tolerance: T, buffers: &mut NodingBuffers<T>, noded: &mut Vec<Line2D<T>>) {
+    let NodingBuffers { segments: kept, splits, order } = buffers;
+    kept.clear();
+    kept.extend(segments.iter().filter(|s| s.length() > tolerance).cloned());
+
+    // the per-segment lists keep their capacity from earlier calls
+    splits.iter_mut().for_each(Vec::clear);
+    if splits.len() < kept.len() {
+        splits.resize_with(kept.len(), Vec::new);
+    }
+
+    // sweep left to right; a segment only meets those starting before its right end
+    let min_x = |s: &Line2D<T>| s.p1.x.min(s.p2.x);
+    order.clear();
+    order.extend(0..kept.len());
+    order.sort_by(|&a, &b| min_x(&kept[a]).partial_cmp(&min_x(&kept[b])).unwrap_or(Ordering::Equal));
+    for (k, &i) in order.iter().enumerate() {
+        let right = kept[i].p1.x.max(kept[i].p2.x) + tolerance;
+        for &j in order[k + 1..].iter().take_while(|&&j| min_x(&kept[j]) <= right) {
+            split_pair(kept, i.min(j), i.max(j), tolerance, splits);
+        }
+    }
+
+    noded.clear();
+    for (segment, params) in kept.iter().zip(splits.iter_mut()) {
+        params.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+        let length = segment.length();
+
+        let mut start = segment.p1;
+        let mut last_param = T::zero();
+        for &(t, point) in params.iter() {
+            if (t - last_param) * length <= tolerance {
+                continue;
+            }
+            noded.push(Line2D::new(start, point));
+            start = point;
+            last_param = t;
+        }
+        noded.push(Line2D::new(start, segment.p2));
+    }
+}
+
+fn split_pair<T: Float + Debug>(segments: &[Line2D<T>], i: usize, j: usize, tolerance: T, splits: &mut [Vec<(T, Point2D<T>)>]) {
+    let (a, b) = (&segments[i], &segments[j]);
+    if a.p1.x.min(a.p2.x) > b.p1.x.max(b.p2.x) + tolerance
+        || b.p1.x.min(b.p2.x) > a.p1.x.max(a.p2.x) + tolerance
+        || a.p1.y.min(a.p2.y) > b.p1.y.max(b.p2.y) + tolerance
+        || b.p1.y.min(b.p2.y) > a.p1.y.max(a.p2.y) + tolerance
+    {
+        return;
+    }
+
+    // endpoints touching the other segment's interior (T-junctions, collinear overlaps)
+    for endpoint in [b.p1, b.p2] {
+        if let Some(t) = interior_param(a, &endpoint, tolerance) {
+            splits[i].push((t, endpoint));
+        }
+    }
+    for endpoint in [a.p1, a.p2] {
+        if let Some(t) = interior_param(b, &endpoint, tolerance) {
+            splits[j].push((t, endpoint));
+        }
+    }
+
+    // proper crossings
+    let da = a.p2 - a.p1;
+    let db = b.p2 - b.p1;
+    let denominator = da.cross_product(&db);
+    if denominator.abs() <= T::epsilon() * a.length() * b.length() {
+        return;
+    }
+    let offset = b.p1 - a.p1;
+    let t = offset.cross_product(&db) / denominator;
+    let u = offset.cross_product(&da) / denominator;
+    let margin_a = tolerance / a.length();
+    let margin_b = tolerance / b.length();
+    if t > margin_a && t < T::one() - margin_a && u > margin_b && u < T::one() - margin_b {
+        let point = a.p1 + da * t;
+        splits[i].push((t, point));
+        splits[j].push((u, point));
+    }
+}
+
+/// Parameter of `point` along `segment` if it lies on the segment's interior.
+pub(crate) fn interior_param<T: Float + Debug>(segment: &Line2D<T>, point: &Point2D<T>, tolerance: T) -> Option<T> {
+    let direction = segment.p2 - segment.p1;
+    let length = segment.length();
+    let t = (*point - segment.p1).dot_product(&direction) / (length * length);
+    let along = t * length;
+    if along <= tolerance || along >= length - tolerance {
+        return None;
+    }
+    let foot = segment.p1 + direction * t;
+    if foot.distance(point) <= tolerance {
+        Some(t)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(x1: f64, y1: f64, x2: f64, y2: f64) -> Line2D<f64> {
+        Line2D::new(Point2D::new(x1, y1), Point2D::new(x2, y2))
+    }
+
+    #[test]
+    fn test_node_segments_crossing() {
+        let noded = node_segments(&[segment(0.0, 0.0, 2.0, 2.0), segment(0.0, 2.0, 2.0, 0.0)], 1e-9);
+        assert_eq!(noded.len(), 4);
+        assert!(noded.iter().all(|s| s.p1 == Point2D::new(1.0, 1.0) || s.p2 == Point2D::new(1.0, 1.0)));
+    }
+
+    #[test]
+    fn test_node_segments_t_junction_and_overlap() {
+        let noded = node_segments(
+            &[
+                segment(0.0, 0.0, 4.0, 0.0),
+                segment(2.0, 0.0, 2.0, 3.0),
+                segment(3.0, 0.0, 6.0, 0.0),
+            ],
+            1e-9,
+        );
+        // the long bottom edge is cut at x = 2 and x = 3, the overlapping one at x = 4
+        assert_eq!(noded.len(), 6);
+    }
+
+    #[test]
+    fn test_node_segments_disjoint_unchanged() {
+        let input = [segment(0.0, 0.0, 1.0, 0.0), segment(0.0, 1.0, 1.0, 1.0)];
+        assert_eq!(node_segments(&input, 1e-9), input.to_vec());
+    }
+
+    #[test]
+    fn test_node_segments_2d_shares_intersection_vertex() {
+        let noded = node_segments_2d(&[segment(0.0, 0.0, 3.0, 1.0), segment(0.0, 1.0, 3.0, 0.0)]);
+        assert_eq!(noded.len(), 4);
+        assert_eq!(noded[0].p2, noded[2].p2);
+    }
+
+    #[test]
+    fn test_snap_tolerance_scales_with_coordinates() {
+        let small = snap_tolerance(vec![Point2D::new(1.0, 1.0)]);
+        let large = snap_tolerance(vec![Point2D::new(1e6, -1.0)]);
+        assert!(large > small);
+        assert!(snap_tolerance(Vec::<Point2D<f64>>::new()) > 0.0);
+    }
+}
@@ -0,0 +1,213 @@
+use num::{Num, Float};
+use alloc::collections::BTreeMap;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::line::Line2D;
+use crate::polygon::Polygon2D;
+use crate::shape::Aabb2D;
+use super::noding::{node_segments, snap_tolerance};
+use super::planar_graph::{PlanarGraph, assemble_rings};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Which parts of the two inputs an overlay keeps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverlayMode {
+    /// Only areas covered by a polygon of both inputs.
+    Intersection,
+    /// All of input `a`, split wherever a polygon of `b` crosses it.
+    Identity,
+    /// Everything covered by either input, split where they overlap.
+    Union,
+}
+
+/// One piece of an overlay result together with the polygons it came from.
+///
+/// `a` and `b` index into the two input slices, so attributes stored alongside
+/// the inputs (zoning codes, parcel owners, ...) can be joined onto every piece.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OverlayPiece<T: Num + Copy + Debug> {
+    /// Counterclockwise outer ring.
+    pub exterior: Polygon2D<T>,
+    /// Clockwise rings cut out of the exterior.
+    pub holes: Vec<Polygon2D<T>>,
+    pub a: Option<usize>,
+    pub b: Option<usize>,
+}
+
+/// Overlays two sets of polygons, keeping track of the parent of every piece.
+///
+/// # Arguments
+/// * `a`, `b` - The two layers, e.g. zoning areas and parcels. Polygons within one
+///   layer may overlap each other.
+/// * `mode` - Which pieces to keep, see [`OverlayMode`].
+///
+/// # Returns
+/// * One [`OverlayPiece`] per connected area with a distinct `(a, b)` parent pair,
+///   ordered by that pair. A parent pair covering several disjoint areas yields
+///   several pieces.
+///
+/// # Algorithm Explanation
+/// 1. All polygon edges are split at their mutual intersections (noding).
+/// 2. The noded edges form a planar graph whose bounded faces are the smallest
+///    areas the two layers can be cut into.
+/// 3. Each face is labelled with the input polygons containing one of its
+///    interior points.
+/// 4. Faces sharing a parent pair are dissolved back into polygons with holes.
+///
+/// # Time Complexity
+/// **O(n²)** in the total number of edges, dominated by noding.
+pub fn overlay_2d<T: Float + Debug>(a: &[Polygon2D<T>], b: &[Polygon2D<T>], mode: OverlayMode) -> Vec<OverlayPiece<T>> {
+    let segments: Vec<Line2D<T>> = a.iter().chain(b.iter()).flat_map(edges).collect();
+    let tolerance = snap_tolerance(a.iter().chain(b.iter()).flat_map(|p| p.vertices().iter().cloned()));
+    let graph = PlanarGraph::new(&node_segments(&segments, tolerance), tolerance);
+    let faces = graph.faces();
+
+    let a_bounds: Vec<_> = a.iter().map(|p| Aabb2D::from_points(p.vertices())).collect();
+    let b_bounds: Vec<_> = b.iter().map(|p| Aabb2D::from_points(p.vertices())).collect();
+
+    let mut regions: BTreeMap<(Option<usize>, Option<usize>), Vec<bool>> = BTreeMap::new();
+    for face in 0..faces.shells.len() {
+        let point = graph.interior_point(&faces, face);
+        let in_a = containing(a, &a_bounds, &point);
+        let in_b = containing(b, &b_bounds, &point);
+
+        for key in parent_pairs(&in_a, &in_b, mode) {
+            regions.entry(key).or_insert_with(|| vec![false; faces.shells.len()])[face] = true;
+        }
+    }
+
+    let mut pieces = Vec::new();
+    for ((parent_a, parent_b), in_region) in regions {
+        for (shell, holes) in assemble_rings(graph.region_rings(&faces, &in_region)) {
+            pieces.push(OverlayPiece {
+                exterior: Polygon2D::new(shell),
+                holes: holes.into_iter().map(Polygon2D::new).collect(),
+                a: parent_a,
+                b: parent_b,
+            });
+        }
+    }
+    pieces
+}
+
+fn parent_pairs(in_a: &[usize], in_b: &[usize], mode: OverlayMode) -> Vec<(Option<usize>, Option<usize>)> {
+    let mut pairs: Vec<_> = in_a
+        .iter()
+        .flat_map(|&i| in_b.iter().map(move |&j| (Some(i), Some(j))))
+        .collect();
+    let keep_a_only = mode != OverlayMode::Intersection && in_b.is_empty();
+    let keep_b_only = mode == OverlayMode::Union && in_a.is_empty();
+    if keep_a_only {
+        pairs.extend(in_a.iter().map(|&i| (Some(i), None)));
+    }
+    if keep_b_only {
+        pairs.extend(in_b.iter().map(|&j| (None, Some(j))));
+    }
+    pairs
+}
+
+fn edges<T: Float + Debug>(polygon: &Polygon2D<T>) -> Vec<Line2D<T>> {
+    let vertices = polygon.vertices();
+    (0..vertices.len())
+        .map(|i| Line2D::new(vertices[i], vertices[(i + 1) % vertices.len()]))
+        .collect()
+}
+
+fn containing<T: Float + Debug>(polygons: &[Polygon2D<T>], bounds: &[Option<Aabb2D<T>>], point: &Point2D<T>) -> Vec<usize> {
+    (0..polygons.len())
+        .filter(|&i| bounds[i].is_some_and(|b| b.contains_point(point)))
+        .filter(|&i| polygons[i].contains_point(point))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polygon::ring::signed_area;
+
+    fn rect(x1: f64, y1: f64, x2: f64, y2: f64) -> Polygon2D<f64> {
+        Polygon2D::new(vec![
+            Point2D::new(x1, y1),
+            Point2D::new(x2, y1),
+            Point2D::new(x2, y2),
+            Point2D::new(x1, y2),
+        ])
+    }
+
+    fn area(piece: &OverlayPiece<f64>) -> f64 {
+        signed_area(piece.exterior.vertices())
+            + piece.holes.iter().map(|h| signed_area(h.vertices())).sum::<f64>()
+    }
+
+    fn summary(pieces: &[OverlayPiece<f64>]) -> Vec<(Option<usize>, Option<usize>, f64)> {
+        pieces.iter().map(|p| (p.a, p.b, area(p))).collect()
+    }
+
+    #[test]
+    fn test_overlay_2d_intersection_of_overlapping_squares() {
+        let pieces = overlay_2d(&[rect(0.0, 0.0, 2.0, 2.0)], &[rect(1.0, 1.0, 3.0, 3.0)], OverlayMode::Intersection);
+        assert_eq!(summary(&pieces), vec![(Some(0), Some(0), 1.0)]);
+        assert_eq!(pieces[0].exterior.len(), 4);
+    }
+
+    #[test]
+    fn test_overlay_2d_identity_keeps_all_of_a() {
+        let pieces = overlay_2d(&[rect(0.0, 0.0, 2.0, 2.0)], &[rect(1.0, 1.0, 3.0, 3.0)], OverlayMode::Identity);
+        assert_eq!(summary(&pieces), vec![(Some(0), None, 3.0), (Some(0), Some(0), 1.0)]);
+    }
+
+    #[test]
+    fn test_overlay_2d_union_keeps_everything() {
+        let pieces = overlay_2d(&[rect(0.0, 0.0, 2.0, 2.0)], &[rect(1.0, 1.0, 3.0, 3.0)], OverlayMode::Union);
+        assert_eq!(
+            summary(&pieces),
+            vec![(None, Some(0), 3.0), (Some(0), None, 3.0), (Some(0), Some(0), 1.0)]
+        );
+    }
+
+    #[test]
+    fn test_overlay_2d_zone_with_parcel_inside_gets_hole() {
+        let zones = [rect(0.0, 0.0, 10.0, 10.0)];
+        let parcels = [rect(4.0, 4.0, 6.0, 6.0)];
+        let pieces = overlay_2d(&zones, &parcels, OverlayMode::Identity);
+
+        assert_eq!(summary(&pieces), vec![(Some(0), None, 96.0), (Some(0), Some(0), 4.0)]);
+        assert_eq!(pieces[0].holes.len(), 1);
+    }
+
+    #[test]
+    fn test_overlay_2d_splits_along_parcel_boundaries() {
+        let zones = [rect(0.0, 0.0, 4.0, 2.0)];
+        let parcels = [rect(-1.0, -1.0, 2.0, 3.0), rect(2.0, -1.0, 5.0, 3.0)];
+        let pieces = overlay_2d(&zones, &parcels, OverlayMode::Intersection);
+
+        assert_eq!(summary(&pieces), vec![(Some(0), Some(0), 4.0), (Some(0), Some(1), 4.0)]);
+        assert!(pieces.iter().all(|p| p.exterior.len() == 4));
+    }
+
+    #[test]
+    fn test_overlay_2d_overlapping_polygons_within_a_layer() {
+        let zones = [rect(0.0, 0.0, 2.0, 2.0), rect(1.0, 0.0, 3.0, 2.0)];
+        let parcels = [rect(0.0, 0.0, 3.0, 1.0)];
+        let pieces = overlay_2d(&zones, &parcels, OverlayMode::Intersection);
+
+        assert_eq!(summary(&pieces), vec![(Some(0), Some(0), 2.0), (Some(1), Some(0), 2.0)]);
+    }
+
+    #[test]
+    fn test_overlay_2d_disjoint_inputs() {
+        let a = [rect(0.0, 0.0, 1.0, 1.0)];
+        let b = [rect(5.0, 5.0, 6.0, 6.0)];
+        assert!(overlay_2d(&a, &b, OverlayMode::Intersection).is_empty());
+        assert_eq!(overlay_2d(&a, &b, OverlayMode::Union).len(), 2);
+    }
+
+    #[test]
+    fn test_overlay_2d_clockwise_input() {
+        let mut vertices = rect(0.0, 0.0, 2.0, 2.0).vertices().to_vec();
+        vertices.reverse();
+        let pieces = overlay_2d(&[Polygon2D::new(vertices)], &[rect(1.0, 1.0, 3.0, 3.0)], OverlayMode::Intersection);
+        assert_eq!(summary(&pieces), vec![(Some(0), Some(0), 1.0)]);
+    }
+}
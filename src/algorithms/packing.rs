@@ -0,0 +1,297 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::{Point2D, total_cmp};
+use crate::polygon::Polygon2D;
+use crate::shape::Aabb2D;
+use crate::transform::Transform2D;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Settings for [`pack_rectangles`] and [`pack_polygons`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PackingOptions<T: Float + Debug> {
+    /// Whether items may be turned a quarter turn to fit better.
+    pub allow_rotation: bool,
+    /// Gap kept between items, e.g. the kerf of a cutting tool. Items may still
+    /// touch the sheet edges.
+    pub spacing: T,
+}
+
+impl<T: Float + Debug> Default for PackingOptions<T> {
+    /// Rotation allowed, no spacing.
+    fn default() -> Self {
+        PackingOptions { allow_rotation: true, spacing: T::zero() }
+    }
+}
+
+/// Where one item went.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Placement<T: Float + Debug> {
+    /// Index of the item in the input.
+    pub item: usize,
+    /// Index of the sheet it is on.
+    pub sheet: usize,
+    /// Maps the item from its own coordinates onto the sheet, whose corner is the origin.
+    pub transform: Transform2D<T>,
+    /// Whether the item was turned a quarter turn counterclockwise.
+    pub rotated: bool,
+}
+
+/// The result of a packing run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Packing<T: Float + Debug> {
+    /// One placement per placed item, in input order.
+    pub placements: Vec<Placement<T>>,
+    /// Items too large for an empty sheet, in input order.
+    pub unplaced: Vec<usize>,
+    /// Number of sheets used.
+    pub sheet_count: usize,
+}
+
+/// Packs rectangles of the given `(width, height)` onto as few `sheet`-sized
+/// sheets as it can, opening a new sheet whenever an item fits on none of the
+/// earlier ones.
+///
+/// Each item is taken to span `[0, width] × [0, height]` in its own coordinates;
+/// the returned transforms move it onto its sheet, which spans
+/// `[0, sheet.0] × [0, sheet.1]`.
+///
+/// # Algorithm Explanation
+/// MaxRects with the best short side fit rule (Jylänki, *A Thousand Ways to
+/// Pack the Bin*):
+/// - Items are placed largest first, by longer side and then area.
+/// - Each sheet keeps the maximal free rectangles left between placed items.
+///   An item goes where the shorter leftover side of a free rectangle is
+///   smallest, trying both orientations if rotation is allowed.
+/// - The free rectangles overlapped by the new item are split into the up to
+///   four maximal rectangles around it, and rectangles inside others dropped.
+///
+/// The result is a heuristic packing, not an optimal one.
+///
+/// # Time Complexity
+/// **O(n · f²)** for `n` items and at most `f` free rectangles per sheet,
+/// typically a small multiple of the items on the sheet.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::packing::{pack_rectangles, PackingOptions};
+///
+/// // above a 3 × 3 square, a 2 × 4 plate only fits on the 4 × 5 sheet lying down
+/// let sizes = [(3.0, 3.0), (2.0, 4.0)];
+/// let packing = pack_rectangles(&sizes, (4.0, 5.0), &PackingOptions::default());
+/// assert_eq!(packing.sheet_count, 1);
+/// assert!(packing.placements[1].rotated);
+/// assert!(packing.unplaced.is_empty());
+/// ```
+pub fn pack_rectangles<T: Float + Debug>(sizes: &[(T, T)], sheet: (T, T), options: &PackingOptions<T>) -> Packing<T> {
+    // an item padded by the spacing on two sides keeps that gap to its neighbours,
+    // and the sheet padded the same way lets items reach its far edges
+    let spacing = options.spacing;
+    let empty_sheet = Aabb2D::new(Point2D::origin(), Point2D::new(sheet.0 + spacing, sheet.1 + spacing));
+
+    let mut order: Vec<usize> = (0..sizes.len()).collect();
+    order.sort_by(|&a, &b| {
+        let key = |(w, h): (T, T)| (w.max(h), w * h);
+        let (ka, kb) = (key(sizes[a]), key(sizes[b]));
+        total_cmp(kb.0, ka.0).then(total_cmp(kb.1, ka.1))
+    });
+
+    let mut sheets: Vec<Vec<Aabb2D<T>>> = Vec::new();
+    let mut placements = Vec::with_capacity(sizes.len());
+    let mut unplaced = Vec::new();
+    for item in order {
+        let (width, height) = sizes[item];
+        let padded = (width + spacing, height + spacing);
+        let found = sheets.iter().enumerate().find_map(|(index, free)| best_fit(free, padded, options.allow_rotation).map(|fit| (index, fit)));
+        let (index, (corner, rotated)) = match found {
+            Some(found) => found,
+            None => match best_fit(&[empty_sheet], padded, options.allow_rotation) {
+                Some(fit) => {
+                    sheets.push(vec![empty_sheet]);
+                    (sheets.len() - 1, fit)
+                }
+                None => {
+                    unplaced.push(item);
+                    continue;
+                }
+            },
+        };
+        let extent = if rotated { Point2D::new(padded.1, padded.0) } else { Point2D::new(padded.0, padded.1) };
+        split_free_rectangles(&mut sheets[index], &Aabb2D::new(corner, corner + extent));
+
+        let transform = if rotated {
+            // (x, y) ↦ (-y, x), then back into the free spot; built exactly rather than from cos 90°
+            Transform2D::new(T::zero(), -T::one(), T::one(), T::zero(), corner.x + height, corner.y)
+        } else {
+            Transform2D::translation(corner.x, corner.y)
+        };
+        placements.push(Placement { item, sheet: index, transform, rotated });
+    }
+    placements.sort_by_key(|placement| placement.item);
+    unplaced.sort_unstable();
+    Packing { placements, unplaced, sheet_count: sheets.len() }
+}
+
+/// Packs polygons onto sheets like [`pack_rectangles`], each by its axis
+/// aligned bounding box.
+///
+/// The transforms apply to the polygons in their own coordinates. Boxes are a
+/// conservative stand-in for the shapes: placed polygons never overlap, but
+/// concave or slanted parts leave unused material. Polygons without vertices
+/// are reported as unplaced.
+pub fn pack_polygons<T: Float + Debug>(polygons: &[Polygon2D<T>], sheet: (T, T), options: &PackingOptions<T>) -> Packing<T> {
+    let boxes: Vec<(usize, Aabb2D<T>)> = polygons
+        .iter()
+        .enumerate()
+        .filter_map(|(index, polygon)| Aabb2D::from_points(polygon.vertices()).map(|aabb| (index, aabb)))
+        .collect();
+    let sizes: Vec<(T, T)> = boxes.iter().map(|(_, aabb)| (aabb.width(), aabb.height())).collect();
+    let packing = pack_rectangles(&sizes, sheet, options);
+
+    let placements = packing
+        .placements
+        .into_iter()
+        .map(|placement| {
+            let (item, aabb) = boxes[placement.item];
+            let to_corner = Transform2D::translation(-aabb.min.x, -aabb.min.y);
+            Placement { item, transform: to_corner.then(&placement.transform), ..placement }
+        })
+        .collect();
+    let mut unplaced: Vec<usize> = packing.unplaced.into_iter().map(|index| boxes[index].0).collect();
+    unplaced.extend((0..polygons.len()).filter(|&index| polygons[index].is_empty()));
+    unplaced.sort_unstable();
+    Packing { placements, unplaced, sheet_count: packing.sheet_count }
+}
+
+/// The lower left corner of the best spot for a `size` item among `free`, and
+/// whether it goes there rotated.
+fn best_fit<T: Float + Debug>(free: &[Aabb2D<T>], size: (T, T), allow_rotation: bool) -> Option<(Point2D<T>, bool)> {
+    let orientations: &[bool] = if allow_rotation { &[false, true] } else { &[false] };
+    let mut best: Option<((T, T), Point2D<T>, bool)> = None;
+    for rect in free {
+        for &rotated in orientations {
+            let (w, h) = if rotated { (size.1, size.0) } else { size };
+            let (left_x, left_y) = (rect.width() - w, rect.height() - h);
+            if !(left_x >= T::zero() && left_y >= T::zero()) {
+                continue;
+            }
+            let score = (left_x.min(left_y), left_x.max(left_y));
+            if best.is_none_or(|(s, _, _)| score.0 < s.0 || (score.0 == s.0 && score.1 < s.1)) {
+                best = Some((score, rect.min, rotated));
+            }
+        }
+    }
+    best.map(|(_, corner, rotated)| (corner, rotated))
+}
+
+/// Replaces the free rectangles overlapping `used` by the maximal rectangles
+/// around it, and drops any free rectangle inside another.
+fn split_free_rectangles<T: Float + Debug>(free: &mut Vec<Aabb2D<T>>, used: &Aabb2D<T>) {
+    let mut split = Vec::with_capacity(free.len() + 4);
+    for rect in free.drain(..) {
+        let overlaps = used.min.x < rect.max.x && used.max.x > rect.min.x && used.min.y < rect.max.y && used.max.y > rect.min.y;
+        if !overlaps {
+            split.push(rect);
+            continue;
+        }
+        if used.min.x > rect.min.x {
+            split.push(Aabb2D::new(rect.min, Point2D::new(used.min.x, rect.max.y)));
+        }
+        if used.max.x < rect.max.x {
+            split.push(Aabb2D::new(Point2D::new(used.max.x, rect.min.y), rect.max));
+        }
+        if used.min.y > rect.min.y {
+            split.push(Aabb2D::new(rect.min, Point2D::new(rect.max.x, used.min.y)));
+        }
+        if used.max.y < rect.max.y {
+            split.push(Aabb2D::new(Point2D::new(rect.min.x, used.max.y), rect.max));
+        }
+    }
+    let inside = |a: &Aabb2D<T>, b: &Aabb2D<T>| a.min.x >= b.min.x && a.min.y >= b.min.y && a.max.x <= b.max.x && a.max.y <= b.max.y;
+    // of two equal rectangles only the first is kept
+    for (i, rect) in split.iter().enumerate() {
+        let redundant = split.iter().enumerate().any(|(j, other)| j != i && inside(rect, other) && (j < i || !inside(other, rect)));
+        if !redundant {
+            free.push(*rect);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shape::{Area, Intersects};
+    use crate::transform::Transformable;
+
+    /// Checks that every placed item lies on its sheet and no two items on a
+    /// sheet overlap by more than touching, returning the placed boxes.
+    fn check_layout(sizes: &[(f64, f64)], sheet: (f64, f64), spacing: f64, packing: &Packing<f64>) -> Vec<(usize, Aabb2D<f64>)> {
+        let boxes: Vec<(usize, Aabb2D<f64>)> = packing
+            .placements
+            .iter()
+            .map(|p| {
+                let (w, h) = sizes[p.item];
+                let corners = [Point2D::new(0.0, 0.0), Point2D::new(w, h)].map(|c| p.transform.transform_point(&c));
+                (p.sheet, Aabb2D::from_points(&corners).unwrap())
+            })
+            .collect();
+        for (i, (sheet_a, a)) in boxes.iter().enumerate() {
+            assert!(a.min.x >= -1e-9 && a.min.y >= -1e-9 && a.max.x <= sheet.0 + 1e-9 && a.max.y <= sheet.1 + 1e-9);
+            for (sheet_b, b) in &boxes[i + 1..] {
+                let gap = (b.min.x - a.max.x).max(a.min.x - b.max.x).max(b.min.y - a.max.y).max(a.min.y - b.max.y);
+                assert!(sheet_a != sheet_b || gap >= spacing - 1e-9, "{a:?} and {b:?} are {gap} apart");
+            }
+        }
+        boxes
+    }
+
+    #[test]
+    fn test_pack_rectangles_fills_sheets_without_overlap() {
+        let sizes: Vec<(f64, f64)> = (0..40).map(|i| (1.0 + (i * 7 % 5) as f64, 1.0 + (i * 3 % 4) as f64)).collect();
+        let sheet = (10.0, 8.0);
+        for options in [PackingOptions::default(), PackingOptions { allow_rotation: false, spacing: 0.25 }] {
+            let packing = pack_rectangles(&sizes, sheet, &options);
+            assert_eq!(packing.placements.len(), sizes.len());
+            assert!(packing.placements.iter().enumerate().all(|(i, p)| p.item == i && p.sheet < packing.sheet_count));
+            assert!(options.allow_rotation || packing.placements.iter().all(|p| !p.rotated));
+            check_layout(&sizes, sheet, options.spacing, &packing);
+            // no worse than twice the lower bound from the total area
+            let total: f64 = sizes.iter().map(|(w, h)| w * h).sum();
+            assert!((packing.sheet_count as f64) < 2.0 * (total / 80.0).ceil());
+        }
+    }
+
+    #[test]
+    fn test_pack_rectangles_exact_fit_and_oversized_items() {
+        // four quarters fill the sheet exactly, the fifth needs another one
+        let sizes = [(5.0, 4.0), (11.0, 1.0), (5.0, 4.0), (5.0, 4.0), (5.0, 4.0), (5.0, 4.0)];
+        let packing = pack_rectangles(&sizes, (10.0, 8.0), &PackingOptions { allow_rotation: false, spacing: 0.0 });
+        assert_eq!(packing.unplaced, vec![1]);
+        assert_eq!(packing.sheet_count, 2);
+        assert_eq!(packing.placements.iter().filter(|p| p.sheet == 0).count(), 4);
+        check_layout(&sizes, (10.0, 8.0), 0.0, &packing);
+
+        let fixed = pack_rectangles(&[(1.0, 8.0)], (10.0, 8.0), &PackingOptions { allow_rotation: false, spacing: 0.0 });
+        assert_eq!(fixed.placements[0].transform, Transform2D::identity());
+        assert_eq!(pack_rectangles::<f64>(&[], (1.0, 1.0), &PackingOptions::default()).sheet_count, 0);
+    }
+
+    #[test]
+    fn test_pack_polygons_moves_shapes_onto_sheet() {
+        let triangle = Polygon2D::new(vec![Point2D::new(100.0, 100.0), Point2D::new(106.0, 100.0), Point2D::new(100.0, 103.0)]);
+        let tall = Polygon2D::new(vec![Point2D::new(-5.0, -5.0), Point2D::new(-3.0, -5.0), Point2D::new(-3.0, 2.0), Point2D::new(-5.0, 2.0)]);
+        let polygons = [triangle, Polygon2D::new(Vec::new()), tall];
+        let packing = pack_polygons(&polygons, (7.0, 5.0), &PackingOptions::default());
+        assert_eq!(packing.unplaced, vec![1]);
+        assert_eq!(packing.sheet_count, 1);
+        let placed: Vec<Polygon2D<f64>> = packing.placements.iter().map(|p| polygons[p.item].apply(&p.transform)).collect();
+        for (polygon, original) in placed.iter().zip([&polygons[0], &polygons[2]]) {
+            assert!((polygon.area() - original.area()).abs() < 1e-9);
+            assert!(polygon.vertices().iter().all(|v| v.x >= -1e-9 && v.y >= -1e-9 && v.x <= 7.0 + 1e-9 && v.y <= 5.0 + 1e-9));
+        }
+        // the 2 × 7 rectangle only fits the 7 × 5 sheet lying down
+        assert!(packing.placements[1].rotated);
+        let shrunk = placed[0].scale_about(0.99, 0.99, placed[0].vertices()[0]);
+        assert!(!shrunk.intersects(&placed[1].scale_about(0.99, 0.99, placed[1].vertices()[0])));
+    }
+}
@@ -0,0 +1,200 @@
+use num::Float;
+use std::fmt::Debug;
+use std::slice;
+use rayon::prelude::*;
+use crate::point::Point2D;
+use crate::shape::Aabb2D;
+use crate::transform::Angle;
+use super::batch::{self, BatchFloat};
+use crate::shape::Geometry2D;
+use super::point_algorithms::{bounding_box_2d, centroid_2d, convex_hull_2d};
+use super::validation::{ValidationReport, validate_collection};
+
+/// Inputs with fewer points than this run on the calling thread.
+///
+/// Handing work to the `rayon` pool costs a few microseconds, more than a
+/// serial pass over a few thousand points takes.
+pub const PARALLEL_THRESHOLD: usize = 16_384;
+
+/// Chunk length splitting `len` items across the `rayon` pool, or `None` when
+/// the input is too small (or the pool too narrow) to be worth splitting.
+fn chunk_len(len: usize) -> Option<usize> {
+    let workers = rayon::current_num_threads();
+    if len < PARALLEL_THRESHOLD || workers < 2 {
+        return None;
+    }
+    Some(len.div_ceil(workers).max(PARALLEL_THRESHOLD / 4))
+}
+
+/// Number of vertices of `geometry`, the unit [`par_validate_collection`] weighs its input in.
+fn vertex_count<T: Float + Debug>(geometry: &Geometry2D<T>) -> usize {
+    match geometry {
+        Geometry2D::Point(_) | Geometry2D::Circle(_) => 1,
+        Geometry2D::Line(_) => 2,
+        Geometry2D::Polyline(polyline) => polyline.vertices().len(),
+        Geometry2D::Polygon(polygon) => polygon.vertices().len(),
+        Geometry2D::MultiPoint(points) => points.len(),
+        Geometry2D::MultiPolyline(polylines) => polylines.iter().map(|p| p.vertices().len()).sum(),
+        Geometry2D::MultiPolygon(polygons) => polygons.iter().map(|p| p.vertices().len()).sum(),
+    }
+}
+
+/// Parallel [`centroid_2d`].
+///
+/// Every worker sums its own chunk, so the result can differ from the serial
+/// version in the last bits.
+pub fn par_centroid_2d<T: Float + Debug + Send + Sync>(points: &[Point2D<T>]) -> Point2D<T> {
+    let Some(chunk) = chunk_len(points.len()) else {
+        return centroid_2d(points);
+    };
+    let sum = points
+        .par_chunks(chunk)
+        .map(|part| part.iter().fold(Point2D::origin(), |acc, &p| acc + p))
+        .reduce(Point2D::origin, |a, b| a + b);
+    sum / T::from(points.len()).unwrap()
+}
+
+/// Parallel [`bounding_box_2d`]; the result is identical to the serial version.
+pub fn par_bounding_box_2d<T: Float + Debug + Send + Sync>(points: &[Point2D<T>]) -> Option<Aabb2D<T>> {
+    let Some(chunk) = chunk_len(points.len()) else {
+        return bounding_box_2d(points);
+    };
+    let corners: Vec<Point2D<T>> = points
+        .par_chunks(chunk)
+        .filter_map(bounding_box_2d)
+        .flat_map_iter(|aabb| [aabb.min, aabb.max])
+        .collect();
+    bounding_box_2d(&corners)
+}
+
+/// Parallel [`convex_hull_2d`].
+///
+/// # Algorithm Explanation
+/// 1. Splits the points into one chunk per core.
+/// 2. Every worker sorts its chunk and builds its hull with the monotone chain.
+/// 3. A point on the overall hull is on the hull of its own chunk, so the final
+///    hull is the hull of the (few) chunk hull vertices, built serially.
+///
+/// # Time Complexity
+/// **O((n / p) log(n / p))** per worker for `p` workers, plus **O(h log h)** for
+/// the merge over the `h` chunk hull vertices.
+pub fn par_convex_hull_2d<T: Float + Debug + Send + Sync>(points: &[Point2D<T>]) -> Vec<Point2D<T>> {
+    let Some(chunk) = chunk_len(points.len()) else {
+        return convex_hull_2d(points);
+    };
+    let candidates: Vec<Point2D<T>> = points.par_chunks(chunk).flat_map_iter(convex_hull_2d).collect();
+    convex_hull_2d(&candidates)
+}
+
+/// Parallel [`validate_collection`]; the report is identical to the serial one.
+///
+/// Every geometry is its own task, so a handful of large polygons spread over
+/// the pool as well as many small ones. Collections with fewer than
+/// [`PARALLEL_THRESHOLD`] vertices in total are checked on the calling thread.
+pub fn par_validate_collection<T: Float + Debug + Send + Sync>(geometries: &[Geometry2D<T>]) -> ValidationReport {
+    let vertices: usize = geometries.iter().map(vertex_count).sum();
+    if chunk_len(vertices).is_none() {
+        return validate_collection(geometries);
+    }
+    let diagnostics = geometries
+        .par_iter()
+        .enumerate()
+        .flat_map_iter(|(index, geometry)| {
+            validate_collection(slice::from_ref(geometry)).diagnostics.into_iter().map(move |mut d| {
+                d.index = index;
+                d
+            })
+        })
+        .collect();
+    ValidationReport { checked: geometries.len(), diagnostics }
+}
+
+/// Parallel [`translate_points_2d`](batch::translate_points_2d).
+pub fn par_translate_points_2d<T: BatchFloat + Send + Sync>(points: &mut [Point2D<T>], delta: (T, T)) {
+    let Some(chunk) = chunk_len(points.len()) else {
+        return batch::translate_points_2d(points, delta);
+    };
+    points.par_chunks_mut(chunk).for_each(|part| batch::translate_points_2d(part, delta));
+}
+
+/// Parallel [`rotate_points_2d`](batch::rotate_points_2d).
+pub fn par_rotate_points_2d<T: BatchFloat + Send + Sync>(points: &mut [Point2D<T>], angle: Angle<T>, center: Option<Point2D<T>>) {
+    let Some(chunk) = chunk_len(points.len()) else {
+        return batch::rotate_points_2d(points, angle, center);
+    };
+    points.par_chunks_mut(chunk).for_each(|part| batch::rotate_points_2d(part, angle, center));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polygon::Polygon2D;
+
+    fn cloud(n: usize) -> Vec<Point2D<f64>> {
+        (0..n)
+            .map(|i| {
+                let t = i as f64;
+                Point2D::new((t * 0.61).sin() * 1000.0, (t * 0.23).cos() * 500.0 + (t * 0.07).sin())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_small_inputs_stay_serial() {
+        assert_eq!(chunk_len(10), None);
+        let points = cloud(100);
+        assert_eq!(par_convex_hull_2d(&points), convex_hull_2d(&points));
+        assert_eq!(par_centroid_2d(&points), centroid_2d(&points));
+    }
+
+    #[test]
+    fn test_par_bounding_box_and_hull_match_serial() {
+        let points = cloud(4 * PARALLEL_THRESHOLD + 17);
+        assert_eq!(par_bounding_box_2d(&points), bounding_box_2d(&points));
+        assert_eq!(par_convex_hull_2d(&points), convex_hull_2d(&points));
+    }
+
+    #[test]
+    fn test_par_centroid_2d_close_to_serial() {
+        let points = cloud(3 * PARALLEL_THRESHOLD);
+        let (a, b) = (par_centroid_2d(&points), centroid_2d(&points));
+        assert!((a.x - b.x).abs() < 1e-9 && (a.y - b.y).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_par_transforms_match_batch() {
+        let mut points = cloud(2 * PARALLEL_THRESHOLD + 3);
+        let mut expected = points.clone();
+        par_translate_points_2d(&mut points, (2.0, -1.0));
+        par_rotate_points_2d(&mut points, Angle::from_degrees(17.0), Some(Point2D::new(1.0, 1.0)));
+        batch::translate_points_2d(&mut expected, (2.0, -1.0));
+        batch::rotate_points_2d(&mut expected, Angle::from_degrees(17.0), Some(Point2D::new(1.0, 1.0)));
+        assert_eq!(points, expected);
+    }
+
+    #[test]
+    fn test_par_validate_collection_matches_serial() {
+        let geometries: Vec<Geometry2D<f64>> = cloud(2 * PARALLEL_THRESHOLD + 5)
+            .into_iter()
+            .enumerate()
+            .map(|(i, p)| if i % 1000 == 7 { Point2D::new(p.x, f64::NAN) } else { p }.into())
+            .collect();
+        let report = par_validate_collection(&geometries);
+        assert_eq!(report, validate_collection(&geometries));
+        assert_eq!(report.invalid_indices().len(), 33);
+    }
+
+    #[test]
+    fn test_par_validate_collection_splits_few_large_polygons() {
+        let ring = |n: usize| -> Geometry2D<f64> {
+            let step = std::f64::consts::TAU / n as f64;
+            Polygon2D::new((0..n).map(|i| Point2D::new((i as f64 * step).cos(), (i as f64 * step).sin())).collect()).into()
+        };
+        let bow_tie = Polygon2D::new(Vec::from([Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0), Point2D::new(1.0, 0.0), Point2D::new(0.0, 1.0)]));
+        let geometries = Vec::from([ring(PARALLEL_THRESHOLD / 2), bow_tie.into(), ring(PARALLEL_THRESHOLD / 2)]);
+        assert!(chunk_len(geometries.len()).is_none());
+        let report = par_validate_collection(&geometries);
+        assert_eq!(report, validate_collection(&geometries));
+        assert_eq!(report.invalid_indices(), [1]);
+    }
+}
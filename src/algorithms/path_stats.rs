@@ -0,0 +1,139 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::line::Polyline2D;
+
+/// Summary measures of a polyline's shape, as computed by [`path_stats`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PathStats<T: Float + Debug> {
+    /// Total length.
+    pub length: T,
+    /// Sum of the absolute turning angles at the interior vertices, in radians:
+    /// zero for a straight path, and short of `2π` by the final turn for a path
+    /// once around a convex loop, as the turn at the start vertex does not count.
+    pub total_turning: T,
+    /// Length over the distance between the ends: 1 for a straight path, larger
+    /// the more it wanders. `None` if the path ends where it starts.
+    pub sinuosity: Option<T>,
+    /// Number of segments, zero-length ones included.
+    pub segment_count: usize,
+    pub min_segment_length: T,
+    pub max_segment_length: T,
+    pub mean_segment_length: T,
+    /// Population standard deviation of the segment lengths.
+    pub segment_length_std_dev: T,
+}
+
+/// Measures the shape of `path` for trajectory analysis.
+///
+/// # Returns
+/// * The statistics, or `None` if the path has fewer than two vertices.
+///   Zero-length segments count towards the segment statistics, but are
+///   skipped when measuring turns.
+///
+/// # Time Complexity
+/// **O(n)** for `n` vertices.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::path_stats::path_stats;
+/// use geomengine::line::Polyline2D;
+/// use geomengine::point::Point2D;
+///
+/// // three sides of a square
+/// let path = Polyline2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(2.0, 0.0), Point2D::new(2.0, 2.0), Point2D::new(0.0, 2.0)]);
+/// let stats = path_stats(&path).unwrap();
+/// assert_eq!(stats.length, 6.0);
+/// assert_eq!(stats.total_turning, std::f64::consts::PI);
+/// assert_eq!(stats.sinuosity, Some(3.0));
+/// assert_eq!((stats.segment_count, stats.mean_segment_length, stats.segment_length_std_dev), (3, 2.0, 0.0));
+/// ```
+pub fn path_stats<T: Float + Debug>(path: &Polyline2D<T>) -> Option<PathStats<T>> {
+    let vertices = path.vertices();
+    if vertices.len() < 2 {
+        return None;
+    }
+    let mut stats = PathStats {
+        length: T::zero(),
+        total_turning: T::zero(),
+        sinuosity: None,
+        segment_count: vertices.len() - 1,
+        min_segment_length: T::infinity(),
+        max_segment_length: T::zero(),
+        mean_segment_length: T::zero(),
+        segment_length_std_dev: T::zero(),
+    };
+    let mut squares = T::zero();
+    let mut previous_direction = None;
+    for segment in path.edges() {
+        let length = segment.length();
+        stats.length = stats.length + length;
+        squares = squares + length * length;
+        stats.min_segment_length = stats.min_segment_length.min(length);
+        stats.max_segment_length = stats.max_segment_length.max(length);
+        if length > T::zero() {
+            let direction = segment.p2 - segment.p1;
+            if let Some(before) = previous_direction {
+                let turn = direction.cross_product(&before).atan2(direction.dot_product(&before));
+                stats.total_turning = stats.total_turning + turn.abs();
+            }
+            previous_direction = Some(direction);
+        }
+    }
+    let count = T::from(stats.segment_count).unwrap();
+    stats.mean_segment_length = stats.length / count;
+    // E[l²] - E[l]², which rounding can push just below zero
+    let variance = squares / count - stats.mean_segment_length * stats.mean_segment_length;
+    stats.segment_length_std_dev = variance.max(T::zero()).sqrt();
+    let span = vertices[0].distance(&vertices[vertices.len() - 1]);
+    stats.sinuosity = (span > T::zero()).then(|| stats.length / span);
+    Some(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::point::Point2D;
+    use approx::assert_relative_eq;
+    use core::f64::consts::PI;
+
+    #[test]
+    fn test_path_stats_zigzag() {
+        // 3-4-5 zigzag: turns of twice the slope angle at each inner vertex
+        let zigzag = Polyline2D::new(vec![
+            Point2D::new(0.0, 0.0),
+            Point2D::new(3.0, 4.0),
+            Point2D::new(6.0, 0.0),
+            Point2D::new(9.0, 4.0),
+            Point2D::new(9.0, 4.0),
+            Point2D::new(10.0, 4.0),
+        ]);
+        let stats = path_stats(&zigzag).unwrap();
+        assert_eq!(stats.length, 16.0);
+        assert_eq!(stats.segment_count, 5);
+        assert_eq!((stats.min_segment_length, stats.max_segment_length), (0.0, 5.0));
+        assert_relative_eq!(stats.mean_segment_length, 3.2);
+        // lengths 5, 5, 5, 0, 1
+        assert_relative_eq!(stats.segment_length_std_dev, (76.0f64 / 5.0 - 3.2 * 3.2).sqrt(), epsilon = 1e-12);
+        let slope = (4.0f64).atan2(3.0);
+        // two turns of 2 * slope, then back to horizontal past the repeated vertex
+        assert_relative_eq!(stats.total_turning, 4.0 * slope + slope, epsilon = 1e-12);
+        assert_relative_eq!(stats.sinuosity.unwrap(), 16.0 / 116f64.sqrt(), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_path_stats_loop_and_degenerate() {
+        // a 36-gon, closed back onto its first vertex
+        let circle = Polyline2D::new((0..=36).map(|i| {
+            let angle = (i % 36) as f64 * PI / 18.0;
+            Point2D::new(angle.cos(), angle.sin())
+        }).collect());
+        let stats = path_stats(&circle).unwrap();
+        assert_relative_eq!(stats.total_turning, 2.0 * PI - PI / 18.0, epsilon = 1e-9);
+        assert_eq!(stats.sinuosity, None);
+        assert_relative_eq!(stats.segment_length_std_dev, 0.0, epsilon = 1e-6);
+
+        assert_eq!(path_stats(&Polyline2D::new(vec![Point2D::new(1.0, 1.0)])), None);
+        let still = path_stats(&Polyline2D::new(vec![Point2D::new(1.0, 1.0), Point2D::new(1.0, 1.0)])).unwrap();
+        assert_eq!((still.length, still.total_turning, still.sinuosity), (0.0, 0.0, None));
+    }
+}
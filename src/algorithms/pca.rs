@@ -0,0 +1,158 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::{Point2D, Vector2D};
+use crate::polygon::Polygon2D;
+use crate::algorithms::accumulators::CovarianceAccumulator;
+use alloc::vec;
+
+/// The principal axes of a point cloud, as found by [`principal_axes`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrincipalAxes<T: Float + Debug> {
+    /// The mean of the points, where the axes cross.
+    pub mean: Point2D<T>,
+    /// Population covariance matrix `[[var_x, cov_xy], [cov_xy, var_y]]`.
+    pub covariance: [[T; 2]; 2],
+    /// Eigenvalues of the covariance, the major one first: the variance of the
+    /// points along each axis.
+    pub eigenvalues: [T; 2],
+    /// Unit eigenvectors matching `eigenvalues`. The minor axis is the major one
+    /// turned a quarter counterclockwise.
+    pub axes: [Vector2D<T>; 2],
+    /// The smallest rectangle aligned with `axes` containing every point, counterclockwise.
+    /// Flat if the points are collinear.
+    pub bounding_box: Polygon2D<T>,
+}
+
+impl<T: Float + Debug> PrincipalAxes<T> {
+    /// Angle of the major axis in radians from the x axis, in `[0, π)`.
+    pub fn orientation(&self) -> T {
+        let pi = T::from(core::f64::consts::PI).unwrap();
+        let angle = self.axes[0].y.atan2(self.axes[0].x);
+        if angle < T::zero() { angle + pi } else if angle >= pi { angle - pi } else { angle }
+    }
+
+    /// `(λ₁ - λ₂) / (λ₁ + λ₂)` for eigenvalues `λ₁ ≥ λ₂`: 0 when the spread is
+    /// the same in every direction (or there is none), 1 when the points are collinear.
+    pub fn anisotropy(&self) -> T {
+        let [major, minor] = self.eigenvalues;
+        let total = major + minor;
+        if total <= T::zero() { T::zero() } else { (major - minor) / total }
+    }
+
+    /// Standard deviation along the minor axis over that along the major axis,
+    /// in `[0, 1]`; 1 if the points coincide.
+    pub fn aspect_ratio(&self) -> T {
+        let [major, minor] = self.eigenvalues;
+        if major <= T::zero() { T::one() } else { (minor / major).sqrt() }
+    }
+}
+
+/// Principal component analysis of `points`: their covariance, its eigenvectors
+/// and eigenvalues, and the bounding box aligned with them.
+///
+/// # Returns
+/// * The axes, or `None` if `points` is empty. Coincident points get the
+///   coordinate axes and a flat bounding box.
+///
+/// # Algorithm Explanation
+/// - The covariance is accumulated with [`CovarianceAccumulator`], which stays
+///   accurate far from the origin.
+/// - The eigenvalues of the symmetric 2×2 covariance `[[a, b], [b, c]]` are
+///   `(a + c) / 2 ± √(((a - c) / 2)² + b²)`, and the major axis makes an angle
+///   of `atan2(2b, a - c) / 2` with the x axis.
+/// - The bounding box spans the extreme projections of the points on both axes.
+///
+/// # Time Complexity
+/// **O(n)**.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::pca::principal_axes;
+/// use geomengine::point::Point2D;
+/// use geomengine::shape::Area;
+///
+/// // points along the diagonal y = x, spread a little across it
+/// let points = [Point2D::new(0.0, 0.0), Point2D::new(4.0, 4.0), Point2D::new(1.0, 3.0), Point2D::new(3.0, 1.0)];
+/// let pca = principal_axes(&points).unwrap();
+/// assert_eq!(pca.mean, Point2D::new(2.0, 2.0));
+/// assert!((pca.orientation() - std::f64::consts::FRAC_PI_4).abs() < 1e-12);
+/// assert!(pca.eigenvalues[0] > pca.eigenvalues[1]);
+/// // 4√2 long and 2√2 wide
+/// let area: f64 = pca.bounding_box.area();
+/// assert!((area - 16.0).abs() < 1e-9);
+/// ```
+pub fn principal_axes<T: Float + Debug>(points: &[Point2D<T>]) -> Option<PrincipalAxes<T>> {
+    let accumulator: CovarianceAccumulator<T> = points.iter().copied().collect();
+    let mean = accumulator.mean()?;
+    let covariance = accumulator.covariance()?;
+    let [[a, b], [_, c]] = covariance;
+
+    let two = T::one() + T::one();
+    let half_sum = (a + c) / two;
+    let radius = ((a - c) / two).hypot(b);
+    let eigenvalues = [half_sum + radius, (half_sum - radius).max(T::zero())];
+    let (sin, cos) = ((b + b).atan2(a - c) / two).sin_cos();
+    let axes = [Vector2D::new(cos, sin), Vector2D::new(-sin, cos)];
+
+    let (mut low, mut high) = (Point2D::new(T::infinity(), T::infinity()), Point2D::new(T::neg_infinity(), T::neg_infinity()));
+    for p in points {
+        let offset = *p - mean;
+        let projected = Point2D::new(offset.dot_product(&axes[0]), offset.dot_product(&axes[1]));
+        low = Point2D::new(low.x.min(projected.x), low.y.min(projected.y));
+        high = Point2D::new(high.x.max(projected.x), high.y.max(projected.y));
+    }
+    let corner = |u: T, v: T| mean + axes[0] * u + axes[1] * v;
+    let bounding_box = Polygon2D::new(vec![corner(low.x, low.y), corner(high.x, low.y), corner(high.x, high.y), corner(low.x, high.y)]);
+
+    Some(PrincipalAxes { mean, covariance, eigenvalues, axes, bounding_box })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shape::Area;
+    use approx::assert_relative_eq;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_principal_axes_of_rotated_rectangle() {
+        // a 6 × 2 grid of points turned by 30° and moved far from the origin
+        let (sin, cos) = 30f64.to_radians().sin_cos();
+        let offset = Point2D::new(1e6, -2e6);
+        let points: Vec<Point2D<f64>> = (0..=12)
+            .flat_map(|i| (0..=4).map(move |j| (i as f64 * 0.5 - 3.0, j as f64 * 0.5 - 1.0)))
+            .map(|(x, y)| offset + Point2D::new(x * cos - y * sin, x * sin + y * cos))
+            .collect();
+        let pca = principal_axes(&points).unwrap();
+        assert_relative_eq!(pca.mean.x, offset.x, epsilon = 1e-6);
+        assert_relative_eq!(pca.orientation(), 30f64.to_radians(), epsilon = 1e-9);
+        assert_relative_eq!(pca.axes[0].cross_product(&pca.axes[1]), 1.0, epsilon = 1e-12);
+        assert_relative_eq!(pca.bounding_box.area(), 12.0, epsilon = 1e-6);
+        // every point is inside or on the box, left of each counterclockwise edge
+        for edge in pca.bounding_box.edges() {
+            assert!(points.iter().all(|p| (edge.p2 - edge.p1).cross_product(&(*p - edge.p1)) > -1e-6));
+        }
+        assert!(pca.anisotropy() > 0.5 && pca.anisotropy() < 1.0);
+        assert!(pca.aspect_ratio() < 0.5);
+    }
+
+    #[test]
+    fn test_principal_axes_degenerate_clouds() {
+        assert_eq!(principal_axes::<f64>(&[]), None);
+
+        let collinear = [Point2D::new(0.0, 1.0), Point2D::new(0.0, 3.0), Point2D::new(0.0, 2.0)];
+        let pca = principal_axes(&collinear).unwrap();
+        assert_relative_eq!(pca.orientation(), core::f64::consts::FRAC_PI_2, epsilon = 1e-12);
+        assert_eq!(pca.eigenvalues[1], 0.0);
+        assert_eq!(pca.anisotropy(), 1.0);
+        assert_relative_eq!(pca.bounding_box.area(), 0.0, epsilon = 1e-12);
+
+        let pca = principal_axes(&[Point2D::new(5.0, 5.0); 3]).unwrap();
+        assert_eq!(pca.eigenvalues, [0.0, 0.0]);
+        assert_eq!((pca.anisotropy(), pca.aspect_ratio()), (0.0, 1.0));
+
+        // a square's corners spread equally in every direction
+        let square = [Point2D::new(0.0, 0.0), Point2D::new(1.0, 0.0), Point2D::new(1.0, 1.0), Point2D::new(0.0, 1.0)];
+        assert_relative_eq!(principal_axes(&square).unwrap().anisotropy(), 0.0, epsilon = 1e-12);
+    }
+}
@@ -0,0 +1,397 @@
+use num::Float;
+use core::cmp::Ordering;
+use alloc::collections::BTreeSet;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::line::Line2D;
+use crate::polygon::ring;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Planar graph over noded segments, stored as paired half-edges.
+///
+/// Half-edge `h` runs from `origins[h]` to `origins[h ^ 1]`; `h ^ 1` is its twin.
+/// `next[h]` continues around the face lying to the left of `h`, so bounded faces
+/// are traced counterclockwise and the outer boundary of every connected
+/// component clockwise.
+pub(crate) struct PlanarGraph<T: Float + Debug> {
+    pub vertices: Vec<Point2D<T>>,
+    pub origins: Vec<usize>,
+    pub next: Vec<usize>,
+    tolerance: T,
+}
+
+/// Faces of a [`PlanarGraph`].
+///
+/// Every counterclockwise cycle is the shell of a bounded face. Clockwise cycles
+/// bound connected components from the outside and are attached as holes to the
+/// smallest face of another component enclosing them.
+pub(crate) struct Faces {
+    pub cycles: Vec<Vec<usize>>,
+    /// Cycle each half-edge belongs to.
+    pub cycle_of: Vec<usize>,
+    pub shells: Vec<usize>,
+    pub holes: Vec<Vec<usize>>,
+    /// Face lying to the left of each half-edge, `None` for the unbounded face.
+    pub owners: Vec<Option<usize>>,
+}
+
+impl<T: Float + Debug> PlanarGraph<T> {
+    /// Builds the graph from segments that only meet at their endpoints.
+    ///
+    /// Endpoints closer than `tolerance` are merged and duplicate edges dropped.
+    pub fn new(segments: &[Line2D<T>], tolerance: T) -> Self {
+        let endpoints: Vec<Point2D<T>> = segments.iter().flat_map(|s| [s.p1, s.p2]).collect();
+        let (vertices, ids) = merge_points(&endpoints, tolerance);
+
+        let mut seen = BTreeSet::new();
+        let mut origins = Vec::new();
+        for pair in ids.chunks(2) {
+            let (u, v) = (pair[0], pair[1]);
+            if u != v && seen.insert((u.min(v), u.max(v))) {
+                origins.push(u);
+                origins.push(v);
+            }
+        }
+
+        let mut outgoing: Vec<Vec<usize>> = vec![Vec::new(); vertices.len()];
+        for (h, &origin) in origins.iter().enumerate() {
+            outgoing[origin].push(h);
+        }
+        let angle = |h: usize| {
+            let d = vertices[origins[h ^ 1]] - vertices[origins[h]];
+            d.y.atan2(d.x)
+        };
+        for edges in outgoing.iter_mut() {
+            edges.sort_by(|&a, &b| angle(a).partial_cmp(&angle(b)).unwrap_or(Ordering::Equal));
+        }
+
+        let mut next = vec![0; origins.len()];
+        for edges in outgoing.iter() {
+            for (k, &h) in edges.iter().enumerate() {
+                // the face left of the incoming twin continues clockwise from it
+                let previous = edges[(k + edges.len() - 1) % edges.len()];
+                next[h ^ 1] = previous;
+            }
+        }
+
+        PlanarGraph { vertices, origins, next, tolerance }
+    }
+
+    pub fn half_edge_count(&self) -> usize {
+        self.origins.len()
+    }
+
+    pub fn origin(&self, h: usize) -> Point2D<T> {
+        self.vertices[self.origins[h]]
+    }
+
+    pub fn cycle_ring(&self, cycle: &[usize]) -> Vec<Point2D<T>> {
+        cycle.iter().map(|&h| self.origin(h)).collect()
+    }
+
+    pub fn faces(&self) -> Faces {
+        let mut cycles = Vec::new();
+        let mut cycle_of = vec![usize::MAX; self.half_edge_count()];
+        for start in 0..self.half_edge_count() {
+            if cycle_of[start] != usize::MAX {
+                continue;
+            }
+            let mut cycle = Vec::new();
+            let mut h = start;
+            while cycle_of[h] == usize::MAX {
+                cycle_of[h] = cycles.len();
+                cycle.push(h);
+                h = self.next[h];
+            }
+            cycles.push(cycle);
+        }
+
+        let areas: Vec<T> = cycles.iter().map(|c| ring::signed_area(&self.cycle_ring(c))).collect();
+        let shells: Vec<usize> = (0..cycles.len()).filter(|&c| areas[c] > T::zero()).collect();
+        let components = self.components();
+
+        let mut owners = vec![None; self.half_edge_count()];
+        let mut holes = vec![Vec::new(); shells.len()];
+        for (face, &shell) in shells.iter().enumerate() {
+            for &h in &cycles[shell] {
+                owners[h] = Some(face);
+            }
+        }
+        for (c, cycle) in cycles.iter().enumerate() {
+            if areas[c] > T::zero() {
+                continue;
+            }
+            let component = components[self.origins[cycle[0]]];
+            let probe = self.origin(cycle[0]);
+            let enclosing = shells
+                .iter()
+                .enumerate()
+                .filter(|&(_, &shell)| components[self.origins[cycles[shell][0]]] != component)
+                .filter(|&(_, &shell)| ring::contains_point(&self.cycle_ring(&cycles[shell]), &probe))
+                .min_by(|a, b| areas[*a.1].partial_cmp(&areas[*b.1]).unwrap_or(Ordering::Equal))
+                .map(|(face, _)| face);
+            if let Some(face) = enclosing {
+                holes[face].push(c);
+                for &h in cycle {
+                    owners[h] = Some(face);
+                }
+            }
+        }
+
+        Faces { cycles, cycle_of, shells, holes, owners }
+    }
+
+    /// Returns a point strictly inside the given face (and outside its holes).
+    ///
+    /// Takes the lexicographically smallest shell vertex `v`, which is convex, and
+    /// its neighbours `a` and `c`. If no other vertex falls inside triangle `avc`
+    /// its centroid is interior; otherwise the diagonal from `v` to the vertex
+    /// deepest inside the triangle is, and its midpoint is used.
+    pub fn interior_point(&self, faces: &Faces, face: usize) -> Point2D<T> {
+        let shell = self.cycle_ring(&faces.cycles[faces.shells[face]]);
+        let n = shell.len();
+        let k = (0..n)
+            .min_by(|&i, &j| shell[i].cmp_xy(&shell[j]))
+            .unwrap();
+        let (a, v, c) = (shell[(k + n - 1) % n], shell[k], shell[(k + 1) % n]);
+
+        let candidates = faces.holes[face]
+            .iter()
+            .flat_map(|&hole| self.cycle_ring(&faces.cycles[hole]))
+            .chain(shell.iter().cloned());
+        let mut deepest: Option<(T, Point2D<T>)> = None;
+        for p in candidates {
+            if p == a || p == v || p == c || !in_triangle(&a, &v, &c, &p) {
+                continue;
+            }
+            let depth = (c - a).cross_product(&(p - a)).abs();
+            if deepest.is_none_or(|(best, _)| depth > best) {
+                deepest = Some((depth, p));
+            }
+        }
+        let two = T::one() + T::one();
+        match deepest {
+            Some((_, w)) => (v + w) / two,
+            None => (a + v + c) / (two + T::one()),
+        }
+    }
+
+    /// Traces the outline of a union of faces.
+    ///
+    /// Keeps the half-edges whose left face is in the region but whose right face
+    /// is not, and chains them by rotating around each vertex past edges interior
+    /// to the region. Shells come out counterclockwise and holes clockwise, with
+    /// vertices that only split a straight edge removed.
+    pub fn region_rings(&self, faces: &Faces, in_region: &[bool]) -> Vec<Vec<Point2D<T>>> {
+        let inside = |h: usize| faces.owners[h].is_some_and(|f| in_region[f]);
+        let boundary: Vec<bool> = (0..self.half_edge_count())
+            .map(|h| inside(h) && !inside(h ^ 1))
+            .collect();
+
+        let mut visited = vec![false; self.half_edge_count()];
+        let mut rings = Vec::new();
+        for start in 0..self.half_edge_count() {
+            if !boundary[start] || visited[start] {
+                continue;
+            }
+            let mut ring = Vec::new();
+            let mut h = start;
+            while !visited[h] {
+                visited[h] = true;
+                ring.push(self.origin(h));
+                let mut g = self.next[h];
+                while !boundary[g] {
+                    g = self.next[g ^ 1];
+                }
+                h = g;
+            }
+            rings.push(remove_collinear(ring, self.tolerance));
+        }
+        rings
+    }
+
+    /// Connected component id of every vertex.
+    fn components(&self) -> Vec<usize> {
+        let mut parent: Vec<usize> = (0..self.vertices.len()).collect();
+        for pair in self.origins.chunks(2) {
+            let (u, v) = (find(&mut parent, pair[0]), find(&mut parent, pair[1]));
+            parent[u] = v;
+        }
+        (0..self.vertices.len()).map(|v| find(&mut parent, v)).collect()
+    }
+}
+
+/// A shell ring together with the hole rings it encloses.
+pub(crate) type ShellWithHoles<T> = (Vec<Point2D<T>>, Vec<Vec<Point2D<T>>>);
+
+/// Groups polygon rings produced by [`PlanarGraph::region_rings`] into shells with their holes.
+pub(crate) fn assemble_rings<T: Float + Debug>(rings: Vec<Vec<Point2D<T>>>) -> Vec<ShellWithHoles<T>> {
+    let (shells, holes): (Vec<_>, Vec<_>) = rings
+        .into_iter()
+        .filter(|r| r.len() >= 3)
+        .partition(|r| ring::signed_area(r) > T::zero());
+
+    let mut polygons: Vec<_> = shells.into_iter().map(|shell| (shell, Vec::new())).collect();
+    for hole in holes {
+        let enclosing = polygons
+            .iter()
+            .enumerate()
+            .filter(|(_, (shell, _))| {
+                hole.iter()
+                    .find(|p| !shell.contains(p))
+                    .is_some_and(|p| ring::contains_point(shell, p))
+            })
+            .min_by(|a, b| {
+                ring::signed_area(&a.1.0)
+                    .partial_cmp(&ring::signed_area(&b.1.0))
+                    .unwrap_or(Ordering::Equal)
+            })
+            .map(|(index, _)| index);
+        if let Some(index) = enclosing {
+            polygons[index].1.push(hole);
+        }
+    }
+    polygons
+}
+
+fn remove_collinear<T: Float + Debug>(mut ring: Vec<Point2D<T>>, tolerance: T) -> Vec<Point2D<T>> {
+    let mut changed = true;
+    while changed && ring.len() > 3 {
+        changed = false;
+        let mut i = 0;
+        while i < ring.len() && ring.len() > 3 {
+            let n = ring.len();
+            let (prev, current, next) = (ring[(i + n - 1) % n], ring[i], ring[(i + 1) % n]);
+            let chord = next - prev;
+            let offset = chord.cross_product(&(current - prev)).abs() / prev.distance(&next);
+            if offset <= tolerance && (current - prev).dot_product(&(next - current)) > T::zero() {
+                ring.remove(i);
+                changed = true;
+            } else {
+                i += 1;
+            }
+        }
+    }
+    ring
+}
+
+/// Clusters points closer than `tolerance`, returning one representative per
+/// cluster (its earliest member) and the cluster index of every input point.
+///
+/// Closeness is per axis, and clusters chain: two points far apart share a
+/// cluster if close points link them, so the graph never keeps two vertices
+/// within `tolerance` of each other. [`dedup_points`](super::point_algorithms::dedup_points)
+/// instead compares Euclidean distances to kept points and does not chain.
+pub(crate) fn merge_points<T: Float + Debug>(points: &[Point2D<T>], tolerance: T) -> (Vec<Point2D<T>>, Vec<usize>) {
+    let mut order: Vec<usize> = (0..points.len()).collect();
+    order.sort_by(|&a, &b| points[a].cmp_xy(&points[b]));
+
+    let mut parent: Vec<usize> = (0..points.len()).collect();
+    for (k, &i) in order.iter().enumerate() {
+        for &j in &order[k + 1..] {
+            if points[j].x - points[i].x > tolerance {
+                break;
+            }
+            if (points[j].y - points[i].y).abs() <= tolerance {
+                let (a, b) = (find(&mut parent, i), find(&mut parent, j));
+                parent[b.max(a)] = b.min(a);
+            }
+        }
+    }
+
+    let mut vertex_of_root = vec![usize::MAX; points.len()];
+    let mut vertices = Vec::new();
+    let mut ids = Vec::with_capacity(points.len());
+    for i in 0..points.len() {
+        let root = find(&mut parent, i);
+        if vertex_of_root[root] == usize::MAX {
+            vertex_of_root[root] = vertices.len();
+            vertices.push(points[root]);
+        }
+        ids.push(vertex_of_root[root]);
+    }
+    (vertices, ids)
+}
+
+fn find(parent: &mut [usize], mut i: usize) -> usize {
+    while parent[i] != i {
+        parent[i] = parent[parent[i]];
+        i = parent[i];
+    }
+    i
+}
+
+fn in_triangle<T: Float + Debug>(a: &Point2D<T>, b: &Point2D<T>, c: &Point2D<T>, p: &Point2D<T>) -> bool {
+    let d1 = (*b - *a).cross_product(&(*p - *a));
+    let d2 = (*c - *b).cross_product(&(*p - *b));
+    let d3 = (*a - *c).cross_product(&(*p - *c));
+    let has_negative = d1 < T::zero() || d2 < T::zero() || d3 < T::zero();
+    let has_positive = d1 > T::zero() || d2 > T::zero() || d3 > T::zero();
+    !(has_negative && has_positive)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segments(ring: &[(f64, f64)]) -> Vec<Line2D<f64>> {
+        (0..ring.len())
+            .map(|i| {
+                let (a, b) = (ring[i], ring[(i + 1) % ring.len()]);
+                Line2D::new(Point2D::new(a.0, a.1), Point2D::new(b.0, b.1))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_planar_graph_square_has_one_face() {
+        let graph = PlanarGraph::new(&segments(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]), 1e-9);
+        let faces = graph.faces();
+
+        assert_eq!(graph.vertices.len(), 4);
+        assert_eq!(faces.cycles.len(), 2);
+        assert_eq!(faces.shells.len(), 1);
+        let p = graph.interior_point(&faces, 0);
+        assert!(p.x > 0.0 && p.x < 1.0 && p.y > 0.0 && p.y < 1.0);
+    }
+
+    #[test]
+    fn test_planar_graph_nested_component_becomes_hole() {
+        let mut input = segments(&[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)]);
+        input.extend(segments(&[(1.0, 1.0), (2.0, 1.0), (2.0, 2.0), (1.0, 2.0)]));
+        let graph = PlanarGraph::new(&input, 1e-9);
+        let faces = graph.faces();
+
+        assert_eq!(faces.shells.len(), 2);
+        let outer = (0..2).find(|&f| !faces.holes[f].is_empty()).unwrap();
+        assert_eq!(faces.holes[outer].len(), 1);
+
+        // the interior point of the outer face must avoid the island
+        let p = graph.interior_point(&faces, outer);
+        assert!(!(p.x >= 1.0 && p.x <= 2.0 && p.y >= 1.0 && p.y <= 2.0));
+    }
+
+    #[test]
+    fn test_region_rings_dissolve_shared_edge() {
+        let mut input = segments(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]);
+        input.extend(segments(&[(1.0, 0.0), (2.0, 0.0), (2.0, 1.0), (1.0, 1.0)]));
+        let graph = PlanarGraph::new(&input, 1e-9);
+        let faces = graph.faces();
+
+        let rings = graph.region_rings(&faces, &[true, true]);
+        assert_eq!(rings.len(), 1);
+        assert_eq!(rings[0].len(), 4);
+        assert_eq!(ring::signed_area(&rings[0]), 2.0);
+    }
+
+    #[test]
+    fn test_merge_points_within_tolerance() {
+        let (vertices, ids) = merge_points(
+            &[Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0), Point2D::new(1e-12, -1e-12)],
+            1e-9,
+        );
+        assert_eq!(vertices.len(), 2);
+        assert_eq!(ids[0], ids[2]);
+    }
+}
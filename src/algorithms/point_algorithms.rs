@@ -1,32 +1,73 @@
-use std::cmp::Ordering;
-use num::{Float};
-use std::fmt::Debug;
-use crate::point::Point2D;
+use core::cmp::Ordering;
+use num::{Float, Num};
+use core::fmt::Debug;
+use crate::point::{Point2D, Vector2D, total_cmp};
+use crate::line::Line2D;
+use crate::shape::Aabb2D;
+use super::accumulators::WeightedCentroidAccumulator;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
 ///Computes the centorid of a set of points
 pub fn centroid_2d<T: Float + Debug>(points: &[Point2D<T>]) -> Point2D<T> {
-    let n = points.len();
-    let sum = points.iter().cloned().fold(Point2D::origin(), |acc, p| acc + p);
+    centroid_iter_2d(points.iter().cloned())
+}
+
+/// Computes the centroid of a stream of points without collecting them first.
+///
+/// Same result as [`centroid_2d`] on the collected points.
+pub fn centroid_iter_2d<T: Float + Debug>(points: impl IntoIterator<Item = Point2D<T>>) -> Point2D<T> {
+    let (sum, n) = points
+        .into_iter()
+        .fold((Point2D::origin(), 0usize), |(acc, n), p| (acc + p, n + 1));
     sum / T::from(n).unwrap()
 }
 
+/// Computes the center of mass of `points`, the `i`-th carrying mass `weights[i]`.
+///
+/// # Returns
+/// * The weighted centroid, or `None` if the weights sum to zero (including
+///   when there are no points).
+///
+/// # Panics
+/// If `points` and `weights` differ in length.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::point_algorithms::weighted_centroid_2d;
+/// use geomengine::point::Point2D;
+///
+/// let points = [Point2D::new(0.0, 0.0), Point2D::new(4.0, 0.0)];
+/// assert_eq!(weighted_centroid_2d(&points, &[3.0, 1.0]), Some(Point2D::new(1.0, 0.0)));
+/// ```
+pub fn weighted_centroid_2d<T: Float + Debug>(points: &[Point2D<T>], weights: &[T]) -> Option<Point2D<T>> {
+    assert_eq!(points.len(), weights.len(), "every point needs a weight");
+    points.iter().copied().zip(weights.iter().copied()).collect::<WeightedCentroidAccumulator<T>>().centroid()
+}
+
 ///Computes the axis aligned bounding box for a set of points
-pub fn bounding_box_2d<T: Float + Debug>(points: &[Point2D<T>]) -> Option<(Point2D<T>, Point2D<T>)> {
-    if points.is_empty() {
-        return None;
-    }
+pub fn bounding_box_2d<T: Float + Debug>(points: &[Point2D<T>]) -> Option<Aabb2D<T>> {
+    bounding_box_iter_2d(points.iter().cloned())
+}
+
+/// Computes the axis aligned bounding box of a stream of points without collecting them first.
+///
+/// Returns `None` if the stream is empty.
+pub fn bounding_box_iter_2d<T: Float + Debug>(points: impl IntoIterator<Item = Point2D<T>>) -> Option<Aabb2D<T>> {
+    let mut points = points.into_iter().peekable();
+    points.peek()?;
 
     let mut max_x = T::min_value();
     let mut max_y = T::min_value();
     let mut min_x = T::max_value();
     let mut min_y = T::max_value();
 
-    for &point in points {
+    for point in points {
         if point.x < min_x { min_x = point.x; }
         if point.y < min_y { min_y = point.y; }
         if point.x > max_x { max_x = point.x; }
         if point.y > max_y { max_y = point.y; }
     }
-    Some((Point2D::new(min_x, min_y), Point2D::new(max_x, max_y)))
+    Some(Aabb2D::new(Point2D::new(min_x, min_y), Point2D::new(max_x, max_y)))
 }
 
 /// Computes the convex hull of a set of 2D points using Andrew's Monotone Chain Algorithm.
@@ -47,100 +88,267 @@ pub fn bounding_box_2d<T: Float + Debug>(points: &[Point2D<T>]) -> Option<(Point
 /// # Time Complexity
 /// **O(n log n)** due to sorting, but hull construction runs in **O(n)**, making this optimal.
 pub fn convex_hull_2d<T: Float + Debug>(points: &[Point2D<T>]) -> Vec<Point2D<T>> {
-    let mut points = points.to_vec();
+    convex_hull_2d_owned(points.to_vec())
+}
 
+/// [`convex_hull_2d`] taking ownership of the points, which saves copying the input.
+///
+/// The points are sorted in place and the hull is built in the same buffer.
+pub fn convex_hull_2d_owned<T: Float + Debug>(mut points: Vec<Point2D<T>>) -> Vec<Point2D<T>> {
     if points.len() < 3 {
         return points;
     }
-    
+    sort_lexicographic(&mut points);
+    monotone_chain(&mut points);
+    points
+}
+
+/// [`convex_hull_2d`] writing into `hull`, which is cleared first.
+///
+/// Reusing the same buffer across calls avoids allocating once it has grown to
+/// about twice the number of input points.
+pub fn convex_hull_2d_into<T: Float + Debug>(points: &[Point2D<T>], hull: &mut Vec<Point2D<T>>) {
+    hull.clear();
+    hull.extend_from_slice(points);
+    if hull.len() >= 3 {
+        sort_lexicographic(hull);
+        monotone_chain(hull);
+    }
+}
+
+/// Sorts points by x, then y, as the monotone chain expects.
+pub(crate) fn sort_lexicographic<T: Float + Debug>(points: &mut [Point2D<T>]) {
+    points.sort_by(Point2D::cmp_xy);
+}
+
+/// Sorts `points` counterclockwise by their direction from `center`, starting
+/// along the positive x axis; points in the same direction are ordered nearest
+/// first.
+///
+/// The order is total even for degenerate input: a point at `center` comes
+/// first and points with NaN coordinates come last.
+///
+/// # Algorithm Explanation
+/// Directions are compared exactly with the sign of a cross product inside
+/// the upper (`[0, π)`) and lower (`[π, 2π)`) half-planes rather than through
+/// `atan2`, whose rounding can misorder nearly parallel directions.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::point_algorithms::sort_radially_about;
+/// use geomengine::point::Point2D;
+///
+/// let mut points = [Point2D::new(0.0, -1.0), Point2D::new(-1.0, 0.0), Point2D::new(2.0, 0.0), Point2D::new(1.0, 1.0)];
+/// sort_radially_about(Point2D::new(0.0, 0.0), &mut points);
+/// assert_eq!(points, [Point2D::new(2.0, 0.0), Point2D::new(1.0, 1.0), Point2D::new(-1.0, 0.0), Point2D::new(0.0, -1.0)]);
+/// ```
+pub fn sort_radially_about<T: Float + Debug>(center: Point2D<T>, points: &mut [Point2D<T>]) {
+    let is_nan = |p: &Vector2D<T>| p.x.is_nan() || p.y.is_nan();
+    let lower_half = |d: &Vector2D<T>| d.y < T::zero() || (d.y == T::zero() && d.x < T::zero());
     points.sort_by(|a, b| {
-                        a.x
-                        .partial_cmp(&b.x)
-                        .unwrap_or(Ordering::Equal)
-                        .then(
-                            a.y
-                            .partial_cmp(&b.y)
-                            .unwrap_or(Ordering::Equal)
-                        )
+        let (u, v) = (*a - center, *b - center);
+        if is_nan(&u) || is_nan(&v) {
+            return is_nan(&u).cmp(&is_nan(&v));
+        }
+        lower_half(&u)
+            .cmp(&lower_half(&v))
+            .then_with(|| T::zero().partial_cmp(&u.cross_product(&v)).unwrap_or(Ordering::Equal))
+            .then_with(|| total_cmp(u.dot_product(&u), v.dot_product(&v)))
     });
+}
 
+/// Replaces at least three sorted points in `buffer` with their convex hull.
+///
+/// Both chains are pushed as a stack behind the sorted points (the upper hull
+/// on top of the lower one), and the sorted points are drained at the end.
+pub(crate) fn monotone_chain<T: Float + Debug>(buffer: &mut Vec<Point2D<T>>) {
     let cross_product = |o: Point2D<T>, a: Point2D<T>, b: Point2D<T>| -> T {
         (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x)
     };
 
-    let mut lower = Vec::new();
-    for &p in points.iter() {
-        while lower.len() >= 2 && cross_product(lower[lower.len()-2], lower[lower.len()-1], p) <= T::zero() {
-            lower.pop();
+    let n = buffer.len();
+    buffer.reserve(n + 1);
+    for i in 0..n {
+        let p = buffer[i];
+        while buffer.len() >= n + 2 && cross_product(buffer[buffer.len()-2], buffer[buffer.len()-1], p) <= T::zero() {
+            buffer.pop();
         }
-        lower.push(p);
+        buffer.push(p);
     }
 
-    let mut upper = Vec::new();
-    for &p in points.iter().rev() {
-        while upper.len() >= 2 && cross_product(upper[upper.len() - 2], upper[upper.len()-1], p) <= T::zero() {
-            upper.pop();
+    // the upper hull starts from the last point of the lower one and may not pop into it
+    let lower_len = buffer.len() + 1;
+    for i in (0..n - 1).rev() {
+        let p = buffer[i];
+        while buffer.len() >= lower_len && cross_product(buffer[buffer.len() - 2], buffer[buffer.len()-1], p) <= T::zero() {
+            buffer.pop();
         }
-        upper.push(p);
+        buffer.push(p);
     }
 
-    lower.pop();
-    upper.pop();
-    lower.extend(upper);
-    lower
+    // the last point pushed is the first point of the lower hull again
+    buffer.pop();
+    buffer.drain(..n);
 }
 
-/// Projects a point onto a given line and returns the closest point on the line.
+/// Projects a point onto a segment, returning the point of the segment closest to it.
 ///
-/// The projection is computed using the formula:
+/// `Line2D` is a segment, so points beyond either end project onto that
+/// endpoint; use [`InfiniteLine2D::project`](crate::line::InfiniteLine2D::project)
+/// to project onto the whole line.
+/// Steep and vertical segments need no special case, and a zero-length
+/// segment projects everything onto its single point.
 ///
-/// ```text
-/// x' = (m² * x₁ + x₀ + m (y₀ - y₁)) / (m² + 1)
-/// y' = m * (x' - x₁) + y₁
+/// # Example
 /// ```
+/// use geomengine::algorithms::point_algorithms::project_point_to_line_2d;
+/// use geomengine::line::Line2D;
+/// use geomengine::point::Point2D;
 ///
-/// where:
-/// - `(x₀, y₀)` is the original point.
-/// - `(x₁, y₁)` is a point on the given line.
-/// - `m` is the slope of the line.
-///
-/// # Parameters
-/// - `point`: A reference to the `Point2D<T>` to be projected.
-/// - `line`: A reference to the `Line2D<T>` onto which the point is projected.
+/// let segment = Line2D::new(Point2D::new(0.0, 0.0), Point2D::new(4.0, 0.0));
+/// assert_eq!(project_point_to_line_2d(&Point2D::new(1.0, 3.0), &segment), Point2D::new(1.0, 0.0));
+/// assert_eq!(project_point_to_line_2d(&Point2D::new(6.0, 3.0), &segment), Point2D::new(4.0, 0.0));
+/// ```
+pub fn project_point_to_line_2d<T: Float + Debug>(point: &Point2D<T>, line: &Line2D<T>) -> Point2D<T> {
+    line.closest_point_to(point)
+}
+
+/// Removes duplicate points, keeping the first of each.
 ///
 /// # Returns
-/// - A `Point2D<T>` representing the closest point on the line to the given point.
+/// * The kept points in input order, and for every input point the index of
+///   the kept point standing in for it.
 ///
-/// # Special Cases
-/// - If the line is vertical (`m` is undefined), the projected point will have the same x-coordinate as the line.
-/// - If the line is horizontal (`m = 0`), the projected point will have the same y-coordinate as the line.
+/// # Algorithm Explanation
+/// Points are bucketed in a grid of `tolerance` sized cells. Each point joins
+/// the first kept point within `tolerance` of it, found among the kept points
+/// of its own and the eight neighbouring cells, or else is kept itself. Chains
+/// of points each within `tolerance` of the next are not merged as a whole.
+/// This differs from [`snap_points_together`](super::snapping::snap_points_together),
+/// which measures `tolerance` per axis (a square, not a circle) and merges such
+/// chains into one cluster; use it when every vertex near a cluster must join it.
+/// A `tolerance` of zero merges only equal coordinates (`-0.0` and `0.0` included).
+/// Along an axis where a coordinate is too large for its cell number to fit an
+/// `i64`, the tolerance is below the float spacing there, so points are
+/// bucketed by the exact coordinate instead.
 ///
-/// # Type Parameters
-/// - `T`: A floating-point type that implements `Float` and `Copy`.
-pub fn project_point_to_line_2d<T: Float + Copy>(point: &Point2D<T>, line: &Line2D<T>) -> Point<T> {
-    let (Point2D{x: x1, y: y1}, Point2D{x: x2, y: y2}) = line;
-    let Point2D{x: x0, y: y0} = point;
-
-    let epsilon = T::from(1e-6).unwrap();
-
-    //in case of vertical lines
-    if (x2 - x1).abs() < epsilon {
-        return Point2D::new(x1, y0);
+/// # Time Complexity
+/// **O(n log n)** unless many kept points crowd into the same cells.
+///
+/// # Panics
+/// If `tolerance` is NaN or infinite.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::point_algorithms::dedup_points;
+/// use geomengine::point::Point2D;
+///
+/// let points = [Point2D::new(0.0, 0.0), Point2D::new(5.0, 5.0), Point2D::new(0.01, 0.0)];
+/// let (unique, remap) = dedup_points(&points, 0.1);
+/// assert_eq!(unique, vec![Point2D::new(0.0, 0.0), Point2D::new(5.0, 5.0)]);
+/// assert_eq!(remap, vec![0, 1, 0]);
+/// ```
+pub fn dedup_points<T: Float + Debug>(points: &[Point2D<T>], tolerance: T) -> (Vec<Point2D<T>>, Vec<usize>) {
+    assert!(tolerance.is_finite(), "dedup tolerance must be finite");
+    let mut unique = Vec::new();
+    let mut remap = Vec::with_capacity(points.len());
+    if tolerance <= T::zero() {
+        // exact: key on the bit patterns, with negative zero made positive
+        let mut seen = BTreeMap::new();
+        for &p in points {
+            let key = ((p.x + T::zero()).integer_decode(), (p.y + T::zero()).integer_decode());
+            remap.push(*seen.entry(key).or_insert_with(|| {
+                unique.push(p);
+                unique.len() - 1
+            }));
+        }
+        return (unique, remap);
     }
 
-    let m = (y2 - y1) / (x2 - x1);
-
-    let x_prime = (m * m * x1 + x0 + m * (y0 - y1)) / (m * m + T::one())
+    let cell_of = |v: T| match (v / tolerance).floor().to_i64() {
+        Some(i) => Cell::Index(i),
+        None => Cell::Exact((v + T::zero()).integer_decode()),
+    };
+    let neighbours = |c: Cell| match c {
+        Cell::Index(i) => [i.checked_sub(1).map(Cell::Index), Some(c), i.checked_add(1).map(Cell::Index)],
+        Cell::Exact(_) => [None, Some(c), None],
+    };
+    let mut grid: BTreeMap<(Cell, Cell), Vec<usize>> = BTreeMap::new();
+    for p in points {
+        let (cx, cy) = (cell_of(p.x), cell_of(p.y));
+        let found = neighbours(cx)
+            .into_iter()
+            .flatten()
+            .flat_map(|x| neighbours(cy).into_iter().flatten().map(move |y| (x, y)))
+            .filter_map(|cell| grid.get(&cell))
+            .flatten()
+            .copied()
+            .filter(|&k| unique[k].distance(p) <= tolerance)
+            .min();
+        remap.push(found.unwrap_or_else(|| {
+            unique.push(*p);
+            grid.entry((cx, cy)).or_default().push(unique.len() - 1);
+            unique.len() - 1
+        }));
+    }
+    (unique, remap)
+}
 
-    let y_prime = m * (x_prime - x1) + y1;
+/// Bucket of one coordinate in [`dedup_points`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Cell {
+    Index(i64),
+    Exact((u64, i16, i8)),
+}
 
-    Point2D::new(x_prime, y_prime)
+/// [`dedup_points`] for exact coordinates such as integers: merges equal points only.
+///
+/// # Time Complexity
+/// **O(n log n)**.
+pub fn dedup_points_exact<T: Num + Copy + Debug + Ord>(points: &[Point2D<T>]) -> (Vec<Point2D<T>>, Vec<usize>) {
+    let mut unique = Vec::new();
+    let mut seen = BTreeMap::new();
+    let remap = points
+        .iter()
+        .map(|&p| {
+            *seen.entry((p.x, p.y)).or_insert_with(|| {
+                unique.push(p);
+                unique.len() - 1
+            })
+        })
+        .collect();
+    (unique, remap)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::point::Point2D;
+    use approx::assert_relative_eq;
+    #[test]
+    fn test_sort_radially_about_degenerate_points() {
+        let center = Point2D::new(1.0, 1.0);
+        let mut points = [
+            Point2D::new(f64::NAN, 1.0),
+            Point2D::new(1.0, 0.0),
+            Point2D::new(3.0, 1.0),
+            Point2D::new(1.0, 1.0),
+            Point2D::new(2.0, 1.0),
+            Point2D::new(1.0 - 1e-12, 0.0),
+            Point2D::new(0.0, 1.0),
+        ];
+        sort_radially_about(center, &mut points);
+        assert_eq!(&points[..6], &[
+            Point2D::new(1.0, 1.0),
+            Point2D::new(2.0, 1.0),
+            Point2D::new(3.0, 1.0),
+            Point2D::new(0.0, 1.0),
+            Point2D::new(1.0 - 1e-12, 0.0),
+            Point2D::new(1.0, 0.0),
+        ]);
+        assert!(points[6].x.is_nan());
+    }
+
     #[test]
     fn test_convex_hull_2d_single_point() {
         let points = vec![Point2D::new(1.0, 1.0)];
@@ -212,7 +420,7 @@ mod tests {
     fn test_bounding_box_2d_single_point() {
         let points = vec![Point2D::new(1.0, 2.0)];
         let bbox = bounding_box_2d(&points);
-        assert_eq!(bbox, Some((Point2D::new(1.0, 2.0), Point2D::new(1.0, 2.0))));
+        assert_eq!(bbox, Some(Aabb2D::new(Point2D::new(1.0, 2.0), Point2D::new(1.0, 2.0))));
     }
 
     #[test]
@@ -224,7 +432,7 @@ mod tests {
             Point2D::new(1.0, 4.0),
         ];
         let bbox = bounding_box_2d(&points);
-        assert_eq!(bbox, Some((Point2D::new(0.0, 0.0), Point2D::new(2.0, 4.0))));
+        assert_eq!(bbox, Some(Aabb2D::new(Point2D::new(0.0, 0.0), Point2D::new(2.0, 4.0))));
     }
     
     #[test]
@@ -236,7 +444,132 @@ mod tests {
             Point2D::new(2.0, 1.0),
         ];
         let bbox = bounding_box_2d(&points);
-        assert_eq!(bbox, Some((Point2D::new(-3.0, -4.0), Point2D::new(2.0, 3.0))));
+        assert_eq!(bbox, Some(Aabb2D::new(Point2D::new(-3.0, -4.0), Point2D::new(2.0, 3.0))));
+    }
+
+    #[test]
+    fn test_iter_variants_without_collecting() {
+        let generated = (0..5).map(|i| Point2D::new(i as f64, (i * i) as f64));
+        assert_eq!(centroid_iter_2d(generated.clone()), Point2D::new(2.0, 6.0));
+        assert_eq!(
+            bounding_box_iter_2d(generated),
+            Some(Aabb2D::new(Point2D::new(0.0, 0.0), Point2D::new(4.0, 16.0)))
+        );
+        assert_eq!(bounding_box_iter_2d(core::iter::empty::<Point2D<f64>>()), None);
+    }
+
+    #[test]
+    fn test_convex_hull_2d_owned_matches_borrowed() {
+        let points: Vec<_> = (0..200)
+            .map(|i| Point2D::new(((i * 37) % 101) as f64, ((i * 53) % 97) as f64))
+            .collect();
+        assert_eq!(convex_hull_2d_owned(points.clone()), convex_hull_2d(&points));
+
+        let same = vec![Point2D::new(1.0, 1.0); 4];
+        assert_eq!(convex_hull_2d_owned(same), vec![Point2D::new(1.0, 1.0); 2]);
+    }
+
+    #[test]
+    fn test_convex_hull_2d_into_reuses_buffer() {
+        let points: Vec<_> = (0..100)
+            .map(|i| Point2D::new(((i * 37) % 101) as f64, ((i * 53) % 97) as f64))
+            .collect();
+        let mut hull = Vec::new();
+        convex_hull_2d_into(&points, &mut hull);
+        assert_eq!(hull, convex_hull_2d(&points));
+
+        let buffer = hull.as_ptr();
+        convex_hull_2d_into(&points[..40], &mut hull);
+        assert_eq!(hull, convex_hull_2d(&points[..40]));
+        assert_eq!(hull.as_ptr(), buffer);
+
+        convex_hull_2d_into(&points[..2], &mut hull);
+        assert_eq!(hull, points[..2].to_vec());
     }
 
-}
\ No newline at end of file
+
+    #[test]
+    fn test_dedup_points_within_tolerance() {
+        let points = [
+            Point2D::new(0.0, 0.0),
+            Point2D::new(1.0, 1.0),
+            Point2D::new(0.05, -0.05),
+            Point2D::new(-0.0, 0.0),
+            Point2D::new(1.09, 1.0),
+            Point2D::new(1.18, 1.0),
+        ];
+        let (unique, remap) = dedup_points(&points, 0.1);
+        // 1.18 is within reach of 1.09 only, which was merged away
+        assert_eq!(unique, vec![Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0), Point2D::new(1.18, 1.0)]);
+        assert_eq!(remap, vec![0, 1, 0, 0, 1, 2]);
+
+        let (unique, remap) = dedup_points(&points, 0.0);
+        assert_eq!(unique.len(), 5);
+        assert_eq!(remap, vec![0, 1, 2, 0, 3, 4]);
+        assert!(remap.iter().zip(&points).all(|(&k, p)| unique[k] == *p));
+    }
+
+    #[test]
+    fn test_dedup_points_extreme_cells() {
+        // the cell number of -2^63 is i64::MIN, whose left neighbour does not exist
+        let edge = -9223372036854775808.0;
+        let (unique, remap) = dedup_points(&[Point2D::new(edge, 0.0), Point2D::new(edge, 0.5), Point2D::new(1e300, 0.0)], 1.0);
+        assert_eq!(unique.len(), 2);
+        assert_eq!(remap, vec![0, 0, 1]);
+
+        // cell numbers overflow i64 everywhere: bucketed by exact coordinates, not all in one
+        let points: Vec<_> = (0..20_000).map(|i| Point2D::new(i as f64, (i % 7) as f64)).chain([Point2D::new(3.0, 3.0)]).collect();
+        let (unique, remap) = dedup_points(&points, 1e-300);
+        assert_eq!(unique.len(), 20_000);
+        assert_eq!(remap[20_000], 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "finite")]
+    fn test_dedup_points_rejects_nan_tolerance() {
+        dedup_points(&[Point2D::new(0.0, 0.0)], f64::NAN);
+    }
+
+    #[test]
+    fn test_dedup_points_exact_integers() {
+        let points = [Point2D::new(3, 4), Point2D::new(-1, 2), Point2D::new(3, 4), Point2D::new(-1, 2), Point2D::new(0, 0)];
+        let (unique, remap) = dedup_points_exact(&points);
+        assert_eq!(unique, vec![Point2D::new(3, 4), Point2D::new(-1, 2), Point2D::new(0, 0)]);
+        assert_eq!(remap, vec![0, 1, 0, 1, 2]);
+        assert_eq!(dedup_points_exact::<i32>(&[]), (vec![], vec![]));
+    }
+
+    #[test]
+    fn test_project_point_to_line_2d_stays_on_segment() {
+        let segment = Line2D::new(Point2D::new(1.0, 1.0), Point2D::new(3.0, 3.0));
+        assert_eq!(project_point_to_line_2d(&Point2D::new(3.0, 1.0), &segment), Point2D::new(2.0, 2.0));
+        assert_eq!(project_point_to_line_2d(&Point2D::new(-4.0, 0.0), &segment), Point2D::new(1.0, 1.0));
+        assert_eq!(project_point_to_line_2d(&Point2D::new(9.0, 5.0), &segment), Point2D::new(3.0, 3.0));
+
+        // short enough to pass for vertical under a fixed cutoff, but diagonal
+        let tiny = Line2D::new(Point2D::new(0.0, 0.0), Point2D::new(5e-7, 5e-7));
+        assert_eq!(project_point_to_line_2d(&Point2D::new(0.0, 1e-6), &tiny), Point2D::new(5e-7, 5e-7));
+        let steep = Line2D::new(Point2D::new(0.0, 0.0), Point2D::new(1e-7, 10.0));
+        let projected = project_point_to_line_2d(&Point2D::new(5.0, 5.0), &steep);
+        assert_relative_eq!(projected.x, 5e-8, epsilon = 1e-15);
+        assert_relative_eq!(projected.y, 5.0 + 5e-8, epsilon = 1e-12);
+        let point = Line2D::new(Point2D::new(2.0, 2.0), Point2D::new(2.0, 2.0));
+        assert_eq!(project_point_to_line_2d(&Point2D::new(0.0, 0.0), &point), Point2D::new(2.0, 2.0));
+    }
+
+    #[test]
+    fn test_dedup_points_does_not_chain_like_snapping() {
+        use crate::algorithms::snapping::snap_points_together;
+        // each point is 0.8 from the next, so only neighbours are within tolerance
+        let chain = [Point2D::new(0.0, 0.0), Point2D::new(0.8, 0.0), Point2D::new(1.6, 0.0)];
+        let (unique, remap) = dedup_points(&chain, 1.0);
+        assert_eq!(unique, vec![Point2D::new(0.0, 0.0), Point2D::new(1.6, 0.0)]);
+        assert_eq!(remap, vec![0, 0, 1]);
+        assert_eq!(snap_points_together(&chain, 1.0), [Point2D::new(0.0, 0.0); 3]);
+
+        // 0.9 apart on both axes: inside the square tolerance, outside the circle
+        let diagonal = [Point2D::new(0.0, 0.0), Point2D::new(0.9, 0.9)];
+        assert_eq!(dedup_points(&diagonal, 1.0).0, diagonal.to_vec());
+        assert_eq!(snap_points_together(&diagonal, 1.0), [Point2D::new(0.0, 0.0); 2]);
+    }
+}
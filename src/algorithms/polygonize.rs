@@ -0,0 +1,150 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::line::Line2D;
+use crate::polygon::Polygon2D;
+use super::noding::{node_segments, snap_tolerance};
+use super::planar_graph::PlanarGraph;
+use alloc::vec::Vec;
+
+/// Assembles raw linework into the polygons it encloses.
+///
+/// # Arguments
+/// * `segments` - Unstructured segments, e.g. digitized boundaries. They do not
+///   need to be noded: crossings and T-junctions are split first.
+///
+/// # Returns
+/// * One `(exterior, holes)` pair per closed face, exteriors counterclockwise and
+///   holes clockwise. Faces nested inside another face are returned as well as
+///   being cut out of it, so the result tiles the enclosed area exactly once.
+///
+/// Dangling edges (overshoots, loose ends) and cut edges (lines bridging two
+/// rings without enclosing anything) do not bound any area and are ignored.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::polygonize::polygonize_2d;
+/// use geomengine::line::Line2D;
+/// use geomengine::point::Point2D;
+///
+/// // a '#' drawn with overshooting strokes encloses only the middle cell
+/// let strokes = [
+///     Line2D::new(Point2D::new(1.0, 0.0), Point2D::new(1.0, 3.0)),
+///     Line2D::new(Point2D::new(2.0, 0.0), Point2D::new(2.0, 3.0)),
+///     Line2D::new(Point2D::new(0.0, 1.0), Point2D::new(3.0, 1.0)),
+///     Line2D::new(Point2D::new(0.0, 2.0), Point2D::new(3.0, 2.0)),
+/// ];
+/// let polygons = polygonize_2d(&strokes);
+/// assert_eq!(polygons.len(), 1);
+/// assert_eq!(polygons[0].0.len(), 4);
+/// ```
+pub fn polygonize_2d<T: Float + Debug>(segments: &[Line2D<T>]) -> Vec<(Polygon2D<T>, Vec<Polygon2D<T>>)> {
+    let tolerance = snap_tolerance(segments.iter().flat_map(|s| [s.p1, s.p2]));
+    let mut edges = node_segments(segments, tolerance);
+
+    loop {
+        let graph = PlanarGraph::new(&edges, tolerance);
+        let faces = graph.faces();
+
+        // an edge with the same cycle on both sides has no area on either side
+        let kept: Vec<Line2D<T>> = (0..graph.half_edge_count())
+            .step_by(2)
+            .filter(|&h| faces.cycle_of[h] != faces.cycle_of[h ^ 1])
+            .map(|h| Line2D::new(graph.origin(h), graph.origin(h ^ 1)))
+            .collect();
+        if kept.len() * 2 < graph.half_edge_count() {
+            edges = kept;
+            continue;
+        }
+
+        return (0..faces.shells.len())
+            .map(|face| {
+                let exterior = Polygon2D::new(graph.cycle_ring(&faces.cycles[faces.shells[face]]));
+                let holes = faces.holes[face]
+                    .iter()
+                    .map(|&hole| Polygon2D::new(graph.cycle_ring(&faces.cycles[hole])))
+                    .collect();
+                (exterior, holes)
+            })
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::point::Point2D;
+    use crate::polygon::ring::signed_area;
+
+    fn segment(x1: f64, y1: f64, x2: f64, y2: f64) -> Line2D<f64> {
+        Line2D::new(Point2D::new(x1, y1), Point2D::new(x2, y2))
+    }
+
+    fn outline(x1: f64, y1: f64, x2: f64, y2: f64) -> Vec<Line2D<f64>> {
+        vec![
+            segment(x1, y1, x2, y1),
+            segment(x2, y1, x2, y2),
+            segment(x2, y2, x1, y2),
+            segment(x1, y2, x1, y1),
+        ]
+    }
+
+    #[test]
+    fn test_polygonize_2d_square_split_by_diagonal() {
+        let mut lines = outline(0.0, 0.0, 2.0, 2.0);
+        lines.push(segment(0.0, 0.0, 2.0, 2.0));
+        let polygons = polygonize_2d(&lines);
+
+        assert_eq!(polygons.len(), 2);
+        for (exterior, holes) in &polygons {
+            assert_eq!(exterior.len(), 3);
+            assert!(holes.is_empty());
+            assert_eq!(signed_area(exterior.vertices()), 2.0);
+        }
+    }
+
+    #[test]
+    fn test_polygonize_2d_crossing_strokes() {
+        // a bow-tie drawn as two crossing diagonals closed by top and bottom edges
+        let lines = vec![
+            segment(0.0, 0.0, 2.0, 2.0),
+            segment(0.0, 2.0, 2.0, 0.0),
+            segment(0.0, 0.0, 2.0, 0.0),
+            segment(0.0, 2.0, 2.0, 2.0),
+        ];
+        let polygons = polygonize_2d(&lines);
+        assert_eq!(polygons.len(), 2);
+        assert!(polygons.iter().all(|(exterior, _)| signed_area(exterior.vertices()) == 1.0));
+    }
+
+    #[test]
+    fn test_polygonize_2d_nested_rings() {
+        let mut lines = outline(0.0, 0.0, 10.0, 10.0);
+        lines.extend(outline(4.0, 4.0, 6.0, 6.0));
+        let polygons = polygonize_2d(&lines);
+
+        assert_eq!(polygons.len(), 2);
+        let outer = polygons.iter().find(|(_, holes)| !holes.is_empty()).unwrap();
+        assert_eq!(outer.1.len(), 1);
+        assert_eq!(signed_area(outer.1[0].vertices()), -4.0);
+    }
+
+    #[test]
+    fn test_polygonize_2d_ignores_dangles_and_cut_edges() {
+        let mut lines = outline(0.0, 0.0, 10.0, 10.0);
+        lines.extend(outline(4.0, 4.0, 6.0, 6.0));
+        lines.push(segment(0.0, 5.0, 4.0, 5.0)); // cut edge bridging the rings
+        lines.push(segment(7.0, 7.0, 8.0, 9.0)); // loose dangle
+        lines.push(segment(10.0, 0.0, 12.0, -1.0)); // overshoot
+        let polygons = polygonize_2d(&lines);
+
+        assert_eq!(polygons.len(), 2);
+        let outer = polygons.iter().find(|(_, holes)| !holes.is_empty()).unwrap();
+        assert_eq!(signed_area(outer.0.vertices()), 100.0);
+    }
+
+    #[test]
+    fn test_polygonize_2d_open_lines_enclose_nothing() {
+        let lines = vec![segment(0.0, 0.0, 1.0, 0.0), segment(1.0, 0.0, 1.0, 1.0)];
+        assert!(polygonize_2d(&lines).is_empty());
+    }
+}
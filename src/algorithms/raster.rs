@@ -0,0 +1,549 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::{Point2D, total_cmp};
+use crate::polygon::Polygon2D;
+use crate::shape::Aabb2D;
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Cells of the Bresenham line from cell `a` to cell `b`, both included.
+///
+/// # Returns
+/// * One cell per step along the major axis, each 8-connected to the one before:
+///   the thinnest gapless line, as drawn for pixel art or walked for grid rays.
+///
+/// # Algorithm Explanation
+/// The minor coordinate advances whenever the accumulated error, the distance of
+/// the ideal line from the cell centers scaled to stay integral, exceeds half a
+/// cell. Only integer additions and comparisons are used.
+///
+/// # Time Complexity
+/// **O(1)** per cell, `max(|dx|, |dy|) + 1` cells.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::raster::rasterize_line;
+/// use geomengine::point::Point2D;
+///
+/// let cells: Vec<_> = rasterize_line(Point2D::new(0, 0), Point2D::new(4, 2)).map(|p| (p.x, p.y)).collect();
+/// assert_eq!(cells, [(0, 0), (1, 1), (2, 1), (3, 2), (4, 2)]);
+/// ```
+pub fn rasterize_line(a: Point2D<i32>, b: Point2D<i32>) -> impl Iterator<Item = Point2D<i32>> {
+    let (dx, dy) = (i64::from(b.x) - i64::from(a.x), i64::from(b.y) - i64::from(a.y));
+    Bresenham { cell: Some(a), end: b, dx: dx.abs(), dy: -dy.abs(), step: (dx.signum() as i32, dy.signum() as i32), error: dx.abs() - dy.abs() }
+}
+
+/// Cells crossed by the segment between the centers of cells `a` and `b`, both included.
+///
+/// # Returns
+/// * Every cell whose interior the segment passes through, in order, each sharing
+///   an edge with the one before. Where the segment runs exactly through a cell
+///   corner, both cells beside the corner are included as well, so nothing the
+///   segment touches is missed, as needed for line-of-sight checks and
+///   conservative collision rasterization.
+///
+/// # Algorithm Explanation
+/// The segment is walked one cell boundary at a time, crossing the vertical or
+/// horizontal boundary that it reaches first. Comparing the crossing parameters
+/// `(2i + 1) / 2|dx|` and `(2j + 1) / 2|dy|` after cross-multiplying keeps
+/// everything in integers; a tie is a corner.
+///
+/// # Time Complexity
+/// **O(1)** per cell, up to `|dx| + |dy| + 1` cells.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::raster::rasterize_line_supercover;
+/// use geomengine::point::Point2D;
+///
+/// let cells: Vec<_> = rasterize_line_supercover(Point2D::new(0, 0), Point2D::new(2, 1)).map(|p| (p.x, p.y)).collect();
+/// assert_eq!(cells, [(0, 0), (1, 0), (1, 1), (2, 1)]);
+/// // a diagonal through the corners picks up the cells on both sides
+/// assert_eq!(rasterize_line_supercover(Point2D::new(0, 0), Point2D::new(2, 2)).count(), 7);
+/// ```
+pub fn rasterize_line_supercover(a: Point2D<i32>, b: Point2D<i32>) -> impl Iterator<Item = Point2D<i32>> {
+    let (dx, dy) = (i64::from(b.x) - i64::from(a.x), i64::from(b.y) - i64::from(a.y));
+    Supercover {
+        cell: a,
+        steps: (dx.abs(), dy.abs()),
+        taken: (0, 0),
+        step: (dx.signum() as i32, dy.signum() as i32),
+        queued: [a; 3],
+        queue: 0..1,
+    }
+}
+
+/// Boundary cells of the circle of `radius` cells around `center`, by the midpoint circle algorithm.
+///
+/// # Returns
+/// * The cells sorted by row, then column, each once. Each is 8-connected to its
+///   neighbours on the circle. A radius of zero gives the center alone, a negative
+///   one nothing.
+///
+/// # Algorithm Explanation
+/// One octant is traced from the top of the circle, stepping one column at a
+/// time and dropping a row whenever the midpoint between the two candidate cells
+/// falls outside the circle, decided by an incrementally updated integer. The
+/// other seven octants are mirror images.
+///
+/// # Time Complexity
+/// **O(r log r)**: O(r) cells, sorted.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::raster::{rasterize_circle, rasterize_circle_filled};
+/// use geomengine::point::Point2D;
+///
+/// let ring = rasterize_circle(Point2D::new(0, 0), 2);
+/// assert_eq!(ring.len(), 12);
+/// assert!(ring.contains(&Point2D::new(2, 1)) && !ring.contains(&Point2D::new(2, 2)));
+/// assert_eq!(rasterize_circle_filled(Point2D::new(0, 0), 2).len(), 21);
+/// ```
+pub fn rasterize_circle(center: Point2D<i32>, radius: i32) -> Vec<Point2D<i32>> {
+    let mut cells = Vec::new();
+    let (mut x, mut y, mut decision) = (radius, 0, 1 - radius);
+    while y <= x {
+        for (a, b) in [(x, y), (y, x)] {
+            for (sa, sb) in [(1, 1), (-1, 1), (1, -1), (-1, -1)] {
+                cells.push(Point2D::new(center.x + sa * a, center.y + sb * b));
+            }
+        }
+        y += 1;
+        if decision < 0 {
+            decision += 2 * y + 1;
+        } else {
+            x -= 1;
+            decision += 2 * (y - x) + 1;
+        }
+    }
+    sorted(cells)
+}
+
+/// Cells on and inside [`rasterize_circle`]'s boundary, sorted by row, then column.
+pub fn rasterize_circle_filled(center: Point2D<i32>, radius: i32) -> Vec<Point2D<i32>> {
+    fill_rows(&rasterize_circle(center, radius))
+}
+
+/// Boundary cells of the axis-aligned ellipse with semi-axes `radius_x` and
+/// `radius_y` around `center`, by the midpoint ellipse algorithm.
+///
+/// # Returns
+/// * The cells sorted by row, then column, each once, 8-connected along the
+///   ellipse. A zero semi-axis degenerates to a line, a negative one gives nothing.
+///
+/// # Algorithm Explanation
+/// One quadrant is traced from the top of the ellipse: while its slope is below
+/// one the column advances every step and the row only when the midpoint
+/// decision says so, past that the roles swap. The decision variable is kept
+/// at four times its usual value so that it stays integral.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::raster::rasterize_ellipse;
+/// use geomengine::point::Point2D;
+///
+/// let ring = rasterize_ellipse(Point2D::new(10, 10), 4, 2);
+/// assert!(ring.contains(&Point2D::new(14, 10)) && ring.contains(&Point2D::new(10, 8)));
+/// assert_eq!(ring.len(), 16);
+/// ```
+pub fn rasterize_ellipse(center: Point2D<i32>, radius_x: i32, radius_y: i32) -> Vec<Point2D<i32>> {
+    if radius_x < 0 || radius_y < 0 {
+        return Vec::new();
+    }
+    if radius_y == 0 {
+        return (-radius_x..=radius_x).map(|x| Point2D::new(center.x + x, center.y)).collect();
+    }
+    let mut cells = Vec::new();
+    let mut plot = |x: i64, y: i64| {
+        let (x, y) = (x as i32, y as i32);
+        for (sx, sy) in [(1, 1), (-1, 1), (1, -1), (-1, -1)] {
+            cells.push(Point2D::new(center.x + sx * x, center.y + sy * y));
+        }
+    };
+    let (rx2, ry2) = (i64::from(radius_x).pow(2), i64::from(radius_y).pow(2));
+    let (mut x, mut y) = (0, i64::from(radius_y));
+    // the gradient components, doubled
+    let (mut px, mut py) = (0, 2 * rx2 * y);
+
+    let mut decision = 4 * ry2 - 4 * rx2 * y + rx2;
+    while px < py {
+        plot(x, y);
+        x += 1;
+        px += 2 * ry2;
+        if decision < 0 {
+            decision += 4 * (ry2 + px);
+        } else {
+            y -= 1;
+            py -= 2 * rx2;
+            decision += 4 * (ry2 + px - py);
+        }
+    }
+    decision = ry2 * (2 * x + 1).pow(2) + 4 * rx2 * (y - 1).pow(2) - 4 * rx2 * ry2;
+    while y >= 0 {
+        plot(x, y);
+        y -= 1;
+        py -= 2 * rx2;
+        if decision > 0 {
+            decision += 4 * (rx2 - py);
+        } else {
+            x += 1;
+            px += 2 * ry2;
+            decision += 4 * (rx2 - py + px);
+        }
+    }
+    sorted(cells)
+}
+
+/// Cells on and inside [`rasterize_ellipse`]'s boundary, sorted by row, then column.
+pub fn rasterize_ellipse_filled(center: Point2D<i32>, radius_x: i32, radius_y: i32) -> Vec<Point2D<i32>> {
+    fill_rows(&rasterize_ellipse(center, radius_x, radius_y))
+}
+
+/// Which cells along a polygon's boundary count as covered by [`rasterize_polygon`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EdgeRule {
+    /// Cells whose center lies inside (even-odd rule). Centers exactly on the
+    /// boundary count for left and bottom edges only, so polygons sharing an edge
+    /// never both claim a cell.
+    #[default]
+    CellCenter,
+    /// Cells whose interior the polygon overlaps at all, for conservative
+    /// collision and visibility grids.
+    Touching,
+    /// Cells lying completely inside the polygon, whose interior no edge crosses.
+    Inside,
+}
+
+/// Cells of a grid over `bbox` covered by `polygon`.
+///
+/// # Arguments
+/// * `polygon` - The shape to rasterize, in either orientation.
+/// * `cell_size` - Side of the square cells.
+/// * `bbox` - The area gridded: cell `(0, 0)` has its lower left corner at
+///   `bbox.min`, and columns and rows continue until they cover `bbox.max`.
+/// * `rule` - Which cells along the boundary to include.
+///
+/// # Returns
+/// * The covered `(column, row)` cells, sorted by row, then column.
+///
+/// # Algorithm Explanation
+/// Scanline fill: in each row the edges crossing the line through the cell
+/// centers are intersected with it, and the cells between successive pairs of
+/// crossings are inside. The other rules additionally clip every edge to the
+/// row and mark the columns its clipped part passes through.
+///
+/// # Time Complexity
+/// **O(h (n log n + w))** for `n` edges and a `w` by `h` grid.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::raster::{rasterize_polygon, EdgeRule};
+/// use geomengine::point::Point2D;
+/// use geomengine::polygon::Polygon2D;
+/// use geomengine::shape::Aabb2D;
+///
+/// let triangle = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(4.0, 0.0), Point2D::new(0.0, 4.0)]);
+/// let bbox = Aabb2D::new(Point2D::new(0.0, 0.0), Point2D::new(4.0, 4.0));
+/// assert_eq!(rasterize_polygon(&triangle, 1.0, &bbox, EdgeRule::CellCenter).len(), 6);
+/// assert_eq!(rasterize_polygon(&triangle, 1.0, &bbox, EdgeRule::Touching).len(), 10);
+/// assert_eq!(rasterize_polygon(&triangle, 1.0, &bbox, EdgeRule::Inside).len(), 6);
+/// ```
+pub fn rasterize_polygon<T: Float + Debug>(polygon: &Polygon2D<T>, cell_size: T, bbox: &Aabb2D<T>, rule: EdgeRule) -> Vec<Point2D<i32>> {
+    let columns = (bbox.width() / cell_size).ceil().to_usize().unwrap_or(0);
+    let rows = (bbox.height() / cell_size).ceil().to_usize().unwrap_or(0);
+    let mut cells = Vec::new();
+    scan_polygon(polygon, bbox.min, cell_size, (columns, rows), rule, |column, row| {
+        cells.push(Point2D::new(column as i32, row as i32));
+    });
+    cells
+}
+
+/// Calls `mark(column, row)` for each cell of a `size.0` by `size.1` grid at
+/// `origin` that `polygon` covers under `rule`, row by row.
+pub(crate) fn scan_polygon<T: Float + Debug>(
+    polygon: &Polygon2D<T>,
+    origin: Point2D<T>,
+    cell_size: T,
+    (columns, rows): (usize, usize),
+    rule: EdgeRule,
+    mut mark: impl FnMut(usize, usize),
+) {
+    let half = T::from(0.5).unwrap();
+    // cell coordinates, so cell (i, j) spans i..i + 1 by j..j + 1
+    let vertices: Vec<Point2D<T>> = polygon.vertices().iter().map(|&v| Point2D::from((v - origin) / cell_size)).collect();
+    let edges = || (0..vertices.len()).map(|i| (vertices[i], vertices[(i + 1) % vertices.len()]));
+    let last_column = columns as isize - 1;
+    let mut crossings = Vec::new();
+    let mut inside = vec![false; columns];
+    let mut touched = vec![false; columns];
+
+    for row in 0..rows {
+        let (bottom, top) = (T::from(row).unwrap(), T::from(row + 1).unwrap());
+        let center = bottom + half;
+        inside.fill(false);
+        crossings.clear();
+        for (a, b) in edges() {
+            // half-open in y so a vertex on the scanline counts once
+            if (a.y > center) != (b.y > center) {
+                crossings.push(a.x + (center - a.y) * (b.x - a.x) / (b.y - a.y));
+            }
+        }
+        crossings.sort_by(|&a, &b| total_cmp(a, b));
+        for pair in crossings.chunks_exact(2) {
+            // columns whose centers lie in [pair[0], pair[1])
+            let first = to_isize((pair[0] - half).ceil()).max(0);
+            let last = to_isize((pair[1] - half).ceil() - T::one()).min(last_column);
+            for column in first..=last {
+                inside[column as usize] = true;
+            }
+        }
+
+        if rule != EdgeRule::CellCenter {
+            touched.fill(false);
+            for (a, b) in edges() {
+                let Some((left, right)) = clip_to_row(a, b, bottom, top) else { continue };
+                // columns whose open interior meets [left, right]
+                let (first, last) = if left == right && left == left.floor() {
+                    (1, 0)
+                } else if left == right {
+                    (to_isize(left.floor()), to_isize(left.floor()))
+                } else {
+                    (to_isize(left.floor()), to_isize(right.ceil()) - 1)
+                };
+                for column in first.max(0)..=last.min(last_column) {
+                    touched[column as usize] = true;
+                }
+            }
+        }
+
+        for column in 0..columns {
+            let covered = match rule {
+                EdgeRule::CellCenter => inside[column],
+                EdgeRule::Touching => inside[column] || touched[column],
+                EdgeRule::Inside => inside[column] && !touched[column],
+            };
+            if covered {
+                mark(column, row);
+            }
+        }
+    }
+}
+
+/// x-range of the part of segment `a`-`b` inside the open band `bottom < y < top`.
+fn clip_to_row<T: Float + Debug>(a: Point2D<T>, b: Point2D<T>, bottom: T, top: T) -> Option<(T, T)> {
+    if a.y == b.y {
+        return (a.y > bottom && a.y < top).then(|| (a.x.min(b.x), a.x.max(b.x)));
+    }
+    let at = |y: T| a.x + (y - a.y) * (b.x - a.x) / (b.y - a.y);
+    let (low, high) = if a.y < b.y { (a, b) } else { (b, a) };
+    if high.y <= bottom || low.y >= top {
+        return None;
+    }
+    let from = if low.y > bottom { low.x } else { at(bottom) };
+    let to = if high.y < top { high.x } else { at(top) };
+    Some((from.min(to), from.max(to)))
+}
+
+/// Saturating conversion of an integral float to a column index.
+fn to_isize<T: Float>(value: T) -> isize {
+    value.to_isize().unwrap_or(if value < T::zero() { isize::MIN } else { isize::MAX })
+}
+
+fn sorted(mut cells: Vec<Point2D<i32>>) -> Vec<Point2D<i32>> {
+    cells.sort_by_key(|p| (p.y, p.x));
+    cells.dedup();
+    cells
+}
+
+/// Every cell between the leftmost and rightmost cell of each row of a convex boundary.
+fn fill_rows(boundary: &[Point2D<i32>]) -> Vec<Point2D<i32>> {
+    let mut rows = BTreeMap::new();
+    for p in boundary {
+        let span = rows.entry(p.y).or_insert((p.x, p.x));
+        *span = (span.0.min(p.x), span.1.max(p.x));
+    }
+    rows.into_iter().flat_map(|(y, (first, last))| (first..=last).map(move |x| Point2D::new(x, y))).collect()
+}
+
+#[derive(Debug, Clone)]
+struct Bresenham {
+    // `None` once `end` has been produced
+    cell: Option<Point2D<i32>>,
+    end: Point2D<i32>,
+    dx: i64,
+    // negative, so the error can be updated with additions alone
+    dy: i64,
+    step: (i32, i32),
+    error: i64,
+}
+
+impl Iterator for Bresenham {
+    type Item = Point2D<i32>;
+
+    fn next(&mut self) -> Option<Point2D<i32>> {
+        let current = self.cell?;
+        if current == self.end {
+            self.cell = None;
+            return Some(current);
+        }
+        let mut next = current;
+        let doubled = 2 * self.error;
+        if doubled >= self.dy {
+            self.error += self.dy;
+            next.x += self.step.0;
+        }
+        if doubled <= self.dx {
+            self.error += self.dx;
+            next.y += self.step.1;
+        }
+        self.cell = Some(next);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let left = self.cell.map_or(0, |c| (self.end.x.abs_diff(c.x)).max(self.end.y.abs_diff(c.y)) as usize + 1);
+        (left, Some(left))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Supercover {
+    cell: Point2D<i32>,
+    steps: (i64, i64),
+    taken: (i64, i64),
+    step: (i32, i32),
+    // cells found by the last step but not yet produced
+    queued: [Point2D<i32>; 3],
+    queue: core::ops::Range<usize>,
+}
+
+impl Iterator for Supercover {
+    type Item = Point2D<i32>;
+
+    fn next(&mut self) -> Option<Point2D<i32>> {
+        if let Some(i) = self.queue.next() {
+            return Some(self.queued[i]);
+        }
+        let ((nx, ny), (ix, iy)) = (self.steps, self.taken);
+        if ix == nx && iy == ny {
+            return None;
+        }
+        let side_x = Point2D::new(self.cell.x + self.step.0, self.cell.y);
+        let side_y = Point2D::new(self.cell.x, self.cell.y + self.step.1);
+        let decision = (1 + 2 * ix) * ny - (1 + 2 * iy) * nx;
+        if decision == 0 {
+            // through the corner: both neighbours, then the diagonal cell
+            self.cell = Point2D::new(side_x.x, side_y.y);
+            self.taken = (ix + 1, iy + 1);
+            self.queued = [side_x, side_y, self.cell];
+            self.queue = 1..3;
+            return Some(side_x);
+        }
+        if decision < 0 {
+            self.cell = side_x;
+            self.taken.0 += 1;
+        } else {
+            self.cell = side_y;
+            self.taken.1 += 1;
+        }
+        Some(self.cell)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::AbsDiffEq;
+
+    fn cells(iter: impl Iterator<Item = Point2D<i32>>) -> Vec<(i32, i32)> {
+        iter.map(|p| (p.x, p.y)).collect()
+    }
+
+    #[test]
+    fn test_rasterize_line_all_octants() {
+        let center = Point2D::new(0, 0);
+        for (x, y) in [(5, 2), (2, 5), (-2, 5), (-5, 2), (-5, -2), (-2, -5), (2, -5), (5, -2), (3, 3), (0, -4), (0, 0)] {
+            let end = Point2D::new(x, y);
+            let line = cells(rasterize_line(center, end));
+            assert_eq!(line.len(), x.abs().max(y.abs()) as usize + 1);
+            assert_eq!((line[0], line[line.len() - 1]), ((0, 0), (x, y)));
+            assert!(line.windows(2).all(|w| (w[1].0 - w[0].0).abs() <= 1 && (w[1].1 - w[0].1).abs() <= 1));
+            // symmetric up to the midpoint tie-breaks
+            let reversed = cells(rasterize_line(end, center));
+            assert_eq!(reversed.len(), line.len());
+        }
+        assert_eq!(rasterize_line(Point2D::new(1, 1), Point2D::new(7, 3)).size_hint(), (7, Some(7)));
+    }
+
+    #[test]
+    fn test_supercover_covers_every_crossed_cell() {
+        // (0, 0) to (3, 1): the center line y = x / 3 crosses y = 0.5 at x = 1.5, through a corner
+        assert_eq!(cells(rasterize_line_supercover(Point2D::new(0, 0), Point2D::new(3, 1))), [(0, 0), (1, 0), (2, 0), (1, 1), (2, 1), (3, 1)]);
+        assert_eq!(cells(rasterize_line_supercover(Point2D::new(0, 0), Point2D::new(-1, -3))), [(0, 0), (0, -1), (-1, -1), (0, -2), (-1, -2), (-1, -3)]);
+        // no corners on the way: one cell per boundary crossed
+        let line = cells(rasterize_line_supercover(Point2D::new(2, 6), Point2D::new(9, 2)));
+        assert_eq!(line.len(), 7 + 4 + 1);
+        assert!(line.windows(2).all(|w| (w[1].0 - w[0].0).abs() + (w[1].1 - w[0].1).abs() == 1));
+        assert_eq!(cells(rasterize_line_supercover(Point2D::new(4, 4), Point2D::new(4, 4))), [(4, 4)]);
+    }
+
+    #[test]
+    fn test_circle_and_ellipse_boundaries() {
+        assert_eq!(rasterize_circle(Point2D::new(3, 3), 0), [Point2D::new(3, 3)]);
+        assert!(rasterize_circle(Point2D::new(3, 3), -1).is_empty());
+
+        let ring = rasterize_circle(Point2D::new(0, 0), 10);
+        for p in &ring {
+            // within half a cell of the true circle, and mirrored in both axes and the diagonal
+            assert!(((p.x * p.x + p.y * p.y) as f64).sqrt().abs_diff_eq(&10.0, 0.5));
+            assert!(ring.contains(&Point2D::new(-p.x, p.y)) && ring.contains(&Point2D::new(p.y, p.x)));
+        }
+        let filled = rasterize_circle_filled(Point2D::new(0, 0), 10);
+        assert!(ring.iter().all(|p| filled.contains(p)));
+        // boundary cells reach half a cell past the radius
+        assert!((filled.len() as f64 - core::f64::consts::PI * 10.5 * 10.5).abs() < 10.0);
+
+        let ellipse = rasterize_ellipse(Point2D::new(0, 0), 8, 3);
+        for p in &ellipse {
+            let level = (p.x * p.x) as f64 / 64.0 + (p.y * p.y) as f64 / 9.0;
+            assert!((0.6..1.4).contains(&level));
+            assert!(ellipse.contains(&Point2D::new(-p.x, -p.y)));
+        }
+        // degenerate semi-axes are lines
+        assert_eq!(rasterize_ellipse(Point2D::new(0, 0), 3, 0).len(), 7);
+        assert_eq!(rasterize_ellipse_filled(Point2D::new(0, 0), 0, 2).len(), 5);
+    }
+
+    #[test]
+    fn test_rasterize_polygon_edge_rules() {
+        // a square aligned with the grid covers exactly its cells under every rule
+        let square = Polygon2D::new(vec![Point2D::new(1.0, 1.0), Point2D::new(3.0, 1.0), Point2D::new(3.0, 3.0), Point2D::new(1.0, 3.0)]);
+        let bbox = Aabb2D::new(Point2D::new(0.0, 0.0), Point2D::new(4.0, 4.0));
+        for rule in [EdgeRule::CellCenter, EdgeRule::Touching, EdgeRule::Inside] {
+            assert_eq!(cells(rasterize_polygon(&square, 1.0, &bbox, rule).into_iter()), [(1, 1), (2, 1), (1, 2), (2, 2)]);
+        }
+
+        // shifted by half a cell, it only touches the ring of cells around its inside one
+        let shifted = Polygon2D::new(square.vertices().iter().map(|&v| v + Point2D::new(0.5, 0.5)).collect());
+        assert_eq!(rasterize_polygon(&shifted, 1.0, &bbox, EdgeRule::Touching).len(), 9);
+        assert_eq!(cells(rasterize_polygon(&shifted, 1.0, &bbox, EdgeRule::Inside).into_iter()), [(2, 2)]);
+        // centers on the left and bottom edges are in, on the right and top ones out
+        assert_eq!(cells(rasterize_polygon(&shifted, 1.0, &bbox, EdgeRule::CellCenter).into_iter()), [(1, 1), (2, 1), (1, 2), (2, 2)]);
+
+        // a sliver smaller than a cell still touches it; the grid is clipped to the box
+        let sliver = Polygon2D::new(vec![Point2D::new(0.2, 0.2), Point2D::new(0.3, 0.2), Point2D::new(0.3, 0.25)]);
+        assert_eq!(cells(rasterize_polygon(&sliver, 1.0, &bbox, EdgeRule::Touching).into_iter()), [(0, 0)]);
+        assert!(rasterize_polygon(&sliver, 1.0, &bbox, EdgeRule::CellCenter).is_empty());
+        let small = Aabb2D::new(Point2D::new(0.0, 0.0), Point2D::new(1.5, 1.5));
+        assert_eq!(rasterize_polygon(&square, 1.0, &small, EdgeRule::Touching).len(), 1);
+
+        // a non-finite vertex must not panic the crossing sort
+        for bad in [f64::NAN, f64::INFINITY] {
+            let broken = Polygon2D::new(vec![Point2D::new(1.0, 1.0), Point2D::new(bad, 2.0), Point2D::new(1.0, 3.0), Point2D::new(0.5, 2.0)]);
+            for rule in [EdgeRule::CellCenter, EdgeRule::Touching, EdgeRule::Inside] {
+                assert!(rasterize_polygon(&broken, 1.0, &bbox, rule).len() <= 16);
+            }
+        }
+    }
+}
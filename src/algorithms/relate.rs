@@ -0,0 +1,399 @@
+use num::Float;
+use core::fmt::{self, Debug, Display};
+use crate::point::Point2D;
+use crate::line::{Line2D, Polyline2D};
+use crate::polygon::Polygon2D;
+use super::noding::{node_segments, snap_tolerance};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Dimension of a point set, as stored in an [`IntersectionMatrix`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Dimension {
+    /// No points (`F`).
+    Empty,
+    /// Isolated points (`0`).
+    Point,
+    /// Curves (`1`).
+    Line,
+    /// Areas (`2`).
+    Area,
+}
+
+/// Part of a geometry a point can lie in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    Interior,
+    Boundary,
+    Exterior,
+}
+
+/// The DE-9IM matrix: dimension of the intersection of the interior, boundary
+/// and exterior of `a` (rows) with those of `b` (columns).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntersectionMatrix {
+    cells: [[Dimension; 3]; 3],
+    dimension_a: Dimension,
+    dimension_b: Dimension,
+}
+
+impl IntersectionMatrix {
+    /// Dimension of the intersection of part `a` of the first geometry with part `b` of the second.
+    pub fn get(&self, a: Location, b: Location) -> Dimension {
+        self.cells[a as usize][b as usize]
+    }
+
+    /// Tests the matrix against a 9 character pattern (rows first) where `T` means
+    /// non-empty, `F` empty, `0`/`1`/`2` that exact dimension and `*` anything.
+    ///
+    /// # Panics
+    /// If the pattern is not 9 characters of `TF012*`.
+    pub fn matches(&self, pattern: &str) -> bool {
+        assert_eq!(pattern.len(), 9, "DE-9IM pattern must have 9 characters");
+        pattern.chars().zip(self.cells.iter().flatten()).all(|(expected, &dimension)| match expected {
+            '*' => true,
+            'T' => dimension != Dimension::Empty,
+            'F' => dimension == Dimension::Empty,
+            '0' => dimension == Dimension::Point,
+            '1' => dimension == Dimension::Line,
+            '2' => dimension == Dimension::Area,
+            other => panic!("invalid DE-9IM pattern character {other:?}"),
+        })
+    }
+
+    /// The geometries share no point.
+    pub fn is_disjoint(&self) -> bool {
+        self.matches("FF*FF****")
+    }
+    /// The geometries share at least one point.
+    pub fn is_intersects(&self) -> bool {
+        !self.is_disjoint()
+    }
+    /// The geometries meet only at their boundaries.
+    pub fn is_touches(&self) -> bool {
+        let only_points = self.dimension_a == Dimension::Point && self.dimension_b == Dimension::Point;
+        !only_points && (self.matches("FT*******") || self.matches("F**T*****") || self.matches("F***T****"))
+    }
+    /// The geometries share some but not all interior points, and the intersection
+    /// has a lower dimension than the larger input (point/line, point/area,
+    /// line/area and line/line only).
+    pub fn is_crosses(&self) -> bool {
+        match (self.dimension_a, self.dimension_b) {
+            (Dimension::Line, Dimension::Line) => self.matches("0********"),
+            (a, b) if a < b && a != Dimension::Area => self.matches("T*T******"),
+            (a, b) if a > b && b != Dimension::Area => self.matches("T*****T**"),
+            _ => false,
+        }
+    }
+    /// Every point of `a` lies in `b`, and their interiors meet.
+    pub fn is_within(&self) -> bool {
+        self.matches("T*F**F***")
+    }
+    /// Every point of `b` lies in `a`, and their interiors meet.
+    pub fn is_contains(&self) -> bool {
+        self.matches("T*****FF*")
+    }
+    /// The geometries have the same dimension, share interior points, and each has
+    /// points outside the other.
+    pub fn is_overlaps(&self) -> bool {
+        match (self.dimension_a, self.dimension_b) {
+            (Dimension::Line, Dimension::Line) => self.matches("1*T***T**"),
+            (a, b) if a == b => self.matches("T*T***T**"),
+            _ => false,
+        }
+    }
+    /// The geometries cover exactly the same points.
+    pub fn is_equals(&self) -> bool {
+        self.matches("T*F**FFF*")
+    }
+}
+
+impl Display for IntersectionMatrix {
+    /// The matrix as its 9 character string, e.g. `"212101212"`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for &dimension in self.cells.iter().flatten() {
+            let symbol = match dimension {
+                Dimension::Empty => 'F',
+                Dimension::Point => '0',
+                Dimension::Line => '1',
+                Dimension::Area => '2',
+            };
+            write!(f, "{symbol}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Geometries [`relate_2d`] accepts: points, lines, polylines, polygons and
+/// collections of one of them.
+pub trait Relate<T: Float + Debug>: private::Sealed {
+    #[doc(hidden)]
+    fn parts(&self) -> private::Parts<T>;
+}
+
+mod private {
+    use super::*;
+
+    pub trait Sealed {}
+
+    /// A geometry split into isolated points, open curves and polygon rings.
+    pub struct Parts<T: Float + Debug> {
+        pub points: Vec<Point2D<T>>,
+        pub curves: Vec<Vec<Point2D<T>>>,
+        pub rings: Vec<Vec<Point2D<T>>>,
+    }
+
+    impl<T: Float + Debug> Default for Parts<T> {
+        fn default() -> Self {
+            Parts { points: Vec::new(), curves: Vec::new(), rings: Vec::new() }
+        }
+    }
+}
+
+use private::Parts;
+
+macro_rules! impl_relate {
+    ($($shape:ty => |$value:ident, $parts:ident| $collect:expr),* $(,)?) => {
+        $(
+            impl<T: Float + Debug> private::Sealed for $shape {}
+            impl<T: Float + Debug> Relate<T> for $shape {
+                fn parts(&self) -> Parts<T> {
+                    let mut $parts = Parts::default();
+                    let $value = self;
+                    $collect;
+                    $parts
+                }
+            }
+        )*
+    };
+}
+
+impl_relate!(
+    Point2D<T> => |point, parts| parts.points.push(*point),
+    Vec<Point2D<T>> => |points, parts| parts.points.extend_from_slice(points),
+    Line2D<T> => |line, parts| parts.curves.push(vec![line.p1, line.p2]),
+    Polyline2D<T> => |polyline, parts| add_polyline(&mut parts, polyline),
+    Vec<Polyline2D<T>> => |polylines, parts| polylines.iter().for_each(|p| add_polyline(&mut parts, p)),
+    Polygon2D<T> => |polygon, parts| parts.rings.push(polygon.vertices().to_vec()),
+    Vec<Polygon2D<T>> => |polygons, parts| parts.rings.extend(polygons.iter().map(|p| p.vertices().to_vec())),
+);
+
+fn add_polyline<T: Float + Debug>(parts: &mut Parts<T>, polyline: &Polyline2D<T>) {
+    match polyline.vertices() {
+        [] => {}
+        [single] => parts.points.push(*single),
+        vertices => parts.curves.push(vertices.to_vec()),
+    }
+}
+
+impl<T: Float + Debug> Parts<T> {
+    fn dimension(&self) -> Dimension {
+        if !self.rings.is_empty() {
+            Dimension::Area
+        } else if !self.curves.is_empty() {
+            Dimension::Line
+        } else if !self.points.is_empty() {
+            Dimension::Point
+        } else {
+            Dimension::Empty
+        }
+    }
+
+    fn coordinates(&self) -> impl Iterator<Item = Point2D<T>> + '_ {
+        self.points.iter().chain(self.curves.iter().flatten()).chain(self.rings.iter().flatten()).copied()
+    }
+
+    fn curve_segments(&self) -> impl Iterator<Item = Line2D<T>> + '_ {
+        self.curves.iter().flat_map(|curve| curve.windows(2).map(|w| Line2D::new(w[0], w[1])))
+    }
+
+    fn ring_segments(&self) -> impl Iterator<Item = Line2D<T>> + '_ {
+        self.rings.iter().flat_map(|ring| {
+            (0..ring.len()).map(move |i| Line2D::new(ring[i], ring[(i + 1) % ring.len()]))
+        })
+    }
+
+    /// Curve endpoints shared by an odd number of curves (the "mod 2" boundary rule),
+    /// so closed curves and curves joined end to end have no boundary there.
+    fn curve_boundary(&self) -> Vec<Point2D<T>> {
+        let mut boundary: Vec<Point2D<T>> = Vec::new();
+        for curve in &self.curves {
+            for end in [curve[0], curve[curve.len() - 1]] {
+                match boundary.iter().position(|&p| p == end) {
+                    Some(i) => {
+                        boundary.swap_remove(i);
+                    }
+                    None => boundary.push(end),
+                }
+            }
+        }
+        boundary
+    }
+
+    fn on_linework(&self, point: &Point2D<T>, tolerance: T) -> bool {
+        self.ring_segments().chain(self.curve_segments()).any(|s| s.closest_point_to(point).distance(point) <= tolerance)
+    }
+
+    fn locate(&self, point: &Point2D<T>, boundary: &[Point2D<T>], tolerance: T) -> Location {
+        let near = |segment: Line2D<T>| segment.closest_point_to(point).distance(point) <= tolerance;
+        if self.ring_segments().any(near) {
+            Location::Boundary
+        } else if self.rings.iter().any(|ring| crate::polygon::ring::contains_point(ring, point)) {
+            Location::Interior
+        } else if boundary.iter().any(|b| b.distance(point) <= tolerance) {
+            Location::Boundary
+        } else if self.curve_segments().any(near) || self.points.iter().any(|p| p.distance(point) <= tolerance) {
+            Location::Interior
+        } else {
+            Location::Exterior
+        }
+    }
+}
+
+/// Computes the DE-9IM intersection matrix of two geometries.
+///
+/// # Arguments
+/// * `a`, `b` - Points, lines, polylines, polygons or `Vec`s of one of them; see [`Relate`].
+///
+/// # Returns
+/// * The [`IntersectionMatrix`], from which the named predicates
+///   ([`is_touches`](IntersectionMatrix::is_touches),
+///   [`is_within`](IntersectionMatrix::is_within), ...) are derived.
+///
+/// # Algorithm Explanation
+/// 1. All curves and polygon rings of both inputs are split at their mutual
+///    intersections (noding).
+/// 2. Every node, isolated point and curve endpoint is located in both inputs
+///    and contributes a point to the matching matrix cell.
+/// 3. The midpoint of every noded piece contributes a line.
+/// 4. Points just to either side of every piece are located to find which
+///    interiors and exteriors share an area.
+///
+/// Coordinates closer than a small tolerance relative to the input's magnitude
+/// are treated as coincident, as in noding.
+///
+/// # Time Complexity
+/// **O(n²)** in the total number of segments.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::relate::relate_2d;
+/// use geomengine::line::Line2D;
+/// use geomengine::point::Point2D;
+/// use geomengine::polygon::Polygon2D;
+///
+/// let square = Polygon2D::new(vec![
+///     Point2D::new(0.0, 0.0), Point2D::new(2.0, 0.0), Point2D::new(2.0, 2.0), Point2D::new(0.0, 2.0),
+/// ]);
+/// let line = Line2D::new(Point2D::new(1.0, 1.0), Point2D::new(3.0, 1.0));
+/// let matrix = relate_2d(&line, &square);
+/// assert_eq!(matrix.to_string(), "1010F0212");
+/// assert!(matrix.is_crosses());
+/// ```
+pub fn relate_2d<T: Float + Debug, A: Relate<T>, B: Relate<T>>(a: &A, b: &B) -> IntersectionMatrix {
+    let (a, b) = (a.parts(), b.parts());
+    let tolerance = snap_tolerance(a.coordinates().chain(b.coordinates()));
+    let (boundary_a, boundary_b) = (a.curve_boundary(), b.curve_boundary());
+
+    let mut matrix = IntersectionMatrix {
+        cells: [[Dimension::Empty; 3]; 3],
+        dimension_a: a.dimension(),
+        dimension_b: b.dimension(),
+    };
+    // the exteriors of two bounded geometries always share an area
+    matrix.cells[2][2] = Dimension::Area;
+    let mut mark = |point: &Point2D<T>, dimension: Dimension| {
+        let cell = &mut matrix.cells[a.locate(point, &boundary_a, tolerance) as usize][b.locate(point, &boundary_b, tolerance) as usize];
+        *cell = (*cell).max(dimension);
+    };
+
+    for point in a.points.iter().chain(&b.points).chain(&boundary_a).chain(&boundary_b) {
+        mark(point, Dimension::Point);
+    }
+
+    let segments: Vec<Line2D<T>> = a.curve_segments().chain(a.ring_segments()).chain(b.curve_segments()).chain(b.ring_segments()).collect();
+    // side samples must stay clear of the piece itself but closer than any other feature
+    let offset = tolerance * T::from(16.0).unwrap();
+    for piece in node_segments(&segments, tolerance) {
+        mark(&piece.p1, Dimension::Point);
+        mark(&piece.p2, Dimension::Point);
+        let mid = piece.midpoint();
+        mark(&mid, Dimension::Line);
+
+        let direction = piece.p2 - piece.p1;
+        let normal = direction.perpendicular() * (offset / piece.length());
+        for side in [mid + normal, mid - normal] {
+            if !a.on_linework(&side, tolerance) && !b.on_linework(&side, tolerance) {
+                mark(&side, Dimension::Area);
+            }
+        }
+    }
+    matrix
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(min: f64, max: f64) -> Polygon2D<f64> {
+        Polygon2D::new(vec![
+            Point2D::new(min, min),
+            Point2D::new(max, min),
+            Point2D::new(max, max),
+            Point2D::new(min, max),
+        ])
+    }
+
+    fn line(x1: f64, y1: f64, x2: f64, y2: f64) -> Line2D<f64> {
+        Line2D::new(Point2D::new(x1, y1), Point2D::new(x2, y2))
+    }
+
+    #[test]
+    fn test_relate_polygons() {
+        let overlapping = relate_2d(&square(0.0, 2.0), &square(1.0, 3.0));
+        assert_eq!(overlapping.to_string(), "212101212");
+        assert!(overlapping.is_overlaps() && !overlapping.is_touches());
+
+        let touching = relate_2d(&square(0.0, 1.0), &square(1.0, 2.0));
+        assert_eq!(touching.to_string(), "FF2F01212");
+        assert!(touching.is_touches());
+
+        let inner = relate_2d(&square(1.0, 2.0), &square(0.0, 3.0));
+        assert!(inner.is_within() && !inner.is_contains());
+        assert!(relate_2d(&square(0.0, 3.0), &square(1.0, 2.0)).is_contains());
+        assert!(relate_2d(&square(0.0, 1.0), &square(0.0, 1.0)).is_equals());
+        assert!(relate_2d(&square(0.0, 1.0), &square(5.0, 6.0)).is_disjoint());
+    }
+
+    #[test]
+    fn test_relate_lines() {
+        let crossing = relate_2d(&line(0.0, 0.0, 2.0, 2.0), &line(0.0, 2.0, 2.0, 0.0));
+        assert_eq!(crossing.to_string(), "0F1FF0102");
+        assert!(crossing.is_crosses());
+
+        let end_to_end = relate_2d(&line(0.0, 0.0, 1.0, 0.0), &line(1.0, 0.0, 2.0, 1.0));
+        assert!(end_to_end.is_touches());
+
+        let overlap = relate_2d(&line(0.0, 0.0, 2.0, 0.0), &line(1.0, 0.0, 3.0, 0.0));
+        assert!(overlap.is_overlaps());
+
+        let closed = Polyline2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(1.0, 0.0), Point2D::new(0.0, 1.0), Point2D::new(0.0, 0.0)]);
+        let point_on_ring = relate_2d(&Point2D::new(0.0, 0.0), &closed);
+        assert!(point_on_ring.is_within(), "a closed curve has no boundary");
+    }
+
+    #[test]
+    fn test_relate_points_with_lines_and_polygons() {
+        let polygon = square(0.0, 2.0);
+        assert!(relate_2d(&Point2D::new(1.0, 1.0), &polygon).is_within());
+        assert!(relate_2d(&Point2D::new(2.0, 1.0), &polygon).is_touches());
+        assert!(relate_2d(&polygon, &vec![Point2D::new(1.0, 1.0), Point2D::new(1.5, 0.5)]).is_contains());
+        assert!(relate_2d(&vec![Point2D::new(1.0, 1.0), Point2D::new(5.0, 5.0)], &polygon).is_crosses());
+
+        let segment = line(0.0, 0.0, 2.0, 0.0);
+        assert!(relate_2d(&Point2D::new(0.0, 0.0), &segment).is_touches());
+        assert!(relate_2d(&Point2D::new(1.0, 0.0), &segment).is_within());
+        assert!(relate_2d(&segment, &polygon).is_touches());
+        assert_eq!(relate_2d(&Point2D::new(1.0, 1.0), &Point2D::new(1.0, 1.0)).to_string(), "0FFFFFFF2");
+    }
+}
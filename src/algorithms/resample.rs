@@ -0,0 +1,136 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::line::Polyline2D;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Points along `path` at equal arc-length intervals.
+///
+/// # Arguments
+/// * `path` - The line to sample.
+/// * `spacing` - Distance along the path between consecutive samples.
+///
+/// # Returns
+/// * The points at arc length `0`, `spacing`, `2 spacing`, ... and finally the
+///   path's end, so only the last interval may be shorter. The path's corners
+///   are cut: samples lie on the path, but only some of its vertices are kept.
+///   A path with fewer than two vertices, or a `spacing` that is not positive,
+///   is returned as is.
+///
+/// # Time Complexity
+/// **O(n + L / s)** for `n` vertices, length `L` and spacing `s`.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::resample::resample_by_distance;
+/// use geomengine::line::Polyline2D;
+/// use geomengine::point::Point2D;
+///
+/// let path = Polyline2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(3.0, 0.0), Point2D::new(3.0, 2.0)]);
+/// let samples = resample_by_distance(&path, 2.0);
+/// assert_eq!(samples.vertices(), &[Point2D::new(0.0, 0.0), Point2D::new(2.0, 0.0), Point2D::new(3.0, 1.0), Point2D::new(3.0, 2.0)]);
+/// ```
+pub fn resample_by_distance<T: Float + Debug>(path: &Polyline2D<T>, spacing: T) -> Polyline2D<T> {
+    let vertices = path.vertices();
+    if vertices.len() < 2 || spacing <= T::zero() || spacing.is_nan() {
+        return path.clone();
+    }
+    let mut samples = vec![vertices[0]];
+    // arc length still to go until the next sample
+    let mut remaining = spacing;
+    for segment in path.edges() {
+        let length = segment.length();
+        let mut travelled = T::zero();
+        while length - travelled >= remaining {
+            travelled = travelled + remaining;
+            samples.push(segment.p1 + (segment.p2 - segment.p1) * (travelled / length));
+            remaining = spacing;
+        }
+        remaining = remaining - (length - travelled);
+    }
+    let end = vertices[vertices.len() - 1];
+    // a last sample a rounding error short of the end stands for it
+    match samples.last_mut() {
+        Some(last) if remaining == spacing || last.distance(&end) <= spacing * T::epsilon().sqrt() => *last = end,
+        _ => samples.push(end),
+    }
+    if samples.len() == 1 {
+        samples.push(end);
+    }
+    Polyline2D::new(samples)
+}
+
+/// `path` with vertices inserted so that no segment is longer than `max_segment_length`.
+///
+/// Each segment is split into the fewest equal pieces that are short enough,
+/// so the shape of the path is unchanged and all its vertices are kept. A
+/// `max_segment_length` that is not positive leaves the path as is.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::resample::densify;
+/// use geomengine::line::Polyline2D;
+/// use geomengine::point::Point2D;
+///
+/// let path = Polyline2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(3.0, 0.0), Point2D::new(3.0, 1.0)]);
+/// let dense = densify(&path, 1.2);
+/// assert_eq!(dense.vertices(), &[Point2D::new(0.0, 0.0), Point2D::new(1.0, 0.0), Point2D::new(2.0, 0.0), Point2D::new(3.0, 0.0), Point2D::new(3.0, 1.0)]);
+/// ```
+pub fn densify<T: Float + Debug>(path: &Polyline2D<T>, max_segment_length: T) -> Polyline2D<T> {
+    if max_segment_length <= T::zero() || max_segment_length.is_nan() {
+        return path.clone();
+    }
+    let mut points: Vec<Point2D<T>> = path.vertices().first().copied().into_iter().collect();
+    for segment in path.edges() {
+        let pieces = (segment.length() / max_segment_length).ceil().to_usize().unwrap_or(1).max(1);
+        // the original vertex itself, not the last sample, which may be a rounding error off
+        points.extend(segment.sample_points(pieces).skip(1).take(pieces - 1));
+        points.push(segment.p2);
+    }
+    Polyline2D::new(points)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn wave() -> Polyline2D<f64> {
+        Polyline2D::new((0..=20).map(|i| Point2D::new(i as f64 * 0.5, (i as f64 * 0.5).sin())).collect())
+    }
+
+    #[test]
+    fn test_resample_by_distance_spacing() {
+        let path = wave();
+        let samples = resample_by_distance(&path, 0.3);
+        let along: Vec<f64> = samples.edges().map(|s| s.length()).collect();
+        // chords across corners are at most the spacing
+        assert!(along.iter().all(|&l| l <= 0.3 + 1e-12));
+        assert_eq!(samples.vertices()[0], path.vertices()[0]);
+        assert_eq!(samples.vertices().last(), path.vertices().last());
+        assert_eq!(samples.len(), (path.length() / 0.3).ceil() as usize + 1);
+
+        // an exact multiple of the spacing ends on the last sample without a duplicate
+        let straight = Polyline2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(1.0, 0.0), Point2D::new(1.0, 1.0)]);
+        let samples = resample_by_distance(&straight, 0.5);
+        assert_eq!(samples.len(), 5);
+        assert_eq!(samples.vertices()[4], Point2D::new(1.0, 1.0));
+        // a spacing longer than the path keeps just the ends
+        assert_eq!(resample_by_distance(&straight, 10.0).vertices(), &[Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0)]);
+        assert_eq!(resample_by_distance(&straight, 0.0), straight);
+    }
+
+    #[test]
+    fn test_densify_keeps_shape() {
+        let path = wave();
+        let dense = densify(&path, 0.1);
+        assert!(dense.edges().all(|s| s.length() <= 0.1));
+        assert_relative_eq!(dense.length(), path.length(), epsilon = 1e-9);
+        // every original vertex is still there, in order
+        let mut rest = dense.vertices().iter();
+        assert!(path.vertices().iter().all(|v| rest.any(|d| d == v)));
+        assert_eq!(densify(&path, 100.0), path);
+        assert_eq!(densify(&Polyline2D::<f64>::new(vec![]), 1.0).len(), 0);
+    }
+}
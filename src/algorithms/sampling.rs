@@ -0,0 +1,529 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::polygon::{Polygon2D, ring};
+use crate::shape::{Aabb2D, BoundingBox, Circle2D, Contains};
+use super::triangulate::triangulate_2d;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Source of uniformly distributed random bits for the sampling functions.
+///
+/// Implemented by the crate's own [`XorShift`]; wrap any other generator by
+/// forwarding its `next_u64`.
+pub trait Rng {
+    fn next_u64(&mut self) -> u64;
+
+    /// Uniform in `0..1`, from the top 53 bits of [`next_u64`](Self::next_u64).
+    fn unit<T: Float>(&mut self) -> T {
+        let bits = self.next_u64() >> 11;
+        T::from(bits as f64 / (1u64 << 53) as f64).unwrap()
+    }
+}
+
+impl<R: Rng + ?Sized> Rng for &mut R {
+    fn next_u64(&mut self) -> u64 {
+        (**self).next_u64()
+    }
+}
+
+/// xorshift64* generator, small and reproducible; not for anything security related.
+#[derive(Debug, Clone)]
+pub struct XorShift(u64);
+
+impl XorShift {
+    pub fn new(seed: u64) -> Self {
+        // the all-zero state is a fixed point
+        XorShift(seed.max(1))
+    }
+}
+
+impl Rng for XorShift {
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+}
+
+/// Fisher-Yates shuffle.
+pub(crate) fn shuffle<E>(items: &mut [E], rng: &mut impl Rng) {
+    for i in (1..items.len()).rev() {
+        let j = (rng.next_u64() % (i as u64 + 1)) as usize;
+        items.swap(i, j);
+    }
+}
+
+/// A uniformly random point inside the triangle `a`, `b`, `c`.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::sampling::{sample_triangle, XorShift};
+/// use geomengine::point::Point2D;
+/// use geomengine::polygon::Polygon2D;
+///
+/// let (a, b, c) = (Point2D::new(0.0, 0.0), Point2D::new(4.0, 0.0), Point2D::new(0.0, 4.0));
+/// let triangle = Polygon2D::new(vec![a, b, c]);
+/// let mut rng = XorShift::new(1);
+/// assert!((0..100).all(|_| triangle.contains_point(&sample_triangle(a, b, c, &mut rng))));
+/// ```
+pub fn sample_triangle<T: Float + Debug>(a: Point2D<T>, b: Point2D<T>, c: Point2D<T>, rng: &mut impl Rng) -> Point2D<T> {
+    let (mut u, mut v) = (rng.unit::<T>(), rng.unit::<T>());
+    // fold the far half of the parallelogram back onto the triangle
+    if u + v > T::one() {
+        (u, v) = (T::one() - u, T::one() - v);
+    }
+    a + (b - a) * u + (c - a) * v
+}
+
+/// A uniformly random point inside `circle`.
+pub fn sample_circle<T: Float + Debug>(circle: &Circle2D<T>, rng: &mut impl Rng) -> Point2D<T> {
+    // the square root spreads the radii so that the density per area is constant
+    let radius = circle.radius * rng.unit::<T>().sqrt();
+    let (sin, cos) = (T::from(core::f64::consts::TAU).unwrap() * rng.unit::<T>()).sin_cos();
+    circle.center + Point2D::new(cos, sin) * radius
+}
+
+/// A uniformly random point inside `aabb`.
+pub fn sample_aabb<T: Float + Debug>(aabb: &Aabb2D<T>, rng: &mut impl Rng) -> Point2D<T> {
+    Point2D::new(aabb.min.x + rng.unit::<T>() * aabb.width(), aabb.min.y + rng.unit::<T>() * aabb.height())
+}
+
+/// A uniformly random point inside `polygon`, or `None` if it has no area.
+///
+/// Triangulates the polygon on every call; use a [`PolygonSampler`] to draw many points.
+pub fn sample_polygon<T: Float + Debug>(polygon: &Polygon2D<T>, rng: &mut impl Rng) -> Option<Point2D<T>> {
+    PolygonSampler::new(polygon, &[]).map(|sampler| sampler.sample(rng))
+}
+
+/// Draws uniformly random points inside a polygon with holes.
+///
+/// # Algorithm Explanation
+/// The polygon is triangulated once with [`triangulate_2d`]. Each sample picks
+/// a triangle with probability proportional to its area, by binary search over
+/// the running sums of the areas, then a point inside it with [`sample_triangle`].
+///
+/// # Time Complexity
+/// **O(n²)** to build for `n` vertices, then **O(log n)** per sample.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::sampling::{PolygonSampler, XorShift};
+/// use geomengine::point::Point2D;
+/// use geomengine::polygon::Polygon2D;
+///
+/// let square = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(4.0, 0.0), Point2D::new(4.0, 4.0), Point2D::new(0.0, 4.0)]);
+/// let hole = Polygon2D::new(vec![Point2D::new(1.0, 1.0), Point2D::new(3.0, 1.0), Point2D::new(3.0, 3.0), Point2D::new(1.0, 3.0)]);
+/// let sampler = PolygonSampler::new(&square, &[hole.clone()]).unwrap();
+/// assert_eq!(sampler.area(), 12.0);
+///
+/// let mut rng = XorShift::new(9);
+/// for _ in 0..100 {
+///     let p = sampler.sample(&mut rng);
+///     assert!(square.contains_point(&p) && !hole.contains_point(&p));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct PolygonSampler<T: Float + Debug> {
+    triangles: Vec<[Point2D<T>; 3]>,
+    // running sums of the triangle areas
+    cumulative: Vec<T>,
+}
+
+impl<T: Float + Debug> PolygonSampler<T> {
+    /// Triangulates the polygon, or returns `None` if it has no area.
+    pub fn new(exterior: &Polygon2D<T>, holes: &[Polygon2D<T>]) -> Option<Self> {
+        let points: Vec<Point2D<T>> = exterior.vertices().iter().chain(holes.iter().flat_map(|h| h.vertices())).copied().collect();
+        let mut sampler = PolygonSampler { triangles: Vec::new(), cumulative: Vec::new() };
+        let mut total = T::zero();
+        for [a, b, c] in triangulate_2d(exterior, holes) {
+            let triangle = [points[a], points[b], points[c]];
+            let area = ring::signed_area(&triangle).abs();
+            if area > T::zero() {
+                total = total + area;
+                sampler.triangles.push(triangle);
+                sampler.cumulative.push(total);
+            }
+        }
+        (total > T::zero()).then_some(sampler)
+    }
+
+    /// The area samples are drawn from.
+    pub fn area(&self) -> T {
+        self.cumulative[self.cumulative.len() - 1]
+    }
+
+    pub fn sample(&self, rng: &mut impl Rng) -> Point2D<T> {
+        let target = rng.unit::<T>() * self.area();
+        let index = self.cumulative.partition_point(|&sum| sum <= target).min(self.triangles.len() - 1);
+        let [a, b, c] = self.triangles[index];
+        sample_triangle(a, b, c, rng)
+    }
+}
+
+/// Candidates tried around each active point before it is retired.
+const CANDIDATES: usize = 30;
+/// Random points tried to find a first point inside the region.
+const SEED_ATTEMPTS: usize = 100;
+
+/// Fills a region with random points no closer than `min_distance` to each
+/// other (blue noise), using Bridson's algorithm.
+///
+/// # Arguments
+/// * `region` - Any shape with an area, such as an [`Aabb2D`](crate::shape::Aabb2D)
+///   or a [`Polygon2D`](crate::polygon::Polygon2D).
+/// * `min_distance` - The smallest distance between two points.
+/// * `rng` - The source of randomness; the same state gives the same points.
+///
+/// # Returns
+/// * Points inside `region`, pairwise at least `min_distance` apart, and
+///   packed so that no more fit between them except near the boundary. Empty if
+///   `min_distance` is not positive, or if none of the first 100 random points
+///   in the region's bounding box lands inside it.
+///
+/// # Algorithm Explanation
+/// - A background grid of cells with diagonal `min_distance` holds at most one
+///   point each, so conflicts are found among the 5x5 cells around a candidate.
+/// - Starting from one random point, an active point is picked at random, and
+///   up to 30 candidates are drawn uniformly from the annulus between
+///   `min_distance` and twice that around it. The first that lies in the region
+///   and conflicts with no point is added and becomes active; if none does, the
+///   point is retired. Sampling ends when no active points are left.
+///
+/// # Time Complexity
+/// **O(n + A / d²)** for `n` points produced, minimum distance `d` and the
+/// area `A` of the region's bounding box, which the grid covers.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::sampling::{poisson_disk_sample, XorShift};
+/// use geomengine::point::Point2D;
+/// use geomengine::shape::Aabb2D;
+///
+/// let area = Aabb2D::new(Point2D::new(0.0, 0.0), Point2D::new(10.0, 10.0));
+/// let points = poisson_disk_sample(&area, 1.0, &mut XorShift::new(7));
+/// assert!(points.len() > 50);
+/// for (i, a) in points.iter().enumerate() {
+///     assert!(points[i + 1..].iter().all(|b| a.distance(b) >= 1.0));
+/// }
+/// ```
+pub fn poisson_disk_sample<T, S, R>(region: &S, min_distance: T, rng: &mut R) -> Vec<Point2D<T>>
+where
+    T: Float + Debug,
+    S: Contains<Point2D<T>> + BoundingBox<T> + ?Sized,
+    R: Rng,
+{
+    let Some(bounds) = region.bounding_box() else {
+        return Vec::new();
+    };
+    if min_distance <= T::zero() || min_distance.is_nan() {
+        return Vec::new();
+    }
+    let two = T::one() + T::one();
+    let cell = min_distance / two.sqrt();
+    let columns = (bounds.width() / cell).floor().to_usize().unwrap_or(0) + 1;
+    let rows = (bounds.height() / cell).floor().to_usize().unwrap_or(0) + 1;
+    let cell_of = |p: &Point2D<T>| {
+        let column = ((p.x - bounds.min.x) / cell).to_usize().unwrap_or(0).min(columns - 1);
+        let row = ((p.y - bounds.min.y) / cell).to_usize().unwrap_or(0).min(rows - 1);
+        (column, row)
+    };
+
+    let Some(first) = (0..SEED_ATTEMPTS).map(|_| sample_aabb(&bounds, rng)).find(|p| region.contains(p)) else {
+        return Vec::new();
+    };
+    let mut grid: Vec<Option<usize>> = vec![None; columns * rows];
+    let mut points = vec![first];
+    let mut active = vec![0];
+    let (column, row) = cell_of(&first);
+    grid[row * columns + column] = Some(0);
+
+    let tau = T::from(core::f64::consts::TAU).unwrap();
+    let three = two + T::one();
+    while !active.is_empty() {
+        let slot = (rng.unit::<T>() * T::from(active.len()).unwrap()).to_usize().unwrap_or(0).min(active.len() - 1);
+        let center = points[active[slot]];
+        let accepted = (0..CANDIDATES).find_map(|_| {
+            // uniform over the annulus' area: r² uniform between min_distance² and 4 min_distance²
+            let radius = min_distance * (T::one() + three * rng.unit::<T>()).sqrt();
+            let (sin, cos) = (tau * rng.unit::<T>()).sin_cos();
+            let candidate = center + Point2D::new(cos, sin) * radius;
+            if !region.contains(&candidate) {
+                return None;
+            }
+            let (column, row) = cell_of(&candidate);
+            let free = (row.saturating_sub(2)..(row + 3).min(rows)).all(|r| {
+                (column.saturating_sub(2)..(column + 3).min(columns))
+                    .all(|c| grid[r * columns + c].is_none_or(|other| points[other].distance(&candidate) >= min_distance))
+            });
+            free.then_some((candidate, row * columns + column))
+        });
+        match accepted {
+            Some((candidate, cell_index)) => {
+                grid[cell_index] = Some(points.len());
+                active.push(points.len());
+                points.push(candidate);
+            }
+            None => {
+                active.swap_remove(slot);
+            }
+        }
+    }
+    points
+}
+
+/// Low-discrepancy sequences of points in the unit square.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LowDiscrepancy {
+    /// Radical inverses in bases 2 and 3; see [`halton_2d`].
+    #[default]
+    Halton,
+    /// The first two Sobol dimensions; see [`sobol_2d`].
+    Sobol,
+}
+
+impl LowDiscrepancy {
+    /// Point `index` of the sequence, in `[0, 1)²`.
+    pub fn point<T: Float + Debug>(self, index: u64) -> Point2D<T> {
+        match self {
+            LowDiscrepancy::Halton => halton_2d(index),
+            LowDiscrepancy::Sobol => sobol_2d(index),
+        }
+    }
+}
+
+/// Point `index` of the 2D Halton sequence, in `[0, 1)²`.
+///
+/// The coordinates are the radical inverses of `index` in bases 2 and 3: its
+/// digits mirrored around the radix point. Point 0 is the origin, and the first
+/// `2^i 3^j` points fall one into each cell of a `2^i` by `3^j` grid.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::sampling::halton_2d;
+/// use geomengine::point::Point2D;
+///
+/// assert_eq!(halton_2d::<f64>(1), Point2D::new(0.5, 1.0 / 3.0));
+/// assert_eq!(halton_2d::<f64>(2), Point2D::new(0.25, 2.0 / 3.0));
+/// ```
+pub fn halton_2d<T: Float + Debug>(index: u64) -> Point2D<T> {
+    Point2D::new(radical_inverse(index, 2), radical_inverse(index, 3))
+}
+
+/// Point `index` of the 2D Sobol sequence, in `[0, 1)²`.
+///
+/// The first coordinate is the base-2 radical inverse; the second XORs the
+/// direction numbers of the primitive polynomial `x + 1` for the set bits of
+/// `index`. Point 0 is the origin, and the first `2^k` points fall one into
+/// each of any `2^k` equal boxes with power of two sides, like a 4x4 grid for 16 points.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::sampling::sobol_2d;
+/// use geomengine::point::Point2D;
+///
+/// assert_eq!(sobol_2d::<f64>(1), Point2D::new(0.5, 0.5));
+/// assert_eq!(sobol_2d::<f64>(2), Point2D::new(0.25, 0.75));
+/// ```
+pub fn sobol_2d<T: Float + Debug>(index: u64) -> Point2D<T> {
+    let (mut x, mut y) = (0u64, 0u64);
+    let mut direction = 1u64 << 63;
+    for bit in 0..64 {
+        if index >> bit & 1 == 1 {
+            x ^= 1 << (63 - bit);
+            y ^= direction;
+        }
+        direction ^= direction >> 1;
+    }
+    let scale = |bits: u64| T::from((bits >> 11) as f64 / (1u64 << 53) as f64).unwrap();
+    Point2D::new(scale(x), scale(y))
+}
+
+fn radical_inverse<T: Float>(mut index: u64, base: u64) -> T {
+    let (mut inverse, mut digit_value) = (0.0, 1.0);
+    while index > 0 {
+        digit_value /= base as f64;
+        inverse += (index % base) as f64 * digit_value;
+        index /= base;
+    }
+    T::from(inverse).unwrap()
+}
+
+/// Spreads the first `count` points of a low-discrepancy sequence over the
+/// bounding box of `region`, keeping those inside it.
+///
+/// # Returns
+/// * All `count` points for a box; for other shapes about `count` times the
+///   share of the bounding box they cover. Unlike random sampling, the same
+///   call always gives the same points, and they cover the region evenly, so a
+///   quasi-Monte Carlo estimate converges close to `1 / count` rather than
+///   `1 / sqrt(count)`.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::sampling::{quasi_random_points, LowDiscrepancy};
+/// use geomengine::point::Point2D;
+/// use geomengine::shape::Circle2D;
+///
+/// // the unit disk covers pi / 4 of its bounding box
+/// let disk = Circle2D::new(Point2D::new(0.0, 0.0), 1.0);
+/// let inside = quasi_random_points(&disk, 4096, LowDiscrepancy::Halton).len();
+/// assert!((4.0 * inside as f64 / 4096.0 - std::f64::consts::PI).abs() < 0.005);
+/// ```
+pub fn quasi_random_points<T, S>(region: &S, count: usize, sequence: LowDiscrepancy) -> Vec<Point2D<T>>
+where
+    T: Float + Debug,
+    S: Contains<Point2D<T>> + BoundingBox<T> + ?Sized,
+{
+    let Some(bounds) = region.bounding_box() else {
+        return Vec::new();
+    };
+    let size = Point2D::new(bounds.width(), bounds.height());
+    (0..count as u64)
+        .map(|index| {
+            let unit = sequence.point::<T>(index);
+            bounds.min + Point2D::new(unit.x * size.x, unit.y * size.y)
+        })
+        .filter(|p| region.contains(p))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shape::Area;
+
+    fn assert_spaced(points: &[Point2D<f64>], min_distance: f64) {
+        for (i, a) in points.iter().enumerate() {
+            for b in &points[i + 1..] {
+                assert!(a.distance(b) >= min_distance, "{a:?} and {b:?} too close");
+            }
+        }
+    }
+
+    #[test]
+    fn test_xorshift_units_are_reproducible() {
+        let draw = |seed| {
+            let mut rng = XorShift::new(seed);
+            (0..1000).map(|_| rng.unit::<f64>()).collect::<Vec<f64>>()
+        };
+        assert_eq!(draw(3), draw(3));
+        assert_ne!(draw(3), draw(4));
+        let units = draw(3);
+        assert!(units.iter().all(|u| (0.0..1.0).contains(u)));
+        let mean = units.iter().sum::<f64>() / 1000.0;
+        assert!((mean - 0.5).abs() < 0.05);
+        // a zero seed still produces numbers
+        assert_ne!(XorShift::new(0).next_u64(), 0);
+    }
+
+    #[test]
+    fn test_poisson_disk_box_is_spaced_and_maximal() {
+        let area = Aabb2D::new(Point2D::new(0.0, 0.0), Point2D::new(20.0, 10.0));
+        let points = poisson_disk_sample(&area, 1.0, &mut XorShift::new(11));
+        assert_spaced(&points, 1.0);
+        assert!(points.iter().all(|p| area.contains(p)));
+        // maximal: every spot away from the border is within 2 of a point
+        for x in 1..20 {
+            for y in 1..10 {
+                let spot = Point2D::new(x as f64, y as f64);
+                assert!(points.iter().any(|p| p.distance(&spot) < 2.0));
+            }
+        }
+        assert_eq!(points, poisson_disk_sample(&area, 1.0, &mut XorShift::new(11)));
+        assert!(poisson_disk_sample(&area, 0.0, &mut XorShift::new(11)).is_empty());
+    }
+
+    #[test]
+    fn test_poisson_disk_polygon() {
+        let triangle = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(12.0, 0.0), Point2D::new(0.0, 12.0)]);
+        let points = poisson_disk_sample(&triangle, 0.5, &mut XorShift::new(5));
+        assert_spaced(&points, 0.5);
+        assert!(points.iter().all(|p| triangle.contains_point(p)));
+        // 72 square units at roughly one point per 0.6 square units
+        assert!((100..=200).contains(&points.len()), "{} points", points.len());
+    }
+
+    #[test]
+    fn test_samplers_monte_carlo_areas() {
+        let mut rng = XorShift::new(21);
+        let n = 20_000;
+        // the unit circle covers pi / 4 of its bounding square
+        let square = Aabb2D::new(Point2D::new(-1.0, -1.0), Point2D::new(1.0, 1.0));
+        let circle = Circle2D::new(Point2D::new(0.0, 0.0), 1.0);
+        let hits = (0..n).filter(|_| circle.contains(&sample_aabb(&square, &mut rng))).count();
+        assert!((4.0 * hits as f64 / n as f64 - core::f64::consts::PI).abs() < 0.05);
+
+        // points in the disk: half fall within radius 1 / sqrt(2), and all inside
+        let disk: Vec<Point2D<f64>> = (0..n).map(|_| sample_circle(&Circle2D::new(Point2D::new(5.0, 5.0), 2.0), &mut rng)).collect();
+        assert!(disk.iter().all(|p| p.distance(&Point2D::new(5.0, 5.0)) <= 2.0));
+        let inner = disk.iter().filter(|p| p.distance(&Point2D::new(5.0, 5.0)) < 2.0 / 2f64.sqrt()).count();
+        assert!((inner as f64 / n as f64 - 0.5).abs() < 0.02);
+
+        // the mean of points in a triangle is its centroid
+        let (a, b, c) = (Point2D::new(0.0, 0.0), Point2D::new(6.0, 0.0), Point2D::new(0.0, 3.0));
+        let sum = (0..n).map(|_| sample_triangle(a, b, c, &mut rng)).fold(Point2D::new(0.0, 0.0), |sum, p| sum + p);
+        let mean = sum / n as f64;
+        assert!(mean.distance(&Point2D::new(2.0, 1.0)) < 0.05);
+    }
+
+    #[test]
+    fn test_polygon_sampler_proportional_to_area() {
+        // an L of 6 square units left of x = 2 and an arm of 3 to the right
+        let l_shape = Polygon2D::new(vec![
+            Point2D::new(0.0, 0.0),
+            Point2D::new(5.0, 0.0),
+            Point2D::new(5.0, 1.0),
+            Point2D::new(2.0, 1.0),
+            Point2D::new(2.0, 3.0),
+            Point2D::new(0.0, 3.0),
+        ]);
+        let sampler = PolygonSampler::new(&l_shape, &[]).unwrap();
+        assert_eq!(sampler.area(), l_shape.area());
+        let mut rng = XorShift::new(2);
+        let n = 20_000;
+        let points: Vec<Point2D<f64>> = (0..n).map(|_| sampler.sample(&mut rng)).collect();
+        assert!(points.iter().all(|p| l_shape.contains_point(p)));
+        let in_arm = points.iter().filter(|p| p.x > 2.0).count();
+        assert!((in_arm as f64 / n as f64 - 3.0 / 9.0).abs() < 0.02);
+
+        let flat = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0), Point2D::new(2.0, 2.0)]);
+        assert!(sample_polygon(&flat, &mut rng).is_none());
+        assert!(sample_polygon(&l_shape, &mut rng).is_some_and(|p| l_shape.contains_point(&p)));
+    }
+
+    #[test]
+    fn test_low_discrepancy_stratification() {
+        // the first 6 Halton points fill a 2x3 grid, the first 16 Sobol points a 4x4 one
+        for (sequence, count, columns, rows) in [(LowDiscrepancy::Halton, 6, 2.0, 3.0), (LowDiscrepancy::Sobol, 16, 4.0, 4.0)] {
+            let mut cells: Vec<(u32, u32)> = (0..count)
+                .map(|i| {
+                    let p = sequence.point::<f64>(i);
+                    assert!((0.0..1.0).contains(&p.x) && (0.0..1.0).contains(&p.y));
+                    ((p.x * columns) as u32, (p.y * rows) as u32)
+                })
+                .collect();
+            cells.sort();
+            cells.dedup();
+            assert_eq!(cells.len(), count as usize, "{sequence:?}");
+        }
+        assert_eq!(halton_2d::<f64>(0), Point2D::new(0.0, 0.0));
+        assert_eq!(sobol_2d::<f64>(3), Point2D::new(0.75, 0.25));
+        assert_eq!(halton_2d::<f64>(3).y, 1.0 / 9.0);
+    }
+
+    #[test]
+    fn test_quasi_random_points_in_polygon() {
+        let triangle = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(4.0, 0.0), Point2D::new(1.0, 3.0)]);
+        for sequence in [LowDiscrepancy::Halton, LowDiscrepancy::Sobol] {
+            let points = quasi_random_points(&triangle, 1000, sequence);
+            assert!(points.iter().all(|p| triangle.contains_point(p)));
+            // half of the bounding box
+            assert!((points.len() as f64 / 1000.0 - 0.5).abs() < 0.01);
+            assert_eq!(points, quasi_random_points(&triangle, 1000, sequence));
+        }
+        let square = Aabb2D::new(Point2D::new(2.0, 2.0), Point2D::new(3.0, 5.0));
+        assert_eq!(quasi_random_points(&square, 50, LowDiscrepancy::Halton).len(), 50);
+    }
+}
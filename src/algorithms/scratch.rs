@@ -0,0 +1,96 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::line::Line2D;
+use super::noding::{NodingBuffers, node_segments_with, snap_tolerance};
+use super::point_algorithms::convex_hull_2d_into;
+use alloc::vec::Vec;
+
+/// Reusable working memory for repeated geometry operations.
+///
+/// Interactive tools recompute the same operations every frame. The plain
+/// functions allocate their intermediate buffers on every call; the methods here
+/// keep them in the `Scratch` instead, so once the buffers have grown to the
+/// working size, further calls allocate nothing.
+///
+/// Results borrow from the scratch and stay valid until its next use.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::scratch::Scratch;
+/// use geomengine::point::Point2D;
+///
+/// let mut scratch = Scratch::new();
+/// for frame in 0..3 {
+///     let t = frame as f64;
+///     let points = [Point2D::new(t, 0.0), Point2D::new(t + 2.0, 0.0), Point2D::new(t, 2.0), Point2D::new(t + 0.5, 0.5)];
+///     assert_eq!(scratch.convex_hull_2d(&points).len(), 3);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Scratch<T: Float + Debug> {
+    hull: Vec<Point2D<T>>,
+    noding: NodingBuffers<T>,
+    noded: Vec<Line2D<T>>,
+}
+
+impl<T: Float + Debug> Default for Scratch<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Float + Debug> Scratch<T> {
+    /// Creates an empty scratch; buffers grow on first use.
+    pub fn new() -> Self {
+        Scratch { hull: Vec::new(), noding: NodingBuffers::default(), noded: Vec::new() }
+    }
+
+    /// Same result as [`convex_hull_2d`](super::point_algorithms::convex_hull_2d).
+    pub fn convex_hull_2d(&mut self, points: &[Point2D<T>]) -> &[Point2D<T>] {
+        convex_hull_2d_into(points, &mut self.hull);
+        &self.hull
+    }
+
+    /// Same result as [`node_segments_2d`](super::noding::node_segments_2d), the
+    /// first stage of the overlay, union and polygonize operations.
+    pub fn node_segments_2d(&mut self, segments: &[Line2D<T>]) -> &[Line2D<T>] {
+        let tolerance = snap_tolerance(segments.iter().flat_map(|s| [s.p1, s.p2]));
+        node_segments_with(segments, tolerance, &mut self.noding, &mut self.noded);
+        &self.noded
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::noding::node_segments_2d;
+    use crate::algorithms::point_algorithms::convex_hull_2d;
+
+    #[test]
+    fn test_scratch_matches_plain_functions_across_calls() {
+        let mut scratch = Scratch::new();
+        for n in [50, 3, 200, 2, 80] {
+            let points: Vec<_> = (0..n)
+                .map(|i| Point2D::new(((i * 31) % 43) as f64, ((i * 17) % 29) as f64))
+                .collect();
+            assert_eq!(scratch.convex_hull_2d(&points), convex_hull_2d(&points).as_slice());
+
+            let segments: Vec<_> = points.windows(2).map(|w| Line2D::new(w[0], w[1])).collect();
+            assert_eq!(scratch.node_segments_2d(&segments), node_segments_2d(&segments).as_slice());
+        }
+    }
+
+    #[test]
+    fn test_scratch_stops_allocating_once_warm() {
+        let mut scratch = Scratch::new();
+        let points: Vec<_> = (0..100).map(|i| Point2D::new((i as f64).sin(), (i as f64).cos())).collect();
+        let segments: Vec<_> = points.windows(2).map(|w| Line2D::new(w[0], w[1])).collect();
+        scratch.convex_hull_2d(&points);
+        scratch.node_segments_2d(&segments);
+        let (hull, noded) = (scratch.hull.as_ptr(), scratch.noded.as_ptr());
+        scratch.convex_hull_2d(&points[..60]);
+        scratch.node_segments_2d(&segments[..30]);
+        assert_eq!((hull, noded), (scratch.hull.as_ptr(), scratch.noded.as_ptr()));
+    }
+}
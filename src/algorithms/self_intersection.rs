@@ -0,0 +1,243 @@
+use num::Float;
+use core::cmp::Ordering;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::line::{Line2D, Polyline2D};
+use crate::polygon::{Polygon2D, ring};
+use super::noding::{interior_param, node_segments, snap_tolerance};
+use super::planar_graph::{PlanarGraph, assemble_rings};
+use alloc::vec::Vec;
+
+/// A chain of segments, open or closed, checked by [`find_self_intersections`].
+pub trait SegmentChain<T: Float + Debug> {
+    /// The segments in order along the chain.
+    fn chain_segments(&self) -> Vec<Line2D<T>>;
+    /// Whether the last segment connects back to the first.
+    fn is_closed(&self) -> bool;
+}
+
+impl<T: Float + Debug> SegmentChain<T> for Polyline2D<T> {
+    fn chain_segments(&self) -> Vec<Line2D<T>> {
+        self.edges().collect()
+    }
+    /// A polyline ending where it starts is closed.
+    fn is_closed(&self) -> bool {
+        self.len() > 2 && self.vertices().first() == self.vertices().last()
+    }
+}
+
+impl<T: Float + Debug> SegmentChain<T> for Polygon2D<T> {
+    fn chain_segments(&self) -> Vec<Line2D<T>> {
+        self.edges().collect()
+    }
+    fn is_closed(&self) -> bool {
+        true
+    }
+}
+
+/// A point where two segments of a chain meet although they are not neighbours,
+/// or where neighbours overlap beyond their shared vertex.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SelfIntersection<T: Float + Debug> {
+    pub point: Point2D<T>,
+    /// Indices of the two segments along the chain, the smaller first.
+    pub segments: [usize; 2],
+}
+
+/// Finds every place where `path`, a polyline or polygon ring, touches or crosses itself.
+///
+/// # Returns
+/// * The intersections ordered by segment pair; an empty list means the chain is
+///   simple. Crossings, vertices touching another segment and collinear overlaps
+///   (reported at the overlap's ends) all count. Neighbouring segments only count
+///   if they fold back onto each other.
+///
+/// Coordinates closer than a small tolerance relative to the input's magnitude
+/// are treated as touching.
+///
+/// # Time Complexity
+/// A sweep over the segments sorted by their left end only pairs segments whose
+/// x-ranges overlap: **O(n log n + k)** for k overlapping pairs, **O(n²)** at worst.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::self_intersection::find_self_intersections;
+/// use geomengine::point::Point2D;
+/// use geomengine::polygon::Polygon2D;
+///
+/// let bow_tie = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(2.0, 2.0), Point2D::new(2.0, 0.0), Point2D::new(0.0, 2.0)]);
+/// let crossings = find_self_intersections(&bow_tie);
+/// assert_eq!(crossings.len(), 1);
+/// assert_eq!(crossings[0].point, Point2D::new(1.0, 1.0));
+/// assert_eq!(crossings[0].segments, [0, 2]);
+/// ```
+pub fn find_self_intersections<T: Float + Debug, P: SegmentChain<T>>(path: &P) -> Vec<SelfIntersection<T>> {
+    let segments = path.chain_segments();
+    let n = segments.len();
+    let closed = path.is_closed();
+    let tolerance = snap_tolerance(segments.iter().flat_map(|s| [s.p1, s.p2]));
+    let adjacent = |i: usize, j: usize| j == i + 1 || (closed && i == 0 && j == n - 1);
+
+    let min_x = |s: &Line2D<T>| s.p1.x.min(s.p2.x);
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&a, &b| min_x(&segments[a]).partial_cmp(&min_x(&segments[b])).unwrap_or(Ordering::Equal));
+    let mut found = Vec::new();
+    for (k, &first) in order.iter().enumerate() {
+        let right = segments[first].p1.x.max(segments[first].p2.x) + tolerance;
+        for &second in order[k + 1..].iter().take_while(|&&j| min_x(&segments[j]) <= right) {
+            let (i, j) = (first.min(second), first.max(second));
+            for point in contacts(&segments[i], &segments[j], adjacent(i, j), tolerance) {
+                found.push(SelfIntersection { point, segments: [i, j] });
+            }
+        }
+    }
+    found.sort_by_key(|intersection| intersection.segments);
+    found
+}
+
+/// Splits a self-intersecting ring, such as a bow tie, into simple polygons.
+///
+/// # Returns
+/// * One `(exterior, holes)` pair per piece, exteriors counterclockwise and holes
+///   clockwise, covering what the ring encloses under the even-odd rule, so an
+///   area the ring winds around twice is left out. Spikes and other parts
+///   enclosing no area are dropped, as are vertices that only split a straight
+///   edge. A hole touching its exterior at a vertex is traced as part of the exterior.
+///
+/// # Algorithm Explanation
+/// 1. The ring's edges are split at all their mutual intersections (noding).
+/// 2. The noded edges form a planar graph whose bounded faces are the areas the
+///    ring cuts the plane into.
+/// 3. Faces whose interior point the ring contains are kept and their outline
+///    traced back into rings.
+///
+/// # Time Complexity
+/// **O(n²)** in the number of edges, dominated by noding.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::self_intersection::repair_self_intersections;
+/// use geomengine::point::Point2D;
+/// use geomengine::polygon::Polygon2D;
+/// use geomengine::shape::Area;
+///
+/// let bow_tie = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(2.0, 2.0), Point2D::new(2.0, 0.0), Point2D::new(0.0, 2.0)]);
+/// let repaired = repair_self_intersections(&bow_tie);
+/// assert_eq!(repaired.len(), 2);
+/// for (lobe, holes) in &repaired {
+///     assert_eq!(lobe.len(), 3);
+///     assert_eq!(lobe.area(), 1.0);
+///     assert!(holes.is_empty());
+/// }
+/// ```
+pub fn repair_self_intersections<T: Float + Debug>(polygon: &Polygon2D<T>) -> Vec<(Polygon2D<T>, Vec<Polygon2D<T>>)> {
+    let vertices = polygon.vertices();
+    if vertices.len() < 3 {
+        return Vec::new();
+    }
+    let tolerance = snap_tolerance(vertices.iter().cloned());
+    let edges: Vec<Line2D<T>> = polygon.edges().collect();
+    let graph = PlanarGraph::new(&node_segments(&edges, tolerance), tolerance);
+    let faces = graph.faces();
+    let in_region: Vec<bool> = (0..faces.shells.len())
+        .map(|face| ring::contains_point(vertices, &graph.interior_point(&faces, face)))
+        .collect();
+    assemble_rings(graph.region_rings(&faces, &in_region))
+        .into_iter()
+        .map(|(shell, holes)| (Polygon2D::new(shell), holes.into_iter().map(Polygon2D::new).collect()))
+        .collect()
+}
+
+/// Points where segments `a` and `b` meet, leaving out the vertex shared by neighbours.
+fn contacts<T: Float + Debug>(a: &Line2D<T>, b: &Line2D<T>, adjacent: bool, tolerance: T) -> Vec<Point2D<T>> {
+    let mut points = Vec::new();
+    // endpoints on the other segment's interior: T-junctions, overlaps and fold-backs
+    points.extend([b.p1, b.p2].into_iter().filter(|p| interior_param(a, p, tolerance).is_some()));
+    points.extend([a.p1, a.p2].into_iter().filter(|p| interior_param(b, p, tolerance).is_some()));
+    if !adjacent {
+        for p in [a.p1, a.p2] {
+            if [b.p1, b.p2].iter().any(|q| p.distance(q) <= tolerance) {
+                points.push(p);
+            }
+        }
+        let (da, db) = (a.p2 - a.p1, b.p2 - b.p1);
+        let denominator = da.cross_product(&db);
+        if denominator.abs() > T::epsilon() * a.length() * b.length() {
+            let offset = b.p1 - a.p1;
+            let t = offset.cross_product(&db) / denominator;
+            let u = offset.cross_product(&da) / denominator;
+            if t > T::zero() && t < T::one() && u > T::zero() && u < T::one() {
+                points.push(a.p1 + da * t);
+            }
+        }
+    }
+    let mut distinct: Vec<Point2D<T>> = Vec::with_capacity(points.len());
+    for p in points {
+        if distinct.iter().all(|q| q.distance(&p) > tolerance) {
+            distinct.push(p);
+        }
+    }
+    distinct
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shape::Area;
+    use alloc::vec;
+
+    fn points(coordinates: &[(f64, f64)]) -> Vec<Point2D<f64>> {
+        coordinates.iter().map(|&(x, y)| Point2D::new(x, y)).collect()
+    }
+
+    #[test]
+    fn test_find_self_intersections_in_polylines() {
+        let simple = Polyline2D::new(points(&[(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)]));
+        assert!(find_self_intersections(&simple).is_empty());
+        // closing the square only makes the first and last segments neighbours
+        let closed = Polyline2D::new(points(&[(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0), (0.0, 0.0)]));
+        assert!(find_self_intersections(&closed).is_empty());
+
+        // a loop crossing the first segment, then touching a vertex, then folding back
+        let tangled = Polyline2D::new(points(&[(0.0, 0.0), (4.0, 0.0), (4.0, 2.0), (2.0, 2.0), (2.0, -2.0), (6.0, -2.0), (4.0, 2.0), (4.0, 1.0)]));
+        let found = find_self_intersections(&tangled);
+        let pairs: Vec<[usize; 2]> = found.iter().map(|f| f.segments).collect();
+        assert_eq!(pairs, [[0, 3], [1, 5], [1, 6], [1, 6], [2, 5], [2, 6]]);
+        assert_eq!(found[0].point, Point2D::new(2.0, 0.0));
+        // the last segment runs back down the second, from (4, 2) to (4, 1)
+        assert!(found.iter().any(|f| f.segments == [1, 6] && f.point == Point2D::new(4.0, 1.0)));
+        // segments meeting at (4, 2) without being neighbours along the path
+        assert!(found[4..].iter().all(|f| f.point == Point2D::new(4.0, 2.0)));
+    }
+
+    #[test]
+    fn test_repair_self_intersections() {
+        // already simple rings come back unchanged, apart from orientation
+        let square = Polygon2D::new(points(&[(0.0, 0.0), (0.0, 3.0), (3.0, 3.0), (3.0, 0.0)]));
+        let repaired = repair_self_intersections(&square);
+        assert_eq!(repaired.len(), 1);
+        assert_eq!(repaired[0].0.area(), 9.0);
+        assert!(ring::signed_area(repaired[0].0.vertices()) > 0.0);
+
+        // a pentagram winds twice around its middle, which the even-odd rule leaves
+        // out, so only the five points remain, meeting at their corners
+        let pentagram = Polygon2D::new(
+            (0..5)
+                .map(|i| {
+                    let angle = (i * 2) as f64 * 2.0 * core::f64::consts::PI / 5.0;
+                    Point2D::new(angle.cos(), angle.sin())
+                })
+                .collect(),
+        );
+        assert_eq!(find_self_intersections(&pentagram).len(), 5);
+        let repaired = repair_self_intersections(&pentagram);
+        assert_eq!(repaired.len(), 5);
+        for (point, holes) in &repaired {
+            assert_eq!(point.len(), 3);
+            assert!(holes.is_empty());
+            assert!((point.area() - repaired[0].0.area()).abs() < 1e-12);
+            assert!(find_self_intersections(point).is_empty());
+        }
+        assert!(repair_self_intersections(&Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0)])).is_empty());
+    }
+}
@@ -0,0 +1,368 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::line::Polyline2D;
+use crate::spatial::KdTree;
+use super::resample::densify;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Directed Hausdorff distance from `from` to `to`: how far the point of `from`
+/// lying furthest from `to` is from its nearest point there.
+///
+/// # Returns
+/// * `None` if either set is empty.
+///
+/// # Time Complexity
+/// **O((n + m) log m)** for `n` points in `from` and `m` in `to`, which are
+/// indexed in a [`KdTree`].
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::similarity::hausdorff_directed;
+/// use geomengine::point::Point2D;
+///
+/// let a = [Point2D::new(0.0, 0.0), Point2D::new(1.0, 0.0)];
+/// let b = [Point2D::new(0.0, 0.0), Point2D::new(1.0, 0.0), Point2D::new(5.0, 0.0)];
+/// // every point of a is in b, but b's last point is 4 away from a
+/// assert_eq!(hausdorff_directed(&a, &b), Some(0.0));
+/// assert_eq!(hausdorff_directed(&b, &a), Some(4.0));
+/// ```
+pub fn hausdorff_directed<T: Float + Debug>(from: &[Point2D<T>], to: &[Point2D<T>]) -> Option<T> {
+    if from.is_empty() || to.is_empty() {
+        return None;
+    }
+    let tree = KdTree::new(to);
+    from.iter().map(|p| tree.nearest(p).map_or(T::zero(), |(_, distance)| distance)).reduce(T::max)
+}
+
+/// Hausdorff distance between two point sets: the larger of the two
+/// [directed](hausdorff_directed) distances, so that every point of either set
+/// lies within it of the other set.
+///
+/// # Returns
+/// * `None` if either set is empty.
+pub fn hausdorff_distance<T: Float + Debug>(a: &[Point2D<T>], b: &[Point2D<T>]) -> Option<T> {
+    Some(hausdorff_directed(a, b)?.max(hausdorff_directed(b, a)?))
+}
+
+/// Hausdorff distance between two polylines, e.g. to measure how far a
+/// simplified path strays from the original.
+///
+/// # Arguments
+/// * `a`, `b` - The polylines.
+/// * `max_spacing` - Segments are sampled at points no further apart than this.
+///
+/// # Returns
+/// * The Hausdorff distance between the sampled points, which is within
+///   `max_spacing / 2` of the exact distance between the lines. `None` if
+///   either polyline has no vertices.
+///
+/// # Algorithm Explanation
+/// Vertices alone miss where a line bulges away from the other between them,
+/// so every segment is split evenly into pieces of at most `max_spacing` by
+/// [`densify`] and
+/// [`hausdorff_distance`] is taken between all the resulting points. Every
+/// point of a line is then within `max_spacing / 2` of a sample.
+///
+/// # Time Complexity
+/// **O(k log k)** for `k` samples, about the total length over `max_spacing`
+/// plus the vertex count.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::similarity::polyline_hausdorff_distance;
+/// use geomengine::line::Polyline2D;
+/// use geomengine::point::Point2D;
+///
+/// let path = Polyline2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(5.0, 3.0), Point2D::new(10.0, 0.0)]);
+/// let simplified = Polyline2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(10.0, 0.0)]);
+/// // the peak is 3 away from the straight line, and no point of the line is further from the path
+/// let distance: f64 = polyline_hausdorff_distance(&path, &simplified, 0.01).unwrap();
+/// assert!((distance - 3.0).abs() <= 0.005);
+/// ```
+pub fn polyline_hausdorff_distance<T: Float + Debug>(a: &Polyline2D<T>, b: &Polyline2D<T>, max_spacing: T) -> Option<T> {
+    hausdorff_distance(densify(a, max_spacing).vertices(), densify(b, max_spacing).vertices())
+}
+
+/// Discrete Fréchet distance between two polylines: the shortest leash that
+/// lets two walkers step through the vertices of `a` and `b` in order, each
+/// step advancing one walker or both, without ever backtracking.
+///
+/// Unlike the Hausdorff distance it respects the order along the lines, so it
+/// tells apart tracks that cover the same ground in different directions.
+///
+/// # Returns
+/// * `None` if either polyline has no vertices.
+///
+/// # Algorithm Explanation
+/// `d(i, j)`, the distance for the first `i + 1` and `j + 1` vertices, is the
+/// larger of `|a_i - b_j|` and the smallest of `d(i - 1, j)`, `d(i, j - 1)` and
+/// `d(i - 1, j - 1)`. The table is filled row by row, keeping one row.
+///
+/// # Time Complexity
+/// **O(n m)** time and **O(m)** space for `n` and `m` vertices.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::similarity::discrete_frechet_distance;
+/// use geomengine::line::Polyline2D;
+/// use geomengine::point::Point2D;
+///
+/// let a = Polyline2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(1.0, 0.0), Point2D::new(2.0, 0.0)]);
+/// let b = Polyline2D::new(vec![Point2D::new(0.0, 1.0), Point2D::new(2.0, 1.0)]);
+/// assert_eq!(discrete_frechet_distance(&a, &b), Some(2f64.sqrt()));
+/// // the same points walked backwards are far apart at the start
+/// let reversed = Polyline2D::new(vec![Point2D::new(2.0, 1.0), Point2D::new(0.0, 1.0)]);
+/// assert_eq!(discrete_frechet_distance(&a, &reversed), Some(5f64.sqrt()));
+/// ```
+pub fn discrete_frechet_distance<T: Float + Debug>(a: &Polyline2D<T>, b: &Polyline2D<T>) -> Option<T> {
+    let (a, b) = (a.vertices(), b.vertices());
+    if a.is_empty() || b.is_empty() {
+        return None;
+    }
+    let mut row: Vec<T> = Vec::with_capacity(b.len());
+    for (j, q) in b.iter().enumerate() {
+        let previous = if j == 0 { T::zero() } else { row[j - 1] };
+        row.push(previous.max(a[0].distance(q)));
+    }
+    for p in &a[1..] {
+        let mut diagonal = row[0];
+        row[0] = row[0].max(p.distance(&b[0]));
+        for j in 1..b.len() {
+            let best = diagonal.min(row[j]).min(row[j - 1]);
+            diagonal = row[j];
+            row[j] = best.max(p.distance(&b[j]));
+        }
+    }
+    row.last().copied()
+}
+
+/// Whether the [discrete Fréchet distance](discrete_frechet_distance) between
+/// `a` and `b` is at most `epsilon`, e.g. to match a GPS track against a route.
+///
+/// Cheaper than computing the distance: only which vertex pairs are reachable
+/// with leash `epsilon` is tracked, and the search stops as soon as a row has
+/// none. `false` if either polyline has no vertices.
+///
+/// # Time Complexity
+/// **O(n m)** in the worst case, often far less for tracks that do not match.
+pub fn frechet_within<T: Float + Debug>(a: &Polyline2D<T>, b: &Polyline2D<T>, epsilon: T) -> bool {
+    let (a, b) = (a.vertices(), b.vertices());
+    if a.is_empty() || b.is_empty() || a[0].distance(&b[0]) > epsilon {
+        return false;
+    }
+    let close = |p: &Point2D<T>, q: &Point2D<T>| p.distance(q) <= epsilon;
+    let mut reachable: Vec<bool> = Vec::with_capacity(b.len());
+    for (j, q) in b.iter().enumerate() {
+        reachable.push((j == 0 || reachable[j - 1]) && close(&a[0], q));
+    }
+    for p in &a[1..] {
+        let mut diagonal = reachable[0];
+        reachable[0] = reachable[0] && close(p, &b[0]);
+        let mut any = reachable[0];
+        for j in 1..b.len() {
+            let from = diagonal || reachable[j] || reachable[j - 1];
+            diagonal = reachable[j];
+            reachable[j] = from && close(p, &b[j]);
+            any |= reachable[j];
+        }
+        if !any {
+            return false;
+        }
+    }
+    reachable[b.len() - 1]
+}
+
+/// The result of [`dtw`]: the total cost and the alignment achieving it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Warping<T: Float + Debug> {
+    /// Sum of the distances between all matched pairs.
+    pub distance: T,
+    /// Matched index pairs from `(0, 0)` to both last indices; each step
+    /// advances one index or both by one.
+    pub path: Vec<(usize, usize)>,
+}
+
+/// Dynamic time warping distance between two point sequences, with the
+/// optimal warping path.
+///
+/// Where [`discrete_frechet_distance`] reports the single worst match, DTW adds
+/// up every match, so it measures how similar two trajectories sampled at
+/// varying speeds are overall.
+///
+/// # Arguments
+/// * `a`, `b` - The sequences, such as the vertices of two tracks.
+/// * `band` - Optional Sakoe-Chiba band: only pairs `(i, j)` with `|i - j|` at
+///   most this many steps are matched. It is widened to the difference in
+///   length if narrower, so a path always exists.
+///
+/// # Returns
+/// * `None` if either sequence is empty.
+///
+/// # Algorithm Explanation
+/// - `D(i, j)`, the cheapest alignment of the first `i + 1` and `j + 1` points,
+///   is `|a_i - b_j|` plus the least of `D(i - 1, j)`, `D(i, j - 1)` and
+///   `D(i - 1, j - 1)`; cells outside the band are never reached.
+/// - The path is traced back from the last cell through the cheapest
+///   predecessors, preferring the diagonal on ties.
+///
+/// # Time Complexity
+/// **O(n m)** time and space, or **O(n w)** time with a band of `w` steps.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::similarity::dtw;
+/// use geomengine::point::Point2D;
+///
+/// // the same track, recorded with a pause at the second point
+/// let a = [Point2D::new(0.0, 0.0), Point2D::new(1.0, 0.0), Point2D::new(2.0, 0.0)];
+/// let b = [Point2D::new(0.0, 0.0), Point2D::new(1.0, 0.0), Point2D::new(1.0, 0.0), Point2D::new(2.0, 0.0)];
+/// let warping = dtw(&a, &b, None).unwrap();
+/// assert_eq!(warping.distance, 0.0);
+/// assert_eq!(warping.path, [(0, 0), (1, 1), (1, 2), (2, 3)]);
+/// ```
+pub fn dtw<T: Float + Debug>(a: &[Point2D<T>], b: &[Point2D<T>], band: Option<usize>) -> Option<Warping<T>> {
+    if a.is_empty() || b.is_empty() {
+        return None;
+    }
+    let (n, m) = (a.len(), b.len());
+    let band = band.map_or(usize::MAX, |w| w.max(n.abs_diff(m)));
+    // cost[i * m + j], infinite outside the band
+    let mut cost = vec![T::infinity(); n * m];
+    let at = |cost: &[T], i: Option<usize>, j: Option<usize>| match (i, j) {
+        (Some(i), Some(j)) => cost[i * m + j],
+        _ => T::infinity(),
+    };
+    for i in 0..n {
+        for j in i.saturating_sub(band)..m.min(i.saturating_add(band).saturating_add(1)) {
+            let previous = if i == 0 && j == 0 {
+                T::zero()
+            } else {
+                let (up, left) = (i.checked_sub(1), j.checked_sub(1));
+                at(&cost, up, left).min(at(&cost, up, Some(j))).min(at(&cost, Some(i), left))
+            };
+            cost[i * m + j] = previous + a[i].distance(&b[j]);
+        }
+    }
+
+    let mut path = vec![(n - 1, m - 1)];
+    let (mut i, mut j) = (n - 1, m - 1);
+    while (i, j) != (0, 0) {
+        let (up, left) = (i.checked_sub(1), j.checked_sub(1));
+        let diagonal = at(&cost, up, left);
+        (i, j) = if i > 0 && j > 0 && diagonal <= at(&cost, up, Some(j)) && diagonal <= at(&cost, Some(i), left) {
+            (i - 1, j - 1)
+        } else if j == 0 || (i > 0 && at(&cost, up, Some(j)) <= at(&cost, Some(i), left)) {
+            (i - 1, j)
+        } else {
+            (i, j - 1)
+        };
+        path.push((i, j));
+    }
+    path.reverse();
+    Some(Warping { distance: cost[n * m - 1], path })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::sampling::{Rng, XorShift};
+
+    fn grid(offset: f64) -> Vec<Point2D<f64>> {
+        (0..20).flat_map(|x| (0..20).map(move |y| Point2D::new(x as f64 + offset, y as f64))).collect()
+    }
+
+    #[test]
+    fn test_hausdorff_point_sets() {
+        let a = grid(0.0);
+        let b = grid(0.25);
+        // shifted grids: matching points are 0.25 apart in both directions
+        assert_eq!(hausdorff_distance(&a, &b), Some(0.25));
+
+        // one outlier dominates, in one direction only
+        let mut with_outlier = a.clone();
+        with_outlier.push(Point2D::new(10.0, 30.0));
+        assert_eq!(hausdorff_directed(&a, &with_outlier), Some(0.0));
+        assert_eq!(hausdorff_directed(&with_outlier, &a), Some(11.0));
+        assert_eq!(hausdorff_distance(&with_outlier, &a), Some(11.0));
+        assert_eq!(hausdorff_distance(&a, &[]), None);
+
+        // agrees with brute force
+        let brute = with_outlier.iter().map(|p| b.iter().map(|q| p.distance(q)).fold(f64::INFINITY, f64::min)).fold(0.0, f64::max);
+        assert_eq!(hausdorff_directed(&with_outlier, &b), Some(brute));
+    }
+
+    #[test]
+    fn test_polyline_hausdorff_sees_between_vertices() {
+        // the vertices alone put the zigzag's tip sqrt(8) from the flat line's ends,
+        // though the line passes 2 below it
+        let zigzag = Polyline2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(2.0, 2.0), Point2D::new(4.0, 0.0)]);
+        let flat = Polyline2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(4.0, 0.0)]);
+        assert_eq!(hausdorff_distance(zigzag.vertices(), flat.vertices()), Some(8f64.sqrt()));
+        let approximate = polyline_hausdorff_distance(&zigzag, &flat, 0.05).unwrap();
+        assert!((approximate - 2.0).abs() <= 0.025);
+
+        let single = Polyline2D::new(vec![Point2D::new(1.0, 1.0)]);
+        assert_eq!(polyline_hausdorff_distance(&single, &single, 1.0), Some(0.0));
+        assert_eq!(polyline_hausdorff_distance(&single, &Polyline2D::new(vec![]), 1.0), None);
+    }
+
+    #[test]
+    fn test_discrete_frechet_distance() {
+        let line = |points: &[(f64, f64)]| Polyline2D::new(points.iter().map(|&(x, y)| Point2D::new(x, y)).collect());
+        let a = line(&[(0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (3.0, 0.0)]);
+        // the same track sampled at other points: the leash spans the largest gap
+        let b = line(&[(0.0, 0.0), (1.5, 0.0), (3.0, 0.0)]);
+        assert_eq!(discrete_frechet_distance(&a, &b), Some(0.5));
+        assert_eq!(discrete_frechet_distance(&b, &a), Some(0.5));
+        // the same vertices with a detour: Hausdorff sees nothing, Fréchet the way back
+        let detour = line(&[(0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (1.0, 0.0), (2.0, 0.0), (3.0, 0.0)]);
+        assert_eq!(hausdorff_distance(a.vertices(), detour.vertices()), Some(0.0));
+        assert_eq!(discrete_frechet_distance(&a, &detour), Some(1.0));
+        assert_eq!(discrete_frechet_distance(&a, &line(&[])), None);
+        assert_eq!(discrete_frechet_distance(&line(&[(0.0, 0.0)]), &line(&[(3.0, 4.0), (0.0, 1.0)])), Some(5.0));
+    }
+
+    #[test]
+    fn test_frechet_within_matches_distance() {
+        let mut rng = XorShift::new(6);
+        let mut track = |n: usize| {
+            Polyline2D::new((0..n).map(|i| Point2D::new(i as f64 + rng.unit::<f64>(), rng.unit::<f64>() * 2.0)).collect())
+        };
+        for _ in 0..20 {
+            let (a, b) = (track(12), track(9));
+            let distance = discrete_frechet_distance(&a, &b).unwrap();
+            assert!(frechet_within(&a, &b, distance));
+            assert!(!frechet_within(&a, &b, distance * 0.999));
+        }
+        let point = Polyline2D::new(vec![Point2D::new(0.0, 0.0)]);
+        assert!(!frechet_within(&point, &Polyline2D::new(vec![]), 10.0));
+        assert!(frechet_within(&point, &point, 0.0));
+    }
+
+    #[test]
+    fn test_dtw_path_and_band() {
+        let a: Vec<Point2D<f64>> = (0..6).map(|i| Point2D::new(i as f64, 0.0)).collect();
+        // the same line walked at half speed for a while
+        let b: Vec<Point2D<f64>> = [0.0, 0.5, 1.0, 1.5, 2.0, 3.0, 4.0, 5.0].iter().map(|&x| Point2D::new(x, 0.0)).collect();
+        let warping = dtw(&a, &b, None).unwrap();
+        // the half steps each cost 0.5
+        assert_eq!(warping.distance, 1.0);
+        assert_eq!(warping.path.first(), Some(&(0, 0)));
+        assert_eq!(warping.path.last(), Some(&(5, 7)));
+        for pair in warping.path.windows(2) {
+            let ((i0, j0), (i1, j1)) = (pair[0], pair[1]);
+            assert!(i1 - i0 <= 1 && j1 - j0 <= 1 && (i1, j1) != (i0, j0));
+        }
+        let total: f64 = warping.path.iter().map(|&(i, j)| a[i].distance(&b[j])).sum();
+        assert_eq!(total, warping.distance);
+
+        // a band narrower than the length difference is widened to it
+        let banded = dtw(&a, &b, Some(0)).unwrap();
+        assert!(banded.path.iter().all(|&(i, j)| i.abs_diff(j) <= 2));
+        assert!(banded.distance >= warping.distance);
+        assert_eq!(dtw(&a, &b, Some(100)), Some(warping));
+        assert_eq!(dtw(&a, &[], None), None);
+    }
+}
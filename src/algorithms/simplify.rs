@@ -0,0 +1,120 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::line::Line2D;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Simplifies a planar polyline with the Douglas–Peucker algorithm.
+///
+/// # Arguments
+/// * `points` - Vertices of the path.
+/// * `tolerance` - Maximum distance of a dropped vertex from the simplified path.
+///
+/// # Returns
+/// * The retained vertices, always including the first and last point.
+///
+/// # Algorithm Explanation
+/// The vertex farthest from the segment between the first and last point is kept
+/// if it is more than `tolerance` away, and both halves are simplified the same
+/// way; otherwise every vertex in between is dropped. For `(lon, lat)` tracks use
+/// [`simplify_geodesic`](crate::geodesic::simplify_geodesic) instead, which
+/// measures the tolerance in meters on the sphere.
+///
+/// # Time Complexity
+/// **O(n log n)** on typical paths, **O(n²)** in the worst case.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::simplify::simplify_2d;
+/// use geomengine::point::Point2D;
+///
+/// let path = [Point2D::new(0.0, 0.0), Point2D::new(1.0, 0.1), Point2D::new(2.0, 0.0), Point2D::new(2.0, 2.0)];
+/// assert_eq!(simplify_2d(&path, 0.5), [path[0], path[2], path[3]]);
+/// ```
+pub fn simplify_2d<T: Float + Debug>(points: &[Point2D<T>], tolerance: T) -> Vec<Point2D<T>> {
+    let mut simplified = Vec::new();
+    simplify_2d_into(points, tolerance, &mut simplified);
+    simplified
+}
+
+/// [`simplify_2d`] writing into `simplified`, which is cleared first.
+pub fn simplify_2d_into<T: Float + Debug>(points: &[Point2D<T>], tolerance: T, simplified: &mut Vec<Point2D<T>>) {
+    simplified.clear();
+    if points.len() < 3 {
+        simplified.extend_from_slice(points);
+        return;
+    }
+
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+
+    // explicit stack instead of recursion so long paths cannot overflow it
+    let mut ranges = vec![(0, points.len() - 1)];
+    while let Some((first, last)) = ranges.pop() {
+        let chord = Line2D::new(points[first], points[last]);
+        let mut max_distance = T::zero();
+        let mut max_index = first;
+        for (i, point) in points.iter().enumerate().take(last).skip(first + 1) {
+            let distance = chord.closest_point_to(point).distance(point);
+            if distance > max_distance {
+                max_distance = distance;
+                max_index = i;
+            }
+        }
+        if max_distance > tolerance {
+            keep[max_index] = true;
+            ranges.push((first, max_index));
+            ranges.push((max_index, last));
+        }
+    }
+
+    simplified.extend(
+        points
+            .iter()
+            .zip(keep)
+            .filter_map(|(&point, kept)| if kept { Some(point) } else { None }),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simplify_2d_short_input_unchanged() {
+        let points = vec![Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0)];
+        assert_eq!(simplify_2d(&points, 10.0), points);
+    }
+
+    #[test]
+    fn test_simplify_2d_keeps_vertices_beyond_tolerance() {
+        let points = vec![
+            Point2D::new(0.0, 0.0),
+            Point2D::new(1.0, 0.4),
+            Point2D::new(2.0, 0.0),
+            Point2D::new(3.0, 1.5),
+            Point2D::new(4.0, 0.0),
+        ];
+        assert_eq!(simplify_2d(&points, 0.5), vec![points[0], points[2], points[3], points[4]]);
+        assert_eq!(simplify_2d(&points, 0.3), points);
+        assert_eq!(simplify_2d(&points, 2.0), vec![points[0], points[4]]);
+    }
+
+    #[test]
+    fn test_simplify_2d_closed_ring_keeps_far_side() {
+        // first and last point coincide, so the chord is a single point
+        let square = [(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0), (0.0, 0.0)].map(|(x, y)| Point2D::new(x, y));
+        let simplified = simplify_2d(&square, 0.1);
+        assert_eq!(simplified, square.to_vec());
+    }
+
+    #[test]
+    fn test_simplify_2d_into_clears_buffer() {
+        let points: Vec<_> = (0..=10).map(|i| Point2D::new(i as f64, 0.0)).collect();
+        let mut simplified = vec![Point2D::new(99.0, 99.0); 20];
+        simplify_2d_into(&points, 0.1, &mut simplified);
+        assert_eq!(simplified, vec![points[0], points[10]]);
+    }
+}
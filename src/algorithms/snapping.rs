@@ -0,0 +1,284 @@
+use num::Float;
+use core::cmp::Ordering;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::polygon::Polygon2D;
+use crate::shape::MapCoords;
+use super::planar_graph::merge_points;
+use alloc::vec::Vec;
+
+/// Snaps the boundaries of neighbouring polygons together.
+///
+/// # Arguments
+/// * `polygons` - Features that should share boundaries but were digitized
+///   independently, leaving micro-gaps and sliver overlaps between them.
+/// * `tolerance` - Largest distance that is still considered the same location.
+///
+/// # Returns
+/// * The cleaned polygons, one per input and in the same order. A polygon that
+///   collapses entirely (all of it thinner than `tolerance`) comes back with fewer
+///   than three vertices so callers can decide whether to drop it.
+///
+/// # Algorithm Explanation
+/// 1. Vertices of all polygons closer than `tolerance` are clustered and moved to
+///    the earliest vertex of their cluster, so earlier features win ties.
+/// 2. Every edge passing within `tolerance` of a vertex (of any polygon) gets that
+///    vertex inserted, bending it onto the neighbour's boundary.
+/// 3. Repeated vertices and the zero-width spikes left behind are removed.
+///
+/// After snapping, shared boundaries consist of the same vertices on both sides,
+/// so overlays and unions no longer produce slivers along them.
+pub fn snap_polygons_2d<T: Float + Debug>(polygons: &[Polygon2D<T>], tolerance: T) -> Vec<Polygon2D<T>> {
+    let all: Vec<Point2D<T>> = polygons.iter().flat_map(|p| p.vertices().iter().cloned()).collect();
+    let (representatives, ids) = merge_points(&all, tolerance);
+
+    let mut by_x = representatives.clone();
+    by_x.sort_by(|a, b| a.x.partial_cmp(&b.x).unwrap_or(Ordering::Equal));
+
+    let mut offset = 0;
+    polygons
+        .iter()
+        .map(|polygon| {
+            let snapped: Vec<Point2D<T>> = ids[offset..offset + polygon.len()]
+                .iter()
+                .map(|&id| representatives[id])
+                .collect();
+            offset += polygon.len();
+
+            let mut ring = Vec::with_capacity(snapped.len());
+            for i in 0..snapped.len() {
+                let (start, end) = (snapped[i], snapped[(i + 1) % snapped.len()]);
+                ring.push(start);
+                ring.extend(vertices_near_edge(&by_x, &start, &end, tolerance));
+            }
+            Polygon2D::new(remove_spikes(ring))
+        })
+        .collect()
+}
+
+/// Moves `point` to the nearest node of the grid with square cells of side
+/// `cell_size` and a node at `origin`.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::snapping::snap_to_grid;
+/// use geomengine::point::Point2D;
+///
+/// let origin = Point2D::new(0.5, 0.0);
+/// assert_eq!(snap_to_grid(Point2D::new(1.2, 1.9), 1.0, origin), Point2D::new(1.5, 2.0));
+/// assert_eq!(snap_to_grid(Point2D::new(-0.4, 0.2), 0.25, origin), Point2D::new(-0.5, 0.25));
+/// ```
+pub fn snap_to_grid<T: Float + Debug>(point: Point2D<T>, cell_size: T, origin: Point2D<T>) -> Point2D<T> {
+    let snap = |value: T, origin: T| origin + ((value - origin) / cell_size).round() * cell_size;
+    Point2D::new(snap(point.x, origin.x), snap(point.y, origin.y))
+}
+
+/// [`snap_to_grid`] applied to every coordinate of a geometry.
+///
+/// Vertices may end up on the same node, so a snapped ring can repeat
+/// vertices or degenerate; clean it with the geometry's builder if needed.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::snapping::snap_geometry_to_grid;
+/// use geomengine::line::Polyline2D;
+/// use geomengine::point::Point2D;
+///
+/// let digitized = Polyline2D::new(vec![Point2D::new(0.1, 0.05), Point2D::new(0.98, 0.02), Point2D::new(1.03, 2.1)]);
+/// let snapped = snap_geometry_to_grid(&digitized, 0.5, Point2D::new(0.0, 0.0));
+/// assert_eq!(snapped.vertices(), &[Point2D::new(0.0, 0.0), Point2D::new(1.0, 0.0), Point2D::new(1.0, 2.0)]);
+/// ```
+pub fn snap_geometry_to_grid<T: Float + Debug, G: MapCoords<T>>(geometry: &G, cell_size: T, origin: Point2D<T>) -> G {
+    geometry.map_coords(|p| snap_to_grid(p, cell_size, origin))
+}
+
+/// Snaps near-coincident points onto each other, e.g. vertices of hand-digitized
+/// input that should meet but miss by a little.
+///
+/// # Returns
+/// * One point per input, in the same order: points within `tolerance` in both
+///   x and y of each other, directly or through a chain of such points, are
+///   replaced by the earliest of them.
+///
+/// Both the per-axis test and the chaining differ from
+/// [`dedup_points`](super::point_algorithms::dedup_points), which only merges
+/// points within Euclidean distance `tolerance` of a kept point, so a chain of
+/// close points there splits into several.
+///
+/// # Time Complexity
+/// **O(n log n)** for sorting plus the pairs within `tolerance` in x.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::snapping::snap_points_together;
+/// use geomengine::point::Point2D;
+///
+/// let points = [Point2D::new(1.0, 1.0), Point2D::new(5.0, 5.0), Point2D::new(1.001, 0.999)];
+/// let snapped = snap_points_together(&points, 0.01);
+/// assert_eq!(snapped, [Point2D::new(1.0, 1.0), Point2D::new(5.0, 5.0), Point2D::new(1.0, 1.0)]);
+/// ```
+pub fn snap_points_together<T: Float + Debug>(points: &[Point2D<T>], tolerance: T) -> Vec<Point2D<T>> {
+    let (representatives, ids) = merge_points(points, tolerance);
+    ids.into_iter().map(|id| representatives[id]).collect()
+}
+
+/// Vertices lying within `tolerance` of the interior of edge `start`-`end`, ordered along it.
+fn vertices_near_edge<T: Float + Debug>(by_x: &[Point2D<T>], start: &Point2D<T>, end: &Point2D<T>, tolerance: T) -> Vec<Point2D<T>> {
+    let direction = *end - *start;
+    let length_sq = direction.dot_product(&direction);
+    if length_sq == T::zero() {
+        return Vec::new();
+    }
+    let length = length_sq.sqrt();
+    let (min_x, max_x) = (start.x.min(end.x) - tolerance, start.x.max(end.x) + tolerance);
+    let (min_y, max_y) = (start.y.min(end.y) - tolerance, start.y.max(end.y) + tolerance);
+
+    let first = by_x.partition_point(|p| p.x < min_x);
+    let mut hits: Vec<(T, Point2D<T>)> = by_x[first..]
+        .iter()
+        .take_while(|p| p.x <= max_x)
+        .filter(|p| p.y >= min_y && p.y <= max_y)
+        .filter_map(|&p| {
+            let t = (p - *start).dot_product(&direction) / length_sq;
+            let along = t * length;
+            let off = direction.cross_product(&(p - *start)).abs() / length;
+            if along > tolerance && along < length - tolerance && off <= tolerance {
+                Some((t, p))
+            } else {
+                None
+            }
+        })
+        .collect();
+    hits.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+    hits.into_iter().map(|(_, p)| p).collect()
+}
+
+/// Removes repeated vertices and back-and-forth spikes `a, b, a` from a ring.
+fn remove_spikes<T: Float + Debug>(ring: Vec<Point2D<T>>) -> Vec<Point2D<T>> {
+    let mut out: Vec<Point2D<T>> = Vec::with_capacity(ring.len());
+    for p in ring {
+        if out.last() == Some(&p) {
+            continue;
+        }
+        if out.len() >= 2 && out[out.len() - 2] == p {
+            out.pop();
+            continue;
+        }
+        out.push(p);
+    }
+    // the same rules across the seam between the last and first vertex
+    loop {
+        let n = out.len();
+        if n >= 2 && out[0] == out[n - 1] {
+            out.pop();
+        } else if n >= 3 && out[n - 2] == out[0] {
+            out.truncate(n - 1);
+            out.remove(0);
+        } else if n >= 3 && out[n - 1] == out[1] {
+            out.remove(0);
+            out.remove(0);
+        } else {
+            return out;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::union::union_all_2d;
+
+    fn rect(x1: f64, y1: f64, x2: f64, y2: f64) -> Polygon2D<f64> {
+        Polygon2D::new(vec![
+            Point2D::new(x1, y1),
+            Point2D::new(x2, y1),
+            Point2D::new(x2, y2),
+            Point2D::new(x1, y2),
+        ])
+    }
+
+    #[test]
+    fn test_snap_polygons_2d_closes_micro_gap() {
+        let parcels = [rect(0.0, 0.0, 1.0, 1.0), rect(1.0004, 0.0, 2.0, 1.0)];
+        assert_eq!(union_all_2d(&parcels).len(), 2);
+
+        let snapped = snap_polygons_2d(&parcels, 0.001);
+        assert_eq!(snapped[0], parcels[0]);
+        assert_eq!(snapped[1], rect(1.0, 0.0, 2.0, 1.0));
+        assert_eq!(union_all_2d(&snapped).len(), 1);
+    }
+
+    #[test]
+    fn test_snap_polygons_2d_inserts_vertices_into_neighbour_edges() {
+        let base = rect(0.0, 0.0, 2.0, 1.0);
+        let top = rect(0.5, 1.0003, 1.5, 2.0);
+        let snapped = snap_polygons_2d(&[base, top.clone()], 0.001);
+
+        assert_eq!(snapped[1], top);
+        assert_eq!(snapped[0].len(), 6);
+        assert!(snapped[0].vertices().contains(&Point2D::new(1.5, 1.0003)));
+        assert!(snapped[0].vertices().contains(&Point2D::new(0.5, 1.0003)));
+
+        let union = union_all_2d(&snapped);
+        assert_eq!(union.len(), 1);
+        assert!(union[0].1.is_empty());
+    }
+
+    #[test]
+    fn test_snap_polygons_2d_collapses_sliver() {
+        let sliver = Polygon2D::new(vec![
+            Point2D::new(0.0, 0.0),
+            Point2D::new(5.0, 0.0005),
+            Point2D::new(10.0, 0.0),
+            Point2D::new(5.0, -0.0005),
+        ]);
+        let neighbour = Polygon2D::new(vec![
+            Point2D::new(0.0, 0.0),
+            Point2D::new(5.0, 0.0),
+            Point2D::new(10.0, 0.0),
+            Point2D::new(5.0, 5.0),
+        ]);
+        let snapped = snap_polygons_2d(&[neighbour, sliver], 0.001);
+        assert!(snapped[1].len() < 3);
+    }
+
+    #[test]
+    fn test_remove_spikes() {
+        let a = Point2D::new(0.0, 0.0);
+        let b = Point2D::new(1.0, 0.0);
+        let c = Point2D::new(1.0, 1.0);
+        let d = Point2D::new(2.0, 2.0);
+        assert_eq!(remove_spikes(vec![a, b, b, c]), vec![a, b, c]);
+        assert_eq!(remove_spikes(vec![a, b, c, d, c]), vec![a, b, c]);
+        assert_eq!(remove_spikes(vec![d, a, b, c, a]), vec![b, c, a]);
+    }
+
+    #[test]
+    fn test_snap_to_grid_geometries() {
+        let origin = Point2D::new(10.0, -3.0);
+        for &(x, y) in &[(12.4, 1.1), (-7.7, -3.2), (10.0, -3.0)] {
+            let snapped = snap_to_grid(Point2D::new(x, y), 2.0, origin);
+            // on the grid, and within half a cell in each direction
+            assert_eq!(((snapped.x - origin.x) % 2.0, (snapped.y - origin.y) % 2.0), (0.0, 0.0));
+            assert!((snapped.x - x).abs() <= 1.0 && (snapped.y - y).abs() <= 1.0);
+        }
+        let square = rect(0.1, -0.1, 1.9, 2.2);
+        assert_eq!(snap_geometry_to_grid(&square, 1.0, Point2D::new(0.0, 0.0)), rect(0.0, 0.0, 2.0, 2.0));
+    }
+
+    #[test]
+    fn test_snap_points_together_chains_clusters() {
+        let points = [
+            Point2D::new(0.0, 0.0),
+            Point2D::new(0.8, 0.0),
+            Point2D::new(1.6, 0.1),
+            Point2D::new(5.0, 5.0),
+            Point2D::new(5.5, 5.5),
+        ];
+        let snapped = snap_points_together(&points, 1.0);
+        // the first three chain together although the ends are 1.6 apart
+        assert_eq!(snapped[..3], [points[0]; 3]);
+        assert_eq!(snapped[3..], [points[3]; 2]);
+        assert_eq!(snap_points_together(&points, 0.1), points);
+    }
+}
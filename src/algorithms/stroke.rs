@@ -0,0 +1,428 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::{Point2D, Vector2D};
+use crate::line::Polyline2D;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// How the open ends of a stroke are finished.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineCap {
+    /// Cut square at the end points.
+    #[default]
+    Butt,
+    /// A half disk around each end point.
+    Round,
+    /// Cut square half the width beyond each end point.
+    Square,
+}
+
+/// How the outside of a corner of a stroke is filled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineJoin {
+    /// The outer edges extended until they meet, falling back to
+    /// [`Bevel`](Self::Bevel) past the [`miter_limit`](StrokeStyle::miter_limit).
+    #[default]
+    Miter,
+    /// An arc around the corner.
+    Round,
+    /// The outer corners of the two segments joined straight.
+    Bevel,
+}
+
+/// Width, caps and joins of a stroke, with the SVG defaults.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StrokeStyle<T: Float + Debug> {
+    pub width: T,
+    pub cap: LineCap,
+    pub join: LineJoin,
+    /// Longest miter allowed, as a multiple of the width; sharper corners are beveled.
+    pub miter_limit: T,
+}
+
+impl<T: Float + Debug> StrokeStyle<T> {
+    /// Butt caps and miter joins with a limit of 4.
+    pub fn new(width: T) -> Self {
+        StrokeStyle { width, cap: LineCap::Butt, join: LineJoin::Miter, miter_limit: T::from(4).unwrap() }
+    }
+}
+
+/// Tessellates a thick line along `polyline` into triangles.
+///
+/// # Arguments
+/// * `polyline` - The center line. If it ends where it starts, it is stroked as
+///   a closed loop with a join there instead of caps.
+/// * `style` - Width, cap and join styles.
+///
+/// # Returns
+/// * Vertices, and counterclockwise triangles indexing them. Triangles may
+///   overlap, e.g. on the inside of corners, so the mesh is meant to be filled
+///   in one color rather than blended per triangle.
+///
+/// # Algorithm Explanation
+/// - Every segment becomes a rectangle of the stroke's width around it.
+/// - At each corner, the wedge left open on the outside between the two
+///   rectangles is filled as the join style says: by the triangle to the miter
+///   point and its mirror image, by one bevel triangle, or by a fan of triangles
+///   approximating the arc, one per 22.5° at most. Round caps are fans as well.
+///
+/// # Time Complexity
+/// **O(n)** for `n` vertices.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::stroke::{stroke_2d, LineCap, StrokeStyle};
+/// use geomengine::line::Polyline2D;
+/// use geomengine::point::Point2D;
+///
+/// let path = Polyline2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(4.0, 0.0), Point2D::new(4.0, 4.0)]);
+/// let mut style = StrokeStyle::new(2.0);
+/// style.cap = LineCap::Square;
+/// let (vertices, triangles) = stroke_2d(&path, &style);
+///
+/// let area: f64 = triangles.iter().map(|&[a, b, c]| (vertices[b] - vertices[a]).cross_product(&(vertices[c] - vertices[a])) / 2.0).sum();
+/// // two 2x4 rectangles, two 2x1 caps and a 1x1 miter, with the rectangles overlapping in 1x1
+/// assert_eq!(area, 8.0 + 8.0 + 2.0 + 2.0 + 1.0);
+/// ```
+pub fn stroke_2d<T: Float + Debug>(polyline: &Polyline2D<T>, style: &StrokeStyle<T>) -> (Vec<Point2D<T>>, Vec<[usize; 3]>) {
+    let mut points: Vec<Point2D<T>> = polyline.vertices().to_vec();
+    points.dedup();
+    let closed = points.len() > 2 && points.first() == points.last();
+    if closed {
+        points.pop();
+    }
+    let half = style.width / (T::one() + T::one());
+    let mut mesh = Mesh { vertices: Vec::new(), triangles: Vec::new() };
+    if half <= T::zero() || points.is_empty() {
+        return (mesh.vertices, mesh.triangles);
+    }
+    let unit_x = Vector2D::new(T::one(), T::zero());
+    if points.len() == 1 {
+        // a dot: both caps back to back
+        mesh.cap(points[0], unit_x * -T::one(), half, style.cap);
+        mesh.cap(points[0], unit_x, half, style.cap);
+        return (mesh.vertices, mesh.triangles);
+    }
+
+    let segments = if closed { points.len() } else { points.len() - 1 };
+    let direction = |i: usize| (points[(i + 1) % points.len()] - points[i]).normalize().unwrap();
+    for i in 0..segments {
+        let (a, b) = (points[i], points[(i + 1) % points.len()]);
+        let offset = left(direction(i)) * half;
+        mesh.quad([a - offset, b - offset, b + offset, a + offset]);
+    }
+    let corners = if closed { 0..points.len() } else { 1..points.len() - 1 };
+    for i in corners {
+        let before = direction((i + segments - 1) % segments);
+        mesh.join(points[i], before, direction(i), half, style);
+    }
+    if !closed {
+        mesh.cap(points[0], direction(0) * -T::one(), half, style.cap);
+        mesh.cap(points[points.len() - 1], direction(segments - 1), half, style.cap);
+    }
+    (mesh.vertices, mesh.triangles)
+}
+
+/// Splits `path` into the dashes of a dash pattern, measured by arc length.
+///
+/// # Arguments
+/// * `path` - The line to dash.
+/// * `pattern` - Alternating dash and gap lengths, starting with a dash. An odd
+///   number of lengths is repeated once to make it even, as in SVG's
+///   `stroke-dasharray`, so `[2, 1, 1]` means dash 2, gap 1, dash 1, gap 2.
+/// * `offset` - How far into the pattern the path starts. Negative offsets and
+///   offsets beyond the pattern's length wrap around; NaN and infinite offsets
+///   count as zero.
+///
+/// # Returns
+/// * The dashes in order along the path, each keeping the path's vertices it
+///   passes. A dash of zero length has two equal points, which
+///   [`stroke_2d`] draws as a dot with round or square caps. If the pattern is
+///   empty, has a negative length, sums to zero or is too fine to advance
+///   along the path at its floating point resolution, the path is one solid dash.
+///
+/// # Time Complexity
+/// **O(n + d)** for `n` vertices and `d` dashes.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::stroke::dash;
+/// use geomengine::line::Polyline2D;
+/// use geomengine::point::Point2D;
+///
+/// let path = Polyline2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(5.0, 0.0), Point2D::new(5.0, 5.0)]);
+/// let dashes = dash(&path, &[3.0, 1.0], 1.0);
+/// assert_eq!(dashes.len(), 3);
+/// // the first dash is cut short by the offset, the second turns the corner
+/// assert_eq!(dashes[0].vertices(), &[Point2D::new(0.0, 0.0), Point2D::new(2.0, 0.0)]);
+/// assert_eq!(dashes[1].vertices(), &[Point2D::new(3.0, 0.0), Point2D::new(5.0, 0.0), Point2D::new(5.0, 1.0)]);
+/// assert_eq!(dashes[2].vertices(), &[Point2D::new(5.0, 2.0), Point2D::new(5.0, 5.0)]);
+/// ```
+pub fn dash<T: Float + Debug>(path: &Polyline2D<T>, pattern: &[T], offset: T) -> Vec<Polyline2D<T>> {
+    let pattern: Vec<T> = if pattern.len() % 2 == 1 { pattern.iter().chain(pattern).copied().collect() } else { pattern.to_vec() };
+    let total = pattern.iter().fold(T::zero(), |sum, &length| sum + length);
+    if path.is_empty() || total <= T::zero() || total.is_nan() || pattern.iter().any(|&length| length < T::zero()) {
+        return if path.is_empty() { Vec::new() } else { vec![path.clone()] };
+    }
+
+    // a pattern within rounding error of the longest segment cannot be laid along it
+    let longest = path.edges().fold(T::zero(), |longest, segment| longest.max(segment.length()));
+    if total <= longest * T::epsilon() {
+        return vec![path.clone()];
+    }
+
+    let mut phase = if offset.is_finite() { offset % total } else { T::zero() };
+    if phase < T::zero() {
+        phase = phase + total;
+    }
+    let mut index = 0;
+    // a zero-length dash right at the start still counts
+    while phase > T::zero() && phase >= pattern[index] {
+        phase = phase - pattern[index];
+        index = (index + 1) % pattern.len();
+    }
+    let mut remaining = pattern[index] - phase;
+    let mut current = (index % 2 == 0).then(|| vec![path.vertices()[0]]);
+
+    let mut dashes = Vec::new();
+    for segment in path.edges() {
+        let length = segment.length();
+        if length == T::zero() {
+            continue;
+        }
+        let mut travelled = T::zero();
+        // pattern entries since `travelled` last grew; a whole cycle of them means no progress
+        let mut stalled = 0;
+        loop {
+            let left = length - travelled;
+            if remaining > left {
+                remaining = remaining - left;
+                if let Some(points) = current.as_mut().filter(|points| points.last() != Some(&segment.p2)) {
+                    points.push(segment.p2);
+                }
+                break;
+            }
+            if travelled + remaining > travelled {
+                stalled = 0;
+            } else {
+                stalled += 1;
+                if stalled > pattern.len() {
+                    return vec![path.clone()];
+                }
+            }
+            travelled = travelled + remaining;
+            let point = segment.p1 + (segment.p2 - segment.p1) * (travelled / length);
+            match current.take() {
+                Some(mut points) => {
+                    points.push(point);
+                    dashes.push(Polyline2D::new(points));
+                }
+                None => current = Some(vec![point]),
+            }
+            index = (index + 1) % pattern.len();
+            remaining = pattern[index];
+        }
+    }
+    dashes.extend(current.filter(|points| points.len() > 1).map(Polyline2D::new));
+    dashes
+}
+
+/// The unit normal to the left of unit vector `d`.
+fn left<T: Float + Debug>(d: Vector2D<T>) -> Vector2D<T> {
+    d.perpendicular()
+}
+
+struct Mesh<T: Float + Debug> {
+    vertices: Vec<Point2D<T>>,
+    triangles: Vec<[usize; 3]>,
+}
+
+impl<T: Float + Debug> Mesh<T> {
+    fn triangle(&mut self, a: Point2D<T>, b: Point2D<T>, c: Point2D<T>) {
+        let area = (b - a).cross_product(&(c - a));
+        if area == T::zero() {
+            return;
+        }
+        let base = self.vertices.len();
+        self.vertices.extend([a, b, c]);
+        self.triangles.push(if area > T::zero() { [base, base + 1, base + 2] } else { [base, base + 2, base + 1] });
+    }
+
+    fn quad(&mut self, [a, b, c, d]: [Point2D<T>; 4]) {
+        self.triangle(a, b, c);
+        self.triangle(a, c, d);
+    }
+
+    /// Fills the outside of the corner at `point` where direction `before` turns into `after`.
+    fn join(&mut self, point: Point2D<T>, before: Vector2D<T>, after: Vector2D<T>, half: T, style: &StrokeStyle<T>) {
+        let turn = before.cross_product(&after);
+        if turn == T::zero() && before.dot_product(&after) > T::zero() {
+            return;
+        }
+        // the outside is on the right of a left turn, and the arc around it runs counterclockwise
+        let side = if turn > T::zero() { -T::one() } else { T::one() };
+        let (from, to) = (left(before) * side, left(after) * side);
+        match style.join {
+            LineJoin::Round => self.arc(point, from, to, half, -side),
+            LineJoin::Bevel => self.triangle(point, point + from * half, point + to * half),
+            LineJoin::Miter => {
+                let bisector = from + to;
+                // 1 / cos of half the angle between the normals
+                let ratio = (T::one() + T::one()) / bisector.dot_product(&bisector).sqrt();
+                if !ratio.is_finite() || ratio > style.miter_limit {
+                    self.triangle(point, point + from * half, point + to * half);
+                } else {
+                    let miter = point + bisector.normalize().unwrap() * (half * ratio);
+                    self.triangle(point, point + from * half, miter);
+                    self.triangle(point, miter, point + to * half);
+                }
+            }
+        }
+    }
+
+    /// Finishes a stroke ending at `point` while heading in unit direction `outward`.
+    fn cap(&mut self, point: Point2D<T>, outward: Vector2D<T>, half: T, cap: LineCap) {
+        let normal = left(outward) * half;
+        match cap {
+            LineCap::Butt => {}
+            LineCap::Square => {
+                let ahead = outward * half;
+                self.quad([point - normal, point + ahead - normal, point + ahead + normal, point + normal]);
+            }
+            // clockwise from the right side round the front to the left one
+            LineCap::Round => self.arc(point, left(outward) * -T::one(), left(outward), half, T::one()),
+        }
+    }
+
+    /// Fan around `center` from unit vector `from` to `to`, turning counterclockwise
+    /// if `side` is positive and clockwise otherwise.
+    fn arc(&mut self, center: Point2D<T>, from: Vector2D<T>, to: Vector2D<T>, radius: T, side: T) {
+        let mut angle = from.cross_product(&to).atan2(from.dot_product(&to));
+        if angle * side < T::zero() || (angle == T::zero() && from != to) {
+            angle = angle + side * T::from(core::f64::consts::TAU).unwrap();
+        }
+        let max_step = T::from(core::f64::consts::FRAC_PI_8).unwrap();
+        let steps = (angle.abs() / max_step).ceil().max(T::one());
+        let (sin, cos) = (angle / steps).sin_cos();
+        let mut current = from;
+        for _ in 0..steps.to_usize().unwrap() {
+            let next = Vector2D::new(current.x * cos - current.y * sin, current.x * sin + current.y * cos);
+            self.triangle(center, center + current * radius, center + next * radius);
+            current = next;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use core::f64::consts::PI;
+
+    fn area(mesh: &(Vec<Point2D<f64>>, Vec<[usize; 3]>)) -> f64 {
+        let (vertices, triangles) = mesh;
+        triangles
+            .iter()
+            .map(|&[a, b, c]| {
+                let doubled = (vertices[b] - vertices[a]).cross_product(&(vertices[c] - vertices[a]));
+                assert!(doubled > 0.0, "triangles run counterclockwise");
+                doubled / 2.0
+            })
+            .sum()
+    }
+
+    fn path(points: &[(f64, f64)]) -> Polyline2D<f64> {
+        Polyline2D::new(points.iter().map(|&(x, y)| Point2D::new(x, y)).collect())
+    }
+
+    fn style(cap: LineCap, join: LineJoin) -> StrokeStyle<f64> {
+        StrokeStyle { cap, join, ..StrokeStyle::new(2.0) }
+    }
+
+    #[test]
+    fn test_stroke_caps() {
+        let segment = path(&[(0.0, 0.0), (3.0, 4.0)]);
+        assert_relative_eq!(area(&stroke_2d(&segment, &style(LineCap::Butt, LineJoin::Miter))), 10.0, epsilon = 1e-12);
+        assert_relative_eq!(area(&stroke_2d(&segment, &style(LineCap::Square, LineJoin::Miter))), 14.0, epsilon = 1e-12);
+        // 8 triangles per round cap, each inscribed in the unit circle
+        let round = area(&stroke_2d(&segment, &style(LineCap::Round, LineJoin::Miter)));
+        assert_relative_eq!(round, 10.0 + 16.0 * (PI / 8.0).sin() / 2.0, epsilon = 1e-9);
+
+        // a single point with round caps is a 16-gon inscribed in the disk
+        let dot = stroke_2d(&path(&[(1.0, 1.0), (1.0, 1.0)]), &style(LineCap::Round, LineJoin::Miter));
+        assert_relative_eq!(area(&dot), 16.0 * (PI / 8.0).sin() / 2.0, epsilon = 1e-9);
+        assert!(stroke_2d(&path(&[(1.0, 1.0)]), &style(LineCap::Butt, LineJoin::Miter)).1.is_empty());
+    }
+
+    #[test]
+    fn test_stroke_joins() {
+        // a right angle: the rectangles overlap in a 1x1 square on the inside
+        let corner = path(&[(0.0, 0.0), (2.0, 0.0), (2.0, 2.0)]);
+        assert_eq!(area(&stroke_2d(&corner, &style(LineCap::Butt, LineJoin::Miter))), 9.0);
+        assert_eq!(area(&stroke_2d(&corner, &style(LineCap::Butt, LineJoin::Bevel))), 8.5);
+        let round = area(&stroke_2d(&corner, &style(LineCap::Butt, LineJoin::Round)));
+        assert_relative_eq!(round, 8.0 + 4.0 * (PI / 8.0).sin() / 2.0, epsilon = 1e-9);
+
+        // a hairpin turn is too sharp for the miter limit
+        let hairpin = path(&[(0.0, 0.0), (4.0, 0.0), (0.0, 0.5)]);
+        let miter = stroke_2d(&hairpin, &style(LineCap::Butt, LineJoin::Miter));
+        let bevel = stroke_2d(&hairpin, &style(LineCap::Butt, LineJoin::Bevel));
+        assert_relative_eq!(area(&miter), area(&bevel), epsilon = 1e-12);
+
+        // a closed square loop gets four mitered corners and no caps
+        let loop_ = path(&[(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0), (0.0, 0.0)]);
+        assert_eq!(area(&stroke_2d(&loop_, &style(LineCap::Square, LineJoin::Miter))), 4.0 * 8.0 + 4.0);
+    }
+
+    #[test]
+    fn test_dash_pattern_and_offset() {
+        let line = path(&[(0.0, 0.0), (10.0, 0.0)]);
+        let spans = |dashes: Vec<Polyline2D<f64>>| -> Vec<(f64, f64)> {
+            dashes.iter().map(|d| (d.vertices()[0].x, d.vertices()[d.len() - 1].x)).collect()
+        };
+        assert_eq!(spans(dash(&line, &[2.0, 1.0], 0.0)), [(0.0, 2.0), (3.0, 5.0), (6.0, 8.0), (9.0, 10.0)]);
+        // offsets wrap around the pattern in both directions
+        assert_eq!(spans(dash(&line, &[2.0, 1.0], 2.5)), [(0.5, 2.5), (3.5, 5.5), (6.5, 8.5), (9.5, 10.0)]);
+        assert_eq!(spans(dash(&line, &[2.0, 1.0], -0.5)), spans(dash(&line, &[2.0, 1.0], 2.5)));
+        // an odd pattern repeats: dash 4, gap 1, dash 1, gap 4
+        assert_eq!(spans(dash(&line, &[4.0, 1.0, 1.0], 0.0)), [(0.0, 4.0), (5.0, 6.0)]);
+        // dots every 2 units
+        let dots = dash(&line, &[0.0, 2.0], 0.0);
+        assert_eq!(spans(dots.clone()), [(0.0, 0.0), (2.0, 2.0), (4.0, 4.0), (6.0, 6.0), (8.0, 8.0), (10.0, 10.0)]);
+        assert!(dots.iter().all(|d| d.len() == 2));
+
+        // degenerate patterns leave the path solid
+        assert_eq!(dash(&line, &[], 0.0), core::slice::from_ref(&line));
+        assert_eq!(dash(&line, &[0.0, 0.0], 0.0), core::slice::from_ref(&line));
+        assert_eq!(dash(&line, &[2.0, -1.0], 0.0), core::slice::from_ref(&line));
+    }
+
+    #[test]
+    fn test_dash_terminates_on_degenerate_offsets_and_patterns() {
+        let line = path(&[(0.0, 0.0), (10.0, 0.0)]);
+        let plain = dash(&line, &[2.0, 1.0], 0.0);
+        assert_eq!(dash(&line, &[2.0, 1.0], f64::NAN), plain);
+        assert_eq!(dash(&line, &[2.0, 1.0], f64::INFINITY), plain);
+        assert_eq!(dash(&line, &[2.0, 1.0], f64::NEG_INFINITY), plain);
+        // far finer than the resolution of distances along the segment
+        assert_eq!(dash(&line, &[1e-16, 1e-16], 0.0), core::slice::from_ref(&line));
+        let long = path(&[(0.0, 0.0), (1e20, 0.0)]);
+        assert_eq!(dash(&long, &[1.0, 1.0], 0.5), core::slice::from_ref(&long));
+    }
+
+    #[test]
+    fn test_dash_lengths_along_polyline() {
+        let zigzag = path(&[(0.0, 0.0), (3.0, 4.0), (6.0, 0.0), (6.0, 0.0), (9.0, 4.0)]);
+        let dashes = dash(&zigzag, &[4.0, 2.0], 2.0);
+        let lengths: Vec<f64> = dashes.iter().map(|d| d.length()).collect();
+        // 15 units long with corners at 5 and 10, starting 2 into the first dash
+        assert_eq!(lengths.len(), 3);
+        for (length, expected) in lengths.iter().zip([2.0, 4.0, 4.0]) {
+            assert_relative_eq!(*length, expected, epsilon = 1e-12);
+        }
+        assert_eq!(dashes[1].len(), 3, "the second dash turns the first corner");
+        // the third starts right at the repeated corner without repeating it
+        assert_eq!(dashes[2].vertices()[0], Point2D::new(6.0, 0.0));
+        assert_eq!(dashes[2].len(), 2);
+    }
+}
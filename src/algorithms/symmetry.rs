@@ -0,0 +1,209 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::polygon::Polygon2D;
+use crate::spatial::KdTree;
+use crate::transform::Transform2D;
+use alloc::vec::Vec;
+
+/// The symmetries of a point set or polygon, as found by [`point_set_symmetries`]
+/// or [`polygon_symmetries`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Symmetries<T: Float + Debug> {
+    /// The fixed point of every symmetry: the mean of the points.
+    pub center: Point2D<T>,
+    /// How many rotations, the identity included, map the shape onto itself:
+    /// 1 if it has no rotational symmetry, 4 for a square.
+    pub rotation_order: usize,
+    /// Angles of the mirror axes through `center`, in radians from the x axis, in `[0, π)`.
+    pub reflection_axes: Vec<T>,
+    /// Every symmetry as a transform: the rotations by increasing angle,
+    /// starting with the identity, then the reflections in the order of `reflection_axes`.
+    pub transforms: Vec<Transform2D<T>>,
+}
+
+/// Finds the rotations and reflections mapping `points` onto themselves, up to `tolerance`.
+///
+/// # Returns
+/// * The symmetries, or `None` if there are no points or they all coincide,
+///   as then every rotation and reflection would do.
+///
+/// # Algorithm Explanation
+/// - A symmetry of a finite set keeps its mean fixed and maps the point
+///   furthest from it to another point equally far. Each such point therefore
+///   gives one candidate rotation and one candidate reflection, with the axis
+///   halfway between the two.
+/// - A candidate counts if every point lands within `tolerance` of a point,
+///   which is checked with a [`KdTree`].
+///
+/// # Time Complexity
+/// **O(n² log n)** in the worst case, when all points are equally far from the center.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::symmetry::point_set_symmetries;
+/// use geomengine::point::Point2D;
+///
+/// // a rectangle's corners: half turns, and mirrors along both axes
+/// let corners = [Point2D::new(0.0, 0.0), Point2D::new(4.0, 0.0), Point2D::new(4.0, 2.0), Point2D::new(0.0, 2.0)];
+/// let symmetries = point_set_symmetries(&corners, 1e-9).unwrap();
+/// assert_eq!(symmetries.center, Point2D::new(2.0, 1.0));
+/// assert_eq!(symmetries.rotation_order, 2);
+/// assert_eq!(symmetries.reflection_axes.len(), 2);
+/// assert_eq!(symmetries.transforms.len(), 4);
+/// ```
+pub fn point_set_symmetries<T: Float + Debug>(points: &[Point2D<T>], tolerance: T) -> Option<Symmetries<T>> {
+    let tree = KdTree::new(points);
+    find_symmetries(points, tolerance, |transform| {
+        points.iter().all(|p| tree.nearest(&transform.transform_point(p)).is_some_and(|(_, d)| d <= tolerance))
+    })
+}
+
+/// Finds the rotations and reflections mapping `polygon` onto itself, up to `tolerance`.
+///
+/// Like [`point_set_symmetries`] on the vertices, except that a symmetry must
+/// also map every edge onto an edge, so rings visiting the same vertices in a
+/// different order are told apart.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::symmetry::polygon_symmetries;
+/// use geomengine::point::Point2D;
+/// use geomengine::polygon::Polygon2D;
+///
+/// // an L-shape is mirrored in its diagonal and nothing else
+/// let l_shape = Polygon2D::new(vec![
+///     Point2D::new(0.0, 0.0),
+///     Point2D::new(2.0, 0.0),
+///     Point2D::new(2.0, 1.0),
+///     Point2D::new(1.0, 1.0),
+///     Point2D::new(1.0, 2.0),
+///     Point2D::new(0.0, 2.0),
+/// ]);
+/// let symmetries = polygon_symmetries(&l_shape, 1e-9).unwrap();
+/// assert_eq!(symmetries.rotation_order, 1);
+/// assert_eq!(symmetries.reflection_axes.len(), 1);
+/// assert!((symmetries.reflection_axes[0] - std::f64::consts::FRAC_PI_4).abs() < 1e-9);
+/// ```
+pub fn polygon_symmetries<T: Float + Debug>(polygon: &Polygon2D<T>, tolerance: T) -> Option<Symmetries<T>> {
+    let vertices = polygon.vertices();
+    let n = vertices.len();
+    let tree = KdTree::new(vertices);
+    find_symmetries(vertices, tolerance, |transform| {
+        let mut images = Vec::with_capacity(n);
+        for p in vertices {
+            match tree.nearest(&transform.transform_point(p)) {
+                Some((index, d)) if d <= tolerance => images.push(index),
+                _ => return false,
+            }
+        }
+        // neighbours stay neighbours, one step along the ring forwards or, for mirror
+        // images and half turns of self-crossing rings, backwards
+        [1, n - 1].into_iter().any(|step| (0..n).all(|i| images[(i + 1) % n] == (images[i] + step) % n))
+    })
+}
+
+fn find_symmetries<T: Float + Debug>(
+    points: &[Point2D<T>],
+    tolerance: T,
+    is_symmetry: impl Fn(&Transform2D<T>) -> bool,
+) -> Option<Symmetries<T>> {
+    let count = T::from(points.len())?;
+    let center = points.iter().fold(Point2D::new(T::zero(), T::zero()), |sum, &p| sum + p) / count;
+    let (far, radius) = points
+        .iter()
+        .map(|p| (*p, p.distance(&center)))
+        .fold((center, T::zero()), |best, candidate| if candidate.1 > best.1 { candidate } else { best });
+    if radius <= tolerance {
+        return None;
+    }
+
+    let angle_of = |p: Point2D<T>| (p.y - center.y).atan2(p.x - center.x);
+    let (tau, pi) = (T::from(core::f64::consts::TAU).unwrap(), T::from(core::f64::consts::PI).unwrap());
+    let to_center = Transform2D::translation(-center.x, -center.y);
+    let from_center = Transform2D::translation(center.x, center.y);
+    let about_center = |linear: Transform2D<T>| to_center.then(&linear).then(&from_center);
+
+    let mut rotations: Vec<(T, Transform2D<T>)> = Vec::new();
+    let mut reflections: Vec<(T, Transform2D<T>)> = Vec::new();
+    for &target in points.iter().filter(|p| (p.distance(&center) - radius).abs() <= tolerance) {
+        let mut turn = angle_of(target) - angle_of(far);
+        if turn < T::zero() {
+            turn = turn + tau;
+        }
+        let (sin, cos) = turn.sin_cos();
+        let rotation = about_center(Transform2D::new(cos, -sin, sin, cos, T::zero(), T::zero()));
+        if is_symmetry(&rotation) {
+            // the far point itself gives the identity, however close to a full turn rounding puts it
+            let turn = if far == target { T::zero() } else { turn };
+            rotations.push((turn, rotation));
+        }
+
+        let mut axis = (angle_of(target) + angle_of(far)) / (T::one() + T::one());
+        axis = axis - (axis / pi).floor() * pi;
+        let (sin2, cos2) = (axis + axis).sin_cos();
+        let reflection = about_center(Transform2D::new(cos2, sin2, sin2, -cos2, T::zero(), T::zero()));
+        if is_symmetry(&reflection) {
+            reflections.push((axis, reflection));
+        }
+    }
+    rotations.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    reflections.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    Some(Symmetries {
+        center,
+        rotation_order: rotations.len(),
+        reflection_axes: reflections.iter().map(|r| r.0).collect(),
+        transforms: rotations.into_iter().chain(reflections).map(|r| r.1).collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use core::f64::consts::PI;
+
+    fn regular(n: usize, radius: f64, center: Point2D<f64>) -> Vec<Point2D<f64>> {
+        (0..n).map(|i| {
+            let angle = 0.3 + i as f64 * 2.0 * PI / n as f64;
+            center + Point2D::new(angle.cos(), angle.sin()) * radius
+        }).collect()
+    }
+
+    #[test]
+    fn test_regular_polygons_and_tolerance() {
+        for n in [3, 4, 7] {
+            let ring = Polygon2D::new(regular(n, 2.0, Point2D::new(5.0, -1.0)));
+            let symmetries = polygon_symmetries(&ring, 1e-9).unwrap();
+            assert_eq!(symmetries.rotation_order, n);
+            assert_eq!(symmetries.reflection_axes.len(), n);
+            assert_relative_eq!(symmetries.center.x, 5.0, epsilon = 1e-12);
+            // every transform maps the first vertex onto some vertex
+            for transform in &symmetries.transforms {
+                let image = transform.transform_point(&ring.vertices()[0]);
+                assert!(ring.vertices().iter().any(|v| v.distance(&image) < 1e-9));
+            }
+            // the first is the identity
+            assert!(ring.vertices().iter().all(|v| symmetries.transforms[0].transform_point(v).distance(v) < 1e-9));
+        }
+
+        // a slightly dented square is only symmetric within a loose tolerance
+        let mut dented = regular(4, 1.0, Point2D::new(0.0, 0.0));
+        dented[0] = dented[0] * 1.001;
+        assert_eq!(point_set_symmetries(&dented, 1e-6).unwrap().rotation_order, 1);
+        assert_eq!(point_set_symmetries(&dented, 1e-2).unwrap().rotation_order, 4);
+        assert_eq!(point_set_symmetries(&[Point2D::new(1.0, 1.0); 3], 1e-9), None);
+        assert_eq!(point_set_symmetries::<f64>(&[], 1e-9), None);
+    }
+
+    #[test]
+    fn test_polygon_ring_order_matters() {
+        // the corners of a square visited as a bow tie: the set has all 8
+        // symmetries, but the ring only keeps those that map edges to edges
+        let corners = [Point2D::new(0.0, 0.0), Point2D::new(1.0, 0.0), Point2D::new(0.0, 1.0), Point2D::new(1.0, 1.0)];
+        assert_eq!(point_set_symmetries(&corners, 1e-9).unwrap().transforms.len(), 8);
+        let bow_tie = polygon_symmetries(&Polygon2D::new(corners.to_vec()), 1e-9).unwrap();
+        assert_eq!(bow_tie.rotation_order, 2);
+        assert_eq!(bow_tie.reflection_axes, [0.0, PI / 2.0]);
+    }
+}
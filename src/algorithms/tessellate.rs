@@ -0,0 +1,163 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::polygon::Polygon2D;
+use super::triangulate::triangulate_2d;
+use alloc::vec::Vec;
+
+/// Interleaved vertex data and triangle indices, laid out for upload as a
+/// vertex buffer and an index buffer (`wgpu::PrimitiveTopology::TriangleList`,
+/// `GL_TRIANGLES` with `GL_UNSIGNED_INT`).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VertexBuffers {
+    /// `stride` floats per vertex: `x`, `y`, then the vertex's attributes.
+    pub vertices: Vec<f32>,
+    /// Three vertex indices per counterclockwise triangle.
+    pub indices: Vec<u32>,
+    pub stride: usize,
+}
+
+impl VertexBuffers {
+    pub fn vertex_count(&self) -> usize {
+        self.vertices.len() / self.stride.max(1)
+    }
+    pub fn triangle_count(&self) -> usize {
+        self.indices.len() / 3
+    }
+}
+
+/// Triangulates polygons with holes into [`VertexBuffers`].
+///
+/// Each polygon is triangulated with [`triangulate_2d`]; its vertices are
+/// appended in the same order, exterior first, then each hole. Every vertex
+/// can carry a fixed number of extra floats, such as texture coordinates or a
+/// color, filled in by [`add_polygon_with`](Self::add_polygon_with).
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::tessellate::Tessellator;
+/// use geomengine::point::Point2D;
+/// use geomengine::polygon::Polygon2D;
+///
+/// let square = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(2.0, 0.0), Point2D::new(2.0, 2.0), Point2D::new(0.0, 2.0)]);
+///
+/// // positions plus texture coordinates spanning the square
+/// let mut tessellator = Tessellator::new(2);
+/// tessellator.add_polygon_with(&square, &[], |p, uv| uv.copy_from_slice(&[p.x as f32 / 2.0, p.y as f32 / 2.0]));
+/// let buffers = tessellator.build();
+/// assert_eq!((buffers.stride, buffers.vertex_count(), buffers.triangle_count()), (4, 4, 2));
+/// assert_eq!(&buffers.vertices[8..12], &[2.0, 2.0, 1.0, 1.0]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Tessellator {
+    buffers: VertexBuffers,
+}
+
+impl Default for Tessellator {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl Tessellator {
+    /// A tessellator for vertices of `x`, `y` and `attributes` more floats.
+    pub fn new(attributes: usize) -> Self {
+        Tessellator { buffers: VertexBuffers { stride: 2 + attributes, ..VertexBuffers::default() } }
+    }
+
+    /// Adds a polygon with its attributes set to zero.
+    pub fn add_polygon<T: Float + Debug>(&mut self, exterior: &Polygon2D<T>, holes: &[Polygon2D<T>]) -> &mut Self {
+        self.add_polygon_with(exterior, holes, |_, _| {})
+    }
+
+    /// Adds a polygon, calling `attributes` with each vertex and its zeroed
+    /// attribute slots to fill in.
+    pub fn add_polygon_with<T: Float + Debug>(
+        &mut self,
+        exterior: &Polygon2D<T>,
+        holes: &[Polygon2D<T>],
+        mut attributes: impl FnMut(Point2D<T>, &mut [f32]),
+    ) -> &mut Self {
+        let buffers = &mut self.buffers;
+        let base = buffers.vertex_count() as u32;
+        buffers.indices.extend(triangulate_2d(exterior, holes).into_iter().flatten().map(|i| base + i as u32));
+        for &p in exterior.vertices().iter().chain(holes.iter().flat_map(|h| h.vertices())) {
+            let start = buffers.vertices.len();
+            buffers.vertices.extend([to_f32(p.x), to_f32(p.y)]);
+            buffers.vertices.resize(start + buffers.stride, 0.0);
+            attributes(p, &mut buffers.vertices[start + 2..]);
+        }
+        self
+    }
+
+    pub fn buffers(&self) -> &VertexBuffers {
+        &self.buffers
+    }
+
+    /// The buffers of everything added so far.
+    pub fn build(&self) -> VertexBuffers {
+        self.buffers.clone()
+    }
+}
+
+fn to_f32<T: Float>(value: T) -> f32 {
+    value.to_f32().unwrap_or(f32::NAN)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn polygon(points: &[(f64, f64)]) -> Polygon2D<f64> {
+        Polygon2D::new(points.iter().map(|&(x, y)| Point2D::new(x, y)).collect())
+    }
+
+    fn covered_area(buffers: &VertexBuffers) -> f32 {
+        let at = |i: u32| {
+            let start = i as usize * buffers.stride;
+            (buffers.vertices[start], buffers.vertices[start + 1])
+        };
+        buffers
+            .indices
+            .chunks_exact(3)
+            .map(|t| {
+                let (a, b, c) = (at(t[0]), at(t[1]), at(t[2]));
+                ((b.0 - a.0) * (c.1 - a.1) - (c.0 - a.0) * (b.1 - a.1)) / 2.0
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_tessellate_several_polygons_with_holes() {
+        let frame = polygon(&[(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)]);
+        let window = polygon(&[(1.0, 1.0), (3.0, 1.0), (3.0, 3.0), (1.0, 3.0)]);
+        let triangle = polygon(&[(10.0, 0.0), (12.0, 0.0), (10.0, 2.0)]);
+
+        let mut tessellator = Tessellator::new(0);
+        tessellator.add_polygon(&frame, &[window]).add_polygon(&triangle, &[]);
+        let buffers = tessellator.build();
+        assert_eq!((buffers.stride, buffers.vertex_count(), buffers.triangle_count()), (2, 11, 9));
+        // the triangle's indices point past the frame's eight vertices
+        assert_eq!(buffers.indices[24..].iter().copied().min(), Some(8));
+        assert_eq!(&buffers.vertices[16..18], &[10.0, 0.0]);
+        assert_eq!(covered_area(&buffers), 12.0 + 2.0);
+    }
+
+    #[test]
+    fn test_tessellate_attributes_per_polygon() {
+        let colors = [[1.0, 0.0, 0.0], [0.0, 0.0, 1.0]];
+        let mut tessellator = Tessellator::new(3);
+        for (i, x) in [0.0, 5.0].into_iter().enumerate() {
+            let square = polygon(&[(x, 0.0), (x + 1.0, 0.0), (x + 1.0, 1.0), (x, 1.0)]);
+            tessellator.add_polygon_with(&square, &[], |_, rgb| rgb.copy_from_slice(&colors[i]));
+        }
+        let buffers = tessellator.buffers();
+        assert_eq!(buffers.vertices.len(), 8 * 5);
+        assert_eq!(&buffers.vertices[2..5], &colors[0]);
+        assert_eq!(&buffers.vertices[7 * 5 + 2..], &colors[1]);
+
+        let mut plain = Tessellator::new(1);
+        plain.add_polygon(&polygon(&[(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)]), &[]);
+        assert_eq!(plain.build().vertices, [0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0]);
+    }
+}
@@ -0,0 +1,180 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::line::Line2D;
+use super::planar_graph::merge_points;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Node/edge graph of a segment network, as built by [`topology_graph_2d`].
+///
+/// Nodes are the merged segment endpoints and every edge joins two distinct
+/// nodes. Parallel edges are kept, since two roads between the same junctions
+/// are both real.
+#[derive(Debug, Clone)]
+pub struct TopologyGraph<T: Float + Debug> {
+    nodes: Vec<Point2D<T>>,
+    edges: Vec<(usize, usize)>,
+    /// Edges incident to each node.
+    incident: Vec<Vec<usize>>,
+}
+
+/// Builds the topology of a segment network, e.g. roads or rivers.
+///
+/// # Arguments
+/// * `segments` - The network's segments. Only shared endpoints connect them:
+///   segments crossing without a common endpoint (bridges, overpasses) stay
+///   unconnected. Node the input first with
+///   [`node_segments_2d`](super::noding::node_segments_2d) to join crossings.
+/// * `tolerance` - Endpoints closer than this are merged into one node.
+///
+/// # Returns
+/// * The graph; segments whose endpoints merge into the same node are dropped.
+///
+/// # Time Complexity
+/// **O(n log n)** for well-spread endpoints, **O(n²)** if most of them share an x-range of width `tolerance`.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::topology::topology_graph_2d;
+/// use geomengine::line::Line2D;
+/// use geomengine::point::Point2D;
+///
+/// // a 'T' junction digitized with a small gap
+/// let graph = topology_graph_2d(&[
+///     Line2D::new(Point2D::new(0.0, 0.0), Point2D::new(1.0, 0.0)),
+///     Line2D::new(Point2D::new(1.0, 0.0), Point2D::new(2.0, 0.0)),
+///     Line2D::new(Point2D::new(1.0, 0.001), Point2D::new(1.0, 1.0)),
+/// ], 0.01);
+/// assert_eq!(graph.node_count(), 4);
+/// assert_eq!(graph.degree(1), 3);
+/// assert_eq!(graph.dangling_edges(), [0, 1, 2]);
+/// assert_eq!(graph.connected_components().len(), 1);
+/// ```
+pub fn topology_graph_2d<T: Float + Debug>(segments: &[Line2D<T>], tolerance: T) -> TopologyGraph<T> {
+    let endpoints: Vec<Point2D<T>> = segments.iter().flat_map(|s| [s.p1, s.p2]).collect();
+    let (nodes, ids) = merge_points(&endpoints, tolerance);
+
+    let edges: Vec<(usize, usize)> = ids.chunks(2).map(|pair| (pair[0], pair[1])).filter(|(u, v)| u != v).collect();
+    let mut incident = vec![Vec::new(); nodes.len()];
+    for (e, &(u, v)) in edges.iter().enumerate() {
+        incident[u].push(e);
+        incident[v].push(e);
+    }
+    TopologyGraph { nodes, edges, incident }
+}
+
+impl<T: Float + Debug> TopologyGraph<T> {
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+    pub fn nodes(&self) -> &[Point2D<T>] {
+        &self.nodes
+    }
+    /// Node pairs of the edges, in input order.
+    pub fn edges(&self) -> &[(usize, usize)] {
+        &self.edges
+    }
+    pub fn edge_segment(&self, edge: usize) -> Line2D<T> {
+        let (u, v) = self.edges[edge];
+        Line2D::new(self.nodes[u], self.nodes[v])
+    }
+
+    /// Number of edges meeting at `node`.
+    pub fn degree(&self, node: usize) -> usize {
+        self.incident[node].len()
+    }
+    /// Edges meeting at `node`.
+    pub fn incident_edges(&self, node: usize) -> &[usize] {
+        &self.incident[node]
+    }
+    /// Nodes joined to `node` by an edge, once per edge.
+    pub fn neighbors(&self, node: usize) -> impl Iterator<Item = usize> + '_ {
+        self.incident[node].iter().map(move |&e| {
+            let (u, v) = self.edges[e];
+            if u == node { v } else { u }
+        })
+    }
+
+    /// Nodes with exactly one edge: dead ends, sources and sinks.
+    pub fn dangling_nodes(&self) -> Vec<usize> {
+        (0..self.nodes.len()).filter(|&n| self.degree(n) == 1).collect()
+    }
+    /// Edges with an endpoint of degree one, in index order.
+    pub fn dangling_edges(&self) -> Vec<usize> {
+        (0..self.edges.len())
+            .filter(|&e| self.degree(self.edges[e].0) == 1 || self.degree(self.edges[e].1) == 1)
+            .collect()
+    }
+
+    /// The nodes of each connected component, components ordered by their smallest node.
+    pub fn connected_components(&self) -> Vec<Vec<usize>> {
+        let mut component = vec![usize::MAX; self.nodes.len()];
+        let mut components = Vec::new();
+        for start in 0..self.nodes.len() {
+            if component[start] != usize::MAX {
+                continue;
+            }
+            let mut members = vec![start];
+            component[start] = components.len();
+            let mut k = 0;
+            while k < members.len() {
+                let node = members[k];
+                k += 1;
+                for neighbor in self.neighbors(node) {
+                    if component[neighbor] == usize::MAX {
+                        component[neighbor] = components.len();
+                        members.push(neighbor);
+                    }
+                }
+            }
+            members.sort_unstable();
+            components.push(members);
+        }
+        components
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(x1: f64, y1: f64, x2: f64, y2: f64) -> Line2D<f64> {
+        Line2D::new(Point2D::new(x1, y1), Point2D::new(x2, y2))
+    }
+
+    #[test]
+    fn test_topology_graph_components_and_degrees() {
+        let graph = topology_graph_2d(
+            &[
+                // a closed triangle
+                segment(0.0, 0.0, 1.0, 0.0),
+                segment(1.0, 0.0, 0.0, 1.0),
+                segment(0.0, 1.0, 0.0, 0.0),
+                // a separate two-segment river
+                segment(5.0, 5.0, 6.0, 5.0),
+                segment(6.0, 5.0, 7.0, 6.0),
+                // collapses to a single node and is dropped
+                segment(9.0, 9.0, 9.0, 9.0),
+            ],
+            1e-9,
+        );
+        assert_eq!(graph.edge_count(), 5);
+        assert_eq!(graph.connected_components(), [vec![0, 1, 2], vec![3, 4, 5], vec![6]]);
+        assert_eq!(graph.degree(0), 2);
+        assert_eq!(graph.degree(6), 0);
+        assert_eq!(graph.dangling_nodes(), [3, 5]);
+        assert_eq!(graph.dangling_edges(), [3, 4]);
+        assert_eq!(graph.neighbors(4).collect::<Vec<_>>(), [3, 5]);
+    }
+
+    #[test]
+    fn test_topology_graph_keeps_crossings_unconnected() {
+        let graph = topology_graph_2d(&[segment(0.0, 0.0, 2.0, 2.0), segment(0.0, 2.0, 2.0, 0.0)], 1e-9);
+        assert_eq!(graph.connected_components().len(), 2);
+        assert_eq!(graph.edge_segment(1), segment(0.0, 2.0, 2.0, 0.0));
+    }
+}
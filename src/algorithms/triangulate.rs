@@ -0,0 +1,211 @@
+use num::Float;
+use core::cmp::Ordering;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::line::Line2D;
+use crate::polygon::{Polygon2D, ring};
+use crate::shape::Intersects;
+use alloc::vec::Vec;
+
+/// Triangulates a polygon with holes by ear clipping.
+///
+/// # Arguments
+/// * `exterior` - The outer ring, in either orientation.
+/// * `holes` - Rings inside the exterior that must stay uncovered, in either orientation.
+///
+/// # Returns
+/// * Counterclockwise triangles as indices into the exterior's vertices followed
+///   by the vertices of each hole in turn. A simple polygon with `n` vertices and
+///   `h` holes yields `n + 2h - 2` triangles (counting hole vertices in `n`).
+///
+/// # Algorithm Explanation
+/// - Every hole is joined to the outer ring by a bridge: a pair of coincident
+///   edges from its rightmost vertex to the nearest ring vertex that can see it.
+///   Holes are bridged from right to left, each to the ring grown so far.
+/// - The resulting single ring is cut down by repeatedly clipping an ear: a
+///   convex vertex whose triangle with its two neighbours holds no other vertex.
+///
+/// # Time Complexity
+/// **O(n³)** in the worst case, where `n` is the total number of vertices:
+/// finding each ear may test every vertex against the whole ring. Bridging adds
+/// **O(h n²)** for `h` holes. Convex and nearly convex rings find ears at once
+/// and take **O(n²)**.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::triangulate::triangulate_2d;
+/// use geomengine::point::Point2D;
+/// use geomengine::polygon::Polygon2D;
+///
+/// let square = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(4.0, 0.0), Point2D::new(4.0, 4.0), Point2D::new(0.0, 4.0)]);
+/// let hole = Polygon2D::new(vec![Point2D::new(1.0, 1.0), Point2D::new(3.0, 1.0), Point2D::new(3.0, 3.0), Point2D::new(1.0, 3.0)]);
+/// assert_eq!(triangulate_2d(&square, &[]).len(), 2);
+/// assert_eq!(triangulate_2d(&square, &[hole]).len(), 8);
+/// ```
+pub fn triangulate_2d<T: Float + Debug>(exterior: &Polygon2D<T>, holes: &[Polygon2D<T>]) -> Vec<[usize; 3]> {
+    let points: Vec<Point2D<T>> = exterior.vertices().iter().chain(holes.iter().flat_map(|h| h.vertices())).copied().collect();
+
+    let mut outer = oriented(0, exterior.vertices(), true);
+    let mut hole_rings = Vec::with_capacity(holes.len());
+    let mut offset = exterior.len();
+    for hole in holes {
+        if hole.len() >= 3 {
+            hole_rings.push(oriented(offset, hole.vertices(), false));
+        }
+        offset += hole.len();
+    }
+
+    // bridge the hole reaching furthest right first, so later bridges never cross it
+    let rightmost = |ring: &Vec<usize>| (0..ring.len()).max_by(|&a, &b| points[ring[a]].cmp_xy(&points[ring[b]])).unwrap();
+    hole_rings.sort_by(|a, b| points[b[rightmost(b)]].cmp_xy(&points[a[rightmost(a)]]));
+    for h in 0..hole_rings.len() {
+        let mut hole = hole_rings[h].clone();
+        let start = rightmost(&hole);
+        hole.rotate_left(start);
+        if let Some(k) = bridge_vertex(&points, &outer, &hole_rings[h + 1..], hole[0]) {
+            let mut merged = Vec::with_capacity(outer.len() + hole.len() + 2);
+            merged.extend_from_slice(&outer[..=k]);
+            merged.extend_from_slice(&hole);
+            merged.push(hole[0]);
+            merged.push(outer[k]);
+            merged.extend_from_slice(&outer[k + 1..]);
+            outer = merged;
+        }
+    }
+
+    clip_ears(&points, outer)
+}
+
+/// Indices `offset..offset + ring.len()`, reversed if needed to run counterclockwise (or clockwise).
+fn oriented<T: Float + Debug>(offset: usize, ring: &[Point2D<T>], counterclockwise: bool) -> Vec<usize> {
+    let mut indices: Vec<usize> = (offset..offset + ring.len()).collect();
+    if (ring::signed_area(ring) > T::zero()) != counterclockwise {
+        indices.reverse();
+    }
+    indices
+}
+
+/// Position in `outer` of the nearest vertex that `points[m]` can be joined to
+/// without crossing `outer` or any of the `pending` holes.
+fn bridge_vertex<T: Float + Debug>(points: &[Point2D<T>], outer: &[usize], pending: &[Vec<usize>], m: usize) -> Option<usize> {
+    let target = points[m];
+    let n = outer.len();
+    let mut candidates: Vec<usize> = (0..n).collect();
+    candidates.sort_by(|&a, &b| {
+        points[outer[a]].distance(&target).partial_cmp(&points[outer[b]].distance(&target)).unwrap_or(Ordering::Equal)
+    });
+
+    let edges = |ring: &[usize]| {
+        let len = ring.len();
+        (0..len).map(move |i| (ring[i], ring[(i + 1) % len])).collect::<Vec<_>>()
+    };
+    let mut obstacles = edges(outer);
+    for hole in pending {
+        obstacles.extend(edges(hole));
+    }
+
+    candidates.into_iter().find(|&k| {
+        let (previous, vertex, next) = (points[outer[(k + n - 1) % n]], points[outer[k]], points[outer[(k + 1) % n]]);
+        if !in_cone(previous, vertex, next, target) {
+            return false;
+        }
+        let bridge = Line2D::new(vertex, target);
+        !obstacles.iter().any(|&(a, b)| {
+            let (a, b) = (points[a], points[b]);
+            // edges sharing an end with the bridge meet it there anyway
+            a != vertex && a != target && b != vertex && b != target && bridge.intersects(&Line2D::new(a, b))
+        })
+    })
+}
+
+/// Does `target` lie inside the interior angle at `vertex` of a counterclockwise ring?
+fn in_cone<T: Float + Debug>(previous: Point2D<T>, vertex: Point2D<T>, next: Point2D<T>, target: Point2D<T>) -> bool {
+    let (to_previous, to_next, to_target) = (previous - vertex, next - vertex, target - vertex);
+    if (vertex - previous).cross_product(&to_next) > T::zero() {
+        to_next.cross_product(&to_target) > T::zero() && to_target.cross_product(&to_previous) > T::zero()
+    } else {
+        // reflex: inside unless in the exterior wedge from `previous` round to `next`
+        !(to_previous.cross_product(&to_target) >= T::zero() && to_target.cross_product(&to_next) >= T::zero())
+    }
+}
+
+fn clip_ears<T: Float + Debug>(points: &[Point2D<T>], mut ring: Vec<usize>) -> Vec<[usize; 3]> {
+    let mut triangles = Vec::with_capacity(ring.len().saturating_sub(2));
+    let corner = |ring: &[usize], i: usize| {
+        let n = ring.len();
+        (ring[(i + n - 1) % n], ring[i], ring[(i + 1) % n])
+    };
+    let turn = |(a, b, c): (usize, usize, usize)| (points[b] - points[a]).cross_product(&(points[c] - points[b]));
+
+    while ring.len() > 3 {
+        let is_ear = |i: usize| {
+            let (a, b, c) = corner(&ring, i);
+            turn((a, b, c)) > T::zero()
+                && !ring.iter().any(|&v| {
+                    let p = points[v];
+                    p != points[a] && p != points[b] && p != points[c] && in_triangle(points[a], points[b], points[c], p)
+                })
+        };
+        if let Some(i) = (0..ring.len()).find(|&i| is_ear(i)) {
+            let (a, b, c) = corner(&ring, i);
+            triangles.push([a, b, c]);
+            ring.remove(i);
+        } else if let Some(i) = (0..ring.len()).find(|&i| turn(corner(&ring, i)) == T::zero()) {
+            // a flat vertex adds no area
+            ring.remove(i);
+        } else if let Some(i) = (0..ring.len()).find(|&i| turn(corner(&ring, i)) > T::zero()) {
+            // no clean ear is left in invalid input; clip a convex vertex anyway
+            let (a, b, c) = corner(&ring, i);
+            triangles.push([a, b, c]);
+            ring.remove(i);
+        } else {
+            break;
+        }
+    }
+    if ring.len() == 3 && turn((ring[0], ring[1], ring[2])) > T::zero() {
+        triangles.push([ring[0], ring[1], ring[2]]);
+    }
+    triangles
+}
+
+/// Inclusive point-in-triangle test for a counterclockwise triangle.
+fn in_triangle<T: Float + Debug>(a: Point2D<T>, b: Point2D<T>, c: Point2D<T>, p: Point2D<T>) -> bool {
+    (b - a).cross_product(&(p - a)) >= T::zero() && (c - b).cross_product(&(p - b)) >= T::zero() && (a - c).cross_product(&(p - c)) >= T::zero()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn polygon(points: &[(f64, f64)]) -> Polygon2D<f64> {
+        Polygon2D::new(points.iter().map(|&(x, y)| Point2D::new(x, y)).collect())
+    }
+
+    fn covered_area(points: &[Point2D<f64>], triangles: &[[usize; 3]]) -> f64 {
+        triangles.iter().map(|t| ring::signed_area(&[points[t[0]], points[t[1]], points[t[2]]])).sum()
+    }
+
+    #[test]
+    fn test_triangulate_concave_polygon() {
+        // a comb with three teeth, given clockwise
+        let comb = polygon(&[(0.0, 0.0), (0.0, 3.0), (1.0, 3.0), (1.0, 1.0), (2.0, 1.0), (2.0, 3.0), (3.0, 3.0), (3.0, 1.0), (4.0, 1.0), (4.0, 3.0), (5.0, 3.0), (5.0, 0.0)]);
+        let triangles = triangulate_2d(&comb, &[]);
+        assert_eq!(triangles.len(), 10);
+        assert_eq!(covered_area(comb.vertices(), &triangles), 11.0);
+    }
+
+    #[test]
+    fn test_triangulate_with_several_holes() {
+        let outer = polygon(&[(0.0, 0.0), (10.0, 0.0), (10.0, 4.0), (0.0, 4.0)]);
+        let holes = [
+            polygon(&[(1.0, 1.0), (3.0, 1.0), (3.0, 3.0), (1.0, 3.0)]),
+            polygon(&[(6.0, 1.0), (6.0, 3.0), (8.0, 3.0)]),
+            polygon(&[(4.0, 1.0), (5.0, 1.0), (4.5, 3.0)]),
+        ];
+        let triangles = triangulate_2d(&outer, &holes);
+        let points: Vec<_> = outer.vertices().iter().chain(holes.iter().flat_map(|h| h.vertices())).copied().collect();
+        assert_eq!(triangles.len(), 4 + 10 + 2 * 3 - 2);
+        assert_eq!(covered_area(&points, &triangles), 40.0 - 4.0 - 2.0 - 1.0);
+        assert!(triangles.iter().all(|t| ring::signed_area(&[points[t[0]], points[t[1]], points[t[2]]]) > 0.0));
+    }
+}
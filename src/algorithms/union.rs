@@ -0,0 +1,157 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::line::Line2D;
+use crate::polygon::{Polygon2D, ring};
+use crate::shape::Aabb2D;
+use super::noding::{node_segments, snap_tolerance};
+use super::planar_graph::{PlanarGraph, ShellWithHoles, assemble_rings};
+use crate::spatial::str_groups;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Number of neighbouring inputs merged together at every level of the cascade.
+const GROUP_SIZE: usize = 16;
+
+/// Unions a collection of polygons into the smallest set of disjoint polygons.
+///
+/// # Arguments
+/// * `polygons` - Polygons to merge; they may overlap, touch or be disjoint.
+///
+/// # Returns
+/// * One `(exterior, holes)` pair per connected area of the union, exteriors
+///   counterclockwise and holes clockwise.
+///
+/// # Why cascaded?
+/// Adding polygons one by one to a growing result re-nodes the whole result every
+/// time, which is quadratic in the number of inputs. Instead, the inputs are packed
+/// into groups of spatial neighbours with sort-tile-recursive (STR) tiling, as when
+/// bulk-loading an R-tree. Every group is unioned on its own, the results are
+/// grouped again, and so on up to a single root. Shared boundaries between
+/// neighbours dissolve early, so each merge only sees small inputs.
+pub fn union_all_2d<T: Float + Debug>(polygons: &[Polygon2D<T>]) -> Vec<(Polygon2D<T>, Vec<Polygon2D<T>>)> {
+    let mut items: Vec<Vec<ShellWithHoles<T>>> = polygons
+        .iter()
+        .filter(|p| p.len() >= 3)
+        .map(|p| {
+            let mut shell = p.vertices().to_vec();
+            if ring::signed_area(&shell) < T::zero() {
+                shell.reverse();
+            }
+            vec![(shell, Vec::new())]
+        })
+        .collect();
+
+    while items.len() > 1 {
+        let bounds: Vec<_> = items.iter().map(|item| item_bounds(item)).collect();
+        items = str_groups(&bounds, GROUP_SIZE)
+            .into_iter()
+            .map(|group| dissolve(group.iter().map(|&i| &items[i])))
+            .collect();
+    }
+
+    items
+        .pop()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(shell, holes)| (Polygon2D::new(shell), holes.into_iter().map(Polygon2D::new).collect()))
+        .collect()
+}
+
+/// Merges a handful of already-unioned items into one.
+fn dissolve<'a, T: Float + Debug + 'a>(items: impl Iterator<Item = &'a Vec<ShellWithHoles<T>>>) -> Vec<ShellWithHoles<T>> {
+    let parts: Vec<&ShellWithHoles<T>> = items.flatten().collect();
+    if parts.len() == 1 {
+        return vec![parts[0].clone()];
+    }
+
+    let rings = || parts.iter().flat_map(|(shell, holes)| core::iter::once(shell).chain(holes.iter()));
+    let segments: Vec<Line2D<T>> = rings()
+        .flat_map(|r| (0..r.len()).map(move |i| Line2D::new(r[i], r[(i + 1) % r.len()])))
+        .collect();
+    let tolerance = snap_tolerance(rings().flatten().cloned());
+    let graph = PlanarGraph::new(&node_segments(&segments, tolerance), tolerance);
+    let faces = graph.faces();
+
+    let covered: Vec<bool> = (0..faces.shells.len())
+        .map(|face| {
+            let point = graph.interior_point(&faces, face);
+            parts.iter().any(|(shell, holes)| {
+                ring::contains_point(shell, &point) && !holes.iter().any(|h| ring::contains_point(h, &point))
+            })
+        })
+        .collect();
+    assemble_rings(graph.region_rings(&faces, &covered))
+}
+
+fn item_bounds<T: Float + Debug>(item: &[ShellWithHoles<T>]) -> Aabb2D<T> {
+    let nothing = Aabb2D::new(Point2D::new(T::infinity(), T::infinity()), Point2D::new(T::neg_infinity(), T::neg_infinity()));
+    item.iter().flat_map(|(shell, _)| shell.iter()).fold(nothing, |bounds, p| bounds.expand_to_include(p))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(x: f64, y: f64) -> Polygon2D<f64> {
+        Polygon2D::new(vec![
+            Point2D::new(x, y),
+            Point2D::new(x + 1.0, y),
+            Point2D::new(x + 1.0, y + 1.0),
+            Point2D::new(x, y + 1.0),
+        ])
+    }
+
+    fn area(polygon: &(Polygon2D<f64>, Vec<Polygon2D<f64>>)) -> f64 {
+        ring::signed_area(polygon.0.vertices())
+            + polygon.1.iter().map(|h| ring::signed_area(h.vertices())).sum::<f64>()
+    }
+
+    #[test]
+    fn test_union_all_2d_empty() {
+        assert!(union_all_2d::<f64>(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_union_all_2d_grid_of_parcels() {
+        let parcels: Vec<_> = (0..10)
+            .flat_map(|i| (0..10).map(move |j| square(i as f64, j as f64)))
+            .collect();
+        let union = union_all_2d(&parcels);
+
+        assert_eq!(union.len(), 1);
+        assert_eq!(union[0].0.len(), 4);
+        assert!(union[0].1.is_empty());
+        assert_eq!(area(&union[0]), 100.0);
+    }
+
+    #[test]
+    fn test_union_all_2d_disjoint_stay_separate() {
+        let union = union_all_2d(&[square(0.0, 0.0), square(5.0, 5.0)]);
+        assert_eq!(union.len(), 2);
+    }
+
+    #[test]
+    fn test_union_all_2d_ring_of_squares_has_hole() {
+        let parcels: Vec<_> = (0..3)
+            .flat_map(|i| (0..3).map(move |j| (i, j)))
+            .filter(|&(i, j)| (i, j) != (1, 1))
+            .map(|(i, j)| square(i as f64, j as f64))
+            .collect();
+        let union = union_all_2d(&parcels);
+
+        assert_eq!(union.len(), 1);
+        assert_eq!(union[0].1.len(), 1);
+        assert_eq!(area(&union[0]), 8.0);
+    }
+
+    #[test]
+    fn test_union_all_2d_overlapping_and_clockwise() {
+        let mut clockwise = square(0.5, 0.0).vertices().to_vec();
+        clockwise.reverse();
+        let union = union_all_2d(&[square(0.0, 0.0), Polygon2D::new(clockwise)]);
+
+        assert_eq!(union.len(), 1);
+        assert_eq!(area(&union[0]), 1.5);
+    }
+}
@@ -0,0 +1,196 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::line::Polyline2D;
+use crate::shape::{Geometry2D, ValidityError};
+use super::self_intersection::{SegmentChain, find_self_intersections};
+use alloc::vec::Vec;
+
+/// A problem found in one geometry of a collection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Position of the geometry in the collection.
+    pub index: usize,
+    /// Position of the offending member inside a `Multi*` geometry.
+    pub member: Option<usize>,
+    pub error: ValidityError,
+}
+
+/// Outcome of [`validate_collection`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationReport {
+    /// Number of geometries checked.
+    pub checked: usize,
+    /// Every problem found, ordered by geometry and member.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl ValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.diagnostics.is_empty()
+    }
+    /// Indices of the geometries with at least one problem, in increasing order.
+    pub fn invalid_indices(&self) -> Vec<usize> {
+        let mut indices: Vec<usize> = self.diagnostics.iter().map(|d| d.index).collect();
+        indices.dedup();
+        indices
+    }
+}
+
+/// Checks every geometry of a collection and lists what is wrong with each.
+///
+/// # Checks
+/// - Coordinates must be finite: [`NonFiniteCoordinate`](ValidityError::NonFiniteCoordinate)
+///   names the first bad vertex (a circle's center is vertex 0, its radius counts too).
+/// - Consecutive vertices must differ, the last and first of a polygon included:
+///   [`DuplicateVertex`](ValidityError::DuplicateVertex).
+/// - Lines and polylines need two distinct vertices and polygons three
+///   ([`TooFewVertices`](ValidityError::TooFewVertices)); polygons must not be
+///   collinear and circles need a positive radius ([`ZeroArea`](ValidityError::ZeroArea)).
+/// - Polylines and polygons must not touch or cross themselves:
+///   [`SelfIntersection`](ValidityError::SelfIntersection) for every pair of
+///   segments, as found by [`find_self_intersections`]. Skipped for geometries
+///   already failing one of the checks above.
+///
+/// With the `parallel` feature, `algorithms::parallel::par_validate_collection`
+/// spreads the work over all cores.
+///
+/// # Time Complexity
+/// **O(n log n + k)** per polyline or polygon with `n` vertices, see
+/// [`find_self_intersections`].
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::validation::validate_collection;
+/// use geomengine::point::Point2D;
+/// use geomengine::polygon::Polygon2D;
+/// use geomengine::shape::{Geometry2D, ValidityError};
+///
+/// let square = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(1.0, 0.0), Point2D::new(1.0, 1.0), Point2D::new(0.0, 1.0)]);
+/// let bow_tie = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0), Point2D::new(1.0, 0.0), Point2D::new(0.0, 1.0)]);
+/// let geometries: Vec<Geometry2D<f64>> = vec![square.into(), Point2D::new(f64::NAN, 0.0).into(), bow_tie.into()];
+///
+/// let report = validate_collection(&geometries);
+/// assert_eq!(report.invalid_indices(), vec![1, 2]);
+/// assert_eq!(report.diagnostics[1].error, ValidityError::SelfIntersection { first: 0, second: 2 });
+/// ```
+pub fn validate_collection<T: Float + Debug>(geometries: &[Geometry2D<T>]) -> ValidationReport {
+    let mut diagnostics = Vec::new();
+    for (index, geometry) in geometries.iter().enumerate() {
+        let mut report = |member: Option<usize>, errors: Vec<ValidityError>| {
+            diagnostics.extend(errors.into_iter().map(|error| Diagnostic { index, member, error }));
+        };
+        match geometry {
+            Geometry2D::Point(point) => report(None, check_point(point)),
+            Geometry2D::Line(line) => report(None, check_chain(&[line.p1, line.p2], &Polyline2D::new(Vec::from([line.p1, line.p2])), false)),
+            Geometry2D::Polyline(polyline) => report(None, check_chain(polyline.vertices(), polyline, false)),
+            Geometry2D::Polygon(polygon) => report(None, check_chain(polygon.vertices(), polygon, true)),
+            Geometry2D::Circle(circle) => {
+                let errors = if !is_finite(&circle.center) || !circle.radius.is_finite() {
+                    Vec::from([ValidityError::NonFiniteCoordinate { index: 0 }])
+                } else if circle.radius <= T::zero() {
+                    Vec::from([ValidityError::ZeroArea])
+                } else {
+                    Vec::new()
+                };
+                report(None, errors)
+            }
+            Geometry2D::MultiPoint(points) => points.iter().enumerate().for_each(|(i, p)| report(Some(i), check_point(p))),
+            Geometry2D::MultiPolyline(polylines) => {
+                polylines.iter().enumerate().for_each(|(i, p)| report(Some(i), check_chain(p.vertices(), p, false)))
+            }
+            Geometry2D::MultiPolygon(polygons) => {
+                polygons.iter().enumerate().for_each(|(i, p)| report(Some(i), check_chain(p.vertices(), p, true)))
+            }
+        }
+    }
+    ValidationReport { checked: geometries.len(), diagnostics }
+}
+
+fn is_finite<T: Float + Debug>(point: &Point2D<T>) -> bool {
+    point.x.is_finite() && point.y.is_finite()
+}
+
+fn check_point<T: Float + Debug>(point: &Point2D<T>) -> Vec<ValidityError> {
+    if is_finite(point) { Vec::new() } else { Vec::from([ValidityError::NonFiniteCoordinate { index: 0 }]) }
+}
+
+/// Do all `vertices` lie on one line? A bow tie has zero signed area too, but
+/// is reported as self-intersecting instead.
+fn is_collinear<T: Float + Debug>(vertices: &[Point2D<T>]) -> bool {
+    let origin = vertices[0];
+    let Some(&other) = vertices.iter().find(|&&v| v != origin) else {
+        return true;
+    };
+    vertices.iter().all(|&v| (other - origin).cross_product(&(v - origin)) == T::zero())
+}
+
+/// Checks the vertices of a polyline, or of a polygon ring if `closed`.
+fn check_chain<T: Float + Debug>(vertices: &[Point2D<T>], chain: &impl SegmentChain<T>, closed: bool) -> Vec<ValidityError> {
+    if let Some(index) = vertices.iter().position(|v| !is_finite(v)) {
+        return Vec::from([ValidityError::NonFiniteCoordinate { index }]);
+    }
+    let n = vertices.len();
+    let pairs = if closed && n > 1 { n } else { n.saturating_sub(1) };
+    let mut errors: Vec<ValidityError> = (0..pairs)
+        .filter(|&i| vertices[i] == vertices[(i + 1) % n])
+        .map(|i| ValidityError::DuplicateVertex { first: i, second: (i + 1) % n })
+        .collect();
+
+    let required = if closed { 3 } else { 2 };
+    let distinct = n - errors.len().min(n.saturating_sub(1));
+    if distinct < required {
+        errors.push(ValidityError::TooFewVertices { found: distinct, required });
+    } else if closed && is_collinear(vertices) {
+        errors.push(ValidityError::ZeroArea);
+    }
+    if errors.is_empty() {
+        let mut pairs: Vec<[usize; 2]> = find_self_intersections(chain).into_iter().map(|x| x.segments).collect();
+        pairs.dedup();
+        errors.extend(pairs.into_iter().map(|[first, second]| ValidityError::SelfIntersection { first, second }));
+    }
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::line::Line2D;
+    use crate::polygon::Polygon2D;
+    use crate::shape::Circle2D;
+
+    #[test]
+    fn test_validate_collection_diagnostics() {
+        let p = |x: f64, y: f64| Point2D::new(x, y);
+        let geometries: Vec<Geometry2D<f64>> = vec![
+            Line2D::new(p(1.0, 1.0), p(1.0, 1.0)).into(),
+            Polyline2D::new(vec![p(0.0, 0.0), p(2.0, 0.0), p(2.0, 0.0), p(1.0, 1.0)]).into(),
+            Polyline2D::new(vec![p(0.0, 0.0), p(2.0, 2.0), p(2.0, 0.0), p(0.0, 2.0)]).into(),
+            Polygon2D::new(vec![p(0.0, 0.0), p(1.0, 1.0), p(2.0, 2.0)]).into(),
+            Circle2D::new(p(0.0, 0.0), 0.0).into(),
+            Circle2D::new(p(0.0, 0.0), f64::INFINITY).into(),
+            Geometry2D::MultiPoint(vec![p(0.0, 0.0), p(0.0, f64::NAN)]),
+            Geometry2D::MultiPolygon(vec![
+                Polygon2D::new(vec![p(0.0, 0.0), p(1.0, 0.0), p(0.0, 1.0)]),
+                Polygon2D::new(vec![p(0.0, 0.0), p(1.0, 0.0), p(1.0, 0.0), p(0.0, 0.0)]),
+            ]),
+            Polygon2D::new(vec![p(0.0, 0.0), p(1.0, 0.0), p(0.0, 1.0)]).into(),
+        ];
+        let report = validate_collection(&geometries);
+        assert_eq!(report.checked, 9);
+        assert_eq!(report.invalid_indices(), (0..8).collect::<Vec<_>>());
+        let errors = |index: usize| report.diagnostics.iter().filter(|d| d.index == index).map(|d| d.error).collect::<Vec<_>>();
+        assert_eq!(errors(0), vec![ValidityError::DuplicateVertex { first: 0, second: 1 }, ValidityError::TooFewVertices { found: 1, required: 2 }]);
+        assert_eq!(errors(1), vec![ValidityError::DuplicateVertex { first: 1, second: 2 }]);
+        assert_eq!(errors(2), vec![ValidityError::SelfIntersection { first: 0, second: 2 }]);
+        assert_eq!(errors(3), vec![ValidityError::ZeroArea]);
+        assert_eq!(errors(4), vec![ValidityError::ZeroArea]);
+        assert_eq!(errors(5), vec![ValidityError::NonFiniteCoordinate { index: 0 }]);
+        assert_eq!(report.diagnostics.iter().find(|d| d.index == 6).unwrap().member, Some(1));
+        // the closing vertex of the second member was dropped by Polygon2D::new
+        let last: Vec<_> = report.diagnostics.iter().filter(|d| d.index == 7).collect();
+        assert!(last.iter().all(|d| d.member == Some(1)));
+        assert_eq!(last[0].error, ValidityError::DuplicateVertex { first: 1, second: 2 });
+        assert!(validate_collection::<f64>(&[]).is_valid());
+    }
+}
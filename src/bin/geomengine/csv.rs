@@ -0,0 +1,40 @@
+use geomengine::point::Point2D;
+use super::geometry::Geometry;
+
+/// One point per `x,y` line; blank lines and lines starting with `#` are
+/// skipped and anything but two finite numbers is rejected.
+pub fn read(text: &str) -> Result<Vec<Geometry>, String> {
+    let mut points = Vec::new();
+    for (number, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let coord = |field: Option<&str>| field.and_then(|f| f.trim().parse::<f64>().ok()).filter(|v| v.is_finite());
+        let mut fields = line.split(',');
+        match (coord(fields.next()), coord(fields.next()), fields.next()) {
+            (Some(x), Some(y), None) => points.push(Geometry::Point(Point2D::new(x, y))),
+            _ => return Err(format!("line {}: expected `x,y` with finite numbers, got `{line}`", number + 1)),
+        }
+    }
+    Ok(points)
+}
+
+/// Every vertex of every geometry as an `x,y` line; lines and rings are flattened.
+pub fn write(geometries: &[Geometry]) -> String {
+    geometries.iter().flat_map(Geometry::vertices).map(|p| format!("{},{}\n", p.x, p.y)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_write() {
+        let points = read("# a comment\n0,0\n\n 1.5 , -2\n").unwrap();
+        assert_eq!(points, vec![Geometry::Point(Point2D::new(0.0, 0.0)), Geometry::Point(Point2D::new(1.5, -2.0))]);
+        assert_eq!(write(&points), "0,0\n1.5,-2\n");
+        assert_eq!(read("0,0\n1,nan\n"), Err("line 2: expected `x,y` with finite numbers, got `1,nan`".to_string()));
+        assert!(read("1,2,3\n").is_err());
+    }
+}
@@ -0,0 +1,165 @@
+use geomengine::line::Polyline2D;
+use geomengine::point::Point2D;
+use geomengine::polygon::{Polygon2D, PolygonWithHoles};
+use super::geometry::Geometry;
+use super::json::{self, Value};
+
+/// The geometries of a GeoJSON `FeatureCollection`, `Feature` or bare geometry.
+/// Features without a geometry are skipped and properties are dropped.
+pub fn read(text: &str) -> Result<Vec<Geometry>, String> {
+    let value = json::parse(text)?;
+    match string(&value, "type")? {
+        "FeatureCollection" => match value.get("features") {
+            Some(Value::Array(features)) => features.iter().filter_map(|feature| feature_geometry(feature).transpose()).collect(),
+            _ => Err("GeoJSON: a FeatureCollection needs a `features` array".to_string()),
+        },
+        "Feature" => Ok(feature_geometry(&value)?.into_iter().collect()),
+        _ => Ok(vec![geometry(&value)?]),
+    }
+}
+
+/// The geometries as a `FeatureCollection` with one feature each and no properties.
+pub fn write(geometries: &[Geometry]) -> String {
+    let features: Vec<String> = geometries
+        .iter()
+        .map(|g| format!(r#"{{"type":"Feature","geometry":{},"properties":null}}"#, geometry_json(g)))
+        .collect();
+    format!("{{\"type\":\"FeatureCollection\",\"features\":[{}]}}\n", features.join(","))
+}
+
+fn string<'a>(value: &'a Value, key: &str) -> Result<&'a str, String> {
+    match value.get(key) {
+        Some(Value::String(text)) => Ok(text),
+        _ => Err(format!("GeoJSON: expected an object with a string `{key}`")),
+    }
+}
+
+fn feature_geometry(feature: &Value) -> Result<Option<Geometry>, String> {
+    if string(feature, "type")? != "Feature" {
+        return Err("GeoJSON: expected a Feature in `features`".to_string());
+    }
+    match feature.get("geometry") {
+        None | Some(Value::Null) => Ok(None),
+        Some(value) => geometry(value).map(Some),
+    }
+}
+
+fn geometry(value: &Value) -> Result<Geometry, String> {
+    let kind = string(value, "type")?;
+    if kind == "GeometryCollection" {
+        return match value.get("geometries") {
+            Some(Value::Array(members)) => members.iter().map(geometry).collect::<Result<_, _>>().map(Geometry::Collection),
+            _ => Err("GeoJSON: a GeometryCollection needs a `geometries` array".to_string()),
+        };
+    }
+    let coordinates = value.get("coordinates").ok_or_else(|| format!("GeoJSON: the {kind} has no `coordinates`"))?;
+    Ok(match kind {
+        "Point" => Geometry::Point(position(coordinates)?),
+        "LineString" => Geometry::LineString(line(coordinates)?),
+        "Polygon" => Geometry::Polygon(polygon(coordinates)?),
+        "MultiPoint" => Geometry::MultiPoint(list(coordinates, position)?),
+        "MultiLineString" => Geometry::MultiLineString(list(coordinates, line)?),
+        "MultiPolygon" => Geometry::MultiPolygon(list(coordinates, polygon)?),
+        _ => return Err(format!("GeoJSON: unknown geometry type `{kind}`")),
+    })
+}
+
+fn list<T>(value: &Value, item: impl Fn(&Value) -> Result<T, String>) -> Result<Vec<T>, String> {
+    match value {
+        Value::Array(values) => values.iter().map(item).collect(),
+        _ => Err("GeoJSON: expected an array of coordinates".to_string()),
+    }
+}
+
+/// `[x, y]`, with any further coordinates (altitude) ignored.
+fn position(value: &Value) -> Result<Point2D<f64>, String> {
+    match value {
+        Value::Array(values) => match values[..] {
+            [Value::Number(x), Value::Number(y), ..] => Ok(Point2D::new(x, y)),
+            _ => Err("GeoJSON: a position needs two numbers".to_string()),
+        },
+        _ => Err("GeoJSON: expected a position `[x, y]`".to_string()),
+    }
+}
+
+fn line(value: &Value) -> Result<Polyline2D<f64>, String> {
+    list(value, position).map(Polyline2D::new)
+}
+
+fn polygon(value: &Value) -> Result<PolygonWithHoles<f64>, String> {
+    let mut rings = list(value, |ring| list(ring, position).map(Polygon2D::new))?.into_iter();
+    Ok(match rings.next() {
+        Some(exterior) => PolygonWithHoles::new(exterior, rings.collect()),
+        None => Polygon2D::new(Vec::new()).into(),
+    })
+}
+
+fn positions(points: impl IntoIterator<Item = Point2D<f64>>) -> String {
+    let positions: Vec<String> = points.into_iter().map(|p| format!("[{},{}]", p.x, p.y)).collect();
+    format!("[{}]", positions.join(","))
+}
+
+/// Rings repeat their first position at the end, as GeoJSON requires.
+fn rings(polygon: &PolygonWithHoles<f64>) -> String {
+    if polygon.exterior().is_empty() {
+        return "[]".to_string();
+    }
+    let rings: Vec<String> = polygon
+        .rings()
+        .map(|ring| positions(ring.vertices().iter().chain(ring.vertices().first()).copied()))
+        .collect();
+    format!("[{}]", rings.join(","))
+}
+
+fn geometry_json(geometry: &Geometry) -> String {
+    let (kind, coordinates) = match geometry {
+        Geometry::Point(p) => ("Point", format!("[{},{}]", p.x, p.y)),
+        Geometry::LineString(line) => ("LineString", positions(line.vertices().iter().copied())),
+        Geometry::Polygon(polygon) => ("Polygon", rings(polygon)),
+        Geometry::MultiPoint(points) => ("MultiPoint", positions(points.iter().copied())),
+        Geometry::MultiLineString(lines) => {
+            let lines: Vec<String> = lines.iter().map(|line| positions(line.vertices().iter().copied())).collect();
+            ("MultiLineString", format!("[{}]", lines.join(",")))
+        }
+        Geometry::MultiPolygon(polygons) => {
+            let polygons: Vec<String> = polygons.iter().map(rings).collect();
+            ("MultiPolygon", format!("[{}]", polygons.join(",")))
+        }
+        Geometry::Collection(members) => {
+            let members: Vec<String> = members.iter().map(geometry_json).collect();
+            return format!(r#"{{"type":"GeometryCollection","geometries":[{}]}}"#, members.join(","));
+        }
+    };
+    format!(r#"{{"type":"{kind}","coordinates":{coordinates}}}"#)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_features_and_geometries() {
+        let text = r#"{"type": "FeatureCollection", "features": [
+            {"type": "Feature", "geometry": {"type": "Point", "coordinates": [1, 2, 30]}, "properties": {"name": "a"}},
+            {"type": "Feature", "geometry": null, "properties": {}},
+            {"type": "Feature", "geometry": {"type": "Polygon", "coordinates": [[[0, 0], [4, 0], [0, 4], [0, 0]], [[1, 1], [1, 2], [2, 1], [1, 1]]]}}
+        ]}"#;
+        let geometries = read(text).unwrap();
+        assert_eq!(geometries.len(), 2);
+        assert_eq!(geometries[0], Geometry::Point(Point2D::new(1.0, 2.0)));
+        let Geometry::Polygon(polygon) = &geometries[1] else { panic!("a polygon") };
+        assert_eq!(polygon.exterior().len(), 3);
+        assert_eq!(polygon.holes().len(), 1);
+
+        let bare = read(r#"{"type": "MultiLineString", "coordinates": [[[0, 0], [1, 1]]]}"#).unwrap();
+        assert_eq!(bare, vec![Geometry::MultiLineString(vec![Polyline2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0)])])]);
+        assert!(read(r#"{"type": "Circle", "coordinates": [0, 0]}"#).unwrap_err().contains("unknown geometry type"));
+        assert!(read(r#"{"type": "Point", "coordinates": [0]}"#).is_err());
+    }
+
+    #[test]
+    fn test_write_round_trips() {
+        let text = r#"{"type":"FeatureCollection","features":[{"type":"Feature","geometry":{"type":"Polygon","coordinates":[[[0,0],[4,0],[0,4],[0,0]]]},"properties":null},{"type":"Feature","geometry":{"type":"GeometryCollection","geometries":[{"type":"Point","coordinates":[1.5,2]}]},"properties":null}]}"#;
+        assert_eq!(write(&read(text).unwrap()), format!("{text}\n"));
+    }
+}
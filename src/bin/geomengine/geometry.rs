@@ -0,0 +1,193 @@
+use geomengine::algorithms::buffer::{buffer_2d, buffer_polygons_2d};
+use geomengine::algorithms::point_algorithms::convex_hull_2d_owned;
+use geomengine::algorithms::simplify::simplify_2d;
+use geomengine::line::Polyline2D;
+use geomengine::point::Point2D;
+use geomengine::polygon::{Polygon2D, PolygonWithHoles};
+use geomengine::shape::{Centroid, Geometry2D, Perimeter};
+
+/// A geometry as WKT and GeoJSON describe it. Rings are stored without
+/// repeating their first vertex at the end.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Geometry {
+    Point(Point2D<f64>),
+    LineString(Polyline2D<f64>),
+    Polygon(PolygonWithHoles<f64>),
+    MultiPoint(Vec<Point2D<f64>>),
+    MultiLineString(Vec<Polyline2D<f64>>),
+    MultiPolygon(Vec<PolygonWithHoles<f64>>),
+    Collection(Vec<Geometry>),
+}
+
+impl Geometry {
+    /// Every vertex, in order.
+    pub fn vertices(&self) -> Vec<Point2D<f64>> {
+        let mut vertices = Vec::new();
+        self.for_each_part(&mut |part| match part {
+            Part::Point(point) => vertices.push(*point),
+            Part::Line(line) => vertices.extend_from_slice(line.vertices()),
+            Part::Area(polygon) => vertices.extend(polygon.rings().flat_map(|ring| ring.vertices().iter().copied())),
+        });
+        vertices
+    }
+
+    fn for_each_part(&self, f: &mut impl FnMut(Part<'_>)) {
+        match self {
+            Geometry::Point(point) => f(Part::Point(point)),
+            Geometry::LineString(line) => f(Part::Line(line)),
+            Geometry::Polygon(polygon) => f(Part::Area(polygon)),
+            Geometry::MultiPoint(points) => points.iter().for_each(|point| f(Part::Point(point))),
+            Geometry::MultiLineString(lines) => lines.iter().for_each(|line| f(Part::Line(line))),
+            Geometry::MultiPolygon(polygons) => polygons.iter().for_each(|polygon| f(Part::Area(polygon))),
+            Geometry::Collection(members) => members.iter().for_each(|member| member.for_each_part(f)),
+        }
+    }
+}
+
+enum Part<'a> {
+    Point(&'a Point2D<f64>),
+    Line(&'a Polyline2D<f64>),
+    Area(&'a PolygonWithHoles<f64>),
+}
+
+/// The convex hull of all vertices: a polygon, or a point or line if they do not span an area.
+pub fn hull(geometries: &[Geometry]) -> Geometry {
+    let hull = convex_hull_2d_owned(geometries.iter().flat_map(Geometry::vertices).collect());
+    match hull[..] {
+        [point] => Geometry::Point(point),
+        [_, _] => Geometry::LineString(Polyline2D::new(hull)),
+        _ => Geometry::Polygon(Polygon2D::new(hull).into()),
+    }
+}
+
+/// The center of mass of all geometries, weighting areas by area, lines by
+/// length and points by count; only the highest dimension with any weight counts.
+pub fn centroid(geometries: &[Geometry]) -> Option<Point2D<f64>> {
+    // (weighted sum, total weight) for points, lines and areas
+    let mut sums = [(Point2D::origin(), 0.0); 3];
+    let mut add = |dimension: usize, point: Point2D<f64>, weight: f64| {
+        let (sum, total) = sums[dimension];
+        sums[dimension] = (sum + point * weight, total + weight);
+    };
+    for geometry in geometries {
+        geometry.for_each_part(&mut |part| match part {
+            Part::Point(&point) => add(0, point, 1.0),
+            Part::Line(line) => {
+                if let Some(center) = line.centroid() {
+                    add(1, center, line.length());
+                }
+                line.vertices().iter().for_each(|&point| add(0, point, 1.0));
+            }
+            Part::Area(polygon) => {
+                if let Some(center) = polygon.centroid() {
+                    add(2, center, polygon.area());
+                }
+                for ring in polygon.rings() {
+                    if let Some(center) = closed(ring).centroid() {
+                        add(1, center, ring.perimeter());
+                    }
+                    ring.vertices().iter().for_each(|&point| add(0, point, 1.0));
+                }
+            }
+        });
+    }
+    sums.iter().rev().find(|(_, total)| *total > 0.0).map(|&(sum, total)| sum / total)
+}
+
+/// Drops vertices closer than `tolerance` to the simplified lines and rings.
+/// Rings left with fewer than three vertices are removed.
+pub fn simplify(geometry: &Geometry, tolerance: f64) -> Geometry {
+    let line = |line: &Polyline2D<f64>| Polyline2D::new(simplify_2d(line.vertices(), tolerance));
+    let polygon = |polygon: &PolygonWithHoles<f64>| {
+        let mut rings = polygon
+            .rings()
+            .map(|ring| Polygon2D::new(simplify_2d(closed(ring).vertices(), tolerance)))
+            .map(|ring| if ring.len() < 3 { Polygon2D::new(Vec::new()) } else { ring });
+        let exterior = rings.next().unwrap_or_else(|| Polygon2D::new(Vec::new()));
+        if exterior.is_empty() {
+            return PolygonWithHoles::from(exterior);
+        }
+        PolygonWithHoles::new(exterior, rings.filter(|ring| !ring.is_empty()).collect())
+    };
+    match geometry {
+        Geometry::Point(_) | Geometry::MultiPoint(_) => geometry.clone(),
+        Geometry::LineString(l) => Geometry::LineString(line(l)),
+        Geometry::Polygon(p) => Geometry::Polygon(polygon(p)),
+        Geometry::MultiLineString(lines) => Geometry::MultiLineString(lines.iter().map(line).collect()),
+        Geometry::MultiPolygon(polygons) => {
+            Geometry::MultiPolygon(polygons.iter().map(polygon).filter(|p| !p.exterior().is_empty()).collect())
+        }
+        Geometry::Collection(members) => Geometry::Collection(members.iter().map(|m| simplify(m, tolerance)).collect()),
+    }
+}
+
+/// The area within `distance` of the geometry, or with a negative `distance`,
+/// what is left of its polygons shrunk by `-distance`.
+pub fn buffer(geometry: &Geometry, distance: f64) -> Geometry {
+    let parts = match geometry {
+        Geometry::Point(point) => buffer_2d(&Geometry2D::Point(*point), distance),
+        Geometry::LineString(line) => buffer_2d(&Geometry2D::Polyline(line.clone()), distance),
+        Geometry::MultiPoint(points) => buffer_2d(&Geometry2D::MultiPoint(points.clone()), distance),
+        Geometry::MultiLineString(lines) => buffer_2d(&Geometry2D::MultiPolyline(lines.clone()), distance),
+        Geometry::Polygon(polygon) => buffer_polygons_2d(std::slice::from_ref(polygon), distance),
+        Geometry::MultiPolygon(polygons) => buffer_polygons_2d(polygons, distance),
+        Geometry::Collection(members) => return Geometry::Collection(members.iter().map(|m| buffer(m, distance)).collect()),
+    };
+    let mut polygons: Vec<PolygonWithHoles<f64>> =
+        parts.into_iter().map(|(exterior, holes)| PolygonWithHoles::new(exterior, holes)).collect();
+    match polygons.len() {
+        1 => Geometry::Polygon(polygons.pop().unwrap()),
+        _ => Geometry::MultiPolygon(polygons),
+    }
+}
+
+/// `ring` as a polyline ending where it starts.
+fn closed(ring: &Polygon2D<f64>) -> Polyline2D<f64> {
+    let mut vertices = ring.vertices().to_vec();
+    vertices.extend(vertices.first().copied());
+    Polyline2D::new(vertices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(min: f64, size: f64) -> Polygon2D<f64> {
+        Polygon2D::new(vec![
+            Point2D::new(min, min),
+            Point2D::new(min + size, min),
+            Point2D::new(min + size, min + size),
+            Point2D::new(min, min + size),
+        ])
+    }
+
+    #[test]
+    fn test_centroid_weights_the_highest_dimension() {
+        let frame = Geometry::Polygon(PolygonWithHoles::new(square(0.0, 4.0), vec![square(0.0, 2.0)]));
+        let far_point = Geometry::Point(Point2D::new(100.0, 100.0));
+        // the hole in the corner pulls the centroid away from it; the point has no area
+        let expected = (16.0 * 2.0 - 4.0 * 1.0) / 12.0;
+        assert_eq!(centroid(&[frame, far_point.clone()]), Some(Point2D::new(expected, expected)));
+
+        let line = Geometry::LineString(Polyline2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(4.0, 0.0)]));
+        assert_eq!(centroid(&[line, far_point.clone()]), Some(Point2D::new(2.0, 0.0)));
+        assert_eq!(centroid(&[far_point.clone(), Geometry::MultiPoint(vec![Point2D::new(0.0, 0.0)])]), Some(Point2D::new(50.0, 50.0)));
+        assert_eq!(centroid(&[Geometry::Collection(Vec::new())]), None);
+    }
+
+    #[test]
+    fn test_hull_simplify_and_buffer() {
+        let points = Geometry::MultiPoint(vec![Point2D::new(0.0, 0.0), Point2D::new(2.0, 0.0), Point2D::new(1.0, 0.5), Point2D::new(1.0, 2.0)]);
+        assert_eq!(hull(&[points]), Geometry::Polygon(Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(2.0, 0.0), Point2D::new(1.0, 2.0)]).into()));
+        assert_eq!(hull(&[Geometry::Point(Point2D::new(1.0, 1.0))]), Geometry::Point(Point2D::new(1.0, 1.0)));
+
+        let wiggly = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(2.0, 0.01), Point2D::new(4.0, 0.0), Point2D::new(4.0, 4.0), Point2D::new(0.0, 4.0)]);
+        let tiny_hole = Polygon2D::new(vec![Point2D::new(1.0, 1.0), Point2D::new(1.01, 1.0), Point2D::new(1.0, 1.01)]);
+        let simplified = simplify(&Geometry::Polygon(PolygonWithHoles::new(wiggly, vec![tiny_hole])), 0.1);
+        assert_eq!(simplified, Geometry::Polygon(square(0.0, 4.0).into()));
+
+        let Geometry::Polygon(grown) = buffer(&Geometry::Polygon(square(0.0, 4.0).into()), 1.0) else { panic!("one polygon") };
+        assert!(grown.area() > 35.0 && grown.area() < 36.0);
+        assert_eq!(buffer(&Geometry::Polygon(square(0.0, 4.0).into()), -3.0), Geometry::MultiPolygon(Vec::new()));
+    }
+}
@@ -0,0 +1,217 @@
+//! Just enough JSON to read GeoJSON.
+
+/// A parsed JSON value. Object members keep their order.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    /// The member `key` of an object.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Object(members) => members.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+}
+
+/// Parses one JSON document, with nothing but whitespace after it.
+pub fn parse(text: &str) -> Result<Value, String> {
+    let mut parser = Parser { bytes: text.as_bytes(), position: 0 };
+    let value = parser.value()?;
+    parser.skip_whitespace();
+    match parser.peek() {
+        None => Ok(value),
+        Some(_) => Err(parser.error("end of input")),
+    }
+}
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.position).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.position += 1;
+        }
+    }
+
+    fn error(&self, expected: &str) -> String {
+        match self.peek() {
+            Some(byte) => format!("JSON: expected {expected} at byte {}, found `{}`", self.position, byte as char),
+            None => format!("JSON: expected {expected}, found the end of input"),
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), String> {
+        self.skip_whitespace();
+        if self.peek() != Some(byte) {
+            return Err(self.error(&format!("`{}`", byte as char)));
+        }
+        self.position += 1;
+        Ok(())
+    }
+
+    fn literal(&mut self, word: &str, value: Value) -> Result<Value, String> {
+        if !self.bytes[self.position..].starts_with(word.as_bytes()) {
+            return Err(self.error(&format!("`{word}`")));
+        }
+        self.position += word.len();
+        Ok(value)
+    }
+
+    fn value(&mut self) -> Result<Value, String> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'{') => self.object(),
+            Some(b'[') => self.array(),
+            Some(b'"') => self.string().map(Value::String),
+            Some(b't') => self.literal("true", Value::Bool(true)),
+            Some(b'f') => self.literal("false", Value::Bool(false)),
+            Some(b'n') => self.literal("null", Value::Null),
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            _ => Err(self.error("a value")),
+        }
+    }
+
+    /// Comma separated items up to `close`, the opening bracket already consumed.
+    fn items(&mut self, close: u8, mut item: impl FnMut(&mut Self) -> Result<(), String>) -> Result<(), String> {
+        self.position += 1;
+        self.skip_whitespace();
+        if self.peek() == Some(close) {
+            self.position += 1;
+            return Ok(());
+        }
+        loop {
+            item(self)?;
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.position += 1,
+                Some(byte) if byte == close => {
+                    self.position += 1;
+                    return Ok(());
+                }
+                _ => return Err(self.error(&format!("`,` or `{}`", close as char))),
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<Value, String> {
+        let mut members = Vec::new();
+        self.items(b'}', |parser| {
+            parser.skip_whitespace();
+            let key = parser.string()?;
+            parser.expect(b':')?;
+            members.push((key, parser.value()?));
+            Ok(())
+        })?;
+        Ok(Value::Object(members))
+    }
+
+    fn array(&mut self) -> Result<Value, String> {
+        let mut values = Vec::new();
+        self.items(b']', |parser| {
+            values.push(parser.value()?);
+            Ok(())
+        })?;
+        Ok(Value::Array(values))
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        if self.peek() != Some(b'"') {
+            return Err(self.error("a string"));
+        }
+        self.position += 1;
+        let mut text = String::new();
+        loop {
+            let start = self.position;
+            while !matches!(self.peek(), None | Some(b'"' | b'\\')) {
+                self.position += 1;
+            }
+            // the input is a `str` and both stops are ASCII, so this is a whole number of characters
+            text.push_str(std::str::from_utf8(&self.bytes[start..self.position]).unwrap());
+            match self.peek() {
+                None => return Err(self.error("`\"`")),
+                Some(b'"') => {
+                    self.position += 1;
+                    return Ok(text);
+                }
+                _ => {
+                    self.position += 1;
+                    let escaped = match self.peek() {
+                        Some(b'"') => '"',
+                        Some(b'\\') => '\\',
+                        Some(b'/') => '/',
+                        Some(b'b') => '\u{8}',
+                        Some(b'f') => '\u{c}',
+                        Some(b'n') => '\n',
+                        Some(b'r') => '\r',
+                        Some(b't') => '\t',
+                        Some(b'u') => {
+                            let hex = self.bytes.get(self.position + 1..self.position + 5).and_then(|h| std::str::from_utf8(h).ok());
+                            let code = hex.and_then(|h| u32::from_str_radix(h, 16).ok()).ok_or_else(|| self.error("four hex digits"))?;
+                            self.position += 4;
+                            // lone surrogates have no `char`; they only occur in names, not coordinates
+                            char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER)
+                        }
+                        _ => return Err(self.error("an escape character")),
+                    };
+                    self.position += 1;
+                    text.push(escaped);
+                }
+            }
+        }
+    }
+
+    fn number(&mut self) -> Result<Value, String> {
+        let start = self.position;
+        while matches!(self.peek(), Some(b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9')) {
+            self.position += 1;
+        }
+        let text = std::str::from_utf8(&self.bytes[start..self.position]).unwrap();
+        match text.parse::<f64>() {
+            Ok(number) if number.is_finite() => Ok(Value::Number(number)),
+            _ => {
+                self.position = start;
+                Err(self.error("a number"))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_values() {
+        let value = parse(r#" {"type": "Point", "coordinates": [1.5, -2e3], "id": null, "ok": true, "name": "a\"bé"} "#).unwrap();
+        assert_eq!(value.get("type"), Some(&Value::String("Point".to_string())));
+        assert_eq!(value.get("coordinates"), Some(&Value::Array(vec![Value::Number(1.5), Value::Number(-2000.0)])));
+        assert_eq!(value.get("id"), Some(&Value::Null));
+        assert_eq!(value.get("ok"), Some(&Value::Bool(true)));
+        assert_eq!(value.get("name"), Some(&Value::String("a\"bé".to_string())));
+        assert_eq!(parse("[]"), Ok(Value::Array(Vec::new())));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(parse("[1, 2"), Err("JSON: expected `,` or `]`, found the end of input".to_string()));
+        assert_eq!(parse("[1] x"), Err("JSON: expected end of input at byte 4, found `x`".to_string()));
+        assert!(parse("{1: 2}").is_err());
+        assert!(parse("[1e999]").is_err());
+        assert!(parse("[tru]").is_err());
+    }
+}
@@ -0,0 +1,281 @@
+//! Command line access to the geometry algorithms, for shell pipelines.
+//!
+//! ```text
+//! geomengine <hull|centroid|simplify|buffer|convert> [options] [input]
+//! ```
+//!
+//! Geometries are read from `input`, or stdin without one, as CSV (one `x,y`
+//! point per line), WKT (one geometry after another) or a GeoJSON feature
+//! collection, feature or geometry. Results go to stdout, or the `--output`
+//! file, in any of the three formats; CSV output lists every vertex.
+//!
+//! Formats default to the extension of the input and output paths (`.csv`,
+//! `.wkt`, `.geojson` or `.json`), then to CSV for the input and to the input
+//! format for the output.
+
+mod csv;
+mod geojson;
+mod geometry;
+mod json;
+mod wkt;
+
+use std::env;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::Path;
+use std::process::ExitCode;
+use geometry::Geometry;
+
+const USAGE: &str = "\
+usage: geomengine <command> [options] [input]
+
+commands:
+  hull                  convex hull of all vertices
+  centroid              center of mass of all geometries
+  simplify              drop vertices within --tolerance of each line and ring
+  buffer                grow each geometry by --distance, or shrink it if negative
+  convert               copy the geometries unchanged
+
+options:
+  --from <csv|wkt|geojson>  input format
+  --to <csv|wkt|geojson>    output format
+  -o, --output <path>       write to a file instead of stdout
+  --tolerance <t>           simplify tolerance
+  --distance <d>            buffer distance
+  -h, --help                print this message";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Command {
+    Hull,
+    Centroid,
+    Simplify { tolerance: f64 },
+    Buffer { distance: f64 },
+    Convert,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Csv,
+    Wkt,
+    GeoJson,
+}
+
+impl Format {
+    fn from_name(name: &str) -> Result<Format, String> {
+        match name {
+            "csv" => Ok(Format::Csv),
+            "wkt" => Ok(Format::Wkt),
+            "geojson" => Ok(Format::GeoJson),
+            _ => Err(format!("unknown format `{name}`, use csv, wkt or geojson")),
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Format> {
+        match Path::new(path).extension()?.to_str()?.to_ascii_lowercase().as_str() {
+            "csv" => Some(Format::Csv),
+            "wkt" => Some(Format::Wkt),
+            "geojson" | "json" => Some(Format::GeoJson),
+            _ => None,
+        }
+    }
+
+    fn read(self, text: &str) -> Result<Vec<Geometry>, String> {
+        match self {
+            Format::Csv => csv::read(text),
+            Format::Wkt => wkt::read(text),
+            Format::GeoJson => geojson::read(text),
+        }
+    }
+
+    fn write(self, geometries: &[Geometry]) -> String {
+        match self {
+            Format::Csv => csv::write(geometries),
+            Format::Wkt => wkt::write(geometries),
+            Format::GeoJson => geojson::write(geometries),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Options {
+    command: Command,
+    from: Format,
+    to: Format,
+    input: Option<String>,
+    output: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+enum Invocation {
+    Help,
+    Run(Options),
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = parse_args(&args).and_then(|invocation| match invocation {
+        Invocation::Help => {
+            println!("{USAGE}");
+            Ok(())
+        }
+        Invocation::Run(options) => execute(&options),
+    });
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("geomengine: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse_args(args: &[String]) -> Result<Invocation, String> {
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        return Ok(Invocation::Help);
+    }
+    let (command, flags) = args.split_first().ok_or_else(|| USAGE.to_string())?;
+    let (mut from, mut to, mut input, mut output) = (None, None, None, None);
+    let (mut tolerance, mut distance) = (None, None);
+    let mut flags = flags.iter();
+    while let Some(flag) = flags.next() {
+        if !flag.starts_with('-') || flag == "-" {
+            if input.replace(flag.clone()).is_some() {
+                return Err(format!("unexpected argument `{flag}`\n{USAGE}"));
+            }
+            continue;
+        }
+        let value = flags.next().ok_or_else(|| format!("`{flag}` needs a value"))?;
+        let number = || match value.parse::<f64>() {
+            Ok(number) if number.is_finite() => Ok(number),
+            _ => Err(format!("`{flag}` needs a finite number, got `{value}`")),
+        };
+        match flag.as_str() {
+            "--from" => from = Some(Format::from_name(value)?),
+            "--to" => to = Some(Format::from_name(value)?),
+            "-o" | "--output" => output = Some(value.clone()),
+            "--tolerance" => tolerance = Some(number()?),
+            "--distance" => distance = Some(number()?),
+            _ => return Err(format!("unknown option `{flag}`\n{USAGE}")),
+        }
+    }
+    let command = match command.as_str() {
+        "hull" => Command::Hull,
+        "centroid" => Command::Centroid,
+        "simplify" => Command::Simplify { tolerance: tolerance.ok_or("`simplify` needs --tolerance")? },
+        "buffer" => Command::Buffer { distance: distance.ok_or("`buffer` needs --distance")? },
+        "convert" => Command::Convert,
+        _ => return Err(format!("unknown command `{command}`\n{USAGE}")),
+    };
+    let input = input.filter(|path| path != "-");
+    let from = from.or_else(|| input.as_deref().and_then(Format::from_path)).unwrap_or(Format::Csv);
+    let to = to.or_else(|| output.as_deref().and_then(Format::from_path)).unwrap_or(from);
+    Ok(Invocation::Run(Options { command, from, to, input, output }))
+}
+
+/// Runs `options` against the files or standard streams they name.
+fn execute(options: &Options) -> Result<(), String> {
+    let text = match &options.input {
+        Some(path) => fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))?,
+        None => {
+            let mut text = String::new();
+            io::stdin().read_to_string(&mut text).map_err(|e| e.to_string())?;
+            text
+        }
+    };
+    let result = run(options, &text)?;
+    match &options.output {
+        Some(path) => fs::write(path, result).map_err(|e| format!("{path}: {e}")),
+        None => io::stdout().write_all(result.as_bytes()).map_err(|e| e.to_string()),
+    }
+}
+
+fn run(options: &Options, text: &str) -> Result<String, String> {
+    let geometries = options.from.read(text)?;
+    let results = match options.command {
+        Command::Hull => vec![geometry::hull(&geometries)],
+        Command::Centroid => {
+            let centroid = geometry::centroid(&geometries).ok_or("no points to take the centroid of")?;
+            vec![Geometry::Point(centroid)]
+        }
+        Command::Simplify { tolerance } => geometries.iter().map(|g| geometry::simplify(g, tolerance)).collect(),
+        Command::Buffer { distance } => geometries.iter().map(|g| geometry::buffer(g, distance)).collect(),
+        Command::Convert => geometries,
+    };
+    Ok(options.to.write(&results))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|a| a.to_string()).collect()
+    }
+
+    fn run_str(command: &[&str], input: &str) -> Result<String, String> {
+        match parse_args(&args(command))? {
+            Invocation::Run(options) => run(&options, input),
+            Invocation::Help => panic!("expected a command"),
+        }
+    }
+
+    #[test]
+    fn test_hull_and_centroid_over_csv() {
+        let input = "# square with a point inside\n0,0\n2,0\n\n2,2\n0,2\n1,1\n";
+        assert_eq!(run_str(&["hull"], input).unwrap(), "0,0\n2,0\n2,2\n0,2\n");
+        assert_eq!(run_str(&["hull", "--to", "wkt"], input).unwrap(), "POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))\n");
+        assert_eq!(run_str(&["centroid", "--from", "csv"], input).unwrap(), "1,1\n");
+        assert_eq!(run_str(&["centroid", "--to", "wkt"], "1, 2\n").unwrap(), "POINT (1 2)\n");
+        assert_eq!(run_str(&["centroid"], ""), Err("no points to take the centroid of".to_string()));
+    }
+
+    #[test]
+    fn test_simplify_buffer_and_convert() {
+        let line = "LINESTRING (0 0, 1 0.01, 2 0, 2 2)\n";
+        assert_eq!(run_str(&["simplify", "--from", "wkt", "--tolerance", "0.1"], line).unwrap(), "LINESTRING (0 0, 2 0, 2 2)\n");
+
+        let buffered = run_str(&["buffer", "--from", "wkt", "--distance", "1"], "POINT (0 0)\n").unwrap();
+        assert!(buffered.starts_with("POLYGON (("));
+        assert_eq!(run_str(&["buffer", "--from", "wkt", "--distance", "-1"], "POLYGON ((0 0, 1 0, 0 1, 0 0))").unwrap(), "MULTIPOLYGON EMPTY\n");
+
+        let geojson = run_str(&["convert", "--from", "wkt", "--to", "geojson"], "POINT (1 2)").unwrap();
+        assert_eq!(geojson, "{\"type\":\"FeatureCollection\",\"features\":[{\"type\":\"Feature\",\"geometry\":{\"type\":\"Point\",\"coordinates\":[1,2]},\"properties\":null}]}\n");
+        assert_eq!(run_str(&["convert", "--from", "geojson", "--to", "wkt"], &geojson).unwrap(), "POINT (1 2)\n");
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(parse_args(&args(&["--help"])), Ok(Invocation::Help));
+        assert_eq!(parse_args(&args(&["hull", "-h"])), Ok(Invocation::Help));
+        assert_eq!(
+            parse_args(&args(&["convert", "in.geojson", "-o", "out.WKT"])),
+            Ok(Invocation::Run(Options {
+                command: Command::Convert,
+                from: Format::GeoJson,
+                to: Format::Wkt,
+                input: Some("in.geojson".to_string()),
+                output: Some("out.WKT".to_string()),
+            }))
+        );
+        let Ok(Invocation::Run(options)) = parse_args(&args(&["hull", "--from", "wkt", "-"])) else { panic!("a command") };
+        assert_eq!((options.from, options.to, options.input), (Format::Wkt, Format::Wkt, None));
+    }
+
+    #[test]
+    fn test_rejects_bad_arguments() {
+        assert_eq!(parse_args(&args(&["simplify"])), Err("`simplify` needs --tolerance".to_string()));
+        assert!(parse_args(&args(&["buffer", "--distance", "inf"])).unwrap_err().contains("finite number"));
+        assert!(parse_args(&args(&["hull", "--to", "svg"])).unwrap_err().contains("unknown format"));
+        assert!(parse_args(&args(&["hull", "--to"])).is_err());
+        assert!(parse_args(&args(&["hull", "a.csv", "b.csv"])).unwrap_err().contains("unexpected argument"));
+        assert!(parse_args(&args(&["rotate"])).unwrap_err().contains("unknown command"));
+        assert!(parse_args(&args(&[])).is_err());
+    }
+
+    #[test]
+    fn test_rejects_bad_input() {
+        assert_eq!(run_str(&["hull"], "0,0\n1,nan\n"), Err("line 2: expected `x,y` with finite numbers, got `1,nan`".to_string()));
+        assert!(run_str(&["hull", "--from", "wkt"], "POINT (1 2").is_err());
+        assert!(run_str(&["hull", "--from", "geojson"], "{").is_err());
+    }
+}
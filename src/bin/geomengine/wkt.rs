@@ -0,0 +1,266 @@
+use geomengine::line::Polyline2D;
+use geomengine::point::Point2D;
+use geomengine::polygon::{Polygon2D, PolygonWithHoles};
+use super::geometry::Geometry;
+
+/// Every WKT geometry in `text`, separated by whitespace, e.g. one per line.
+pub fn read(text: &str) -> Result<Vec<Geometry>, String> {
+    let mut parser = Parser { tokens: tokenize(text)?, position: 0 };
+    let mut geometries = Vec::new();
+    while parser.position < parser.tokens.len() {
+        geometries.push(parser.geometry()?);
+    }
+    Ok(geometries)
+}
+
+/// One geometry per line.
+pub fn write(geometries: &[Geometry]) -> String {
+    geometries.iter().map(|g| format!("{}\n", to_wkt(g))).collect()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Number(f64),
+    Open,
+    Close,
+    Comma,
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = text.char_indices().peekable();
+    while let Some(&(start, c)) = chars.peek() {
+        match c {
+            '(' | ')' | ',' => {
+                chars.next();
+                tokens.push(match c {
+                    '(' => Token::Open,
+                    ')' => Token::Close,
+                    _ => Token::Comma,
+                });
+            }
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            _ => {
+                let mut end = start;
+                while let Some(&(i, c)) = chars.peek() {
+                    if c.is_whitespace() || matches!(c, '(' | ')' | ',') {
+                        break;
+                    }
+                    end = i + c.len_utf8();
+                    chars.next();
+                }
+                let word = &text[start..end];
+                tokens.push(if word.starts_with(|c: char| c.is_ascii_alphabetic()) {
+                    Token::Word(word.to_ascii_uppercase())
+                } else {
+                    match word.parse::<f64>() {
+                        Ok(number) if number.is_finite() => Token::Number(number),
+                        _ => return Err(format!("WKT: `{word}` is not a finite number")),
+                    }
+                });
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn expect(&mut self, expected: Token, what: &str) -> Result<(), String> {
+        match self.next() {
+            Some(token) if token == expected => Ok(()),
+            found => Err(unexpected(what, found)),
+        }
+    }
+
+    /// `EMPTY`, consumed, or `false` if an opening parenthesis follows.
+    fn empty(&mut self) -> Result<bool, String> {
+        match self.peek() {
+            Some(Token::Word(word)) if word == "EMPTY" => {
+                self.position += 1;
+                Ok(true)
+            }
+            Some(Token::Open) => Ok(false),
+            Some(Token::Word(word)) if matches!(word.as_str(), "Z" | "M" | "ZM") => {
+                Err(format!("WKT: only 2D coordinates are supported, found `{word}`"))
+            }
+            _ => Err(unexpected("`(` or `EMPTY`", self.peek().cloned())),
+        }
+    }
+
+    /// A parenthesized, comma separated list of `item`s, or `EMPTY`.
+    fn list<T>(&mut self, mut item: impl FnMut(&mut Self) -> Result<T, String>) -> Result<Vec<T>, String> {
+        let mut items = Vec::new();
+        if self.empty()? {
+            return Ok(items);
+        }
+        self.expect(Token::Open, "`(`")?;
+        loop {
+            items.push(item(self)?);
+            match self.next() {
+                Some(Token::Comma) => {}
+                Some(Token::Close) => return Ok(items),
+                found => return Err(unexpected("`,` or `)`", found)),
+            }
+        }
+    }
+
+    fn coordinates(&mut self) -> Result<Point2D<f64>, String> {
+        match (self.next(), self.next()) {
+            (Some(Token::Number(x)), Some(Token::Number(y))) => match self.peek() {
+                Some(Token::Number(_)) => Err("WKT: only 2D coordinates are supported".to_string()),
+                _ => Ok(Point2D::new(x, y)),
+            },
+            (found, _) => Err(unexpected("two coordinates", found)),
+        }
+    }
+
+    /// A point of a `MULTIPOINT`, with or without its own parentheses.
+    fn member_point(&mut self) -> Result<Point2D<f64>, String> {
+        if self.peek() != Some(&Token::Open) {
+            return self.coordinates();
+        }
+        self.position += 1;
+        let point = self.coordinates()?;
+        self.expect(Token::Close, "`)`")?;
+        Ok(point)
+    }
+
+    fn line(&mut self) -> Result<Polyline2D<f64>, String> {
+        self.list(Self::coordinates).map(Polyline2D::new)
+    }
+
+    fn polygon(&mut self) -> Result<PolygonWithHoles<f64>, String> {
+        let mut rings = self.list(|parser| parser.list(Self::coordinates).map(Polygon2D::new))?.into_iter();
+        Ok(match rings.next() {
+            Some(exterior) => PolygonWithHoles::new(exterior, rings.collect()),
+            None => Polygon2D::new(Vec::new()).into(),
+        })
+    }
+
+    fn geometry(&mut self) -> Result<Geometry, String> {
+        let kind = match self.next() {
+            Some(Token::Word(word)) => word,
+            found => return Err(unexpected("a geometry type", found)),
+        };
+        Ok(match kind.as_str() {
+            "POINT" => match self.list(Self::coordinates)?[..] {
+                [point] => Geometry::Point(point),
+                [] => Geometry::MultiPoint(Vec::new()),
+                _ => return Err("WKT: a POINT has one pair of coordinates".to_string()),
+            },
+            "LINESTRING" => Geometry::LineString(self.line()?),
+            "POLYGON" => Geometry::Polygon(self.polygon()?),
+            "MULTIPOINT" => Geometry::MultiPoint(self.list(Self::member_point)?),
+            "MULTILINESTRING" => Geometry::MultiLineString(self.list(Self::line)?),
+            "MULTIPOLYGON" => Geometry::MultiPolygon(self.list(Self::polygon)?),
+            "GEOMETRYCOLLECTION" => Geometry::Collection(self.list(Self::geometry)?),
+            _ => return Err(format!("WKT: unknown geometry type `{kind}`")),
+        })
+    }
+}
+
+fn unexpected(expected: &str, found: Option<Token>) -> String {
+    let found = match found {
+        Some(Token::Word(word)) => format!("`{word}`"),
+        Some(Token::Number(number)) => format!("`{number}`"),
+        Some(Token::Open) => "`(`".to_string(),
+        Some(Token::Close) => "`)`".to_string(),
+        Some(Token::Comma) => "`,`".to_string(),
+        None => "the end of input".to_string(),
+    };
+    format!("WKT: expected {expected}, found {found}")
+}
+
+fn coordinates(points: impl IntoIterator<Item = Point2D<f64>>) -> String {
+    let coordinates: Vec<String> = points.into_iter().map(|p| format!("{} {}", p.x, p.y)).collect();
+    coordinates.join(", ")
+}
+
+/// `(ring), (hole), ...` with every ring closed, or `None` for an empty polygon.
+fn rings(polygon: &PolygonWithHoles<f64>) -> Option<String> {
+    if polygon.exterior().is_empty() {
+        return None;
+    }
+    let rings: Vec<String> = polygon
+        .rings()
+        .map(|ring| format!("({})", coordinates(ring.vertices().iter().chain(ring.vertices().first()).copied())))
+        .collect();
+    Some(rings.join(", "))
+}
+
+/// `KIND (items)`, or `KIND EMPTY` without items.
+fn tagged(kind: &str, items: Vec<String>) -> String {
+    match items.is_empty() {
+        true => format!("{kind} EMPTY"),
+        false => format!("{kind} ({})", items.join(", ")),
+    }
+}
+
+fn to_wkt(geometry: &Geometry) -> String {
+    match geometry {
+        Geometry::Point(point) => point.to_wkt_string(),
+        Geometry::LineString(line) => line.to_wkt_string(),
+        Geometry::Polygon(polygon) => tagged("POLYGON", rings(polygon).into_iter().collect()),
+        Geometry::MultiPoint(points) => tagged("MULTIPOINT", points.iter().map(|&p| format!("({})", coordinates([p]))).collect()),
+        Geometry::MultiLineString(lines) => tagged(
+            "MULTILINESTRING",
+            lines.iter().map(|line| format!("({})", coordinates(line.vertices().iter().copied()))).collect(),
+        ),
+        Geometry::MultiPolygon(polygons) => {
+            tagged("MULTIPOLYGON", polygons.iter().filter_map(rings).map(|rings| format!("({rings})")).collect())
+        }
+        Geometry::Collection(members) => tagged("GEOMETRYCOLLECTION", members.iter().map(to_wkt).collect()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_write_round_trips() {
+        let text = "POINT (1 2)\n\
+                    LINESTRING (0 0, 1.5 -2)\n\
+                    POLYGON ((0 0, 4 0, 0 4, 0 0), (1 1, 1 2, 2 1, 1 1))\n\
+                    MULTIPOINT ((0 0), (1 1))\n\
+                    MULTILINESTRING ((0 0, 1 1), (2 2, 3 3))\n\
+                    MULTIPOLYGON (((0 0, 1 0, 0 1, 0 0)), ((5 5, 6 5, 5 6, 5 5)))\n\
+                    GEOMETRYCOLLECTION (POINT (1 2), LINESTRING EMPTY)\n\
+                    POLYGON EMPTY\n";
+        let geometries = read(text).unwrap();
+        assert_eq!(geometries.len(), 8);
+        assert_eq!(write(&geometries), text);
+    }
+
+    #[test]
+    fn test_read_variants_and_errors() {
+        let geometries = read("multipoint (0 0, 1 1) point(3 4)").unwrap();
+        assert_eq!(geometries, vec![
+            Geometry::MultiPoint(vec![Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0)]),
+            Geometry::Point(Point2D::new(3.0, 4.0)),
+        ]);
+        assert_eq!(read("POINT (1 2"), Err("WKT: expected `,` or `)`, found the end of input".to_string()));
+        assert_eq!(read("POINT Z (1 2 3)"), Err("WKT: only 2D coordinates are supported, found `Z`".to_string()));
+        assert!(read("POINT (1 2 3)").is_err());
+        assert!(read("POINT (1 nan)").is_err());
+        assert!(read("CIRCLE (1 2)").unwrap_err().contains("unknown geometry type"));
+    }
+}
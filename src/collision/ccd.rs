@@ -0,0 +1,122 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Vector2D;
+use crate::line::Line2D;
+use crate::shape::{Circle2D, Distance};
+use crate::raycast::{Ray2D, RayCast};
+
+/// Time of impact of a moving circle against a static segment.
+///
+/// # Arguments
+/// * `circle` - The moving circle at the start of the step.
+/// * `velocity` - Displacement of the circle over the whole step.
+/// * `segment` - A static wall.
+///
+/// # Returns
+/// * The fraction `t` in `0..=1` of the step after which the circle first touches
+///   the segment, `Some(0)` if they already overlap, or `None` if they stay apart.
+///
+/// # Algorithm Explanation
+/// The circle's center touches the segment exactly when it lies on the boundary
+/// of the capsule swept by a disk of the same radius along the segment. The center's
+/// path is cast as a ray against that capsule's two side segments and two end caps,
+/// so thin walls are found no matter how far the circle moves in one step.
+///
+/// # Example
+/// ```
+/// use geomengine::collision::toi_circle_segment;
+/// use geomengine::line::Line2D;
+/// use geomengine::point::{Point2D, Vector2D};
+/// use geomengine::shape::Circle2D;
+///
+/// // a fast bullet would jump right over the wall in one step
+/// let bullet = Circle2D::new(Point2D::new(0.0, 0.0), 0.5);
+/// let wall = Line2D::new(Point2D::new(5.0, -1.0), Point2D::new(5.0, 1.0));
+/// assert_eq!(toi_circle_segment(&bullet, Vector2D::new(10.0, 0.0), &wall), Some(0.45));
+/// assert_eq!(toi_circle_segment(&bullet, Vector2D::new(0.0, 10.0), &wall), None);
+/// ```
+pub fn toi_circle_segment<T: Float + Debug>(circle: &Circle2D<T>, velocity: Vector2D<T>, segment: &Line2D<T>) -> Option<T> {
+    if circle.center.distance_to(segment) <= circle.radius {
+        return Some(T::zero());
+    }
+    let ray = Ray2D::new(circle.center, velocity)?;
+    let caps = [segment.p1, segment.p2].map(|end| Circle2D::new(end, circle.radius).cast_ray(&ray));
+    let edge = segment.p2 - segment.p1;
+    let sides = edge.perpendicular().normalize().map(|normal| {
+        let offset = normal * circle.radius;
+        [offset, -offset].map(|o| Line2D::new(segment.p1 + o, segment.p2 + o).cast_ray(&ray))
+    });
+    let distance = caps
+        .into_iter()
+        .chain(sides.into_iter().flatten())
+        .flatten()
+        .map(|(distance, _)| distance)
+        .fold(T::infinity(), T::min);
+    step_fraction(distance, velocity)
+}
+
+/// Time of impact of a moving circle against a static circle.
+///
+/// Same conventions as [`toi_circle_segment`]: `velocity` is the moving circle's
+/// displacement over the step, and the result the fraction of the step until the
+/// circles first touch.
+///
+/// # Example
+/// ```
+/// use geomengine::collision::toi_circle_circle;
+/// use geomengine::point::{Point2D, Vector2D};
+/// use geomengine::shape::Circle2D;
+///
+/// let ball = Circle2D::new(Point2D::new(0.0, 0.0), 1.0);
+/// let post = Circle2D::new(Point2D::new(6.0, 0.0), 1.0);
+/// assert_eq!(toi_circle_circle(&ball, Vector2D::new(8.0, 0.0), &post), Some(0.5));
+/// ```
+pub fn toi_circle_circle<T: Float + Debug>(circle: &Circle2D<T>, velocity: Vector2D<T>, other: &Circle2D<T>) -> Option<T> {
+    let reach = circle.radius + other.radius;
+    if circle.center.distance(&other.center) <= reach {
+        return Some(T::zero());
+    }
+    let ray = Ray2D::new(circle.center, velocity)?;
+    let (distance, _) = Circle2D::new(other.center, reach).cast_ray(&ray)?;
+    step_fraction(distance, velocity)
+}
+
+/// `distance` travelled along `velocity` as a fraction of the step, if within it.
+fn step_fraction<T: Float + Debug>(distance: T, velocity: Vector2D<T>) -> Option<T> {
+    let t = distance / velocity.magnitude();
+    (t <= T::one()).then_some(t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::point::Point2D;
+    use approx::assert_relative_eq;
+
+    fn circle(x: f64, y: f64, r: f64) -> Circle2D<f64> {
+        Circle2D::new(Point2D::new(x, y), r)
+    }
+
+    #[test]
+    fn test_toi_circle_segment_sides_and_caps() {
+        let wall = Line2D::new(Point2D::new(0.0, 0.0), Point2D::new(4.0, 0.0));
+        // falling flat onto the wall
+        assert_eq!(toi_circle_segment(&circle(2.0, 3.0, 1.0), Vector2D::new(0.0, -4.0), &wall), Some(0.5));
+        // grazing past the end cap at (4, 0)
+        let t = toi_circle_segment(&circle(7.0, 0.5, 1.0), Vector2D::new(-4.0, 0.0), &wall).unwrap();
+        assert_relative_eq!(t, (3.0 - 0.75f64.sqrt()) / 4.0, epsilon = 1e-12);
+        // already touching, standing still, and stopping short
+        assert_eq!(toi_circle_segment(&circle(2.0, 1.0, 1.0), Vector2D::new(0.0, 1.0), &wall), Some(0.0));
+        assert_eq!(toi_circle_segment(&circle(2.0, 3.0, 1.0), Vector2D::new(0.0, 0.0), &wall), None);
+        assert_eq!(toi_circle_segment(&circle(2.0, 3.0, 1.0), Vector2D::new(0.0, -1.0), &wall), None);
+    }
+
+    #[test]
+    fn test_toi_circle_segment_degenerate_wall_and_circles() {
+        let spike = Line2D::new(Point2D::new(5.0, 0.0), Point2D::new(5.0, 0.0));
+        assert_eq!(toi_circle_segment(&circle(0.0, 0.0, 1.0), Vector2D::new(8.0, 0.0), &spike), Some(0.5));
+
+        assert_eq!(toi_circle_circle(&circle(0.0, 0.0, 1.0), Vector2D::new(0.0, 10.0), &circle(5.0, 5.0, 1.0)), None);
+        assert_eq!(toi_circle_circle(&circle(0.0, 0.0, 1.0), Vector2D::new(0.0, 1.0), &circle(1.5, 0.0, 1.0)), Some(0.0));
+    }
+}
@@ -0,0 +1,6 @@
+// module exports
+mod ccd;
+mod sweep;
+
+pub use ccd::{toi_circle_circle, toi_circle_segment};
+pub use sweep::sweep_aabb;
@@ -0,0 +1,353 @@
+use num::Float;
+use core::fmt::{self, Debug, Display};
+use crate::point::Point2D;
+use super::sphere::{to_unit_vector, to_lon_lat};
+
+/// Number of subdivision levels below the six cube faces.
+const MAX_LEVEL: u8 = 30;
+const POS_BITS: u32 = 2 * MAX_LEVEL as u32 + 1;
+
+/// Identifier of a cell in a hierarchical decomposition of the sphere.
+///
+/// The sphere is projected onto the six faces of a cube, and every face is
+/// recursively split into four children down to level 30 (cells of roughly a
+/// centimetre). The layout follows Google's S2 scheme: the three high bits hold
+/// the face, followed by two bits per level and a trailing marker bit, so
+///
+/// * all descendants of a cell form one contiguous range of ids
+///   (see [`CellId::range_min`] / [`CellId::range_max`]), and
+/// * sorting ids keeps nearby cells close together.
+///
+/// Unlike S2, children are ordered along a Z-order (Morton) curve rather than a
+/// Hilbert curve, which keeps the bit manipulation easy to follow.
+///
+/// # Example
+/// ```
+/// use geomengine::geodesic::CellId;
+/// use geomengine::point::Point2D;
+///
+/// let leaf = CellId::from_lon_lat(&Point2D::new(85.32, 27.71), 30);
+/// let bucket = leaf.parent_at(10);
+/// assert!(bucket.contains(leaf));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CellId(u64);
+
+impl CellId {
+    pub const MAX_LEVEL: u8 = MAX_LEVEL;
+
+    /// Returns the level-0 cell covering a whole cube face (`0..6`).
+    pub fn from_face(face: u8) -> Self {
+        assert!(face < 6, "cube face must be in 0..6");
+        CellId(((face as u64) << POS_BITS) | (1u64 << (POS_BITS - 1)))
+    }
+
+    /// Returns the cell at `level` containing a `(lon, lat)` point given in degrees.
+    pub fn from_lon_lat<T: Float + Debug>(point: &Point2D<T>, level: u8) -> Self {
+        let (face, u, v) = xyz_to_face_uv(to_unit_vector(point));
+        let i = st_to_ij(uv_to_st(u));
+        let j = st_to_ij(uv_to_st(v));
+        Self::from_face_ij(face, i, j).parent_at(level)
+    }
+
+    /// Wraps a raw id, returning `None` if it does not encode a valid cell.
+    pub fn from_raw(id: u64) -> Option<Self> {
+        let cell = CellId(id);
+        let zeros = id.trailing_zeros();
+        if (id >> POS_BITS) < 6 && zeros <= 2 * MAX_LEVEL as u32 && zeros.is_multiple_of(2) {
+            Some(cell)
+        } else {
+            None
+        }
+    }
+
+    pub fn raw(&self) -> u64 {
+        self.0
+    }
+
+    pub fn face(&self) -> u8 {
+        (self.0 >> POS_BITS) as u8
+    }
+
+    pub fn level(&self) -> u8 {
+        MAX_LEVEL - (self.0.trailing_zeros() / 2) as u8
+    }
+
+    pub fn is_leaf(&self) -> bool {
+        self.0 & 1 == 1
+    }
+
+    /// Returns the immediate parent, or `None` for a face cell.
+    pub fn parent(&self) -> Option<Self> {
+        match self.level() {
+            0 => None,
+            level => Some(self.parent_at(level - 1)),
+        }
+    }
+
+    /// Returns the ancestor at `level` (or `self` when `level` is not coarser).
+    pub fn parent_at(&self, level: u8) -> Self {
+        if level >= self.level() {
+            return *self;
+        }
+        let lsb = lsb_for_level(level);
+        CellId((self.0 & lsb.wrapping_neg()) | lsb)
+    }
+
+    /// Returns the four children in Z-order, or `None` for a leaf cell.
+    pub fn children(&self) -> Option<[CellId; 4]> {
+        if self.is_leaf() {
+            return None;
+        }
+        let lsb = self.lsb();
+        let first = self.0 - lsb + (lsb >> 2);
+        let step = lsb >> 1;
+        Some([
+            CellId(first),
+            CellId(first + step),
+            CellId(first + 2 * step),
+            CellId(first + 3 * step),
+        ])
+    }
+
+    /// Smallest leaf id contained in this cell.
+    pub fn range_min(&self) -> CellId {
+        CellId(self.0 - (self.lsb() - 1))
+    }
+
+    /// Largest leaf id contained in this cell.
+    pub fn range_max(&self) -> CellId {
+        CellId(self.0 + (self.lsb() - 1))
+    }
+
+    /// Returns true if `other` is this cell or one of its descendants.
+    pub fn contains(&self, other: CellId) -> bool {
+        other >= self.range_min() && other <= self.range_max()
+    }
+
+    /// Returns true if the two cells share at least one leaf.
+    pub fn intersects(&self, other: CellId) -> bool {
+        other.range_min() <= self.range_max() && other.range_max() >= self.range_min()
+    }
+
+    /// Center of the cell as `(lon, lat)` in degrees.
+    pub fn center(&self) -> Point2D<f64> {
+        to_lon_lat(self.center_vector())
+    }
+
+    /// Corners of the cell as `(lon, lat)` in degrees, counterclockwise in face coordinates.
+    pub fn corners(&self) -> [Point2D<f64>; 4] {
+        self.corner_vectors().map(to_lon_lat)
+    }
+
+    pub(crate) fn center_vector(&self) -> [f64; 3] {
+        let (face, i, j, size) = self.face_ij_size();
+        let half = size as f64 / 2.0;
+        face_ij_to_xyz(face, i as f64 + half, j as f64 + half)
+    }
+
+    pub(crate) fn corner_vectors(&self) -> [[f64; 3]; 4] {
+        let (face, i, j, size) = self.face_ij_size();
+        let (i0, j0) = (i as f64, j as f64);
+        let (i1, j1) = ((i + size) as f64, (j + size) as f64);
+        [
+            face_ij_to_xyz(face, i0, j0),
+            face_ij_to_xyz(face, i1, j0),
+            face_ij_to_xyz(face, i1, j1),
+            face_ij_to_xyz(face, i0, j1),
+        ]
+    }
+
+    fn lsb(&self) -> u64 {
+        self.0 & self.0.wrapping_neg()
+    }
+
+    fn from_face_ij(face: u8, i: u32, j: u32) -> Self {
+        let mut pos = 0u64;
+        for bit in (0..MAX_LEVEL as u32).rev() {
+            let digit = (((i >> bit) & 1) << 1 | ((j >> bit) & 1)) as u64;
+            pos = (pos << 2) | digit;
+        }
+        CellId(((face as u64) << POS_BITS) | (pos << 1) | 1)
+    }
+
+    /// Decodes the face, the leaf coordinates of the lower-left corner and the edge length in leaves.
+    fn face_ij_size(&self) -> (u8, u32, u32, u32) {
+        let pos = (self.range_min().0 & ((1u64 << POS_BITS) - 1)) >> 1;
+        let (mut i, mut j) = (0u32, 0u32);
+        for bit in (0..MAX_LEVEL as u32).rev() {
+            let digit = (pos >> (2 * bit)) & 3;
+            i |= ((digit >> 1) as u32) << bit;
+            j |= ((digit & 1) as u32) << bit;
+        }
+        let size = 1u32 << (MAX_LEVEL - self.level());
+        (self.face(), i, j, size)
+    }
+}
+
+impl Display for CellId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/", self.face())?;
+        let (_, i, j, _) = self.face_ij_size();
+        for level in 1..=self.level() {
+            let bit = MAX_LEVEL - level;
+            write!(f, "{}", ((i >> bit) & 1) << 1 | ((j >> bit) & 1))?;
+        }
+        Ok(())
+    }
+}
+
+fn lsb_for_level(level: u8) -> u64 {
+    1u64 << (2 * (MAX_LEVEL - level) as u32)
+}
+
+fn xyz_to_face_uv(v: [f64; 3]) -> (u8, f64, f64) {
+    let [x, y, z] = v;
+    let axis = if x.abs() >= y.abs() && x.abs() >= z.abs() {
+        0
+    } else if y.abs() >= z.abs() {
+        1
+    } else {
+        2
+    };
+    let face = if v[axis] < 0.0 { axis + 3 } else { axis };
+    let (u, v) = match face {
+        0 => (y / x, z / x),
+        1 => (-x / y, z / y),
+        2 => (-x / z, -y / z),
+        3 => (z / x, y / x),
+        4 => (z / y, -x / y),
+        _ => (-y / z, -x / z),
+    };
+    (face as u8, u, v)
+}
+
+fn face_uv_to_xyz(face: u8, u: f64, v: f64) -> [f64; 3] {
+    match face {
+        0 => [1.0, u, v],
+        1 => [-u, 1.0, v],
+        2 => [-u, -v, 1.0],
+        3 => [-1.0, -v, -u],
+        4 => [v, -1.0, -u],
+        _ => [v, u, -1.0],
+    }
+}
+
+/// Quadratic projection from face coordinates `u in [-1, 1]` to `s in [0, 1]`,
+/// which keeps cell areas far more uniform than a linear mapping.
+fn uv_to_st(u: f64) -> f64 {
+    if u >= 0.0 {
+        0.5 * (1.0 + 3.0 * u).sqrt()
+    } else {
+        1.0 - 0.5 * (1.0 - 3.0 * u).sqrt()
+    }
+}
+
+fn st_to_uv(s: f64) -> f64 {
+    if s >= 0.5 {
+        (4.0 * s * s - 1.0) / 3.0
+    } else {
+        (1.0 - 4.0 * (1.0 - s) * (1.0 - s)) / 3.0
+    }
+}
+
+fn st_to_ij(s: f64) -> u32 {
+    let max = (1u32 << MAX_LEVEL) - 1;
+    ((s * (1u64 << MAX_LEVEL) as f64).floor().max(0.0) as u32).min(max)
+}
+
+fn face_ij_to_xyz(face: u8, i: f64, j: f64) -> [f64; 3] {
+    let scale = (1u64 << MAX_LEVEL) as f64;
+    face_uv_to_xyz(face, st_to_uv(i / scale), st_to_uv(j / scale))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_cell_id_face_cells() {
+        for face in 0..6 {
+            let cell = CellId::from_face(face);
+            assert_eq!(cell.face(), face);
+            assert_eq!(cell.level(), 0);
+            assert!(cell.parent().is_none());
+            assert_eq!(CellId::from_raw(cell.raw()), Some(cell));
+        }
+    }
+
+    #[test]
+    fn test_cell_id_from_lon_lat_faces() {
+        assert_eq!(CellId::from_lon_lat(&Point2D::new(0.0, 0.0), 0).face(), 0);
+        assert_eq!(CellId::from_lon_lat(&Point2D::new(90.0, 0.0), 0).face(), 1);
+        assert_eq!(CellId::from_lon_lat(&Point2D::new(0.0, 90.0), 0).face(), 2);
+        assert_eq!(CellId::from_lon_lat(&Point2D::new(180.0, 0.0), 0).face(), 3);
+        assert_eq!(CellId::from_lon_lat(&Point2D::new(-90.0, 0.0), 0).face(), 4);
+        assert_eq!(CellId::from_lon_lat(&Point2D::new(0.0, -90.0), 0).face(), 5);
+    }
+
+    #[test]
+    fn test_cell_id_levels_and_parents() {
+        let leaf = CellId::from_lon_lat(&Point2D::new(-122.4, 37.8), 30);
+        assert!(leaf.is_leaf());
+        assert_eq!(leaf.level(), 30);
+
+        let mut cell = leaf;
+        for level in (0..30).rev() {
+            let parent = cell.parent().unwrap();
+            assert_eq!(parent.level(), level);
+            assert!(parent.contains(cell));
+            assert!(parent.contains(leaf));
+            cell = parent;
+        }
+        assert_eq!(cell, CellId::from_face(leaf.face()));
+    }
+
+    #[test]
+    fn test_cell_id_children_partition_parent() {
+        let cell = CellId::from_lon_lat(&Point2D::new(10.0, 50.0), 5);
+        let children = cell.children().unwrap();
+
+        assert_eq!(children[0].range_min(), cell.range_min());
+        assert_eq!(children[3].range_max(), cell.range_max());
+        for pair in children.windows(2) {
+            assert_eq!(pair[0].range_max().raw() + 2, pair[1].range_min().raw());
+        }
+        for child in children {
+            assert_eq!(child.level(), 6);
+            assert_eq!(child.parent(), Some(cell));
+        }
+    }
+
+    #[test]
+    fn test_cell_id_leaf_has_no_children() {
+        let leaf = CellId::from_lon_lat(&Point2D::new(1.0, 1.0), 30);
+        assert!(leaf.children().is_none());
+    }
+
+    #[test]
+    fn test_cell_id_center_round_trip() {
+        let point = Point2D::new(85.3240, 27.7172);
+        let cell = CellId::from_lon_lat(&point, 30);
+        let center = cell.center();
+
+        assert_relative_eq!(center.x, point.x, epsilon = 1e-6);
+        assert_relative_eq!(center.y, point.y, epsilon = 1e-6);
+        assert_eq!(CellId::from_lon_lat(&center, 30), cell);
+    }
+
+    #[test]
+    fn test_cell_id_from_raw_rejects_invalid() {
+        assert!(CellId::from_raw(0).is_none());
+        assert!(CellId::from_raw(7u64 << 61 | 1).is_none());
+        assert!(CellId::from_raw(0b10).is_none());
+        assert!(CellId::from_raw(1u64 << 62).is_none());
+    }
+
+    #[test]
+    fn test_cell_id_display() {
+        let cell = CellId::from_face(2).children().unwrap()[3].children().unwrap()[1];
+        assert_eq!(cell.to_string(), "2/31");
+    }
+}
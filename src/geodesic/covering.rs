@@ -0,0 +1,212 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use super::cell_id::CellId;
+use super::sphere::{to_unit_vector, to_lon_lat, angle_between};
+use alloc::vec::Vec;
+
+/// Computes a set of cells covering a spherical cap.
+///
+/// # Arguments
+/// * `center` - Center of the cap as `(lon, lat)` in degrees.
+/// * `radius` - Angular radius of the cap in radians (divide a ground distance by
+///   [`EARTH_RADIUS_METERS`](super::EARTH_RADIUS_METERS) to get it).
+/// * `max_level` - Finest level the covering may use.
+///
+/// # Returns
+/// * Cells sorted by id whose union contains the whole cap. Cells fully inside the
+///   cap are kept as coarse as possible; only the boundary is refined to `max_level`.
+///
+/// The covering is conservative: every point of the cap is covered, but a thin band
+/// outside of it may be covered as well. Range-query a dataset bucketed by leaf ids
+/// with [`CellId::range_min`] / [`CellId::range_max`] of each returned cell.
+pub fn cover_cap<T: Float + Debug>(center: &Point2D<T>, radius: T, max_level: u8) -> Vec<CellId> {
+    let axis = to_unit_vector(center);
+    let radius = radius.to_f64().unwrap();
+    cover(max_level, |cell| {
+        let (cell_center, cell_radius) = bounding_cap(cell);
+        let distance = angle_between(axis, cell_center);
+        if distance + cell_radius <= radius {
+            Relation::Contained
+        } else if distance <= cell_radius + radius {
+            Relation::Intersects
+        } else {
+            Relation::Disjoint
+        }
+    })
+}
+
+/// Computes a set of cells covering a longitude/latitude box.
+///
+/// # Arguments
+/// * `min` - South-west corner as `(lon, lat)` in degrees.
+/// * `max` - North-east corner as `(lon, lat)` in degrees; boxes crossing the
+///   antimeridian are expressed with `max.x > 180`.
+/// * `max_level` - Finest level the covering may use.
+///
+/// # Returns
+/// * Cells sorted by id whose union contains the whole box. As with [`cover_cap`]
+///   the covering may extend slightly past the box boundary.
+pub fn cover_bbox<T: Float + Debug>(min: &Point2D<T>, max: &Point2D<T>, max_level: u8) -> Vec<CellId> {
+    let (lon_lo, lat_lo) = (min.x.to_f64().unwrap(), min.y.to_f64().unwrap());
+    let (lon_hi, lat_hi) = (max.x.to_f64().unwrap(), max.y.to_f64().unwrap());
+    cover(max_level, |cell| {
+        let (cell_lat, cell_lon) = cap_lon_lat_bounds(bounding_cap(cell));
+        if cell_lat.1 < lat_lo || cell_lat.0 > lat_hi {
+            return Relation::Disjoint;
+        }
+        let lat_inside = cell_lat.0 >= lat_lo && cell_lat.1 <= lat_hi;
+        let mut relation = Relation::Disjoint;
+        for shift in [-360.0, 0.0, 360.0] {
+            let (lo, hi) = (lon_lo + shift, lon_hi + shift);
+            if cell_lon.1 < lo || cell_lon.0 > hi {
+                continue;
+            }
+            if lat_inside && cell_lon.0 >= lo && cell_lon.1 <= hi {
+                return Relation::Contained;
+            }
+            relation = Relation::Intersects;
+        }
+        relation
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Relation {
+    Disjoint,
+    Intersects,
+    Contained,
+}
+
+fn cover<F: Fn(CellId) -> Relation>(max_level: u8, relate: F) -> Vec<CellId> {
+    let mut cells = Vec::new();
+    for face in 0..6 {
+        visit(CellId::from_face(face), max_level.min(CellId::MAX_LEVEL), &relate, &mut cells);
+    }
+    cells
+}
+
+fn visit<F: Fn(CellId) -> Relation>(cell: CellId, max_level: u8, relate: &F, cells: &mut Vec<CellId>) {
+    match relate(cell) {
+        Relation::Disjoint => {}
+        Relation::Contained => cells.push(cell),
+        Relation::Intersects if cell.level() >= max_level => cells.push(cell),
+        Relation::Intersects => {
+            let before = cells.len();
+            let children = cell.children().unwrap();
+            for child in children {
+                visit(child, max_level, relate, cells);
+            }
+            // four complete siblings are replaced by their parent
+            if cells.len() == before + 4 && cells[before..] == children {
+                cells.truncate(before);
+                cells.push(cell);
+            }
+        }
+    }
+}
+
+/// Smallest cap centered on the cell center containing all four corners.
+///
+/// Cell edges are great-circle arcs, so the cell is the spherical convex hull of its
+/// corners and therefore lies entirely inside this cap.
+fn bounding_cap(cell: CellId) -> ([f64; 3], f64) {
+    let center = cell.center_vector();
+    let radius = cell
+        .corner_vectors()
+        .iter()
+        .map(|&corner| angle_between(center, corner))
+        .fold(0.0, f64::max);
+    (center, radius * (1.0 + 1e-9))
+}
+
+/// Latitude and longitude ranges in degrees containing a cap.
+fn cap_lon_lat_bounds((axis, radius): ([f64; 3], f64)) -> ((f64, f64), (f64, f64)) {
+    let center = to_lon_lat(axis);
+    let radius_deg = radius.to_degrees();
+    let lat = (center.y - radius_deg, center.y + radius_deg);
+    if lat.0 <= -90.0 || lat.1 >= 90.0 {
+        return ((lat.0.max(-90.0), lat.1.min(90.0)), (-180.0, 180.0));
+    }
+    let ratio = radius.sin() / center.y.to_radians().cos();
+    if ratio >= 1.0 {
+        return (lat, (-180.0, 180.0));
+    }
+    let half_width = ratio.asin().to_degrees();
+    (lat, (center.x - half_width, center.x + half_width))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_covered(cells: &[CellId], point: &Point2D<f64>) -> bool {
+        let leaf = CellId::from_lon_lat(point, CellId::MAX_LEVEL);
+        cells.iter().any(|cell| cell.contains(leaf))
+    }
+
+    #[test]
+    fn test_cover_cap_contains_cap_points() {
+        let center = Point2D::new(85.32, 27.71);
+        let radius = 0.002;
+        let cells = cover_cap(&center, radius, 12);
+
+        assert!(!cells.is_empty());
+        assert!(cells.iter().all(|cell| cell.level() <= 12));
+        for step in 0..36 {
+            let bearing = (step as f64 * 10.0).to_radians();
+            let offset = (radius * 0.99).to_degrees();
+            let point = Point2D::new(
+                center.x + offset * bearing.cos() / center.y.to_radians().cos(),
+                center.y + offset * bearing.sin(),
+            );
+            assert!(is_covered(&cells, &point), "point {:?} not covered", point);
+        }
+    }
+
+    #[test]
+    fn test_cover_cap_excludes_far_points() {
+        let cells = cover_cap(&Point2D::new(0.0, 0.0), 0.001, 14);
+        assert!(!is_covered(&cells, &Point2D::new(1.0, 1.0)));
+        assert!(!is_covered(&cells, &Point2D::new(180.0, 0.0)));
+    }
+
+    #[test]
+    fn test_cover_cap_whole_sphere_is_six_faces() {
+        let cells = cover_cap(&Point2D::new(0.0, 0.0), core::f64::consts::PI, 10);
+        assert_eq!(cells, (0..6).map(CellId::from_face).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_cover_bbox_contains_box_points() {
+        let min = Point2D::new(-74.05, 40.68);
+        let max = Point2D::new(-73.90, 40.88);
+        let cells = cover_bbox(&min, &max, 10);
+
+        assert!(!cells.is_empty());
+        for i in 0..=10 {
+            for j in 0..=10 {
+                let point = Point2D::new(
+                    min.x + (max.x - min.x) * i as f64 / 10.0,
+                    min.y + (max.y - min.y) * j as f64 / 10.0,
+                );
+                assert!(is_covered(&cells, &point));
+            }
+        }
+        assert!(!is_covered(&cells, &Point2D::new(2.35, 48.85)));
+    }
+
+    #[test]
+    fn test_cover_bbox_across_antimeridian() {
+        let cells = cover_bbox(&Point2D::new(179.5, -1.0), &Point2D::new(180.5, 1.0), 8);
+        assert!(is_covered(&cells, &Point2D::new(179.8, 0.0)));
+        assert!(is_covered(&cells, &Point2D::new(-179.8, 0.0)));
+        assert!(!is_covered(&cells, &Point2D::new(0.0, 0.0)));
+    }
+
+    #[test]
+    fn test_cover_is_sorted() {
+        let cells = cover_bbox(&Point2D::new(-10.0, -10.0), &Point2D::new(10.0, 10.0), 6);
+        assert!(cells.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
@@ -0,0 +1,10 @@
+// module exports
+mod sphere;
+mod cell_id;
+mod covering;
+mod simplify;
+
+pub use cell_id::CellId;
+pub use covering::{cover_cap, cover_bbox};
+pub use simplify::{simplify_geodesic, simplify_geodesic_into};
+pub use sphere::{EARTH_RADIUS_METERS, great_circle_distance, cross_track_distance};
@@ -0,0 +1,124 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use super::sphere::{to_unit_vector, angle_to_arc, EARTH_RADIUS_METERS};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Simplifies a `(lon, lat)` polyline with the Douglas–Peucker algorithm on the sphere.
+///
+/// # Arguments
+/// * `points` - Vertices of the path as `(lon, lat)` in degrees.
+/// * `tolerance` - Maximum allowed deviation in meters.
+///
+/// # Returns
+/// * The retained vertices, always including the first and last point.
+///
+/// # Why not planar Douglas–Peucker?
+/// Treating degrees as planar coordinates stretches east-west distances by
+/// `1 / cos(lat)`, so a planar tolerance removes far too little near the equator
+/// and far too much near the poles. Here the perpendicular-distance test is the
+/// great-circle cross-track distance, which is the same everywhere on the globe.
+///
+/// # Time Complexity
+/// **O(n log n)** on typical tracks, **O(n²)** in the worst case.
+pub fn simplify_geodesic<T: Float + Debug>(points: &[Point2D<T>], tolerance: T) -> Vec<Point2D<T>> {
+    let mut simplified = Vec::new();
+    simplify_geodesic_into(points, tolerance, &mut simplified);
+    simplified
+}
+
+/// [`simplify_geodesic`] writing into `simplified`, which is cleared first.
+pub fn simplify_geodesic_into<T: Float + Debug>(points: &[Point2D<T>], tolerance: T, simplified: &mut Vec<Point2D<T>>) {
+    simplified.clear();
+    if points.len() < 3 {
+        simplified.extend_from_slice(points);
+        return;
+    }
+
+    let vectors: Vec<[f64; 3]> = points.iter().map(to_unit_vector).collect();
+    let tolerance = tolerance.to_f64().unwrap() / EARTH_RADIUS_METERS;
+
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+
+    // explicit stack instead of recursion so long GPS tracks cannot overflow it
+    let mut ranges = vec![(0, points.len() - 1)];
+    while let Some((first, last)) = ranges.pop() {
+        let mut max_angle = 0.0;
+        let mut max_index = first;
+        for i in first + 1..last {
+            let angle = angle_to_arc(vectors[i], vectors[first], vectors[last]);
+            if angle > max_angle {
+                max_angle = angle;
+                max_index = i;
+            }
+        }
+        if max_angle > tolerance {
+            keep[max_index] = true;
+            ranges.push((first, max_index));
+            ranges.push((max_index, last));
+        }
+    }
+
+    simplified.extend(
+        points
+            .iter()
+            .zip(keep)
+            .filter_map(|(&point, kept)| if kept { Some(point) } else { None }),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simplify_geodesic_short_input_unchanged() {
+        let points = vec![Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0)];
+        assert_eq!(simplify_geodesic(&points, 10.0), points);
+    }
+
+    #[test]
+    fn test_simplify_geodesic_removes_points_on_great_circle() {
+        // points on the equator are on one great circle
+        let points: Vec<_> = (0..=10).map(|i| Point2D::new(i as f64, 0.0)).collect();
+        let simplified = simplify_geodesic(&points, 1.0);
+        assert_eq!(simplified, vec![points[0], points[10]]);
+    }
+
+    #[test]
+    fn test_simplify_geodesic_keeps_significant_corner() {
+        let points = vec![
+            Point2D::new(0.0, 0.0),
+            Point2D::new(0.5, 0.0001),
+            Point2D::new(1.0, 0.0),
+            Point2D::new(1.0, 1.0),
+        ];
+        let simplified = simplify_geodesic(&points, 50.0);
+        assert_eq!(simplified, vec![points[0], points[2], points[3]]);
+    }
+
+    #[test]
+    fn test_simplify_geodesic_parallel_at_high_latitude() {
+        // a parallel is not a great circle: at 80°N the chord between lon 0 and 10
+        // bulges ~4 km poleward, which planar simplification would never notice
+        let points: Vec<_> = (0..=10).map(|i| Point2D::new(i as f64, 80.0)).collect();
+
+        let coarse = simplify_geodesic(&points, 10_000.0);
+        assert_eq!(coarse, vec![points[0], points[10]]);
+
+        let fine = simplify_geodesic(&points, 1_000.0);
+        assert!(fine.len() > 2);
+        assert!(fine.contains(&points[5]));
+    }
+
+    #[test]
+    fn test_simplify_geodesic_into_clears_buffer() {
+        let points: Vec<_> = (0..=10).map(|i| Point2D::new(i as f64, 0.0)).collect();
+        let mut simplified = vec![Point2D::new(99.0, 99.0); 20];
+        simplify_geodesic_into(&points, 1.0, &mut simplified);
+        assert_eq!(simplified, vec![points[0], points[10]]);
+    }
+}
@@ -0,0 +1,116 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Point2D;
+
+/// Mean earth radius in meters, used to turn angular distances into lengths.
+pub const EARTH_RADIUS_METERS: f64 = 6_371_008.8;
+
+/// Converts a `(lon, lat)` point in degrees into a unit vector on the sphere.
+pub(crate) fn to_unit_vector<T: Float + Debug>(point: &Point2D<T>) -> [f64; 3] {
+    let lon = point.x.to_f64().unwrap().to_radians();
+    let lat = point.y.to_f64().unwrap().to_radians();
+    [lat.cos() * lon.cos(), lat.cos() * lon.sin(), lat.sin()]
+}
+
+/// Converts a (not necessarily unit) vector back into a `(lon, lat)` point in degrees.
+pub(crate) fn to_lon_lat(v: [f64; 3]) -> Point2D<f64> {
+    let lon = v[1].atan2(v[0]);
+    let lat = v[2].atan2((v[0] * v[0] + v[1] * v[1]).sqrt());
+    Point2D::new(lon.to_degrees(), lat.to_degrees())
+}
+
+pub(crate) fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+pub(crate) fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+pub(crate) fn norm(a: [f64; 3]) -> f64 {
+    dot(a, a).sqrt()
+}
+
+/// Angle in radians between two vectors; stable for both tiny and near-antipodal angles.
+pub(crate) fn angle_between(a: [f64; 3], b: [f64; 3]) -> f64 {
+    norm(cross(a, b)).atan2(dot(a, b))
+}
+
+/// Angle in radians between `p` and the great-circle arc from `a` to `b`.
+///
+/// When the foot of the perpendicular falls outside the arc the distance to the
+/// nearer endpoint is returned instead, mirroring point-to-segment distance in the plane.
+pub(crate) fn angle_to_arc(p: [f64; 3], a: [f64; 3], b: [f64; 3]) -> f64 {
+    let normal = cross(a, b);
+    let length = norm(normal);
+    if length < 1e-15 {
+        return angle_between(p, a);
+    }
+    let normal = [normal[0] / length, normal[1] / length, normal[2] / length];
+    let offset = dot(p, normal);
+    let foot = [p[0] - offset * normal[0], p[1] - offset * normal[1], p[2] - offset * normal[2]];
+    if dot(cross(a, foot), normal) >= 0.0 && dot(cross(foot, b), normal) >= 0.0 {
+        offset.abs().min(1.0).asin()
+    } else {
+        angle_between(p, a).min(angle_between(p, b))
+    }
+}
+
+/// Great-circle distance in meters between two `(lon, lat)` points given in degrees.
+pub fn great_circle_distance<T: Float + Debug>(a: &Point2D<T>, b: &Point2D<T>) -> T {
+    let angle = angle_between(to_unit_vector(a), to_unit_vector(b));
+    T::from(angle * EARTH_RADIUS_METERS).unwrap()
+}
+
+/// Cross-track distance in meters from `point` to the great-circle arc `start`-`end`.
+///
+/// All points are `(lon, lat)` in degrees. Points whose perpendicular foot lies beyond
+/// either end of the arc are measured to the nearer endpoint.
+pub fn cross_track_distance<T: Float + Debug>(point: &Point2D<T>, start: &Point2D<T>, end: &Point2D<T>) -> T {
+    let angle = angle_to_arc(to_unit_vector(point), to_unit_vector(start), to_unit_vector(end));
+    T::from(angle * EARTH_RADIUS_METERS).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_unit_vector_round_trip() {
+        let p = Point2D::new(-73.5, 45.25);
+        let back = to_lon_lat(to_unit_vector(&p));
+        assert_relative_eq!(back.x, p.x, epsilon = 1e-9);
+        assert_relative_eq!(back.y, p.y, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_great_circle_distance_one_degree_on_equator() {
+        let d = great_circle_distance(&Point2D::new(0.0, 0.0), &Point2D::new(1.0, 0.0));
+        assert_relative_eq!(d, EARTH_RADIUS_METERS.to_radians(), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_cross_track_distance_perpendicular_and_beyond_ends() {
+        let start = Point2D::new(0.0, 0.0);
+        let end = Point2D::new(10.0, 0.0);
+
+        let d = cross_track_distance(&Point2D::new(5.0, 1.0), &start, &end);
+        assert_relative_eq!(d, EARTH_RADIUS_METERS.to_radians(), epsilon = 1e-6);
+
+        let beyond = Point2D::new(12.0, 0.0);
+        let d = cross_track_distance(&beyond, &start, &end);
+        assert_relative_eq!(d, great_circle_distance(&beyond, &end), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_angle_between_quarter_turn() {
+        let a = to_unit_vector(&Point2D::new(0.0, 0.0));
+        let b = to_unit_vector(&Point2D::new(90.0, 0.0));
+        assert_relative_eq!(angle_between(a, b), core::f64::consts::FRAC_PI_2, epsilon = 1e-12);
+    }
+}
@@ -0,0 +1,211 @@
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::Path;
+use crate::viz::{Layer, render_svg};
+
+/// Width of every snapshot in the HTML viewer, in pixels.
+const VIEWER_WIDTH: f64 = 800.0;
+
+/// Collects named snapshots of geometries while an algorithm runs, for stepping
+/// through them afterwards instead of reading printed coordinates.
+///
+/// Take a snapshot at every interesting step, then write an HTML viewer showing
+/// one step at a time (arrow keys or buttons to move), or export everything as
+/// GeoJSON for a GIS tool.
+///
+/// # Example
+/// ```no_run
+/// use geomengine::algorithms::noding::node_segments_2d;
+/// use geomengine::inspect::Inspector;
+/// use geomengine::line::Line2D;
+/// use geomengine::point::Point2D;
+/// use geomengine::viz::Layer;
+///
+/// let segments = [
+///     Line2D::new(Point2D::new(0.0, 0.0), Point2D::new(2.0, 2.0)),
+///     Line2D::new(Point2D::new(0.0, 2.0), Point2D::new(2.0, 0.0)),
+/// ];
+/// let mut inspector = Inspector::new();
+/// inspector.snapshot("input", vec![Layer::segments(&segments)]);
+/// let noded = node_segments_2d(&segments);
+/// inspector.snapshot("noded", vec![Layer::segments(&noded), Layer::points(&[noded[0].p2]).color("black")]);
+/// inspector.write_html("noding.html")?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Inspector {
+    snapshots: Vec<(String, Vec<Layer>)>,
+}
+
+impl Inspector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records `layers` as the next step, under `name`.
+    pub fn snapshot(&mut self, name: &str, layers: Vec<Layer>) {
+        self.snapshots.push((name.to_string(), layers));
+    }
+
+    /// Number of snapshots taken.
+    pub fn len(&self) -> usize {
+        self.snapshots.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.snapshots.is_empty()
+    }
+
+    /// A standalone HTML page showing the snapshots one at a time.
+    pub fn to_html(&self) -> String {
+        let mut html = String::from(concat!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>geomengine inspector</title>\n",
+            "<style>body { font-family: sans-serif; } section { display: none; } section.current { display: block; }</style>\n",
+            "</head>\n<body>\n",
+            "<p><button onclick=\"step(-1)\">&larr; previous</button> <button onclick=\"step(1)\">next &rarr;</button> <span id=\"position\"></span></p>\n",
+        ));
+        for (index, (name, layers)) in self.snapshots.iter().enumerate() {
+            writeln!(html, "<section id=\"snapshot-{index}\">\n<h2>{}</h2>", escape(name)).unwrap();
+            html.push_str(&render_svg(layers, VIEWER_WIDTH));
+            html.push_str("</section>\n");
+        }
+        writeln!(
+            html,
+            concat!(
+                "<script>\n",
+                "const count = {};\n",
+                "let current = 0;\n",
+                "function step(delta) {{\n",
+                "  if (count === 0) return;\n",
+                "  document.getElementById('snapshot-' + current).classList.remove('current');\n",
+                "  current = Math.min(Math.max(current + delta, 0), count - 1);\n",
+                "  document.getElementById('snapshot-' + current).classList.add('current');\n",
+                "  document.getElementById('position').textContent = (current + 1) + ' / ' + count;\n",
+                "}}\n",
+                "document.addEventListener('keydown', e => {{ if (e.key === 'ArrowLeft') step(-1); if (e.key === 'ArrowRight') step(1); }});\n",
+                "step(0);\n",
+                "</script>\n</body>\n</html>",
+            ),
+            self.snapshots.len()
+        )
+        .unwrap();
+        html
+    }
+
+    /// Writes [`to_html`](Self::to_html) to `path`.
+    pub fn write_html(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.to_html())
+    }
+
+    /// All snapshots as one GeoJSON `FeatureCollection`, with one feature per
+    /// kind of geometry in each layer. Every feature has `snapshot` and `layer`
+    /// properties naming the step and numbering the layer within it.
+    ///
+    /// JSON has no NaN or infinity, so positions with a non-finite coordinate
+    /// are left out.
+    pub fn to_geojson(&self) -> String {
+        let mut features = Vec::new();
+        for (name, layers) in &self.snapshots {
+            for (index, layer) in layers.iter().enumerate() {
+                let properties = format!(r#""properties":{{"snapshot":"{}","layer":{index}}}"#, escape_json(name));
+                let mut geometries = Vec::new();
+                if !layer.points.is_empty() {
+                    geometries.push(format!(r#"{{"type":"MultiPoint","coordinates":{}}}"#, positions(&layer.points)));
+                }
+                if !layer.paths.is_empty() {
+                    let lines: Vec<String> = layer.paths.iter().map(|path| positions(path)).collect();
+                    geometries.push(format!(r#"{{"type":"MultiLineString","coordinates":[{}]}}"#, lines.join(",")));
+                }
+                if !layer.rings.is_empty() {
+                    let polygons: Vec<String> = layer
+                        .rings
+                        .iter()
+                        .map(|ring| {
+                            // GeoJSON rings repeat their first position at the end
+                            let mut closed = ring.clone();
+                            closed.extend(ring.first().copied());
+                            format!("[{}]", positions(&closed))
+                        })
+                        .collect();
+                    geometries.push(format!(r#"{{"type":"MultiPolygon","coordinates":[{}]}}"#, polygons.join(",")));
+                }
+                for geometry in geometries {
+                    features.push(format!(r#"{{"type":"Feature","geometry":{geometry},{properties}}}"#));
+                }
+            }
+        }
+        format!(r#"{{"type":"FeatureCollection","features":[{}]}}"#, features.join(","))
+    }
+}
+
+fn positions(points: &[(f64, f64)]) -> String {
+    let positions: Vec<String> = points
+        .iter()
+        .filter(|(x, y)| x.is_finite() && y.is_finite())
+        .map(|(x, y)| format!("[{x},{y}]"))
+        .collect();
+    format!("[{}]", positions.join(","))
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+fn escape_json(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if (c as u32) < 0x20 => write!(escaped, "\\u{:04x}", c as u32).unwrap(),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::point::Point2D;
+    use crate::polygon::Polygon2D;
+
+    fn triangle() -> Polygon2D<f64> {
+        Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(1.0, 0.0), Point2D::new(0.0, 1.0)])
+    }
+
+    #[test]
+    fn test_inspector_html_has_one_section_per_snapshot() {
+        let mut inspector = Inspector::new();
+        assert!(inspector.is_empty());
+        inspector.snapshot("input <raw>", vec![Layer::points(&[Point2D::new(0.5, 0.5)])]);
+        inspector.snapshot("result", vec![Layer::polygon(&triangle())]);
+        assert_eq!(inspector.len(), 2);
+        let html = inspector.to_html();
+        assert_eq!(html.matches("<section").count(), 2);
+        assert_eq!(html.matches("<svg").count(), 2);
+        assert!(html.contains("<h2>input &lt;raw&gt;</h2>"));
+        assert!(html.contains("const count = 2;"));
+        assert!(html.ends_with("</html>\n"));
+    }
+
+    #[test]
+    fn test_inspector_geojson() {
+        let mut inspector = Inspector::new();
+        inspector.snapshot("step \"1\"", vec![Layer::points(&[Point2D::new(0.5, 2.0)]), Layer::polygon(&triangle())]);
+        assert_eq!(
+            inspector.to_geojson(),
+            concat!(
+                r#"{"type":"FeatureCollection","features":["#,
+                r#"{"type":"Feature","geometry":{"type":"MultiPoint","coordinates":[[0.5,2]]},"properties":{"snapshot":"step \"1\"","layer":0}},"#,
+                r#"{"type":"Feature","geometry":{"type":"MultiPolygon","coordinates":[[[[0,0],[1,0],[0,1],[0,0]]]]},"properties":{"snapshot":"step \"1\"","layer":1}}"#,
+                "]}",
+            )
+        );
+        assert_eq!(Inspector::new().to_geojson(), r#"{"type":"FeatureCollection","features":[]}"#);
+
+        let mut inspector = Inspector::new();
+        inspector.snapshot("bad", vec![Layer::points(&[Point2D::new(f64::NAN, 0.0), Point2D::new(1.0, f64::INFINITY), Point2D::new(1.0, 2.0)])]);
+        assert!(inspector.to_geojson().contains(r#""coordinates":[[1,2]]"#));
+    }
+}
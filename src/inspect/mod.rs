@@ -0,0 +1,4 @@
+// module exports
+mod inspector;
+
+pub use inspector::Inspector;
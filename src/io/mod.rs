@@ -0,0 +1,4 @@
+// module exports
+mod point_file;
+
+pub use point_file::{Layout, PointFile};
@@ -0,0 +1,244 @@
+use std::fs::File;
+use std::io;
+use std::marker::PhantomData;
+use std::mem::size_of;
+use std::path::Path;
+use crate::algorithms::batch::BatchFloat;
+use crate::point::Point2D;
+
+/// Arrangement of the coordinates in a binary point file.
+///
+/// Values are stored in native byte order, without a header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    /// `x0, y0, x1, y1, ...` (array of structs); readable as a `&[Point2D<T>]`.
+    Interleaved,
+    /// All x coordinates followed by all y coordinates (struct of arrays).
+    Planar,
+}
+
+/// A binary point file, mapped into memory or read into it.
+///
+/// `open` (with the `mmap` feature) maps the file without reading it: pages are
+/// loaded by the OS when they are first touched and can be evicted again under
+/// memory pressure, so tiles much larger than RAM can be processed.
+/// [`read`](Self::read) always copies the file, and is safe to call.
+///
+/// # Example
+/// ```no_run
+/// use geomengine::algorithms::point_algorithms::bounding_box_2d;
+/// use geomengine::io::{Layout, PointFile};
+///
+/// let tile = PointFile::<f32>::read("tile.bin", Layout::Interleaved)?;
+/// let bounds = bounding_box_2d(tile.as_slice().unwrap());
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct PointFile<T: BatchFloat> {
+    data: Storage,
+    layout: Layout,
+    len: usize,
+    marker: PhantomData<T>,
+}
+
+impl<T: BatchFloat> PointFile<T> {
+    /// Maps the file at `path` read-only.
+    ///
+    /// Fails with [`io::ErrorKind::InvalidData`] if the file size is not a whole
+    /// number of points.
+    ///
+    /// # Safety
+    /// The file must not be truncated or modified, by this or any other process,
+    /// while the returned `PointFile` is alive. Reading a mapped page past the
+    /// new end of a truncated file raises `SIGBUS`, and changed contents may or
+    /// may not show through the slices already handed out. Use
+    /// [`read`](Self::read) for files that can change.
+    #[cfg(feature = "mmap")]
+    pub unsafe fn open(path: impl AsRef<Path>, layout: Layout) -> io::Result<Self> {
+        let file = File::open(path)?;
+        let bytes = point_bytes::<T>(&file)?;
+        let data = if bytes == 0 {
+            // an empty map has no aligned address to build slices from
+            Storage::Copied(Vec::new())
+        } else {
+            // SAFETY: upheld by the caller, see above.
+            Storage::Mapped(unsafe { memmap2::MmapOptions::new().len(bytes).map(&file)? })
+        };
+        Ok(PointFile { data, layout, len: bytes / (2 * size_of::<T>()), marker: PhantomData })
+    }
+
+    /// Reads the whole file at `path` into memory.
+    ///
+    /// Fails with [`io::ErrorKind::InvalidData`] if the file size is not a whole
+    /// number of points.
+    pub fn read(path: impl AsRef<Path>, layout: Layout) -> io::Result<Self> {
+        let file = File::open(path)?;
+        let bytes = point_bytes::<T>(&file)?;
+        let data = Storage::Copied(read_words(&file, bytes)?);
+        Ok(PointFile { data, layout, len: bytes / (2 * size_of::<T>()), marker: PhantomData })
+    }
+
+    /// Number of points in the file.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the file holds no points.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The layout the file was opened with.
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    /// The points as a slice, without copying; `None` for [`Layout::Planar`] files.
+    pub fn as_slice(&self) -> Option<&[Point2D<T>]> {
+        match self.layout {
+            // SAFETY: the data is page aligned (or a Vec<u64>), holds `len` points
+            // worth of initialized bytes, and `Point2D<T>` is `repr(C)` with two
+            // `T` fields; every bit pattern is a valid f32/f64.
+            Layout::Interleaved => Some(unsafe { std::slice::from_raw_parts(self.data.ptr() as *const Point2D<T>, self.len) }),
+            Layout::Planar => None,
+        }
+    }
+
+    /// The x and y coordinates as two slices, without copying; `None` for
+    /// [`Layout::Interleaved`] files.
+    pub fn planes(&self) -> Option<(&[T], &[T])> {
+        match self.layout {
+            Layout::Interleaved => None,
+            Layout::Planar => {
+                // SAFETY: as in `as_slice`, the data holds `2 * len` values.
+                let values = unsafe { std::slice::from_raw_parts(self.data.ptr() as *const T, 2 * self.len) };
+                Some(values.split_at(self.len))
+            }
+        }
+    }
+
+    /// Point `index`, or `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<Point2D<T>> {
+        if index >= self.len {
+            return None;
+        }
+        match (self.as_slice(), self.planes()) {
+            (Some(points), _) => Some(points[index]),
+            (_, Some((xs, ys))) => Some(Point2D::new(xs[index], ys[index])),
+            _ => None,
+        }
+    }
+
+    /// Iterates over the points in either layout.
+    pub fn iter(&self) -> impl Iterator<Item = Point2D<T>> + '_ {
+        (0..self.len).filter_map(move |i| self.get(i))
+    }
+}
+
+/// The file's bytes: mapped, or copied into `u64` words, which keeps them aligned for f64.
+#[derive(Debug)]
+enum Storage {
+    /// Page aligned.
+    #[cfg(feature = "mmap")]
+    Mapped(memmap2::Mmap),
+    Copied(Vec<u64>),
+}
+
+impl Storage {
+    fn ptr(&self) -> *const u8 {
+        match self {
+            #[cfg(feature = "mmap")]
+            Storage::Mapped(map) => map.as_ptr(),
+            Storage::Copied(words) => words.as_ptr() as *const u8,
+        }
+    }
+}
+
+/// Size of `file` in bytes, checked to be a whole number of `Point2D<T>`.
+fn point_bytes<T>(file: &File) -> io::Result<usize> {
+    let bytes = file.metadata()?.len() as usize;
+    let point_size = 2 * size_of::<T>();
+    if !bytes.is_multiple_of(point_size) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("file size {bytes} is not a multiple of the point size {point_size}"),
+        ));
+    }
+    Ok(bytes)
+}
+
+fn read_words(file: &File, len: usize) -> io::Result<Vec<u64>> {
+    use std::io::Read;
+    let mut words = vec![0u64; len.div_ceil(8)];
+    // SAFETY: the byte view covers initialized u64 storage of at least `len` bytes.
+    let bytes = unsafe { std::slice::from_raw_parts_mut(words.as_mut_ptr() as *mut u8, len) };
+    (&*file).read_exact(bytes)?;
+    Ok(words)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use std::path::PathBuf;
+
+    fn write_temp(name: &str, values: &[f64]) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("geomengine-{}-{name}", std::process::id()));
+        let mut file = File::create(&path).unwrap();
+        for v in values {
+            file.write_all(&v.to_ne_bytes()).unwrap();
+        }
+        path
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn test_point_file_interleaved() {
+        let path = write_temp("interleaved", &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        // SAFETY: the test owns the file and leaves it alone while it is mapped.
+        let file = unsafe { PointFile::<f64>::open(&path, Layout::Interleaved) }.unwrap();
+        assert_eq!(file.len(), 3);
+        assert_eq!(
+            file.as_slice().unwrap(),
+            &[Point2D::new(1.0, 2.0), Point2D::new(3.0, 4.0), Point2D::new(5.0, 6.0)]
+        );
+        assert_eq!(file.planes(), None);
+        assert_eq!(file.get(3), None);
+        drop(file);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_point_file_planar() {
+        let path = write_temp("planar", &[1.0, 3.0, 5.0, 2.0, 4.0, 6.0]);
+        let file = PointFile::<f64>::read(&path, Layout::Planar).unwrap();
+        assert_eq!(file.as_slice(), None);
+        assert_eq!(file.planes(), Some((&[1.0, 3.0, 5.0][..], &[2.0, 4.0, 6.0][..])));
+        assert_eq!(file.iter().collect::<Vec<_>>()[2], Point2D::new(5.0, 6.0));
+        drop(file);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_point_file_rejects_partial_points_and_reads_empty() {
+        let path = write_temp("partial", &[1.0, 2.0, 3.0]);
+        let error = PointFile::<f64>::read(&path, Layout::Interleaved).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        // the same 24 bytes are three whole f32 points
+        assert_eq!(PointFile::<f32>::read(&path, Layout::Interleaved).unwrap().len(), 3);
+        std::fs::remove_file(path).unwrap();
+
+        let path = write_temp("empty", &[]);
+        let file = PointFile::<f32>::read(&path, Layout::Interleaved).unwrap();
+        assert!(file.is_empty());
+        assert_eq!(file.as_slice().unwrap(), &[]);
+        #[cfg(feature = "mmap")]
+        {
+            // SAFETY: the test owns the file and leaves it alone while it is mapped.
+            let file = unsafe { PointFile::<f32>::open(&path, Layout::Interleaved) }.unwrap();
+            assert!(file.is_empty());
+            assert_eq!(file.as_slice().unwrap(), &[]);
+        }
+        std::fs::remove_file(path).unwrap();
+    }
+}
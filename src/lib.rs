@@ -1,3 +1,37 @@
+//! 2D and spherical geometry primitives and algorithms.
+//!
+//! The crate is `no_std` + `alloc` when the default `std` feature is disabled.
+//! The `libm` feature then supplies the floating point math
+//! (`--no-default-features --features libm`), e.g.
+//!
+//! ```toml
+//! geomengine = { version = "0.1", default-features = false, features = ["libm"] }
+//! ```
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 pub mod point;
 pub mod line;
-pub mod algorithms;
\ No newline at end of file
+pub mod polygon;
+pub mod shape;
+pub mod transform;
+pub mod scene;
+pub mod raycast;
+pub mod collision;
+pub mod navigation;
+pub mod algorithms;
+pub mod geodesic;
+pub mod spatial;
+#[cfg(feature = "std")]
+pub mod io;
+#[cfg(feature = "rand")]
+pub mod rand;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "units")]
+pub mod units;
+#[cfg(feature = "viz")]
+pub mod viz;
+#[cfg(feature = "inspect")]
+pub mod inspect;
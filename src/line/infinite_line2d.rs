@@ -0,0 +1,164 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::{Point2D, Vector2D};
+use crate::shape::Circle2D;
+use super::Line2D;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Line without ends through `point`, running along the unit vector `direction`.
+///
+/// [`Line2D`] is a segment; this is the line it lies on, for queries that must
+/// not stop at the endpoints. Parameters `t` measure the distance from `point`.
+///
+/// # Example
+/// ```
+/// use geomengine::line::{InfiniteLine2D, Line2D};
+/// use geomengine::point::Point2D;
+///
+/// let segment = Line2D::new(Point2D::new(0.0, 0.0), Point2D::new(1.0, 0.0));
+/// let other = Line2D::new(Point2D::new(3.0, 1.0), Point2D::new(3.0, 2.0));
+/// assert_eq!(segment.intersection(&other), None);
+///
+/// let line = segment.to_infinite_line().unwrap();
+/// let crossing = line.intersection(&other.to_infinite_line().unwrap());
+/// assert_eq!(crossing, Some(Point2D::new(3.0, 0.0)));
+/// assert_eq!(line.project(&Point2D::new(-2.0, 4.0)), Point2D::new(-2.0, 0.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InfiniteLine2D<T: Float + Debug> {
+    pub point: Point2D<T>,
+    pub direction: Vector2D<T>,
+}
+
+impl<T: Float + Debug> InfiniteLine2D<T> {
+    /// Line through `point` along `direction`, which is normalized; `None` if it is zero.
+    pub fn new(point: Point2D<T>, direction: Vector2D<T>) -> Option<Self> {
+        Some(InfiniteLine2D { point, direction: direction.normalize()? })
+    }
+    /// Line through `a` and `b`, directed from `a` to `b`; `None` if they coincide.
+    pub fn through(a: Point2D<T>, b: Point2D<T>) -> Option<Self> {
+        Self::new(a, b - a)
+    }
+    /// Point at distance `t` from `point` along the direction.
+    pub fn point_at(&self, t: T) -> Point2D<T> {
+        self.point + self.direction * t
+    }
+    /// Parameter of the point of the line closest to `point`.
+    pub fn parameter_of(&self, point: &Point2D<T>) -> T {
+        (*point - self.point).dot_product(&self.direction)
+    }
+    /// Orthogonal projection of `point` onto the line.
+    pub fn project(&self, point: &Point2D<T>) -> Point2D<T> {
+        self.point_at(self.parameter_of(point))
+    }
+    /// Distance of `point` from the line, positive on the left of the direction.
+    pub fn signed_distance(&self, point: &Point2D<T>) -> T {
+        self.direction.cross_product(&(*point - self.point))
+    }
+    /// The segment between parameters `from` and `to`.
+    pub fn segment(&self, from: T, to: T) -> Line2D<T> {
+        Line2D::new(self.point_at(from), self.point_at(to))
+    }
+    /// Where the two lines cross; `None` if they are parallel.
+    pub fn intersection(&self, other: &InfiniteLine2D<T>) -> Option<Point2D<T>> {
+        let denominator = self.direction.cross_product(&other.direction);
+        if denominator == T::zero() {
+            return None;
+        }
+        Some(self.point_at((other.point - self.point).cross_product(&other.direction) / denominator))
+    }
+    /// Where the line crosses `segment`; `None` if it misses it or runs along it.
+    pub fn segment_intersection(&self, segment: &Line2D<T>) -> Option<Point2D<T>> {
+        let (s1, s2) = (self.signed_distance(&segment.p1), self.signed_distance(&segment.p2));
+        if s1 == s2 || s1 * s2 > T::zero() {
+            return None;
+        }
+        Some(segment.p1 + (segment.p2 - segment.p1) * (s1 / (s1 - s2)))
+    }
+    /// Points where the line meets the outline of `circle`, in the order of the
+    /// direction: two if it crosses, one if it touches.
+    pub fn circle_intersections(&self, circle: &Circle2D<T>) -> Vec<Point2D<T>> {
+        let t = self.parameter_of(&circle.center);
+        let distance = self.signed_distance(&circle.center);
+        let half_chord_sq = circle.radius * circle.radius - distance * distance;
+        if half_chord_sq < T::zero() {
+            return Vec::new();
+        }
+        if half_chord_sq == T::zero() {
+            return vec![self.point_at(t)];
+        }
+        let half_chord = half_chord_sq.sqrt();
+        vec![self.point_at(t - half_chord), self.point_at(t + half_chord)]
+    }
+}
+
+impl<T: Float + Debug> Line2D<T> {
+    /// The line the segment lies on, directed from `p1` to `p2`; `None` for a zero-length segment.
+    pub fn to_infinite_line(&self) -> Option<InfiniteLine2D<T>> {
+        InfiniteLine2D::through(self.p1, self.p2)
+    }
+    /// The point where the two segments cross or touch, or `None` if they are
+    /// apart or parallel (collinear overlaps included).
+    pub fn intersection(&self, other: &Line2D<T>) -> Option<Point2D<T>> {
+        let (d1, d2) = (self.p2 - self.p1, other.p2 - other.p1);
+        let denominator = d1.cross_product(&d2);
+        if denominator == T::zero() {
+            return None;
+        }
+        let offset = other.p1 - self.p1;
+        let (t, u) = (offset.cross_product(&d2) / denominator, offset.cross_product(&d1) / denominator);
+        let unit = T::zero()..=T::one();
+        (unit.contains(&t) && unit.contains(&u)).then(|| self.p1 + d1 * t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shape::{ClosestPoint, Distance};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_infinite_line_queries() {
+        let line = InfiniteLine2D::through(Point2D::new(0.0, 1.0), Point2D::new(2.0, 1.0)).unwrap();
+        assert_eq!(line.direction, Vector2D::new(1.0, 0.0));
+        assert_eq!(line.signed_distance(&Point2D::new(5.0, 4.0)), 3.0);
+        assert_eq!(line.signed_distance(&Point2D::new(-5.0, -1.0)), -2.0);
+        assert_eq!(line.distance_to(&Point2D::new(-5.0, -1.0)), 2.0);
+        assert_eq!(line.closest_point(&Point2D::new(-5.0, -1.0)), Some(Point2D::new(-5.0, 1.0)));
+        assert_eq!(line.segment(-1.0, 3.0), Line2D::new(Point2D::new(-1.0, 1.0), Point2D::new(3.0, 1.0)));
+        assert_eq!(InfiniteLine2D::through(Point2D::new(1.0, 1.0), Point2D::new(1.0, 1.0)), None);
+
+        // the segment stops short of the line, the infinite line does not
+        let short = Line2D::new(Point2D::new(4.0, 3.0), Point2D::new(4.0, 2.0));
+        assert_eq!(line.segment_intersection(&short), None);
+        assert_eq!(line.intersection(&short.to_infinite_line().unwrap()), Some(Point2D::new(4.0, 1.0)));
+        let crossing = Line2D::new(Point2D::new(4.0, 3.0), Point2D::new(6.0, -1.0));
+        assert_eq!(line.segment_intersection(&crossing), Some(Point2D::new(5.0, 1.0)));
+        assert_eq!(line.segment_intersection(&Line2D::new(Point2D::new(0.0, 1.0), Point2D::new(3.0, 1.0))), None);
+
+        let circle = Circle2D::new(Point2D::new(3.0, 1.0), 2.0);
+        assert_eq!(line.circle_intersections(&circle), vec![Point2D::new(1.0, 1.0), Point2D::new(5.0, 1.0)]);
+        assert_eq!(line.circle_intersections(&Circle2D::new(Point2D::new(0.0, 3.0), 2.0)), vec![Point2D::new(0.0, 1.0)]);
+        assert!(line.circle_intersections(&Circle2D::new(Point2D::new(0.0, 4.0), 2.0)).is_empty());
+    }
+
+    #[test]
+    fn test_segment_intersection_stays_within_segments() {
+        let a = Line2D::new(Point2D::new(0.0, 0.0), Point2D::new(4.0, 4.0));
+        let b = Line2D::new(Point2D::new(0.0, 4.0), Point2D::new(4.0, 0.0));
+        assert_eq!(a.intersection(&b), Some(Point2D::new(2.0, 2.0)));
+        let touching = Line2D::new(Point2D::new(4.0, 4.0), Point2D::new(6.0, 0.0));
+        assert_eq!(a.intersection(&touching), Some(Point2D::new(4.0, 4.0)));
+        let apart = Line2D::new(Point2D::new(5.0, 0.0), Point2D::new(6.0, -1.0));
+        assert_eq!(a.intersection(&apart), None);
+        let parallel = Line2D::new(Point2D::new(1.0, 0.0), Point2D::new(5.0, 4.0));
+        assert_eq!(a.intersection(&parallel), None);
+
+        let diagonal = a.to_infinite_line().unwrap();
+        assert_relative_eq!(diagonal.project(&Point2D::new(4.0, 0.0)).x, 2.0, epsilon = 1e-12);
+        assert_relative_eq!(diagonal.distance_to(&Point2D::new(8.0, 8.0)), 0.0, epsilon = 1e-12);
+        assert_relative_eq!(a.distance_to(&Point2D::new(8.0, 8.0)), 32f64.sqrt(), epsilon = 1e-12);
+    }
+}
@@ -1,6 +1,10 @@
 use num::{Num, Float};
-use std::fmt::Debug;
-use crate::point::Point2D;
+use core::fmt::{Debug, Display, self};
+use crate::point::{Point2D, write_point_list};
+use crate::transform::Angle;
+use alloc::vec::Vec;
+use alloc::string::String;
+use alloc::format;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Line2D<T: Num + Copy + Debug> {
@@ -19,6 +23,22 @@ impl<T: Num + Copy + Debug> Line2D<T> {
     }
 }
 
+/// `[(x1, y1), (x2, y2)]`, or WKT `LINESTRING (x1 y1, x2 y2)` with `{:#}`.
+impl<T: Num + Copy + Debug + Display> Display for Line2D<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", if f.alternate() { "LINESTRING (" } else { "[" })?;
+        write_point_list(f, [self.p1, self.p2])?;
+        write!(f, "{}", if f.alternate() { ")" } else { "]" })
+    }
+}
+
+impl<T: Num + Copy + Debug + Display> Line2D<T> {
+    /// The segment as a WKT `LINESTRING`.
+    pub fn to_wkt_string(&self) -> String {
+        format!("{:#}", self)
+    }
+}
+
 impl<T: Float + Debug> Line2D<T> {
     pub fn length(&self) -> T {
         let x_diff = self.p2.x - self.p1.x;
@@ -31,29 +51,63 @@ impl<T: Float + Debug> Line2D<T> {
             Some((self.p2.y - self.p1.y) / (self.p2.x - self.p1.x))
         }
     }
+    /// Direction from `p1` to `p2` from the x axis, in `(-π, π]`.
+    /// Unlike [`slope`](Self::slope) it is defined for vertical segments; a
+    /// zero-length segment has angle `0`.
+    pub fn angle(&self) -> Angle<T> {
+        Angle::atan2(self.p2.y - self.p1.y, self.p2.x - self.p1.x)
+    }
+    /// Angle between the segment and the x axis, ignoring direction, in `[0, π/2]`.
+    pub fn angle_to_x_axis(&self) -> Angle<T> {
+        Angle::atan2((self.p2.y - self.p1.y).abs(), (self.p2.x - self.p1.x).abs())
+    }
+    /// Is the segment within `tolerance` of the x axis?
+    pub fn is_horizontal(&self, tolerance: Angle<T>) -> bool {
+        self.angle_to_x_axis() <= tolerance
+    }
+    /// Is the segment within `tolerance` of the y axis?
+    pub fn is_vertical(&self, tolerance: Angle<T>) -> bool {
+        Angle::from_radians(T::from(core::f64::consts::FRAC_PI_2).unwrap()) - self.angle_to_x_axis() <= tolerance
+    }
+    /// The point of the segment closest to `point`.
+    pub(crate) fn closest_point_to(&self, point: &Point2D<T>) -> Point2D<T> {
+        let direction = self.p2 - self.p1;
+        let length_sq = direction.dot_product(&direction);
+        if length_sq == T::zero() {
+            return self.p1;
+        }
+        let t = ((*point - self.p1).dot_product(&direction) / length_sq).max(T::zero()).min(T::one());
+        self.p1 + direction * t
+    }
     pub fn subdivide(&self, num_segments: usize) -> Vec<Line2D<T>> {
-        let mut subdivisions = Vec::new();
-        
-        if num_segments == 0 { 
-            return subdivisions; 
+        let mut subdivisions = Vec::with_capacity(num_segments);
+        self.subdivide_into(num_segments, &mut subdivisions);
+        subdivisions
+    }
+    /// [`subdivide`](Self::subdivide) writing into `subdivisions`, which is cleared first.
+    pub fn subdivide_into(&self, num_segments: usize, subdivisions: &mut Vec<Line2D<T>>) {
+        subdivisions.clear();
+        let mut points = self.sample_points(num_segments);
+        if let Some(mut start) = points.next() {
+            for end in points {
+                subdivisions.push(Line2D::new(start, end));
+                start = end;
+            }
         }
-
+    }
+    /// Lazily yields the `num_segments + 1` evenly spaced points from `p1` to `p2`
+    /// (nothing if `num_segments` is 0), i.e. the endpoints of the pieces
+    /// [`subdivide`](Self::subdivide) would return, without allocating.
+    pub fn sample_points(&self, num_segments: usize) -> impl Iterator<Item = Point2D<T>> {
+        let p1 = self.p1;
         let dx = (self.p2.x - self.p1.x) / T::from(num_segments).unwrap();
         let dy = (self.p2.y - self.p1.y) / T::from(num_segments).unwrap();
+        let count = if num_segments == 0 { 0 } else { num_segments + 1 };
 
-        for i in 0..num_segments {
-            let start = Point2D::new(
-                self.p1.x + T::from(i).unwrap() * dx,
-                self.p1.y + T::from(i).unwrap() * dy,
-            );
-            let end = Point2D::new(
-                self.p1.x + T::from(i + 1).unwrap() * dx,
-                self.p1.y + T::from(i + 1).unwrap() * dy,
-            );
-
-            subdivisions.push(Line2D::new(start, end));
-        }
-        subdivisions
+        (0..count).map(move |i| Point2D::new(
+            p1.x + T::from(i).unwrap() * dx,
+            p1.y + T::from(i).unwrap() * dy,
+        ))
     }
 }
 
@@ -79,6 +133,14 @@ mod tests{
         assert_eq!(line.midpoint(), Point2D::new(1.0, 1.0));
     }
 
+    #[test]
+    fn test_line2d_display() {
+        let line = Line2D::new(Point2D::new(0.0, 0.25), Point2D::new(1.0, 1.0));
+        assert_eq!(line.to_string(), "[(0, 0.25), (1, 1)]");
+        assert_eq!(format!("{line:.1}"), "[(0.0, 0.2), (1.0, 1.0)]");
+        assert_eq!(line.to_wkt_string(), "LINESTRING (0 0.25, 1 1)");
+    }
+
     #[test]
     fn test_line2d_length() {
         let p1 = Point2D::new(0.0, 0.0);
@@ -106,6 +168,24 @@ mod tests{
         assert!(vertical_line.slope().is_none(), "Expected None: Cannot find slope of vertical line");
     }
 
+    #[test]
+    fn test_line2d_angle_and_inclination() {
+        use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+        let down = Line2D::new(Point2D::new(1.0, 3.0), Point2D::new(1.0, 2.0));
+        assert_eq!(down.angle().radians(), -FRAC_PI_2);
+        assert_eq!(down.angle_to_x_axis().radians(), FRAC_PI_2);
+        assert!(down.is_vertical(Angle::zero()) && !down.is_horizontal(Angle::from_radians(0.1)));
+
+        let back = Line2D::new(Point2D::new(2.0, 2.0), Point2D::new(0.0, 0.0));
+        assert_eq!(back.angle().radians(), -3.0 * FRAC_PI_4);
+        assert_eq!(back.angle_to_x_axis().radians(), FRAC_PI_4);
+        assert_eq!(Line2D::new(Point2D::new(0.0, 0.0), Point2D::new(-1.0, 0.0)).angle().radians(), PI);
+
+        let nearly_flat = Line2D::new(Point2D::new(0.0, 0.0), Point2D::new(-100.0, 1.0));
+        assert!(nearly_flat.is_horizontal(Angle::from_degrees(0.6)) && !nearly_flat.is_horizontal(Angle::from_degrees(0.5)));
+        assert!(!nearly_flat.is_vertical(Angle::from_radians(0.01)));
+    }
+
     mod subdivisions_tests {
         use super::super::*;
         #[test]
@@ -148,5 +228,32 @@ mod tests{
             assert_eq!(divided_lines[1].p2.x, line.p2.x);
             assert_eq!(divided_lines[1].p2.y, line.p2.y);
         }
+
+        #[test]
+        fn sample_points_are_subdivision_endpoints() {
+            let line = Line2D::new(Point2D::new(0.5, -1.0), Point2D::new(3.0, 2.7));
+            let points: Vec<_> = line.sample_points(5).collect();
+            let lines = line.subdivide(5);
+
+            assert_eq!(points.len(), 6);
+            assert_eq!(points[0], lines[0].p1);
+            for (point, piece) in points[1..].iter().zip(&lines) {
+                assert_eq!(*point, piece.p2);
+            }
+            assert_eq!(line.sample_points(0).count(), 0);
+        }
+
+        #[test]
+        fn subdivide_into_reuses_buffer() {
+            let line = Line2D::new(Point2D::new(0.0, 0.0), Point2D::new(4.0, 0.0));
+            let mut pieces = Vec::new();
+            line.subdivide_into(4, &mut pieces);
+            assert_eq!(pieces, line.subdivide(4));
+
+            line.subdivide_into(2, &mut pieces);
+            assert_eq!(pieces, line.subdivide(2));
+            line.subdivide_into(0, &mut pieces);
+            assert!(pieces.is_empty());
+        }
     }
 }
\ No newline at end of file
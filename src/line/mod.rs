@@ -1,3 +1,7 @@
 mod line2d;
+mod polyline2d;
+mod infinite_line2d;
 
-pub use line2d::Line2D;
\ No newline at end of file
+pub use line2d::Line2D;
+pub use polyline2d::Polyline2D;
+pub use infinite_line2d::InfiniteLine2D;
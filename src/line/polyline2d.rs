@@ -0,0 +1,192 @@
+use num::{Num, Float};
+use core::fmt::{Debug, Display, self};
+use crate::point::{Point2D, Vector2D, write_point_list};
+use super::Line2D;
+use alloc::vec::Vec;
+use alloc::string::String;
+use alloc::format;
+
+/// An open chain of line segments through the vertices, in order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polyline2D<T: Num + Copy + Debug> {
+    vertices: Vec<Point2D<T>>,
+}
+
+impl<T: Num + Copy + Debug> Polyline2D<T> {
+    pub fn new(vertices: Vec<Point2D<T>>) -> Self {
+        Polyline2D { vertices }
+    }
+    pub fn vertices(&self) -> &[Point2D<T>] {
+        &self.vertices
+    }
+    pub fn len(&self) -> usize {
+        self.vertices.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.vertices.is_empty()
+    }
+    /// The `len() - 1` edges between consecutive vertices; unlike
+    /// [`Polygon2D::edges`](crate::polygon::Polygon2D::edges) there is no closing edge.
+    pub fn edges(&self) -> impl Iterator<Item = Line2D<T>> + '_ {
+        self.vertices.windows(2).map(|w| Line2D::new(w[0], w[1]))
+    }
+    /// Reverses the direction of travel in place.
+    pub fn reverse(&mut self) {
+        self.vertices.reverse();
+    }
+    /// The same path travelled from the last vertex to the first.
+    pub fn reversed(&self) -> Self {
+        Polyline2D { vertices: self.vertices.iter().rev().copied().collect() }
+    }
+    /// Inserts `point` before the vertex at `index` (at the end if `index == len()`).
+    ///
+    /// # Returns
+    /// * `false`, leaving the polyline unchanged, if `point` equals a vertex it
+    ///   would be next to, as consecutive duplicates make zero length segments.
+    ///
+    /// # Panics
+    /// If `index > len()`.
+    pub fn insert_vertex(&mut self, index: usize, point: Point2D<T>) -> bool {
+        assert!(index <= self.vertices.len(), "vertex index {index} out of bounds");
+        let before = index.checked_sub(1).map(|i| self.vertices[i]);
+        let after = self.vertices.get(index).copied();
+        if before == Some(point) || after == Some(point) {
+            return false;
+        }
+        self.vertices.insert(index, point);
+        true
+    }
+    /// Removes and returns the vertex at `index`. Should its neighbours coincide,
+    /// the later one is removed too.
+    ///
+    /// # Panics
+    /// If `index >= len()`.
+    pub fn remove_vertex(&mut self, index: usize) -> Point2D<T> {
+        let removed = self.vertices.remove(index);
+        if index > 0 && index < self.vertices.len() && self.vertices[index] == self.vertices[index - 1] {
+            self.vertices.remove(index);
+        }
+        removed
+    }
+    /// Moves the vertex at `index` by `delta`. A vertex moved onto a neighbour
+    /// merges with it.
+    ///
+    /// # Panics
+    /// If `index >= len()`.
+    pub fn translate_vertex(&mut self, index: usize, delta: Vector2D<T>) {
+        self.vertices[index] = self.vertices[index] + delta;
+        let moved = Some(self.vertices[index]);
+        if index.checked_sub(1).map(|i| self.vertices[i]) == moved || self.vertices.get(index + 1).copied() == moved {
+            self.remove_vertex(index);
+        }
+    }
+}
+
+/// `[(x, y), ...]`, or WKT `LINESTRING (x y, ...)` with `{:#}`.
+impl<T: Num + Copy + Debug + Display> Display for Polyline2D<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (f.alternate(), self.vertices.is_empty()) {
+            (true, true) => write!(f, "LINESTRING EMPTY"),
+            (true, false) => {
+                write!(f, "LINESTRING (")?;
+                write_point_list(f, self.vertices.iter().copied())?;
+                write!(f, ")")
+            }
+            (false, _) => {
+                write!(f, "[")?;
+                write_point_list(f, self.vertices.iter().copied())?;
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+impl<T: Num + Copy + Debug + Display> Polyline2D<T> {
+    /// The polyline as a WKT `LINESTRING`.
+    pub fn to_wkt_string(&self) -> String {
+        format!("{:#}", self)
+    }
+}
+
+impl<T: Float + Debug> Polyline2D<T> {
+    /// Starts a [`PolylineBuilder`](crate::shape::PolylineBuilder) for cleaning up messy input.
+    pub fn builder() -> crate::shape::PolylineBuilder<T> {
+        crate::shape::PolylineBuilder::new()
+    }
+    pub fn length(&self) -> T {
+        self.edges().fold(T::zero(), |sum, segment| sum + segment.length())
+    }
+    /// The point `distance` along the path from its first vertex, clamped to
+    /// the ends; `None` for an empty polyline.
+    ///
+    /// # Time Complexity
+    /// **O(n)**, walking the segments up to the one holding the point.
+    pub fn point_at_length(&self, distance: T) -> Option<Point2D<T>> {
+        let mut remaining = distance;
+        for segment in self.edges() {
+            let length = segment.length();
+            if remaining <= length {
+                if remaining <= T::zero() {
+                    return Some(segment.p1);
+                }
+                return Some(segment.p1 + (segment.p2 - segment.p1) * (remaining / length));
+            }
+            remaining = remaining - length;
+        }
+        self.vertices.last().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shape::{Aabb2D, BoundingBox};
+
+    #[test]
+    fn test_polyline2d_segments_and_length() {
+        let polyline = Polyline2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(3.0, 4.0), Point2D::new(3.0, 6.0)]);
+        assert_eq!(polyline.edges().count(), 2);
+        assert_eq!(polyline.length(), 7.0);
+        assert_eq!(Polyline2D::<f64>::new(vec![Point2D::new(1.0, 1.0)]).length(), 0.0);
+    }
+
+    #[test]
+    fn test_polyline2d_point_at_length() {
+        let mut path = Polyline2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(3.0, 4.0), Point2D::new(3.0, 4.0), Point2D::new(3.0, 6.0)]);
+        assert_eq!(path.point_at_length(-1.0), Some(Point2D::new(0.0, 0.0)));
+        assert_eq!(path.point_at_length(2.5), Some(Point2D::new(1.5, 2.0)));
+        assert_eq!(path.point_at_length(6.0), Some(Point2D::new(3.0, 5.0)));
+        assert_eq!(path.point_at_length(10.0), Some(Point2D::new(3.0, 6.0)));
+        assert_eq!(Polyline2D::<f64>::new(Vec::new()).point_at_length(1.0), None);
+
+        assert_eq!(path.reversed().point_at_length(1.0), Some(Point2D::new(3.0, 5.0)));
+        path.reverse();
+        assert_eq!(path.vertices()[0], Point2D::new(3.0, 6.0));
+        assert_eq!(path.bounding_box(), Some(Aabb2D::new(Point2D::new(0.0, 0.0), Point2D::new(3.0, 6.0))));
+    }
+
+    #[test]
+    fn test_polyline2d_vertex_editing() {
+        let mut path = Polyline2D::new(vec![Point2D::new(0, 0), Point2D::new(2, 0), Point2D::new(4, 0)]);
+        assert!(path.insert_vertex(3, Point2D::new(4, 2)));
+        assert!(!path.insert_vertex(0, Point2D::new(0, 0)));
+        assert!(path.insert_vertex(0, Point2D::new(4, 2)), "the ends of an open path are not neighbours");
+
+        path.translate_vertex(0, Vector2D::new(-4, -2));
+        assert_eq!(path.vertices(), &[Point2D::new(0, 0), Point2D::new(2, 0), Point2D::new(4, 0), Point2D::new(4, 2)]);
+
+        // an out-and-back detour collapses when its tip is removed
+        path.translate_vertex(3, Vector2D::new(-2, -2));
+        assert_eq!(path.remove_vertex(2), Point2D::new(4, 0));
+        assert_eq!(path.vertices(), &[Point2D::new(0, 0), Point2D::new(2, 0)]);
+        assert_eq!(path.remove_vertex(1), Point2D::new(2, 0));
+    }
+
+    #[test]
+    fn test_polyline2d_display() {
+        let polyline = Polyline2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(3.0, 4.0), Point2D::new(3.0, 6.5)]);
+        assert_eq!(format!("{polyline:.1}"), "[(0.0, 0.0), (3.0, 4.0), (3.0, 6.5)]");
+        assert_eq!(polyline.to_wkt_string(), "LINESTRING (0 0, 3 4, 3 6.5)");
+        assert_eq!(Polyline2D::<f64>::new(Vec::new()).to_wkt_string(), "LINESTRING EMPTY");
+    }
+}
@@ -0,0 +1,297 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::line::Line2D;
+use crate::shape::Aabb2D;
+use crate::algorithms::merge_points;
+use crate::spatial::RTree;
+use super::navmesh::Candidate;
+use alloc::collections::{BTreeMap, BinaryHeap};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Tuning of [`match_track`], in the units of the coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MapMatchOptions<T> {
+    /// Edges further than this from a fix are not considered for it.
+    pub search_radius: T,
+    /// Standard deviation of the positioning noise.
+    pub noise_sigma: T,
+    /// Scale of the tolerated difference between the route between two
+    /// matches and the straight line between their fixes.
+    pub detour_scale: T,
+    /// Routes longer than the straight line by more than this are ruled out.
+    pub max_detour: T,
+}
+
+impl<T: Float> Default for MapMatchOptions<T> {
+    /// Values suited to GPS fixes in metres.
+    fn default() -> Self {
+        MapMatchOptions {
+            search_radius: T::from(50.0).unwrap(),
+            noise_sigma: T::from(5.0).unwrap(),
+            detour_scale: T::from(5.0).unwrap(),
+            max_detour: T::from(500.0).unwrap(),
+        }
+    }
+}
+
+/// Position of a fix on the network.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MatchedPoint<T: Float + Debug> {
+    /// Index of the matched segment.
+    pub edge: usize,
+    /// Distance along the segment from its `p1`.
+    pub offset: T,
+    /// The matched position.
+    pub point: Point2D<T>,
+}
+
+/// Snaps a sequence of noisy fixes onto a network of segments.
+///
+/// # Arguments
+/// * `network` - Segments connected where their endpoints coincide; they can be travelled both ways.
+/// * `track` - The fixes in the order they were recorded.
+/// * `options` - Search radius, noise level and detour tolerances.
+///
+/// # Returns
+/// * The match of every fix, or `None` for fixes with no edge within the search radius.
+///
+/// # Algorithm Explanation
+/// A hidden Markov model in the style of Newson and Krumm, solved by Viterbi:
+/// - The states of a fix are the closest points of the edges within the search
+///   radius, likelier the closer they are (Gaussian in the distance).
+/// - Moving between the states of consecutive fixes is likelier the closer the
+///   network route between them is to the straight line between the fixes
+///   (exponential in the difference), which keeps the match on a continuous
+///   route rather than jumping to whatever edge is nearest. Routes come from a
+///   Dijkstra search cut off at `max_detour`.
+/// - Where no state can be reached from the previous fix, or a fix has no
+///   states, the run is decoded and a new one starts.
+///
+/// # Time Complexity
+/// **O(m k (E log E))** for `m` fixes with `k` states each, where `E` is the
+/// number of edges a route search reaches within its cutoff.
+///
+/// # Example
+/// ```
+/// use geomengine::line::Line2D;
+/// use geomengine::navigation::{match_track, MapMatchOptions};
+/// use geomengine::point::Point2D;
+///
+/// let network = [
+///     Line2D::new(Point2D::new(0.0, 0.0), Point2D::new(100.0, 0.0)),
+///     Line2D::new(Point2D::new(100.0, 0.0), Point2D::new(100.0, 100.0)),
+/// ];
+/// let track = [Point2D::new(40.0, 3.0), Point2D::new(97.0, 20.0), Point2D::new(103.0, 60.0)];
+/// let matched = match_track(&network, &track, &MapMatchOptions::default());
+/// let edges: Vec<_> = matched.iter().map(|m| m.unwrap().edge).collect();
+/// assert_eq!(edges, [0, 1, 1]);
+/// assert_eq!(matched[2].unwrap().offset, 60.0);
+/// ```
+pub fn match_track<T: Float + Debug>(network: &[Line2D<T>], track: &[Point2D<T>], options: &MapMatchOptions<T>) -> Vec<Option<MatchedPoint<T>>> {
+    let graph = Network::new(network);
+    let mut matched = vec![None; track.len()];
+    let mut run: Vec<Layer<T>> = Vec::new();
+    let mut scores: Vec<T> = Vec::new();
+    let half = T::from(0.5).unwrap();
+
+    for (i, &fix) in track.iter().enumerate() {
+        let states = graph.candidates(fix, options.search_radius);
+        let emission: Vec<T> = states
+            .iter()
+            .map(|s| {
+                let z = s.point.distance(&fix) / options.noise_sigma;
+                -half * z * z
+            })
+            .collect();
+        let mut next = vec![T::neg_infinity(); states.len()];
+        let mut back = vec![usize::MAX; states.len()];
+        if let Some(previous) = run.last() {
+            let straight = track[previous.fix].distance(&fix);
+            for (k, from) in previous.states.iter().enumerate() {
+                let routes = graph.route_lengths(from, &states, straight + options.max_detour);
+                for (j, route) in routes.into_iter().enumerate() {
+                    let Some(route) = route else { continue };
+                    let score = scores[k] - (route - straight).abs() / options.detour_scale + emission[j];
+                    if score > next[j] {
+                        next[j] = score;
+                        back[j] = k;
+                    }
+                }
+            }
+        }
+        if next.iter().all(|score| score.is_infinite()) {
+            decode(&run, &scores, &mut matched);
+            run.clear();
+            next = emission;
+        }
+        if !states.is_empty() {
+            run.push(Layer { fix: i, states, back });
+            scores = next;
+        }
+    }
+    decode(&run, &scores, &mut matched);
+    matched
+}
+
+/// A fix of the current run of the Viterbi search.
+struct Layer<T: Float + Debug> {
+    fix: usize,
+    states: Vec<MatchedPoint<T>>,
+    /// Best previous state for each state.
+    back: Vec<usize>,
+}
+
+/// Follows the back pointers of `run` from its best final state.
+fn decode<T: Float + Debug>(run: &[Layer<T>], scores: &[T], matched: &mut [Option<MatchedPoint<T>>]) {
+    if run.is_empty() {
+        return;
+    }
+    let mut best = (0..scores.len()).fold(0, |best, j| if scores[j] > scores[best] { j } else { best });
+    for layer in run.iter().rev() {
+        matched[layer.fix] = Some(layer.states[best]);
+        best = layer.back[best];
+    }
+}
+
+/// The segments as a graph between their merged endpoints, with an R-tree over their boxes.
+struct Network<'a, T: Float + Debug> {
+    segments: &'a [Line2D<T>],
+    tree: RTree<T>,
+    /// Nodes at `p1` and `p2` of every segment.
+    ends: Vec<(usize, usize)>,
+    /// Neighbouring node and the segment leading there, per node.
+    adjacency: Vec<Vec<(usize, usize)>>,
+}
+
+impl<'a, T: Float + Debug> Network<'a, T> {
+    fn new(segments: &'a [Line2D<T>]) -> Self {
+        let endpoints: Vec<Point2D<T>> = segments.iter().flat_map(|s| [s.p1, s.p2]).collect();
+        let (nodes, ids) = merge_points(&endpoints, T::zero());
+        let ends: Vec<(usize, usize)> = ids.chunks(2).map(|pair| (pair[0], pair[1])).collect();
+        let mut adjacency = vec![Vec::new(); nodes.len()];
+        for (edge, &(a, b)) in ends.iter().enumerate() {
+            adjacency[a].push((b, edge));
+            adjacency[b].push((a, edge));
+        }
+        let boxes: Vec<_> = segments.iter().map(|s| Aabb2D::new(s.p1, s.p1).expand_to_include(&s.p2)).collect();
+        Network { segments, tree: RTree::bulk_load(&boxes), ends, adjacency }
+    }
+
+    /// Closest points of the segments within `radius` of `fix`, by segment index.
+    fn candidates(&self, fix: Point2D<T>, radius: T) -> Vec<MatchedPoint<T>> {
+        let mut edges = self.tree.query(&Aabb2D::new(fix, fix).expand(radius));
+        edges.sort_unstable();
+        edges
+            .into_iter()
+            .filter_map(|edge| {
+                let segment = &self.segments[edge];
+                let point = segment.closest_point_to(&fix);
+                (point.distance(&fix) <= radius).then(|| MatchedPoint { edge, offset: segment.p1.distance(&point), point })
+            })
+            .collect()
+    }
+
+    /// Network distance from `from` to each of `targets`, `None` beyond `limit`.
+    fn route_lengths(&self, from: &MatchedPoint<T>, targets: &[MatchedPoint<T>], limit: T) -> Vec<Option<T>> {
+        let length = |edge: usize| self.segments[edge].length();
+        let (a, b) = self.ends[from.edge];
+        let mut cost: BTreeMap<usize, T> = BTreeMap::new();
+        let mut open = BinaryHeap::new();
+        for (node, start) in [(a, from.offset), (b, length(from.edge) - from.offset)] {
+            if cost.get(&node).is_none_or(|&c| start < c) {
+                cost.insert(node, start);
+                open.push(Candidate { estimate: start, node });
+            }
+        }
+        while let Some(Candidate { estimate, node }) = open.pop() {
+            if estimate > limit {
+                break;
+            }
+            if cost[&node] < estimate {
+                continue;
+            }
+            for &(next, edge) in &self.adjacency[node] {
+                let through = estimate + length(edge);
+                if cost.get(&next).is_none_or(|&c| through < c) {
+                    cost.insert(next, through);
+                    open.push(Candidate { estimate: through, node: next });
+                }
+            }
+        }
+
+        let reached = |node: usize| cost.get(&node).copied().unwrap_or(T::infinity());
+        targets
+            .iter()
+            .map(|to| {
+                let (ta, tb) = self.ends[to.edge];
+                let mut route = (reached(ta) + to.offset).min(reached(tb) + length(to.edge) - to.offset);
+                if to.edge == from.edge {
+                    route = route.min((to.offset - from.offset).abs());
+                }
+                (route <= limit).then_some(route)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Two parallel roads 12 apart, split into pieces of 10 and joined only at their ends.
+    fn ladder() -> Vec<Line2D<f64>> {
+        let mut network = Vec::new();
+        for y in [0.0, 12.0] {
+            for i in 0..10 {
+                network.push(Line2D::new(Point2D::new(10.0 * i as f64, y), Point2D::new(10.0 * (i + 1) as f64, y)));
+            }
+        }
+        network.push(Line2D::new(Point2D::new(0.0, 0.0), Point2D::new(0.0, 12.0)));
+        network.push(Line2D::new(Point2D::new(100.0, 12.0), Point2D::new(100.0, 0.0)));
+        network
+    }
+
+    #[test]
+    fn test_match_track_keeps_continuity() {
+        let network = ladder();
+        // one fix strays closer to the upper road
+        let track: Vec<_> = [(5.0, 1.0), (18.0, -2.0), (33.0, 7.0), (41.0, 1.5), (57.0, -1.0)]
+            .iter()
+            .map(|&(x, y)| Point2D::new(x, y))
+            .collect();
+        let options = MapMatchOptions { search_radius: 10.0, noise_sigma: 4.0, detour_scale: 2.0, max_detour: 50.0 };
+        let matched = match_track(&network, &track, &options);
+
+        let edges: Vec<usize> = matched.iter().map(|m| m.unwrap().edge).collect();
+        assert_eq!(edges, [0, 1, 3, 4, 5]);
+        let third = matched[2].unwrap();
+        assert_eq!((third.offset, third.point), (3.0, Point2D::new(33.0, 0.0)));
+
+        // nearest-edge matching alone would take the upper road for that fix
+        let graph = Network::new(&network);
+        let nearest = graph.candidates(track[2], 10.0).into_iter().min_by(|a, b| a.point.distance(&track[2]).total_cmp(&b.point.distance(&track[2])));
+        assert_eq!(nearest.unwrap().edge, 13);
+    }
+
+    #[test]
+    fn test_match_track_gaps_and_routes() {
+        let network = ladder();
+        let options = MapMatchOptions { search_radius: 3.0, ..MapMatchOptions::default() };
+        let track = [Point2D::new(15.0, 1.0), Point2D::new(50.0, 6.0), Point2D::new(75.0, 11.0)];
+        let matched = match_track(&network, &track, &options);
+        assert_eq!(matched[0].map(|m| m.edge), Some(1));
+        assert_eq!(matched[1], None);
+        assert_eq!(matched[2].map(|m| m.edge), Some(17));
+        assert!(match_track(&network, &[], &options).is_empty());
+
+        // the route between the roads goes around through the far end
+        let graph = Network::new(&network);
+        let from = graph.candidates(Point2D::new(95.0, 0.0), 1.0)[0];
+        let to = graph.candidates(Point2D::new(95.0, 12.0), 1.0)[0];
+        assert_eq!(graph.route_lengths(&from, &[to], 100.0), vec![Some(22.0)]);
+        assert_eq!(graph.route_lengths(&from, &[to], 20.0), vec![None]);
+        assert_eq!(graph.route_lengths(&from, &[from], 0.0), vec![Some(0.0)]);
+    }
+}
@@ -0,0 +1,12 @@
+// module exports
+mod navmesh;
+mod visibility;
+mod rrt;
+mod occupancy;
+mod map_matching;
+
+pub use navmesh::NavMesh;
+pub use visibility::shortest_path;
+pub use rrt::{RrtPlanner, Sampler, UniformSampler};
+pub use occupancy::{CellState, OccupancyGrid};
+pub use map_matching::{match_track, MapMatchOptions, MatchedPoint};
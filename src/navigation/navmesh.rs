@@ -0,0 +1,272 @@
+use num::Float;
+use core::cmp::Ordering;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::line::Polyline2D;
+use crate::polygon::Polygon2D;
+use crate::algorithms::merge_points;
+use crate::algorithms::triangulate::triangulate_2d;
+use alloc::collections::{BTreeMap, BinaryHeap};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Navigation mesh: the walkable area split into triangles, for pathfinding.
+///
+/// Triangle `t` is [`triangles()[t]`](Self::triangles), counterclockwise indices
+/// into [`vertices`](Self::vertices); [`neighbors(t)[k]`](Self::neighbors) is the
+/// triangle across its edge from corner `k` to corner `k + 1`, if walkable.
+///
+/// [`find_path`](Self::find_path) runs A* over the triangles, then pulls the
+/// path taut through the chain of shared edges ("portals") with the funnel
+/// algorithm, so it only bends at obstacle corners.
+///
+/// # Example
+/// ```
+/// use geomengine::navigation::NavMesh;
+/// use geomengine::point::Point2D;
+/// use geomengine::polygon::Polygon2D;
+///
+/// // a room with a square pillar in the middle
+/// let room = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(4.0, 0.0), Point2D::new(4.0, 4.0), Point2D::new(0.0, 4.0)]);
+/// let pillar = Polygon2D::new(vec![Point2D::new(1.0, 1.0), Point2D::new(3.0, 1.0), Point2D::new(3.0, 3.0), Point2D::new(1.0, 3.0)]);
+/// let mesh = NavMesh::new(&[(room, vec![pillar])]);
+///
+/// let path = mesh.find_path(Point2D::new(0.5, 2.0), Point2D::new(3.5, 2.0)).unwrap();
+/// // around two corners of the pillar
+/// assert_eq!(path.len(), 4);
+/// assert!(path.vertices()[1] == Point2D::new(1.0, 1.0) || path.vertices()[1] == Point2D::new(1.0, 3.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct NavMesh<T: Float + Debug> {
+    vertices: Vec<Point2D<T>>,
+    triangles: Vec<[usize; 3]>,
+    neighbors: Vec<[Option<usize>; 3]>,
+}
+
+impl<T: Float + Debug> NavMesh<T> {
+    /// Builds the mesh from walkable regions, each an exterior with obstacle holes.
+    ///
+    /// Regions sharing vertices at identical coordinates are joined along their
+    /// common edges.
+    pub fn new(walkable: &[(Polygon2D<T>, Vec<Polygon2D<T>>)]) -> Self {
+        let mut points = Vec::new();
+        let mut triangles = Vec::new();
+        for (exterior, holes) in walkable {
+            let offset = points.len();
+            triangles.extend(triangulate_2d(exterior, holes).into_iter().map(|t| t.map(|v| v + offset)));
+            points.extend(exterior.vertices().iter().chain(holes.iter().flat_map(|h| h.vertices())).copied());
+        }
+        let (vertices, ids) = merge_points(&points, T::zero());
+        let triangles: Vec<[usize; 3]> = triangles.into_iter().map(|t| t.map(|v| ids[v])).collect();
+
+        let mut edges: BTreeMap<(usize, usize), (usize, usize)> = BTreeMap::new();
+        let mut neighbors = vec![[None; 3]; triangles.len()];
+        for (t, triangle) in triangles.iter().enumerate() {
+            for k in 0..3 {
+                let (a, b) = (triangle[k], triangle[(k + 1) % 3]);
+                // the neighbour runs the shared edge the other way
+                if let Some((other, j)) = edges.remove(&(b, a)) {
+                    neighbors[t][k] = Some(other);
+                    neighbors[other][j] = Some(t);
+                } else {
+                    edges.insert((a, b), (t, k));
+                }
+            }
+        }
+        NavMesh { vertices, triangles, neighbors }
+    }
+
+    pub fn vertices(&self) -> &[Point2D<T>] {
+        &self.vertices
+    }
+    pub fn triangles(&self) -> &[[usize; 3]] {
+        &self.triangles
+    }
+    pub fn neighbors(&self, triangle: usize) -> [Option<usize>; 3] {
+        self.neighbors[triangle]
+    }
+    pub fn triangle(&self, triangle: usize) -> [Point2D<T>; 3] {
+        self.triangles[triangle].map(|v| self.vertices[v])
+    }
+
+    /// The triangle containing `point` (boundary included), if it is walkable.
+    ///
+    /// # Time Complexity
+    /// **O(n)** over the triangles.
+    pub fn locate(&self, point: &Point2D<T>) -> Option<usize> {
+        (0..self.triangles.len()).find(|&t| {
+            let [a, b, c] = self.triangle(t);
+            (b - a).cross_product(&(*point - a)) >= T::zero()
+                && (c - b).cross_product(&(*point - b)) >= T::zero()
+                && (a - c).cross_product(&(*point - c)) >= T::zero()
+        })
+    }
+
+    /// Shortest walkable path from `start` to `goal`, `None` if either lies off the
+    /// mesh or no path connects them.
+    ///
+    /// The path is the taut string through the corridor of triangles chosen by
+    /// A*, which measures steps between shared-edge midpoints, so in rare
+    /// layouts a slightly longer corridor than the optimum may be taken.
+    pub fn find_path(&self, start: Point2D<T>, goal: Point2D<T>) -> Option<Polyline2D<T>> {
+        let corridor = self.corridor(start, goal)?;
+        let mut portals = Vec::with_capacity(corridor.len() + 1);
+        portals.push((start, start));
+        for pair in corridor.windows(2) {
+            let k = (0..3).find(|&k| self.neighbors[pair[0]][k] == Some(pair[1]))?;
+            let triangle = self.triangles[pair[0]];
+            // leaving a counterclockwise triangle, its edge runs from right to left
+            portals.push((self.vertices[triangle[(k + 1) % 3]], self.vertices[triangle[k]]));
+        }
+        portals.push((goal, goal));
+        Some(Polyline2D::new(string_pull(&portals)))
+    }
+
+    /// Triangles from the one holding `start` to the one holding `goal`, by A*.
+    fn corridor(&self, start: Point2D<T>, goal: Point2D<T>) -> Option<Vec<usize>> {
+        let (from, to) = (self.locate(&start)?, self.locate(&goal)?);
+        let mut cost = vec![T::infinity(); self.triangles.len()];
+        let mut entry = vec![start; self.triangles.len()];
+        let mut came_from = vec![usize::MAX; self.triangles.len()];
+        let mut open = BinaryHeap::new();
+        cost[from] = T::zero();
+        open.push(Candidate { estimate: start.distance(&goal), node: from });
+
+        while let Some(Candidate { estimate, node: triangle }) = open.pop() {
+            if triangle == to {
+                let mut corridor = vec![to];
+                while *corridor.last().unwrap() != from {
+                    corridor.push(came_from[*corridor.last().unwrap()]);
+                }
+                corridor.reverse();
+                return Some(corridor);
+            }
+            if estimate > cost[triangle] + entry[triangle].distance(&goal) {
+                continue;
+            }
+            for k in 0..3 {
+                let Some(next) = self.neighbors[triangle][k] else { continue };
+                let (a, b) = (self.vertices[self.triangles[triangle][k]], self.vertices[self.triangles[triangle][(k + 1) % 3]]);
+                let portal = (a + b) / (T::one() + T::one());
+                let through = cost[triangle] + entry[triangle].distance(&portal);
+                if through < cost[next] {
+                    cost[next] = through;
+                    entry[next] = portal;
+                    came_from[next] = triangle;
+                    open.push(Candidate { estimate: through + portal.distance(&goal), node: next });
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Simple stupid funnel algorithm: the taut path through `(left, right)` portals,
+/// the first and last of which are the start and goal points.
+fn string_pull<T: Float + Debug>(portals: &[(Point2D<T>, Point2D<T>)]) -> Vec<Point2D<T>> {
+    let mut path = vec![portals[0].0];
+    let mut apex = portals[0].0;
+    let (mut left, mut right) = (apex, apex);
+    let (mut left_index, mut right_index) = (0, 0);
+
+    let mut i = 1;
+    while i < portals.len() {
+        let (next_left, next_right) = portals[i];
+        let (to_left, to_right) = (next_left - apex, next_right - apex);
+        // tighten the right side unless it would cross over the left one
+        if (right - apex).cross_product(&to_right) >= T::zero() {
+            if apex == right || (left - apex).cross_product(&to_right) <= T::zero() {
+                (right, right_index) = (next_right, i);
+            } else {
+                path.push(left);
+                apex = left;
+                (right, right_index) = (apex, left_index);
+                i = left_index + 1;
+                continue;
+            }
+        }
+
+        if (left - apex).cross_product(&to_left) <= T::zero() {
+            if apex == left || (right - apex).cross_product(&to_left) >= T::zero() {
+                (left, left_index) = (next_left, i);
+            } else {
+                path.push(right);
+                apex = right;
+                (left, left_index) = (apex, right_index);
+                i = right_index + 1;
+                continue;
+            }
+        }
+        i += 1;
+    }
+
+    path.push(portals[portals.len() - 1].0);
+    path.dedup();
+    path
+}
+
+/// Open-list entry of an A* search over numbered nodes, ordered so the heap pops the lowest estimate first.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct Candidate<T> {
+    pub(super) estimate: T,
+    pub(super) node: usize,
+}
+
+impl<T: Float> Eq for Candidate<T> {}
+
+impl<T: Float> PartialOrd for Candidate<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Float> Ord for Candidate<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.partial_cmp(&self.estimate).unwrap_or(Ordering::Equal).then(other.node.cmp(&self.node))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn polygon(points: &[(f64, f64)]) -> Polygon2D<f64> {
+        Polygon2D::new(points.iter().map(|&(x, y)| Point2D::new(x, y)).collect())
+    }
+
+    #[test]
+    fn test_navmesh_adjacency() {
+        let mesh = NavMesh::new(&[(polygon(&[(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)]), vec![])]);
+        assert_eq!(mesh.triangles().len(), 2);
+        assert_eq!(mesh.neighbors(0).iter().flatten().collect::<Vec<_>>(), [&1]);
+        assert_eq!(mesh.locate(&Point2D::new(3.0, 1.0)), None);
+    }
+
+    #[test]
+    fn test_find_path_around_u_bend() {
+        // a U-shaped corridor: down the left arm, along the bottom, up the right arm
+        let u = polygon(&[(0.0, 0.0), (3.0, 0.0), (3.0, 3.0), (2.0, 3.0), (2.0, 1.0), (1.0, 1.0), (1.0, 3.0), (0.0, 3.0)]);
+        let mesh = NavMesh::new(&[(u, vec![])]);
+        let path = mesh.find_path(Point2D::new(0.5, 2.5), Point2D::new(2.5, 2.5)).unwrap();
+        assert_eq!(path.vertices(), &[Point2D::new(0.5, 2.5), Point2D::new(1.0, 1.0), Point2D::new(2.0, 1.0), Point2D::new(2.5, 2.5)]);
+        assert_relative_eq!(path.length(), 2.0 * 2.5f64.sqrt() + 1.0, epsilon = 1e-12);
+
+        // a straight line within the mesh needs no corners
+        let straight = mesh.find_path(Point2D::new(0.5, 0.5), Point2D::new(2.5, 0.5)).unwrap();
+        assert_eq!(straight.len(), 2);
+        assert!(mesh.find_path(Point2D::new(1.5, 2.0), Point2D::new(0.5, 0.5)).is_none());
+    }
+
+    #[test]
+    fn test_find_path_across_joined_regions() {
+        let left = polygon(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]);
+        let right = polygon(&[(1.0, 0.0), (2.0, 0.0), (2.0, 1.0), (1.0, 1.0)]);
+        let island = polygon(&[(5.0, 0.0), (6.0, 0.0), (6.0, 1.0)]);
+        let mesh = NavMesh::new(&[(left, vec![]), (right, vec![]), (island, vec![])]);
+        assert_eq!(mesh.vertices().len(), 9);
+        // starting on a shared edge
+        assert_eq!(mesh.find_path(Point2D::new(0.25, 0.75), Point2D::new(1.75, 0.25)).unwrap().len(), 2);
+        assert!(mesh.find_path(Point2D::new(0.5, 0.5), Point2D::new(5.9, 0.5)).is_none());
+    }
+}
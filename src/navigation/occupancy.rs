@@ -0,0 +1,231 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::line::Line2D;
+use crate::polygon::Polygon2D;
+use crate::shape::Aabb2D;
+use crate::algorithms::clip::clip_segment_to_aabb;
+use crate::algorithms::raster::{rasterize_line, scan_polygon, EdgeRule};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// What is known about one cell of an [`OccupancyGrid`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CellState {
+    #[default]
+    Unknown,
+    Free,
+    Occupied,
+}
+
+/// Regular grid of square cells covering a rectangle of the world, each marked
+/// unknown, free or occupied.
+///
+/// Cell `(0, 0)` has its lower left corner at [`origin`](Self::origin); cell
+/// `(i, j)` spans `resolution` world units from `origin + (i, j) * resolution`.
+/// Cell coordinates are signed so that world points off the grid still map to
+/// a cell; reading such a cell gives `None` and writing it does nothing, which
+/// lets range scans reach past the mapped area.
+///
+/// # Example
+/// ```
+/// use geomengine::navigation::{CellState, OccupancyGrid};
+/// use geomengine::point::Point2D;
+///
+/// let mut grid = OccupancyGrid::new(Point2D::new(-5.0, -5.0), 0.5, 20, 20);
+/// // a range sensor at the center sees a wall 2 units to the right
+/// grid.integrate_ray(Point2D::new(0.0, 0.0), Point2D::new(2.0, 0.0), true);
+/// assert_eq!(grid.world_to_cell(&Point2D::new(2.0, 0.0)), Point2D::new(14, 10));
+/// assert_eq!(grid.get(Point2D::new(14, 10)), Some(CellState::Occupied));
+/// assert_eq!(grid.get(Point2D::new(12, 10)), Some(CellState::Free));
+/// assert_eq!(grid.get(Point2D::new(12, 11)), Some(CellState::Unknown));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct OccupancyGrid<T: Float + Debug> {
+    origin: Point2D<T>,
+    resolution: T,
+    width: usize,
+    height: usize,
+    // row-major, row 0 at the bottom
+    cells: Vec<CellState>,
+}
+
+impl<T: Float + Debug> OccupancyGrid<T> {
+    /// A `width` by `height` grid of unknown cells of side `resolution`.
+    pub fn new(origin: Point2D<T>, resolution: T, width: usize, height: usize) -> Self {
+        OccupancyGrid { origin, resolution, width, height, cells: vec![CellState::Unknown; width * height] }
+    }
+
+    pub fn origin(&self) -> Point2D<T> {
+        self.origin
+    }
+    pub fn resolution(&self) -> T {
+        self.resolution
+    }
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    /// All cells row by row, starting with the bottom row.
+    pub fn cells(&self) -> &[CellState] {
+        &self.cells
+    }
+
+    /// The rectangle of the world covered by the grid.
+    pub fn bounds(&self) -> Aabb2D<T> {
+        let size = Point2D::new(T::from(self.width).unwrap(), T::from(self.height).unwrap());
+        Aabb2D::new(self.origin, self.origin + size * self.resolution)
+    }
+
+    /// The cell containing `point`, which may lie off the grid.
+    pub fn world_to_cell(&self, point: &Point2D<T>) -> Point2D<i32> {
+        let cell = (*point - self.origin) / self.resolution;
+        Point2D::new(to_i32(cell.x.floor()), to_i32(cell.y.floor()))
+    }
+
+    /// The world position of the center of `cell`.
+    pub fn cell_to_world(&self, cell: Point2D<i32>) -> Point2D<T> {
+        let half = T::from(0.5).unwrap();
+        let (i, j) = (T::from(cell.x).unwrap(), T::from(cell.y).unwrap());
+        self.origin + Point2D::new(i + half, j + half) * self.resolution
+    }
+
+    pub fn contains_cell(&self, cell: Point2D<i32>) -> bool {
+        self.index(cell).is_some()
+    }
+
+    /// State of `cell`, `None` if it lies off the grid.
+    pub fn get(&self, cell: Point2D<i32>) -> Option<CellState> {
+        self.index(cell).map(|i| self.cells[i])
+    }
+
+    /// Sets the state of `cell`; cells off the grid are ignored.
+    pub fn set(&mut self, cell: Point2D<i32>, state: CellState) {
+        if let Some(i) = self.index(cell) {
+            self.cells[i] = state;
+        }
+    }
+
+    /// Records one range reading from `sensor` to `end`.
+    ///
+    /// Every cell the beam crosses before `end` is marked free. The cell of `end`
+    /// is marked occupied if the beam hit something there, and free if it is the
+    /// end of a reading that ran out of range.
+    ///
+    /// Only the part of the beam over the grid is walked, so a far away `end`
+    /// costs no more than one on the grid. A reading with a non-finite
+    /// coordinate has no direction to follow and is ignored.
+    pub fn integrate_ray(&mut self, sensor: Point2D<T>, end: Point2D<T>, hit: bool) {
+        let finite = |p: &Point2D<T>| p.x.is_finite() && p.y.is_finite();
+        if !finite(&sensor) || !finite(&end) {
+            return;
+        }
+        let Some(beam) = clip_segment_to_aabb(&Line2D::new(sensor, end), &self.bounds()) else {
+            return;
+        };
+        for cell in rasterize_line(self.world_to_cell(&beam.p1), self.world_to_cell(&beam.p2)) {
+            self.set(cell, CellState::Free);
+        }
+        let to = self.world_to_cell(&end);
+        self.set(to, if hit { CellState::Occupied } else { CellState::Free });
+    }
+
+    /// Records a scan of beams from `sensor`, each hitting something at one of `hits`.
+    pub fn integrate_scan(&mut self, sensor: Point2D<T>, hits: &[Point2D<T>]) {
+        // clear all beams first, so one beam cannot erase another's hit
+        for hit in hits {
+            self.integrate_ray(sensor, *hit, false);
+        }
+        for hit in hits {
+            let cell = self.world_to_cell(hit);
+            self.set(cell, CellState::Occupied);
+        }
+    }
+
+    /// Sets every cell whose center lies inside `polygon` to `state`, following
+    /// [`EdgeRule::CellCenter`](crate::algorithms::raster::EdgeRule::CellCenter).
+    pub fn fill_polygon(&mut self, polygon: &Polygon2D<T>, state: CellState) {
+        let size = (self.width, self.height);
+        scan_polygon(polygon, self.origin, self.resolution, size, EdgeRule::CellCenter, |column, row| {
+            self.cells[row * size.0 + column] = state;
+        });
+    }
+
+    fn index(&self, cell: Point2D<i32>) -> Option<usize> {
+        let (i, j) = (usize::try_from(cell.x).ok()?, usize::try_from(cell.y).ok()?);
+        (i < self.width && j < self.height).then(|| j * self.width + i)
+    }
+}
+
+/// Saturating conversion of an integral float to a cell coordinate.
+fn to_i32<T: Float>(value: T) -> i32 {
+    value.to_i32().unwrap_or(if value < T::zero() { i32::MIN } else { i32::MAX })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_world_cell_round_trip() {
+        let grid = OccupancyGrid::new(Point2D::new(-2.0, 1.0), 0.25, 16, 8);
+        assert_eq!(grid.world_to_cell(&Point2D::new(-2.0, 1.0)), Point2D::new(0, 0));
+        assert_eq!(grid.world_to_cell(&Point2D::new(-1.9, 2.99)), Point2D::new(0, 7));
+        assert_eq!(grid.world_to_cell(&Point2D::new(-2.1, 0.0)), Point2D::new(-1, -4));
+        assert_eq!(grid.cell_to_world(Point2D::new(3, 2)), Point2D::new(-1.125, 1.625));
+        assert_eq!(grid.world_to_cell(&grid.cell_to_world(Point2D::new(15, 7))), Point2D::new(15, 7));
+        assert!(!grid.contains_cell(Point2D::new(16, 0)));
+        assert_eq!(grid.get(Point2D::new(-1, 0)), None);
+    }
+
+    #[test]
+    fn test_integrate_scan_keeps_hits() {
+        let mut grid = OccupancyGrid::new(Point2D::new(0.0, 0.0), 1.0, 10, 10);
+        let sensor = Point2D::new(0.5, 0.5);
+        // the second beam passes through the first one's hit on its way out of the grid
+        grid.integrate_scan(sensor, &[Point2D::new(4.5, 4.5), Point2D::new(20.5, 20.5)]);
+        assert_eq!(grid.get(Point2D::new(4, 4)), Some(CellState::Occupied));
+        assert_eq!(grid.get(Point2D::new(3, 3)), Some(CellState::Free));
+        assert_eq!(grid.get(Point2D::new(9, 9)), Some(CellState::Free));
+        assert_eq!(grid.cells().iter().filter(|&&c| c != CellState::Unknown).count(), 10);
+    }
+
+    #[test]
+    fn test_integrate_ray_clips_to_grid() {
+        let mut grid = OccupancyGrid::new(Point2D::new(0.0, 0.0), 1.0, 10, 10);
+        // a max-range reading far past the grid only walks the cells on it
+        grid.integrate_ray(Point2D::new(0.5, 0.5), Point2D::new(1e300, 0.5), false);
+        assert!((0..10).all(|i| grid.get(Point2D::new(i, 0)) == Some(CellState::Free)));
+        assert_eq!(grid.cells().iter().filter(|&&c| c == CellState::Free).count(), 10);
+
+        // a beam from off the grid into it, and one missing it entirely
+        grid.integrate_ray(Point2D::new(-1e12, 5.5), Point2D::new(3.5, 5.5), true);
+        assert_eq!(grid.get(Point2D::new(0, 5)), Some(CellState::Free));
+        assert_eq!(grid.get(Point2D::new(3, 5)), Some(CellState::Occupied));
+        assert_eq!(grid.get(Point2D::new(4, 5)), Some(CellState::Unknown));
+        let before = grid.clone();
+        grid.integrate_ray(Point2D::new(-5.0, -5.0), Point2D::new(-5.0, 50.0), true);
+        grid.integrate_ray(Point2D::new(0.5, 0.5), Point2D::new(f64::INFINITY, 0.5), true);
+        grid.integrate_ray(Point2D::new(0.5, 0.5), Point2D::new(f64::NAN, 0.5), true);
+        assert_eq!(grid, before);
+    }
+
+    #[test]
+    fn test_fill_polygon_marks_cell_centers_inside() {
+        let mut grid = OccupancyGrid::new(Point2D::new(0.0, 0.0), 1.0, 10, 10);
+        // a right triangle with legs 6: cell centers strictly below the hypotenuse
+        let triangle = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(6.0, 0.0), Point2D::new(0.0, 6.0)]);
+        grid.fill_polygon(&triangle, CellState::Occupied);
+        assert_eq!(grid.cells().iter().filter(|&&c| c == CellState::Occupied).count(), 5 + 4 + 3 + 2 + 1);
+        assert_eq!(grid.get(Point2D::new(4, 0)), Some(CellState::Occupied));
+        // centered on the hypotenuse
+        assert_eq!(grid.get(Point2D::new(5, 0)), Some(CellState::Unknown));
+
+        // clipped to the grid
+        let big = Polygon2D::new(vec![Point2D::new(-5.0, 8.0), Point2D::new(50.0, 8.0), Point2D::new(50.0, 50.0), Point2D::new(-5.0, 50.0)]);
+        grid.fill_polygon(&big, CellState::Free);
+        assert_eq!(grid.cells().iter().filter(|&&c| c == CellState::Free).count(), 20);
+    }
+}
@@ -0,0 +1,333 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::line::{Line2D, Polyline2D};
+use crate::polygon::Polygon2D;
+use crate::shape::{Aabb2D, Distance};
+use crate::spatial::KdTree;
+use crate::algorithms::sampling::{Rng, XorShift};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Source of random configurations for [`RrtPlanner`].
+///
+/// Implemented by [`UniformSampler`] and by any `FnMut() -> Point2D<T>` closure,
+/// e.g. to sample around a known corridor more densely.
+pub trait Sampler<T: Float + Debug> {
+    fn sample(&mut self) -> Point2D<T>;
+}
+
+impl<T: Float + Debug, F: FnMut() -> Point2D<T>> Sampler<T> for F {
+    fn sample(&mut self) -> Point2D<T> {
+        self()
+    }
+}
+
+/// Seeded sampler drawing points uniformly from a box; the same seed gives the same points.
+#[derive(Debug, Clone)]
+pub struct UniformSampler<T: Float + Debug> {
+    bounds: Aabb2D<T>,
+    random: XorShift,
+}
+
+impl<T: Float + Debug> UniformSampler<T> {
+    pub fn new(bounds: Aabb2D<T>, seed: u64) -> Self {
+        UniformSampler { bounds, random: XorShift::new(seed) }
+    }
+}
+
+impl<T: Float + Debug> Sampler<T> for UniformSampler<T> {
+    fn sample(&mut self) -> Point2D<T> {
+        let (u, v) = (self.random.unit::<T>(), self.random.unit::<T>());
+        Point2D::new(self.bounds.min.x + u * self.bounds.width(), self.bounds.min.y + v * self.bounds.height())
+    }
+}
+
+/// Rapidly-exploring random tree planner among polygon obstacles, with optional
+/// RRT* rewiring.
+///
+/// # Algorithm Explanation
+/// - Each iteration draws a target, the goal itself with probability
+///   [`goal_bias`](Self::goal_bias) and otherwise a point from the sampler, and
+///   grows the tree from its nearest node by at most [`step_size`](Self::step_size)
+///   towards it, if that motion keeps [`clearance`](Self::clearance) from every obstacle.
+/// - Plain RRT stops as soon as a node gets within [`goal_tolerance`](Self::goal_tolerance)
+///   of the goal and can reach it directly.
+/// - With [`rewire`](Self::rewire) (RRT*), a new node instead takes the cheapest
+///   parent within the given radius and becomes the parent of neighbours it
+///   offers a shorter route. All iterations run, and the path only gets shorter.
+/// - Nodes are looked up in a [`KdTree`], rebuilt each time the tree doubles;
+///   nodes added since are scanned directly.
+///
+/// # Example
+/// ```
+/// use geomengine::navigation::{RrtPlanner, UniformSampler};
+/// use geomengine::point::Point2D;
+/// use geomengine::polygon::Polygon2D;
+/// use geomengine::shape::Aabb2D;
+///
+/// let wall = Polygon2D::new(vec![Point2D::new(4.0, 0.0), Point2D::new(6.0, 0.0), Point2D::new(6.0, 8.0), Point2D::new(4.0, 8.0)]);
+/// let mut sampler = UniformSampler::new(Aabb2D::new(Point2D::new(0.0, 0.0), Point2D::new(10.0, 10.0)), 7);
+///
+/// let mut planner = RrtPlanner::new(vec![wall]);
+/// planner.step_size(0.5).rewire(1.5).max_iterations(1500);
+/// let path = planner.plan(Point2D::new(1.0, 1.0), Point2D::new(9.0, 1.0), &mut sampler).unwrap();
+/// assert_eq!(path.vertices()[0], Point2D::new(1.0, 1.0));
+/// assert_eq!(*path.vertices().last().unwrap(), Point2D::new(9.0, 1.0));
+/// // over the wall at y = 8
+/// assert!(path.vertices().iter().any(|p| p.y > 8.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RrtPlanner<T: Float + Debug> {
+    obstacles: Vec<Polygon2D<T>>,
+    step_size: T,
+    goal_bias: T,
+    goal_tolerance: T,
+    clearance: T,
+    max_iterations: usize,
+    rewire_radius: Option<T>,
+    seed: u64,
+}
+
+impl<T: Float + Debug> RrtPlanner<T> {
+    /// Plain RRT with step size 1, goal bias 0.05, goal tolerance 1, no clearance
+    /// and at most 5000 iterations.
+    pub fn new(obstacles: Vec<Polygon2D<T>>) -> Self {
+        RrtPlanner {
+            obstacles,
+            step_size: T::one(),
+            goal_bias: T::from(0.05).unwrap(),
+            goal_tolerance: T::one(),
+            clearance: T::zero(),
+            max_iterations: 5000,
+            rewire_radius: None,
+            seed: 0x9e37_79b9_7f4a_7c15,
+        }
+    }
+
+    /// Longest motion added to the tree in one iteration.
+    pub fn step_size(&mut self, step_size: T) -> &mut Self {
+        self.step_size = step_size;
+        self
+    }
+    /// Probability in `0..=1` of steering straight for the goal instead of a sample.
+    pub fn goal_bias(&mut self, goal_bias: T) -> &mut Self {
+        self.goal_bias = goal_bias;
+        self
+    }
+    /// How close a node has to get for the goal to be connected to it directly.
+    pub fn goal_tolerance(&mut self, goal_tolerance: T) -> &mut Self {
+        self.goal_tolerance = goal_tolerance;
+        self
+    }
+    /// Distance every motion has to keep from the obstacles, e.g. the robot's radius.
+    pub fn clearance(&mut self, clearance: T) -> &mut Self {
+        self.clearance = clearance;
+        self
+    }
+    pub fn max_iterations(&mut self, max_iterations: usize) -> &mut Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+    /// Turns on RRT* rewiring among nodes within `radius` of each new node.
+    pub fn rewire(&mut self, radius: T) -> &mut Self {
+        self.rewire_radius = Some(radius);
+        self
+    }
+    /// Seed for the goal bias draws.
+    pub fn seed(&mut self, seed: u64) -> &mut Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Plans a path from `start` to `goal`, or `None` if none was found within
+    /// the iteration budget.
+    pub fn plan<S: Sampler<T>>(&self, start: Point2D<T>, goal: Point2D<T>, sampler: &mut S) -> Option<Polyline2D<T>> {
+        if !self.is_free(start, start) || !self.is_free(goal, goal) {
+            return None;
+        }
+        let mut tree = Tree::new(start);
+        let mut random = XorShift::new(self.seed);
+        for _ in 0..self.max_iterations {
+            let target = if random.unit::<T>() < self.goal_bias { goal } else { sampler.sample() };
+            let (near, distance) = tree.nearest(&target);
+            if distance == T::zero() {
+                continue;
+            }
+            let from = tree.points[near];
+            let new = if distance > self.step_size { from + (target - from) * (self.step_size / distance) } else { target };
+            if !self.is_free(from, new) {
+                continue;
+            }
+
+            match self.rewire_radius {
+                None => {
+                    let node = tree.insert(new, near);
+                    if self.reaches_goal(new, goal) {
+                        return Some(tree.path(node, goal));
+                    }
+                }
+                Some(radius) => {
+                    let neighbors: Vec<usize> = tree.within_radius(&new, radius).into_iter().filter(|&n| self.is_free(tree.points[n], new)).collect();
+                    let parent = neighbors.iter().copied().fold(near, |best, n| {
+                        if tree.cost[n] + tree.points[n].distance(&new) < tree.cost[best] + tree.points[best].distance(&new) { n } else { best }
+                    });
+                    let node = tree.insert(new, parent);
+                    for n in neighbors {
+                        tree.reparent(n, node);
+                    }
+                }
+            }
+        }
+
+        // RRT*: the cheapest node finishing at the goal, now that all costs have settled
+        let finish = (0..tree.points.len())
+            .filter(|&n| self.reaches_goal(tree.points[n], goal))
+            .min_by(|&a, &b| {
+                let cost = |n: usize| tree.cost[n] + tree.points[n].distance(&goal);
+                cost(a).partial_cmp(&cost(b)).unwrap()
+            })?;
+        Some(tree.path(finish, goal))
+    }
+
+    fn reaches_goal(&self, point: Point2D<T>, goal: Point2D<T>) -> bool {
+        point.distance(&goal) <= self.goal_tolerance && self.is_free(point, goal)
+    }
+
+    /// Does moving straight from `a` to `b` keep clear of every obstacle?
+    fn is_free(&self, a: Point2D<T>, b: Point2D<T>) -> bool {
+        let motion = Line2D::new(a, b);
+        self.obstacles.iter().all(|o| motion.distance_to(o) > self.clearance)
+    }
+}
+
+/// The search tree; node 0 is the start.
+struct Tree<T: Float + Debug> {
+    points: Vec<Point2D<T>>,
+    parent: Vec<usize>,
+    children: Vec<Vec<usize>>,
+    // path length from the start
+    cost: Vec<T>,
+    // indexes `points[..index.len()]`; later ones are scanned linearly
+    index: KdTree<T>,
+}
+
+impl<T: Float + Debug> Tree<T> {
+    fn new(start: Point2D<T>) -> Self {
+        Tree { points: vec![start], parent: vec![0], children: vec![Vec::new()], cost: vec![T::zero()], index: KdTree::new(&[]) }
+    }
+
+    fn insert(&mut self, point: Point2D<T>, parent: usize) -> usize {
+        let node = self.points.len();
+        self.points.push(point);
+        self.parent.push(parent);
+        self.children.push(Vec::new());
+        self.children[parent].push(node);
+        self.cost.push(self.cost[parent] + self.points[parent].distance(&point));
+        if self.points.len() >= 2 * self.index.len().max(16) {
+            self.index = KdTree::new(&self.points);
+        }
+        node
+    }
+
+    fn nearest(&self, target: &Point2D<T>) -> (usize, T) {
+        let recent = (self.index.len()..self.points.len()).map(|n| (n, self.points[n].distance(target)));
+        self.index
+            .nearest(target)
+            .into_iter()
+            .chain(recent)
+            .fold((0, T::infinity()), |best, candidate| if candidate.1 < best.1 { candidate } else { best })
+    }
+
+    fn within_radius(&self, center: &Point2D<T>, radius: T) -> Vec<usize> {
+        let mut found = self.index.within_radius(center, radius);
+        found.extend((self.index.len()..self.points.len()).filter(|&n| self.points[n].distance(center) <= radius));
+        found
+    }
+
+    /// Hangs `node` below `parent` if that shortens its path, updating the costs below it.
+    fn reparent(&mut self, node: usize, parent: usize) {
+        let through = self.cost[parent] + self.points[parent].distance(&self.points[node]);
+        if node == 0 || through >= self.cost[node] {
+            return;
+        }
+        let old = self.parent[node];
+        self.children[old].retain(|&c| c != node);
+        self.children[parent].push(node);
+        self.parent[node] = parent;
+
+        let saving = self.cost[node] - through;
+        let mut stack = vec![node];
+        while let Some(n) = stack.pop() {
+            self.cost[n] = self.cost[n] - saving;
+            stack.extend_from_slice(&self.children[n]);
+        }
+    }
+
+    fn path(&self, mut node: usize, goal: Point2D<T>) -> Polyline2D<T> {
+        let mut points = vec![goal];
+        loop {
+            if self.points[node] != goal {
+                points.push(self.points[node]);
+            }
+            if node == 0 {
+                break;
+            }
+            node = self.parent[node];
+        }
+        points.reverse();
+        Polyline2D::new(points)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(x: f64, y: f64, size: f64) -> Polygon2D<f64> {
+        Polygon2D::new(vec![Point2D::new(x, y), Point2D::new(x + size, y), Point2D::new(x + size, y + size), Point2D::new(x, y + size)])
+    }
+
+    fn sampler(seed: u64) -> UniformSampler<f64> {
+        UniformSampler::new(Aabb2D::new(Point2D::new(0.0, 0.0), Point2D::new(10.0, 10.0)), seed)
+    }
+
+    #[test]
+    fn test_rrt_path_is_collision_free() {
+        let obstacles = vec![square(2.0, 2.0, 2.0), square(5.0, 4.0, 3.0), square(3.0, 7.0, 2.0)];
+        let mut planner = RrtPlanner::new(obstacles.clone());
+        planner.step_size(0.5).clearance(0.1);
+        let (start, goal) = (Point2D::new(0.5, 0.5), Point2D::new(9.5, 9.5));
+        let path = planner.plan(start, goal, &mut sampler(1)).unwrap();
+
+        let vertices = path.vertices();
+        assert_eq!((vertices[0], vertices[vertices.len() - 1]), (start, goal));
+        for pair in vertices.windows(2) {
+            assert!(pair[0].distance(&pair[1]) <= 1.0 + 1e-12);
+            assert!(obstacles.iter().all(|o| Line2D::new(pair[0], pair[1]).distance_to(o) > 0.1));
+        }
+        // the same seeds plan the same path
+        assert_eq!(planner.plan(start, goal, &mut sampler(1)).unwrap(), path);
+    }
+
+    #[test]
+    fn test_rrt_star_shortens_paths() {
+        let (start, goal) = (Point2D::new(1.0, 5.0), Point2D::new(9.0, 5.0));
+        let mut planner = RrtPlanner::new(vec![square(4.0, 4.0, 2.0)]);
+        planner.step_size(0.5).max_iterations(1500);
+        let rrt = planner.plan(start, goal, &mut sampler(3)).unwrap();
+        let rrt_star = planner.rewire(2.0).plan(start, goal, &mut sampler(3)).unwrap();
+        // over or under the square the shortest path is 2 * sqrt(10) + 2 long
+        assert!(rrt_star.length() < rrt.length());
+        assert!(rrt_star.length() < (2.0 * 10f64.sqrt() + 2.0) * 1.05);
+    }
+
+    #[test]
+    fn test_rrt_fails_for_blocked_goal() {
+        let mut planner = RrtPlanner::new(vec![square(4.0, 4.0, 2.0)]);
+        assert!(planner.plan(Point2D::new(1.0, 1.0), Point2D::new(5.0, 5.0), &mut sampler(0)).is_none());
+        // a closure sampler that never leaves the start's corner
+        let mut corner = || Point2D::new(0.5, 0.5);
+        assert!(planner.goal_bias(0.0).max_iterations(100).plan(Point2D::new(1.0, 1.0), Point2D::new(9.0, 9.0), &mut corner).is_none());
+    }
+}
@@ -1,4 +1,11 @@
 // module exports
 mod point2d;
+mod point3d;
+mod point_n;
+mod vector2d;
 
-pub use point2d::Point2D;
\ No newline at end of file
+pub use point2d::Point2D;
+pub(crate) use point2d::{write_point_list, total_cmp};
+pub use point3d::Point3D;
+pub use point_n::Point;
+pub use vector2d::Vector2D;
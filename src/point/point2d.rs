@@ -1,8 +1,16 @@
-use num::{Num, Float};
-use std::ops::{Add, Sub, Mul, Div};
-use std::fmt::{Debug, Display, self};
-
+use num::{Num, Float, Zero};
+use core::ops::{Add, Sub, Mul, Div};
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display, self};
+use super::{Point, Vector2D};
+use crate::transform::Angle;
+use alloc::string::String;
+use alloc::format;
+
+/// `repr(C)` guarantees the `x, y` field order, so a `[Point2D<T>]` can be treated
+/// as interleaved coordinates by the batch (SIMD) routines.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
 pub struct Point2D<T: Num + Copy + Debug + PartialEq> {
     pub x: T,
     pub y: T,
@@ -19,6 +27,38 @@ impl<T: Num + Copy + Debug> Point2D<T> {
             y: T::zero(),
         }
     }
+    pub fn x(&self) -> T {
+        self.x
+    }
+    pub fn y(&self) -> T {
+        self.y
+    }
+    /// The coordinates as an `(x, y)` tuple, the form [`translate`](Self::translate) takes.
+    pub fn coords(&self) -> (T, T) {
+        (self.x, self.y)
+    }
+    pub fn set_x(&mut self, x: T) {
+        self.x = x;
+    }
+    pub fn set_y(&mut self, y: T) {
+        self.y = y;
+    }
+    /// A copy of the point with x replaced.
+    pub fn with_x(&self, x: T) -> Self {
+        Point2D { x, y: self.y }
+    }
+    /// A copy of the point with y replaced.
+    pub fn with_y(&self, y: T) -> Self {
+        Point2D { x: self.x, y }
+    }
+    /// The unit vector along the x axis, `(1, 0)`.
+    pub fn unit_x() -> Self {
+        Point2D::new(T::one(), T::zero())
+    }
+    /// The unit vector along the y axis, `(0, 1)`.
+    pub fn unit_y() -> Self {
+        Point2D::new(T::zero(), T::one())
+    }
     pub fn translate(&self, param: (T, T)) -> Self {
         Point2D{
             x: self.x + param.0,
@@ -38,6 +78,20 @@ impl<T: Num + Copy + Debug> Point2D<T> {
         }
     }
 }
+/// The origin, so structs holding points can derive `Default`.
+impl<T: Num + Copy + Debug> Default for Point2D<T> {
+    fn default() -> Self {
+        Point2D::origin()
+    }
+}
+impl<T: Num + Copy + Debug> Zero for Point2D<T> {
+    fn zero() -> Self {
+        Point2D::origin()
+    }
+    fn is_zero(&self) -> bool {
+        self.x.is_zero() && self.y.is_zero()
+    }
+}
 impl<T: Num + Copy + Debug> Add for Point2D<T> {
     type Output = Self;
     fn add(self, other: Point2D<T>) -> Point2D<T> {
@@ -47,13 +101,11 @@ impl<T: Num + Copy + Debug> Add for Point2D<T> {
         }
     }
 }
+/// The vector from `other` to `self`, see [`vector_to`](Self::vector_to).
 impl<T: Num + Copy + Debug> Sub for Point2D<T> {
-    type Output = Self;
+    type Output = Vector2D<T>;
     fn sub(self, other: Self) -> Self::Output {
-        Point2D{
-            x: self.x - other.x,
-            y: self.y - other.y,
-        }
+        Vector2D::new(self.x - other.x, self.y - other.y)
     }
 }
 impl<T: Num + Copy + Debug> Mul<T> for Point2D<T> {
@@ -75,45 +127,78 @@ impl<T: Num + Copy + Debug> Div<T> for Point2D<T> {
         }
     }
 }
+/// `(x, y)`, or WKT `POINT (x y)` with `{:#}`. A precision (`{:.3}`) applies to
+/// both coordinates.
 impl<T: Num + Copy + Debug + Display> Display for Point2D<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "({}, {})", self.x, self.y)
+        if f.alternate() {
+            write!(f, "POINT (")?;
+            self.write_coords(f, " ")?;
+            write!(f, ")")
+        } else {
+            write!(f, "(")?;
+            self.write_coords(f, ", ")?;
+            write!(f, ")")
+        }
     }
 }
 
+impl<T: Num + Copy + Debug + Display> Point2D<T> {
+    /// The point as WKT, e.g. `POINT (1.5 2)`.
+    ///
+    /// # Example
+    /// ```
+    /// use geomengine::point::Point2D;
+    ///
+    /// let point = Point2D::new(1.5, 2.0);
+    /// assert_eq!(point.to_wkt_string(), "POINT (1.5 2)");
+    /// assert_eq!(format!("{point:.2}"), "(1.50, 2.00)");
+    /// assert_eq!(format!("{point:#.1}"), "POINT (1.5 2.0)");
+    /// ```
+    pub fn to_wkt_string(&self) -> String {
+        format!("{:#}", self)
+    }
+    /// Writes `x`, `separator`, `y`, honoring the precision of `f`.
+    pub(crate) fn write_coords(&self, f: &mut fmt::Formatter<'_>, separator: &str) -> fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*}{}{:.*}", precision, self.x, separator, precision, self.y),
+            None => write!(f, "{}{}{}", self.x, separator, self.y),
+        }
+    }
+}
+
+/// Writes `points` as `(x, y), (x, y), ...`, or as the WKT coordinate list
+/// `x y, x y, ...` if `f` is alternate.
+pub(crate) fn write_point_list<T: Num + Copy + Debug + Display>(f: &mut fmt::Formatter<'_>, points: impl IntoIterator<Item = Point2D<T>>) -> fmt::Result {
+    for (i, point) in points.into_iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        if f.alternate() {
+            point.write_coords(f, " ")?;
+        } else {
+            Display::fmt(&point, f)?;
+        }
+    }
+    Ok(())
+}
+
 impl<T: Float + Debug> Point2D<T> {
     pub fn distance(&self, other: &Point2D<T>) -> T {
-        ((self.x - other.x) * (self.x - other.x) + (self.y - other.y) * (self.y - other.y)).sqrt()
+        Point::from(*self).distance(&Point::from(*other))
     }
     pub fn dot_product(&self, other: &Point2D<T>) -> T {
-        self.x * other.x + self.y * other.y
+        Point::from(*self).dot_product(&Point::from(*other))
     }
     pub fn cross_product(&self, other: &Point2D<T>) -> T {
         self.x * other.y - self.y * other.x
     }
 
-    
-    /// Normalize the point: unit vector like conversion
-    pub fn normalize(&self) -> Option<Point2D<T>> {
-        let magnitude = (self.x * self.x + self.y * self.y).sqrt();
-        
-        // if it is a zero vector representation
-        if magnitude == T::zero() {
-            return None; // cannot normalize a zero vector
-        }
-
-        Some( Point2D {
-            x: self.x / magnitude,
-            y: self.y / magnitude,
-        })
-    }
-     /// rotates the point around another point (defaulting to origin)
-     pub fn rotate(&self, angle: f64, center: Option<Point2D<T>>) -> Point2D<T> {
-        let theta = T::from(angle.to_radians()).unwrap();
-        let cos_theta = theta.cos();
-        let sin_theta = theta.sin();
+     /// rotates the point counterclockwise around another point (defaulting to origin)
+     pub fn rotate(&self, angle: Angle<T>, center: Option<Point2D<T>>) -> Point2D<T> {
+        let (sin_theta, cos_theta) = angle.sin_cos();
 
-        let center = center.unwrap_or( Point2D::origin());
+        let center = center.unwrap_or_default();
         
         let new_x = center.x + (self.x - center.x) * cos_theta - (self.y - center.y) * sin_theta;
         let new_y = center.y + (self.x - center.x) * sin_theta + (self.y - center.y) * cos_theta;
@@ -121,9 +206,25 @@ impl<T: Float + Debug> Point2D<T> {
         Point2D { x: new_x, y: new_y }
     }
     /// rotates the point around origin
-    pub fn rotate_origin(&self, angle: f64) -> Point2D<T> {
+    pub fn rotate_origin(&self, angle: Angle<T>) -> Point2D<T> {
         self.rotate(angle, None)
     }
+    /// Total order by x, then y, for sorting and sweeps.
+    ///
+    /// NaN coordinates sort after every number (and equal to each other) instead
+    /// of making the order inconsistent; `-0.0` and `0.0` are equal.
+    pub fn cmp_xy(&self, other: &Point2D<T>) -> Ordering {
+        total_cmp(self.x, other.x).then(total_cmp(self.y, other.y))
+    }
+    /// Total order by y, then x, with NaNs handled as in [`cmp_xy`](Self::cmp_xy).
+    pub fn cmp_yx(&self, other: &Point2D<T>) -> Ordering {
+        total_cmp(self.y, other.y).then(total_cmp(self.x, other.x))
+    }
+}
+
+/// `a.partial_cmp(b)`, with NaN ordered after every number.
+pub(crate) fn total_cmp<T: Float>(a: T, b: T) -> Ordering {
+    a.partial_cmp(&b).unwrap_or_else(|| a.is_nan().cmp(&b.is_nan()))
 }
 
 #[cfg(test)]
@@ -137,6 +238,18 @@ mod tests{
         assert_eq!(point.y, 2.0);
     }
 
+    #[test]
+    fn test_point2d_accessors() {
+        let mut point = Point2D::new(1, 2);
+        assert_eq!((point.x(), point.y()), (1, 2));
+        assert_eq!(point.with_x(5).coords(), (5, 2));
+        assert_eq!(point.with_y(-1), Point2D::new(1, -1));
+        point.set_x(3);
+        point.set_y(4);
+        assert_eq!(point.coords(), (3, 4));
+        assert_eq!(Point2D::origin().translate(point.coords()), point);
+    }
+
     #[test]
     fn test_point2d_origin() {
         let point: Point2D<f64> = Point2D::origin();
@@ -144,6 +257,48 @@ mod tests{
         assert_eq!(point.y, 0.0);
     }
 
+    #[test]
+    fn test_point2d_default_zero_and_units() {
+        #[derive(Default)]
+        struct Marker {
+            position: Point2D<i32>,
+        }
+        assert_eq!(Marker::default().position, Point2D::new(0, 0));
+        assert!(Point2D::<f64>::zero().is_zero());
+        assert!(!Point2D::<f64>::unit_x().is_zero());
+        let sum: Point2D<f64> = [Point2D::unit_x(), Point2D::unit_y(), Point2D::unit_x()]
+            .into_iter()
+            .fold(Zero::zero(), |sum, p| sum + p);
+        assert_eq!(sum, Point2D::new(2.0, 1.0));
+        assert_eq!(Point2D::<f64>::unit_x().cross_product(&Point2D::unit_y()), 1.0);
+    }
+
+    #[test]
+    fn test_point2d_display_precision_and_wkt() {
+        let point = Point2D::new(123456.789, -0.5);
+        assert_eq!(point.to_string(), "(123456.789, -0.5)");
+        assert_eq!(format!("{point:.1}"), "(123456.8, -0.5)");
+        assert_eq!(format!("{point:#}"), "POINT (123456.789 -0.5)");
+        assert_eq!(Point2D::new(3, 4).to_wkt_string(), "POINT (3 4)");
+    }
+
+    #[test]
+    fn test_point2d_cmp_is_total_with_nan() {
+        let mut points = [
+            Point2D::new(1.0, f64::NAN),
+            Point2D::new(1.0, 2.0),
+            Point2D::new(f64::NAN, 0.0),
+            Point2D::new(-0.0, 5.0),
+            Point2D::new(1.0, -2.0),
+            Point2D::new(0.0, 3.0),
+        ];
+        points.sort_by(Point2D::cmp_xy);
+        assert_eq!(&points[..4], &[Point2D::new(0.0, 3.0), Point2D::new(-0.0, 5.0), Point2D::new(1.0, -2.0), Point2D::new(1.0, 2.0)]);
+        assert!(points[4].y.is_nan() && points[5].x.is_nan());
+        assert_eq!(Point2D::new(2.0, 1.0).cmp_yx(&Point2D::new(1.0, 2.0)), Ordering::Less);
+        assert_eq!(Point2D::new(f64::NAN, 1.0).cmp_xy(&Point2D::new(f64::NAN, 1.0)), Ordering::Equal);
+    }
+
     #[test]
     fn test_point2d_add() {
         let p1 = Point2D::new(1.0, 2.0);
@@ -219,22 +374,22 @@ mod tests{
         let p = Point2D { x: 1.0, y: 0.0 };
 
         //90° rotation should rotate (1,0) to (0,1)
-        let rotated = p.rotate(90.0, None);
+        let rotated = p.rotate(Angle::from_degrees(90.0), None);
         assert_relative_eq!(rotated.x, 0.0, epsilon = 1e-6);
         assert_relative_eq!(rotated.y, 1.0, epsilon = 1e-6);
 
         //180° rotation
-        let rotated = p.rotate(180.0, None);
+        let rotated = p.rotate(Angle::from_degrees(180.0), None);
         assert_relative_eq!(rotated.x, -1.0, epsilon=1e-6);
         assert_relative_eq!(rotated.y, 0.0, epsilon=1e-6);
         
         //270° rotation
-        let rotated = p.rotate(270.0, None);
+        let rotated = p.rotate(Angle::from_degrees(270.0), None);
         assert_relative_eq!(rotated.x, 0.0, epsilon=1e-6);
         assert_relative_eq!(rotated.y, -1.0, epsilon=1e-6);
 
         //360° rotation
-        let rotated = p.rotate(360.0, None);
+        let rotated = p.rotate(Angle::from_degrees(360.0), None);
         assert_relative_eq!(rotated.x, 1.0, epsilon=1e-6);
         assert_relative_eq!(rotated.y, 0.0, epsilon=1e-6);
     }
@@ -243,7 +398,7 @@ mod tests{
     fn test_point2d_rotation_negative_coordinates() {
         let p = Point2D {x: -1.0, y: -1.0};
 
-        let rotated = p.rotate(180.0, None);
+        let rotated = p.rotate(Angle::from_degrees(180.0), None);
 
         assert_relative_eq!(rotated.x, 1.0, epsilon=1e-6);
         assert_relative_eq!(rotated.y, 1.0, epsilon=1e-6);
@@ -254,36 +409,12 @@ mod tests{
         let center = Point2D {x: 1.0, y: 1.0 };
         
 
-        let rotated = p.rotate(90.0, Some(center));
+        let rotated = p.rotate(Angle::from_degrees(90.0), Some(center));
         assert_relative_eq!(rotated.x, 0.0, epsilon=1e-6);
         assert_relative_eq!(rotated.y, 2.0, epsilon=1e-6);
 
-        let rotated = p.rotate(180.0, Some(center));
+        let rotated = p.rotate(Angle::from_degrees(180.0), Some(center));
         assert_relative_eq!(rotated.x, 0.0, epsilon=1e-6);
         assert_relative_eq!(rotated.y, 0.0, epsilon=1e-6);
     }
-    #[test]
-    fn test_normalization_nonzero_vector() {
-        let p = Point2D {x: 3.0, y: 4.0};
-        let normalized = p.normalize().unwrap();
-        
-        //expected unit vector (0.6, 0.8)
-        assert_relative_eq!(normalized.x, 0.6, epsilon=1e-6);
-        assert_relative_eq!(normalized.y, 0.8, epsilon=1e-6);
-    }
-
-    #[test]
-    fn test_point2d_normalize_unit_vector() {
-        let p = Point2D {x: 1.0, y: 0.0};
-        let normalized = p.normalize().unwrap();
-        
-        assert_relative_eq!(normalized.x, 1.0, epsilon=1e-6);
-        assert_relative_eq!(normalized.y, 0.0, epsilon=1e-6);
-    }
-
-    #[test]
-    fn test_point2d_normalize_zero_vector() {
-        let p =  Point2D {x: 0.0, y: 0.0};
-        assert!(p.normalize().is_none(), "Expected None: Cannot normalize zero vector");
-    }
 }
@@ -0,0 +1,100 @@
+use num::{Num, Float};
+use core::fmt::Debug;
+use super::Point;
+use crate::transform::Angle;
+
+/// A point in 3D space, `Point3D::new([x, y, z])`.
+///
+/// Arithmetic, `distance`, `dot_product` and `normalize` come from [`Point`];
+/// the methods here only make sense in three dimensions.
+pub type Point3D<T> = Point<T, 3>;
+
+impl<T: Num + Copy + Debug> Point<T, 3> {
+    pub fn x(&self) -> T {
+        self.coords[0]
+    }
+    pub fn y(&self) -> T {
+        self.coords[1]
+    }
+    pub fn z(&self) -> T {
+        self.coords[2]
+    }
+    pub fn translate(&self, param: (T, T, T)) -> Self {
+        let [x, y, z] = self.coords;
+        Point::new([x + param.0, y + param.1, z + param.2])
+    }
+    pub fn cross_product(&self, other: &Self) -> Self {
+        let [a1, a2, a3] = self.coords;
+        let [b1, b2, b3] = other.coords;
+        Point::new([a2 * b3 - a3 * b2, a3 * b1 - a1 * b3, a1 * b2 - a2 * b1])
+    }
+}
+
+impl<T: Float + Debug> Point<T, 3> {
+    /// Rotates the point by `angle` about the line through `center` (defaulting
+    /// to origin) along `axis`, counterclockwise when looking down the axis
+    /// towards `center`.
+    ///
+    /// # Returns
+    /// * The rotated point, or `None` if `axis` is the zero vector.
+    ///
+    /// # Algorithm Explanation
+    /// Rodrigues' formula: with `k` the unit axis and `v` the offset from `center`,
+    /// `v cos θ + (k × v) sin θ + k (k · v)(1 - cos θ)`.
+    ///
+    /// # Example
+    /// ```
+    /// use geomengine::point::Point3D;
+    /// use geomengine::transform::Angle;
+    ///
+    /// let z_axis = Point3D::new([0.0, 0.0, 2.0]);
+    /// let rotated = Point3D::new([1.0, 0.0, 5.0]).rotate(z_axis, Angle::from_degrees(90.0), None).unwrap();
+    /// assert!(rotated.distance(&Point3D::new([0.0, 1.0, 5.0])) < 1e-12);
+    /// ```
+    pub fn rotate(&self, axis: Point3D<T>, angle: Angle<T>, center: Option<Point3D<T>>) -> Option<Point3D<T>> {
+        let k = axis.normalize()?;
+        let center = center.unwrap_or(Point::origin());
+        let v = *self - center;
+        let (sin_theta, cos_theta) = angle.sin_cos();
+        let rotated = v * cos_theta + k.cross_product(&v) * sin_theta + k * (k.dot_product(&v) * (T::one() - cos_theta));
+        Some(center + rotated)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_point3d() {
+        let x = Point3D::new([1.0, 0.0, 0.0]);
+        let y = Point3D::new([0.0, 1.0, 0.0]);
+        assert_eq!(x.cross_product(&y), Point3D::new([0.0, 0.0, 1.0]));
+        assert_relative_eq!(x.distance(&y), 2f64.sqrt());
+        assert_eq!(Point3D::new([0.0, 3.0, 4.0]).norm(), 5.0);
+        assert_eq!(y.y(), 1.0);
+        assert_eq!(x.translate((1.0, 2.0, -3.0)), Point3D::new([2.0, 2.0, -3.0]));
+    }
+
+    #[test]
+    fn test_point3d_rotate_about_axis() {
+        // a third of a turn about the diagonal cycles the axes
+        let diagonal = Point3D::new([1.0, 1.0, 1.0]);
+        let rotated = Point3D::new([1.0, 0.0, 0.0]).rotate(diagonal, Angle::from_degrees(120.0), None).unwrap();
+        assert_relative_eq!(rotated.x(), 0.0, epsilon = 1e-12);
+        assert_relative_eq!(rotated.y(), 1.0, epsilon = 1e-12);
+        assert_relative_eq!(rotated.z(), 0.0, epsilon = 1e-12);
+
+        // about a line through a custom center, points on the axis stay put
+        let center = Point3D::new([1.0, 1.0, 0.0]);
+        let rotated = Point3D::new([2.0, 1.0, 0.0]).rotate(Point3D::new([0.0, 0.0, 1.0]), Angle::from_degrees(180.0), Some(center)).unwrap();
+        assert_relative_eq!(rotated.x(), 0.0, epsilon = 1e-12);
+        assert_relative_eq!(rotated.y(), 1.0, epsilon = 1e-12);
+        let on_axis = Point3D::new([1.0, 1.0, 7.0]);
+        let rotated = on_axis.rotate(Point3D::new([0.0, 0.0, 1.0]), Angle::from_degrees(33.0), Some(center)).unwrap();
+        assert_relative_eq!(rotated.distance(&on_axis), 0.0, epsilon = 1e-12);
+
+        assert_eq!(Point3D::new([1.0, 0.0, 0.0]).rotate(Point3D::origin(), Angle::from_degrees(90.0), None), None);
+    }
+}
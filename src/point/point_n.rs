@@ -0,0 +1,144 @@
+use num::{Num, Float};
+use core::ops::{Add, Sub, Mul, Div, Index, IndexMut};
+use core::fmt::{Debug, Display, self};
+use super::Point2D;
+
+/// A point (or vector) with `N` coordinates.
+///
+/// Arithmetic, dot product, distance and normalization are implemented once for
+/// every dimension, e.g. `Point<f64, 4>` for homogeneous coordinates or feature
+/// vectors. [`Point3D`](super::Point3D) is the 3D instance; [`Point2D`] keeps its named `x`/`y`
+/// fields and converts losslessly to and from `Point<T, 2>`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(transparent)]
+pub struct Point<T: Num + Copy + Debug, const N: usize> {
+    pub coords: [T; N],
+}
+
+impl<T: Num + Copy + Debug, const N: usize> Point<T, N> {
+    pub fn new(coords: [T; N]) -> Self {
+        Point { coords }
+    }
+    pub fn origin() -> Self {
+        Point { coords: [T::zero(); N] }
+    }
+    /// Applies `f` to every pair of coordinates of `self` and `other`.
+    fn zip_with(self, other: Self, f: impl Fn(T, T) -> T) -> Self {
+        Point { coords: core::array::from_fn(|i| f(self.coords[i], other.coords[i])) }
+    }
+    /// Applies `f` to every coordinate.
+    fn map(self, f: impl Fn(T) -> T) -> Self {
+        Point { coords: self.coords.map(f) }
+    }
+}
+
+impl<T: Num + Copy + Debug, const N: usize> Add for Point<T, N> {
+    type Output = Self;
+    fn add(self, other: Self) -> Self::Output {
+        self.zip_with(other, |a, b| a + b)
+    }
+}
+impl<T: Num + Copy + Debug, const N: usize> Sub for Point<T, N> {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self::Output {
+        self.zip_with(other, |a, b| a - b)
+    }
+}
+impl<T: Num + Copy + Debug, const N: usize> Mul<T> for Point<T, N> {
+    type Output = Self;
+    fn mul(self, scalar: T) -> Self::Output {
+        self.map(|a| a * scalar)
+    }
+}
+impl<T: Num + Copy + Debug, const N: usize> Div<T> for Point<T, N> {
+    type Output = Self;
+    fn div(self, scalar: T) -> Self::Output {
+        self.map(|a| a / scalar)
+    }
+}
+impl<T: Num + Copy + Debug, const N: usize> Index<usize> for Point<T, N> {
+    type Output = T;
+    fn index(&self, axis: usize) -> &T {
+        &self.coords[axis]
+    }
+}
+impl<T: Num + Copy + Debug, const N: usize> IndexMut<usize> for Point<T, N> {
+    fn index_mut(&mut self, axis: usize) -> &mut T {
+        &mut self.coords[axis]
+    }
+}
+impl<T: Num + Copy + Debug + Display, const N: usize> Display for Point<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+        for (i, c) in self.coords.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", c)?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl<T: Float + Debug, const N: usize> Point<T, N> {
+    pub fn dot_product(&self, other: &Self) -> T {
+        // starting from the first product (not zero) keeps the 2D results bit-identical to `Point2D`
+        match N {
+            0 => T::zero(),
+            _ => (1..N).fold(self.coords[0] * other.coords[0], |sum, i| sum + self.coords[i] * other.coords[i]),
+        }
+    }
+    /// Euclidean length of the vector.
+    pub fn norm(&self) -> T {
+        self.dot_product(self).sqrt()
+    }
+    pub fn distance(&self, other: &Self) -> T {
+        (*self - *other).norm()
+    }
+    /// Normalize the point: unit vector like conversion
+    pub fn normalize(&self) -> Option<Self> {
+        let magnitude = self.norm();
+        if magnitude == T::zero() {
+            return None; // cannot normalize a zero vector
+        }
+        Some(*self / magnitude)
+    }
+}
+
+impl<T: Num + Copy + Debug> From<Point2D<T>> for Point<T, 2> {
+    fn from(p: Point2D<T>) -> Self {
+        Point::new([p.x, p.y])
+    }
+}
+impl<T: Num + Copy + Debug> From<Point<T, 2>> for Point2D<T> {
+    fn from(p: Point<T, 2>) -> Self {
+        let [x, y] = p.coords;
+        Point2D::new(x, y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_n_arithmetic() {
+        let a = Point::new([1.0, 2.0, 3.0, 1.0]);
+        let b = Point::new([0.5, -1.0, 2.0, 0.0]);
+        assert_eq!(a + b, Point::new([1.5, 1.0, 5.0, 1.0]));
+        assert_eq!(a - b, Point::new([0.5, 3.0, 1.0, 1.0]));
+        assert_eq!(a * 2.0, Point::new([2.0, 4.0, 6.0, 2.0]));
+        assert_eq!(a.dot_product(&b), 4.5);
+        assert_eq!(Point::<f64, 4>::origin().normalize(), None);
+        assert_eq!(a.to_string(), "(1, 2, 3, 1)");
+    }
+
+    #[test]
+    fn test_point2d_round_trip_matches_point2d_math() {
+        let (a, b) = (Point2D::new(0.3, -1.7), Point2D::new(2.9, 0.1));
+        let (pa, pb): (Point<f64, 2>, Point<f64, 2>) = (a.into(), b.into());
+        assert_eq!(Point2D::from(pa + pb), a + b);
+        assert_eq!(pa.distance(&pb), a.distance(&b));
+        assert_eq!(pa.dot_product(&pb), a.dot_product(&b));
+    }
+}
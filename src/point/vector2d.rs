@@ -0,0 +1,214 @@
+use num::{Num, Float, Zero};
+use core::ops::{Add, Sub, Neg, Mul, Div};
+use core::fmt::Debug;
+use super::Point2D;
+use crate::transform::Angle;
+
+/// A displacement in the plane, as opposed to the position a [`Point2D`] stands for.
+///
+/// Positions and displacements combine the way they do on paper: a point plus a
+/// vector is a point, and the difference of two points, `end - start` or
+/// [`Point2D::vector_to`], is the vector between them. Length, direction and
+/// normalization live here, so code cannot accidentally normalize a position.
+///
+/// # Example
+/// ```
+/// use geomengine::point::{Point2D, Vector2D};
+///
+/// let (start, end) = (Point2D::new(1.0, 1.0), Point2D::new(4.0, 5.0));
+/// let step = start.vector_to(&end);
+/// assert_eq!(step.magnitude(), 5.0);
+/// assert_eq!(start + step.normalize().unwrap() * 10.0, Point2D::new(7.0, 9.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Vector2D<T: Num + Copy + Debug> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Num + Copy + Debug> Vector2D<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Vector2D { x, y }
+    }
+    /// The vector from `from` to `to`.
+    pub fn between(from: Point2D<T>, to: Point2D<T>) -> Self {
+        Vector2D::new(to.x - from.x, to.y - from.y)
+    }
+    pub fn dot_product(&self, other: &Self) -> T {
+        self.x * other.x + self.y * other.y
+    }
+    /// The z component of the 3D cross product, positive if `other` is counterclockwise of `self`.
+    pub fn cross_product(&self, other: &Self) -> T {
+        self.x * other.y - self.y * other.x
+    }
+    pub fn magnitude_squared(&self) -> T {
+        self.dot_product(self)
+    }
+    /// The vector turned a quarter turn counterclockwise.
+    pub fn perpendicular(&self) -> Self {
+        Vector2D::new(T::zero() - self.y, self.x)
+    }
+}
+
+impl<T: Float + Debug> Vector2D<T> {
+    pub fn magnitude(&self) -> T {
+        self.x.hypot(self.y)
+    }
+    /// The unit vector in the same direction, or `None` for the zero vector.
+    pub fn normalize(&self) -> Option<Self> {
+        let magnitude = self.magnitude();
+        if magnitude == T::zero() {
+            return None;
+        }
+        Some(*self / magnitude)
+    }
+    /// The direction of the vector, measured counterclockwise from the x axis, in `(-π, π]`.
+    pub fn angle(&self) -> Angle<T> {
+        Angle::atan2(self.y, self.x)
+    }
+    /// The signed angle turning `self` onto `other`, in `(-π, π]`, positive counterclockwise.
+    pub fn angle_to(&self, other: &Self) -> Angle<T> {
+        Angle::atan2(self.cross_product(other), self.dot_product(other))
+    }
+    /// The component of `self` along `onto`, or `None` if `onto` is the zero vector.
+    pub fn project_onto(&self, onto: &Self) -> Option<Self> {
+        let length_sq = onto.magnitude_squared();
+        if length_sq == T::zero() {
+            return None;
+        }
+        Some(*onto * (self.dot_product(onto) / length_sq))
+    }
+}
+
+impl<T: Num + Copy + Debug> Point2D<T> {
+    /// The vector from `self` to `other`.
+    pub fn vector_to(&self, other: &Point2D<T>) -> Vector2D<T> {
+        Vector2D::between(*self, *other)
+    }
+}
+
+/// The zero vector.
+impl<T: Num + Copy + Debug> Default for Vector2D<T> {
+    fn default() -> Self {
+        Vector2D::zero()
+    }
+}
+impl<T: Num + Copy + Debug> Zero for Vector2D<T> {
+    fn zero() -> Self {
+        Vector2D::new(T::zero(), T::zero())
+    }
+    fn is_zero(&self) -> bool {
+        self.x.is_zero() && self.y.is_zero()
+    }
+}
+/// The position vector of the point, i.e. its offset from the origin.
+impl<T: Num + Copy + Debug> From<Point2D<T>> for Vector2D<T> {
+    fn from(p: Point2D<T>) -> Self {
+        Vector2D::new(p.x, p.y)
+    }
+}
+/// The point reached by moving from the origin by the vector.
+impl<T: Num + Copy + Debug> From<Vector2D<T>> for Point2D<T> {
+    fn from(v: Vector2D<T>) -> Self {
+        Point2D::new(v.x, v.y)
+    }
+}
+impl<T: Num + Copy + Debug> Add for Vector2D<T> {
+    type Output = Self;
+    fn add(self, other: Self) -> Self::Output {
+        Vector2D::new(self.x + other.x, self.y + other.y)
+    }
+}
+impl<T: Num + Copy + Debug> Sub for Vector2D<T> {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self::Output {
+        Vector2D::new(self.x - other.x, self.y - other.y)
+    }
+}
+impl<T: Num + Copy + Debug + Neg<Output = T>> Neg for Vector2D<T> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Vector2D::new(-self.x, -self.y)
+    }
+}
+impl<T: Num + Copy + Debug> Mul<T> for Vector2D<T> {
+    type Output = Self;
+    fn mul(self, scalar: T) -> Self::Output {
+        Vector2D::new(self.x * scalar, self.y * scalar)
+    }
+}
+impl<T: Num + Copy + Debug> Div<T> for Vector2D<T> {
+    type Output = Self;
+    fn div(self, scalar: T) -> Self::Output {
+        Vector2D::new(self.x / scalar, self.y / scalar)
+    }
+}
+impl<T: Num + Copy + Debug> Add<Vector2D<T>> for Point2D<T> {
+    type Output = Point2D<T>;
+    fn add(self, v: Vector2D<T>) -> Point2D<T> {
+        Point2D::new(self.x + v.x, self.y + v.y)
+    }
+}
+impl<T: Num + Copy + Debug> Sub<Vector2D<T>> for Point2D<T> {
+    type Output = Point2D<T>;
+    fn sub(self, v: Vector2D<T>) -> Point2D<T> {
+        Point2D::new(self.x - v.x, self.y - v.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_vector2d_points_and_vectors_combine() {
+        let (a, b) = (Point2D::new(2, 3), Point2D::new(5, -1));
+        let v = a.vector_to(&b);
+        assert_eq!(v, Vector2D::new(3, -4));
+        assert_eq!(b - a, v);
+        assert_eq!(a + v, b);
+        assert_eq!(b - v, a);
+        assert_eq!(-v + v, Vector2D::zero());
+        assert_eq!(Vector2D::from(b) - Vector2D::from(a), v);
+        assert_eq!(Point2D::from(v * 2), Point2D::new(6, -8));
+        assert_eq!(v.perpendicular(), Vector2D::new(4, 3));
+        assert_eq!(v.magnitude_squared(), 25);
+    }
+
+    #[test]
+    fn test_vector2d_angles_and_projection() {
+        let x = Vector2D::new(2.0, 0.0);
+        let diagonal = Vector2D::new(-1.0, 1.0);
+        assert_relative_eq!(diagonal.angle().degrees(), 135.0, epsilon = 1e-12);
+        assert_relative_eq!(diagonal.angle_to(&x).degrees(), -135.0, epsilon = 1e-12);
+        assert_eq!(diagonal.project_onto(&x), Some(Vector2D::new(-1.0, 0.0)));
+        assert_eq!(x.project_onto(&Vector2D::zero()), None);
+        assert_eq!(x.normalize(), Some(Vector2D::new(1.0, 0.0)));
+        assert_eq!(Vector2D::<f64>::default().normalize(), None);
+        assert_eq!(diagonal.cross_product(&x), -2.0);
+    }
+
+    #[test]
+    fn test_vector2d_normalize_nonzero() {
+        let p = Vector2D::new(3.0, 4.0);
+        let normalized = p.normalize().unwrap();
+        // expected unit vector (0.6, 0.8)
+        assert_relative_eq!(normalized.x, 0.6, epsilon = 1e-6);
+        assert_relative_eq!(normalized.y, 0.8, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_vector2d_normalize_unit_vector() {
+        let p = Vector2D::new(1.0, 0.0);
+        let normalized = p.normalize().unwrap();
+        assert_relative_eq!(normalized.x, 1.0, epsilon = 1e-6);
+        assert_relative_eq!(normalized.y, 0.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_vector2d_normalize_zero_vector() {
+        let p = Vector2D::new(0.0, 0.0);
+        assert!(p.normalize().is_none(), "Expected None: Cannot normalize zero vector");
+    }
+}
@@ -0,0 +1,372 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use super::Polygon2D;
+use alloc::vec::Vec;
+
+/// A polygon known to be convex, with its vertices counterclockwise.
+///
+/// Checking convexity once up front lets containment run in O(log n) and
+/// intersections in O(n + m), where general polygons need O(n) and O(n m).
+///
+/// # Example
+/// ```
+/// use geomengine::point::Point2D;
+/// use geomengine::polygon::{ConvexPolygon2D, Polygon2D};
+/// use geomengine::shape::Area;
+///
+/// let square = |x: f64, y: f64| Polygon2D::new(vec![Point2D::new(x, y), Point2D::new(x + 2.0, y), Point2D::new(x + 2.0, y + 2.0), Point2D::new(x, y + 2.0)]);
+/// let a = ConvexPolygon2D::new(square(0.0, 0.0)).unwrap();
+/// let b = ConvexPolygon2D::new(square(1.0, 1.0)).unwrap();
+/// assert!(a.contains_point(&Point2D::new(0.5, 1.5)));
+/// assert_eq!(a.intersection(&b).unwrap().area(), 1.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConvexPolygon2D<T: Float + Debug> {
+    polygon: Polygon2D<T>,
+}
+
+/// Which polygon's boundary the output of the intersection currently follows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Inside {
+    Unknown,
+    First,
+    Second,
+}
+
+/// How two segments meet.
+enum Crossing<T: Float + Debug> {
+    Apart,
+    Point(Point2D<T>),
+    /// Collinear and overlapping.
+    Overlap,
+}
+
+impl<T: Float + Debug> ConvexPolygon2D<T> {
+    /// Wraps `polygon`, turned counterclockwise if needed; `None` unless it is
+    /// [convex](Polygon2D::is_convex).
+    pub fn new(polygon: Polygon2D<T>) -> Option<Self> {
+        if !polygon.is_convex() {
+            return None;
+        }
+        if polygon.signed_area() < T::zero() {
+            return Some(ConvexPolygon2D { polygon: Polygon2D::new(polygon.vertices().iter().rev().copied().collect()) });
+        }
+        Some(ConvexPolygon2D { polygon })
+    }
+    pub fn polygon(&self) -> &Polygon2D<T> {
+        &self.polygon
+    }
+    pub fn into_polygon(self) -> Polygon2D<T> {
+        self.polygon
+    }
+    pub fn vertices(&self) -> &[Point2D<T>] {
+        self.polygon.vertices()
+    }
+
+    /// Is `point` inside the polygon or on its boundary?
+    ///
+    /// # Time Complexity
+    /// **O(log n)**: a binary search finds the wedge from the first vertex that
+    /// holds the point, which is then tested against the one edge closing it.
+    pub fn contains_point(&self, point: &Point2D<T>) -> bool {
+        let v = self.vertices();
+        let n = v.len();
+        if orientation(v[0], v[1], *point) < T::zero() || orientation(v[0], v[n - 1], *point) > T::zero() {
+            return false;
+        }
+        let (mut low, mut high) = (1, n - 1);
+        while high - low > 1 {
+            let middle = (low + high) / 2;
+            if orientation(v[0], v[middle], *point) >= T::zero() {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+        orientation(v[low], v[low + 1], *point) >= T::zero()
+    }
+
+    /// The region covered by both polygons, or `None` if they do not overlap
+    /// with positive area.
+    ///
+    /// # Algorithm Explanation
+    /// O'Rourke's rotating edges: one edge of each polygon is advanced in turn,
+    /// always the one aiming at the other's edge line without having reached it,
+    /// so the two edges chase each other around the boundaries. Every crossing
+    /// of the edges is an output vertex and switches which polygon is inside;
+    /// the vertices of the inner polygon passed in between are output too.
+    /// Without crossings, one polygon holds the other or they are apart.
+    ///
+    /// # Time Complexity
+    /// **O(n + m)**.
+    pub fn intersection(&self, other: &ConvexPolygon2D<T>) -> Option<ConvexPolygon2D<T>> {
+        let (p, q) = (self.vertices(), other.vertices());
+        let (n, m) = (p.len(), q.len());
+        let zero = T::zero();
+        let mut out: Vec<Point2D<T>> = Vec::new();
+        let (mut a, mut b, mut advanced_a, mut advanced_b) = (0, 0, 0, 0);
+        let mut inside = Inside::Unknown;
+        loop {
+            let (a1, b1) = ((a + n - 1) % n, (b + m - 1) % m);
+            let (edge_a, edge_b) = (p[a] - p[a1], q[b] - q[b1]);
+            let cross = edge_a.cross_product(&edge_b);
+            // sides of the edges' heads relative to the other edge
+            let a_side = orientation(q[b1], q[b], p[a]);
+            let b_side = orientation(p[a1], p[a], q[b]);
+
+            match segment_crossing(p[a1], p[a], q[b1], q[b]) {
+                Crossing::Point(x) => {
+                    if out.is_empty() {
+                        // count full turns from the first crossing
+                        (advanced_a, advanced_b) = (0, 0);
+                    }
+                    out.push(x);
+                    if a_side > zero {
+                        inside = Inside::First;
+                    } else if b_side > zero {
+                        inside = Inside::Second;
+                    }
+                }
+                // opposite edges on one line: the polygons only touch
+                Crossing::Overlap if edge_a.dot_product(&edge_b) < zero => return None,
+                _ => {}
+            }
+            if cross == zero && a_side < zero && b_side < zero {
+                // parallel edges facing away from each other
+                return None;
+            }
+
+            let advance_a = if cross == zero && a_side == zero && b_side == zero {
+                inside != Inside::First
+            } else if cross >= zero {
+                b_side > zero
+            } else {
+                a_side <= zero
+            };
+            if advance_a {
+                if inside == Inside::First {
+                    out.push(p[a]);
+                }
+                (a, advanced_a) = ((a + 1) % n, advanced_a + 1);
+            } else {
+                if inside == Inside::Second {
+                    out.push(q[b]);
+                }
+                (b, advanced_b) = ((b + 1) % m, advanced_b + 1);
+            }
+            if !((advanced_a < n || advanced_b < m) && advanced_a < 2 * n && advanced_b < 2 * m) {
+                break;
+            }
+        }
+
+        if inside == Inside::Unknown {
+            return if other.contains_point(&p[0]) && self.polygon.signed_area() > zero {
+                Some(self.clone())
+            } else if self.contains_point(&q[0]) && other.polygon.signed_area() > zero {
+                Some(other.clone())
+            } else {
+                None
+            };
+        }
+        out.dedup();
+        // the chase may pass its starting point again before both turns are complete
+        if let Some(repeat) = out.iter().skip(1).position(|&v| v == out[0]) {
+            out.truncate(repeat + 1);
+        }
+        let polygon = Polygon2D::new(out);
+        (polygon.len() >= 3 && polygon.signed_area() > zero).then_some(ConvexPolygon2D { polygon })
+    }
+
+    /// The part of `subject`, which need not be convex, inside this polygon.
+    ///
+    /// Sutherland-Hodgman: `subject` is cut by the inner half-plane of every
+    /// edge in turn. A concave subject split into several pieces comes back as
+    /// one ring joining them along the clip boundary.
+    ///
+    /// # Time Complexity
+    /// **O(n m)** for `n` clip edges and `m` subject vertices.
+    pub fn clip(&self, subject: &Polygon2D<T>) -> Polygon2D<T> {
+        let mut ring = subject.vertices().to_vec();
+        for edge in self.polygon.edges() {
+            let input = core::mem::take(&mut ring);
+            let side = |p: Point2D<T>| orientation(edge.p1, edge.p2, p);
+            for (i, &current) in input.iter().enumerate() {
+                let next = input[(i + 1) % input.len()];
+                let (s_current, s_next) = (side(current), side(next));
+                if s_current >= T::zero() {
+                    ring.push(current);
+                }
+                if (s_current >= T::zero()) != (s_next >= T::zero()) {
+                    ring.push(current + (next - current) * (s_current / (s_current - s_next)));
+                }
+            }
+        }
+        ring.dedup();
+        Polygon2D::new(ring)
+    }
+}
+
+impl<T: Float + Debug> Polygon2D<T> {
+    /// Does the ring turn the same way at every vertex and wind around once?
+    /// Collinear vertices are allowed; rings with fewer than three vertices or
+    /// no area are not convex.
+    pub fn is_convex(&self) -> bool {
+        let v = self.vertices();
+        let n = v.len();
+        if n < 3 {
+            return false;
+        }
+        let (mut sign, mut turning) = (T::zero(), T::zero());
+        for i in 0..n {
+            let (incoming, outgoing) = (v[(i + 1) % n] - v[i], v[(i + 2) % n] - v[(i + 1) % n]);
+            let cross = incoming.cross_product(&outgoing);
+            if cross != T::zero() {
+                if sign == T::zero() {
+                    sign = cross.signum();
+                } else if cross.signum() != sign {
+                    return false;
+                }
+            }
+            turning = turning + cross.atan2(incoming.dot_product(&outgoing));
+        }
+        // a star turns the same way everywhere but winds around more than once
+        let full_turn = T::from(core::f64::consts::TAU).unwrap();
+        sign != T::zero() && (turning.abs() - full_turn).abs() < T::one()
+    }
+}
+
+/// Turn `a -> b -> c`: positive for counterclockwise, zero for collinear.
+fn orientation<T: Float + Debug>(a: Point2D<T>, b: Point2D<T>, c: Point2D<T>) -> T {
+    (b - a).cross_product(&(c - a))
+}
+
+fn segment_crossing<T: Float + Debug>(a: Point2D<T>, b: Point2D<T>, c: Point2D<T>, d: Point2D<T>) -> Crossing<T> {
+    let (r, s, offset) = (b - a, d - c, c - a);
+    let denominator = r.cross_product(&s);
+    let unit = T::zero()..=T::one();
+    if denominator == T::zero() {
+        if offset.cross_product(&r) != T::zero() {
+            return Crossing::Apart;
+        }
+        let length_sq = r.dot_product(&r);
+        let (t0, t1) = (offset.dot_product(&r) / length_sq, (d - a).dot_product(&r) / length_sq);
+        return if t0.max(t1) < T::zero() || t0.min(t1) > T::one() { Crossing::Apart } else { Crossing::Overlap };
+    }
+    let (t, u) = (offset.cross_product(&s) / denominator, offset.cross_product(&r) / denominator);
+    if unit.contains(&t) && unit.contains(&u) { Crossing::Point(a + r * t) } else { Crossing::Apart }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::point::Vector2D;
+    use crate::algorithms::point_algorithms::convex_hull_2d;
+    use crate::algorithms::sampling::{XorShift, sample_aabb};
+    use crate::shape::{Aabb2D, Area, Intersects};
+    use approx::assert_relative_eq;
+
+    fn random_convex(rng: &mut XorShift, center: Point2D<f64>, count: usize) -> ConvexPolygon2D<f64> {
+        let bounds = Aabb2D::new(center - Vector2D::new(3.0, 3.0), center + Vector2D::new(3.0, 3.0));
+        let points: Vec<_> = (0..count).map(|_| sample_aabb(&bounds, rng)).collect();
+        ConvexPolygon2D::new(Polygon2D::new(convex_hull_2d(&points))).unwrap()
+    }
+
+    fn square(x: f64, y: f64, size: f64) -> ConvexPolygon2D<f64> {
+        let ring = vec![Point2D::new(x, y), Point2D::new(x + size, y), Point2D::new(x + size, y + size), Point2D::new(x, y + size)];
+        ConvexPolygon2D::new(Polygon2D::new(ring)).unwrap()
+    }
+
+    #[test]
+    fn test_is_convex() {
+        assert!(square(0.0, 0.0, 1.0).polygon().is_convex());
+        let clockwise = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(0.0, 1.0), Point2D::new(1.0, 0.0)]);
+        assert!(clockwise.is_convex());
+        assert!(ConvexPolygon2D::new(clockwise).unwrap().polygon().signed_area() > 0.0);
+        let notch = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(2.0, 0.0), Point2D::new(1.0, 1.0), Point2D::new(2.0, 2.0), Point2D::new(0.0, 2.0)]);
+        assert!(!notch.is_convex());
+        let pentagram: Vec<_> = (0..5)
+            .map(|i| {
+                let angle = (i * 2) as f64 * core::f64::consts::TAU / 5.0;
+                Point2D::new(angle.cos(), angle.sin())
+            })
+            .collect();
+        assert!(!Polygon2D::new(pentagram).is_convex());
+        assert!(!Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0), Point2D::new(2.0, 2.0)]).is_convex());
+    }
+
+    #[test]
+    fn test_convex_contains_matches_ring_test() {
+        let mut rng = XorShift::new(31);
+        let bounds = Aabb2D::new(Point2D::new(-4.0, -4.0), Point2D::new(4.0, 4.0));
+        for _ in 0..10 {
+            let polygon = random_convex(&mut rng, Point2D::new(0.0, 0.0), 30);
+            for _ in 0..200 {
+                let point = sample_aabb(&bounds, &mut rng);
+                assert_eq!(polygon.contains_point(&point), polygon.polygon().contains_point(&point), "at {point:?}");
+            }
+            assert!(polygon.vertices().iter().all(|v| polygon.contains_point(v)));
+        }
+    }
+
+    #[test]
+    fn test_convex_intersection_matches_clipping() {
+        let mut rng = XorShift::new(77);
+        let bounds = Aabb2D::new(Point2D::new(-2.0, -2.0), Point2D::new(2.0, 2.0));
+        for _ in 0..200 {
+            let a = random_convex(&mut rng, Point2D::new(0.0, 0.0), 12);
+            let center = sample_aabb(&bounds, &mut rng);
+            let b = random_convex(&mut rng, center, 12);
+            let clipped = a.clip(b.polygon()).area();
+            match a.intersection(&b) {
+                Some(overlap) => {
+                    assert_relative_eq!(overlap.area(), clipped, epsilon = 1e-9);
+                    assert_relative_eq!(b.intersection(&a).unwrap().area(), clipped, epsilon = 1e-9);
+                    assert!(a.intersects(&b));
+                }
+                None => assert!(clipped < 1e-12),
+            }
+        }
+    }
+
+    #[test]
+    fn test_convex_intersection_special_cases() {
+        let big = square(0.0, 0.0, 4.0);
+        let small = square(1.0, 1.0, 1.0);
+        assert_eq!(big.intersection(&small), Some(small.clone()));
+        assert_eq!(small.intersection(&big), Some(small.clone()));
+        assert_eq!(big.intersection(&big).unwrap().area(), 16.0);
+
+        // sharing an edge or a corner only
+        let beside = square(4.0, 0.0, 4.0);
+        assert_eq!(big.intersection(&beside), None);
+        assert!(big.intersects(&beside));
+        let corner = square(4.0, 4.0, 1.0);
+        assert_eq!(big.intersection(&corner), None);
+        assert!(big.intersects(&corner));
+        let apart = square(5.0, 0.0, 1.0);
+        assert_eq!(big.intersection(&apart), None);
+        assert!(!big.intersects(&apart));
+
+        // overlapping along part of an edge
+        let shifted = square(2.0, 2.0, 4.0);
+        assert_eq!(big.intersection(&shifted).unwrap().area(), 4.0);
+        let half = square(2.0, 0.0, 4.0);
+        assert_eq!(big.intersection(&half).unwrap().area(), 8.0);
+    }
+
+    #[test]
+    fn test_convex_clip_concave_subject() {
+        let window = square(0.0, 0.0, 2.0);
+        let l_shape = Polygon2D::new(vec![
+            Point2D::new(-1.0, -1.0),
+            Point2D::new(3.0, -1.0),
+            Point2D::new(3.0, 1.0),
+            Point2D::new(1.0, 1.0),
+            Point2D::new(1.0, 3.0),
+            Point2D::new(-1.0, 3.0),
+        ]);
+        assert_eq!(window.clip(&l_shape).area(), 3.0);
+        assert!(window.clip(&square(5.0, 5.0, 1.0).into_polygon()).is_empty());
+    }
+}
@@ -0,0 +1,9 @@
+// module exports
+mod polygon2d;
+mod convex_polygon2d;
+mod polygon_with_holes;
+pub(crate) mod ring;
+
+pub use polygon2d::Polygon2D;
+pub use convex_polygon2d::ConvexPolygon2D;
+pub use polygon_with_holes::PolygonWithHoles;
@@ -0,0 +1,207 @@
+use num::{Num, Float};
+use core::fmt::{Debug, Display, self};
+use crate::point::{Point2D, Vector2D, write_point_list};
+use crate::line::Line2D;
+use super::ring;
+use alloc::vec::Vec;
+use alloc::string::String;
+use alloc::format;
+
+/// A simple polygon stored as an ordered ring of vertices.
+///
+/// The closing edge from the last vertex back to the first is implicit, so a ring
+/// passed in GIS style with the first vertex repeated at the end is stored without
+/// the duplicate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polygon2D<T: Num + Copy + Debug> {
+    vertices: Vec<Point2D<T>>,
+}
+
+impl<T: Num + Copy + Debug> Polygon2D<T> {
+    pub fn new(mut vertices: Vec<Point2D<T>>) -> Self {
+        if vertices.len() > 1 && vertices.first() == vertices.last() {
+            vertices.pop();
+        }
+        Polygon2D { vertices }
+    }
+    pub fn vertices(&self) -> &[Point2D<T>] {
+        &self.vertices
+    }
+    pub fn len(&self) -> usize {
+        self.vertices.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.vertices.is_empty()
+    }
+    /// The edges of the ring, including the closing edge back to the first vertex.
+    pub fn edges(&self) -> impl Iterator<Item = Line2D<T>> + '_ {
+        let n = self.vertices.len();
+        // a single vertex has no edges
+        let count = if n > 1 { n } else { 0 };
+        (0..count).map(move |i| Line2D::new(self.vertices[i], self.vertices[(i + 1) % n]))
+    }
+    /// Inserts `point` before the vertex at `index` (after the last one if `index == len()`).
+    ///
+    /// # Returns
+    /// * `false`, leaving the polygon unchanged, if `point` equals a vertex it
+    ///   would be next to, as consecutive duplicates make degenerate edges.
+    ///
+    /// # Panics
+    /// If `index > len()`.
+    pub fn insert_vertex(&mut self, index: usize, point: Point2D<T>) -> bool {
+        assert!(index <= self.vertices.len(), "vertex index {index} out of bounds");
+        let n = self.vertices.len();
+        if n > 0 && (point == self.vertices[(index + n - 1) % n] || point == self.vertices[index % n]) {
+            return false;
+        }
+        self.vertices.insert(index, point);
+        true
+    }
+    /// Removes and returns the vertex at `index`. Should its neighbours coincide,
+    /// the later one is removed too.
+    ///
+    /// # Panics
+    /// If `index >= len()`.
+    pub fn remove_vertex(&mut self, index: usize) -> Point2D<T> {
+        let removed = self.vertices.remove(index);
+        self.merge_with_previous(index);
+        removed
+    }
+    /// Moves the vertex at `index` by `delta`. A vertex moved onto a neighbour
+    /// merges with it.
+    ///
+    /// # Panics
+    /// If `index >= len()`.
+    pub fn translate_vertex(&mut self, index: usize, delta: Vector2D<T>) {
+        self.vertices[index] = self.vertices[index] + delta;
+        let n = self.vertices.len();
+        let moved = self.vertices[index];
+        if n > 1 && (moved == self.vertices[(index + n - 1) % n] || moved == self.vertices[(index + 1) % n]) {
+            self.remove_vertex(index);
+        }
+    }
+    /// Removes the vertex at `index` (wrapping around) if it equals the one before.
+    fn merge_with_previous(&mut self, index: usize) {
+        let n = self.vertices.len();
+        if n > 1 {
+            let index = index % n;
+            if self.vertices[index] == self.vertices[(index + n - 1) % n] {
+                self.vertices.remove(index);
+            }
+        }
+    }
+}
+
+/// `[(x, y), ...]`, or WKT `POLYGON ((x y, ..., x y))` with `{:#}`, where the
+/// ring is written closed as WKT requires.
+impl<T: Num + Copy + Debug + Display> Display for Polygon2D<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (f.alternate(), self.vertices.first()) {
+            (true, None) => write!(f, "POLYGON EMPTY"),
+            (true, Some(&first)) => {
+                write!(f, "POLYGON ((")?;
+                write_point_list(f, self.vertices.iter().copied().chain([first]))?;
+                write!(f, "))")
+            }
+            (false, _) => {
+                write!(f, "[")?;
+                write_point_list(f, self.vertices.iter().copied())?;
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+impl<T: Num + Copy + Debug + Display> Polygon2D<T> {
+    /// The polygon as a WKT `POLYGON` with a closed ring.
+    pub fn to_wkt_string(&self) -> String {
+        format!("{:#}", self)
+    }
+}
+
+impl<T: Float + Debug> Polygon2D<T> {
+    /// Starts a [`PolygonBuilder`](crate::shape::PolygonBuilder) for cleaning up messy input.
+    pub fn builder() -> crate::shape::PolygonBuilder<T> {
+        crate::shape::PolygonBuilder::new()
+    }
+    /// Shoelace area, positive if the vertices run counterclockwise and negative
+    /// if clockwise. [`Area`](crate::shape::Area) gives the unsigned area,
+    /// [`Perimeter`](crate::shape::Perimeter) and
+    /// [`Centroid`](crate::shape::Centroid) the other measures.
+    pub fn signed_area(&self) -> T {
+        ring::signed_area(&self.vertices)
+    }
+    /// Returns true if the point lies inside the polygon (even-odd rule).
+    ///
+    /// Points exactly on the boundary may be reported either way.
+    pub fn contains_point(&self, point: &Point2D<T>) -> bool {
+        ring::contains_point(&self.vertices, point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_polygon2d_new_drops_closing_vertex() {
+        let polygon = Polygon2D::new(vec![
+            Point2D::new(0.0, 0.0),
+            Point2D::new(1.0, 0.0),
+            Point2D::new(0.0, 1.0),
+            Point2D::new(0.0, 0.0),
+        ]);
+        assert_eq!(polygon.len(), 3);
+        assert_eq!(polygon.vertices()[2], Point2D::new(0.0, 1.0));
+        assert_eq!(polygon.edges().last(), Some(Line2D::new(Point2D::new(0.0, 1.0), Point2D::new(0.0, 0.0))));
+    }
+
+    #[test]
+    fn test_polygon2d_vertex_editing() {
+        let mut square = Polygon2D::new(vec![Point2D::new(0, 0), Point2D::new(2, 0), Point2D::new(2, 2), Point2D::new(0, 2)]);
+        assert!(square.insert_vertex(1, Point2D::new(1, -1)));
+        assert!(!square.insert_vertex(5, Point2D::new(0, 0)), "would duplicate the first vertex across the closing edge");
+        assert_eq!(square.vertices()[1], Point2D::new(1, -1));
+
+        // dragging the new vertex onto its neighbour merges the two
+        square.translate_vertex(1, Vector2D::new(1, 1));
+        assert_eq!(square.vertices(), &[Point2D::new(0, 0), Point2D::new(2, 0), Point2D::new(2, 2), Point2D::new(0, 2)]);
+        square.translate_vertex(2, Vector2D::new(1, 1));
+        assert_eq!(square.vertices()[2], Point2D::new(3, 3));
+
+        // removing the middle of a spike closes it up
+        let mut spike = Polygon2D::new(vec![Point2D::new(0, 0), Point2D::new(4, 0), Point2D::new(5, 5), Point2D::new(4, 0), Point2D::new(0, 4)]);
+        assert_eq!(spike.remove_vertex(2), Point2D::new(5, 5));
+        assert_eq!(spike.vertices(), &[Point2D::new(0, 0), Point2D::new(4, 0), Point2D::new(0, 4)]);
+    }
+
+    #[test]
+    fn test_polygon2d_display() {
+        let polygon = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(1.0, 0.0), Point2D::new(0.0, 1.5)]);
+        assert_eq!(polygon.to_string(), "[(0, 0), (1, 0), (0, 1.5)]");
+        assert_eq!(format!("{polygon:#.2}"), "POLYGON ((0.00 0.00, 1.00 0.00, 0.00 1.50, 0.00 0.00))");
+        assert_eq!(Polygon2D::<f64>::new(Vec::new()).to_wkt_string(), "POLYGON EMPTY");
+    }
+
+    #[test]
+    fn test_polygon2d_signed_area() {
+        let triangle = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(4.0, 0.0), Point2D::new(0.0, 3.0)]);
+        assert_eq!(triangle.signed_area(), 6.0);
+        let clockwise = Polygon2D::new(triangle.vertices().iter().rev().copied().collect());
+        assert_eq!(clockwise.signed_area(), -6.0);
+        assert_eq!(Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0)]).signed_area(), 0.0);
+    }
+
+    #[test]
+    fn test_polygon2d_contains_point() {
+        let polygon = Polygon2D::new(vec![
+            Point2D::new(0.0, 0.0),
+            Point2D::new(4.0, 0.0),
+            Point2D::new(4.0, 4.0),
+            Point2D::new(2.0, 1.0),
+            Point2D::new(0.0, 4.0),
+        ]);
+        assert!(polygon.contains_point(&Point2D::new(1.0, 1.0)));
+        assert!(!polygon.contains_point(&Point2D::new(2.0, 3.0)));
+    }
+}
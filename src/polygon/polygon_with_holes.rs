@@ -0,0 +1,128 @@
+use num::{Num, Float};
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::shape::Triangle2D;
+use crate::algorithms::triangulate::triangulate_2d;
+use super::{Polygon2D, ring};
+use alloc::vec::Vec;
+
+/// A polygon with holes: an exterior ring and interior rings cut out of it.
+///
+/// Rings may run either way round. The holes are expected to lie inside the
+/// exterior without overlapping each other; measures of other inputs are
+/// meaningless.
+///
+/// # Example
+/// ```
+/// use geomengine::point::Point2D;
+/// use geomengine::polygon::{Polygon2D, PolygonWithHoles};
+/// use geomengine::shape::{Area, Centroid};
+///
+/// let square = |min: f64, size: f64| Polygon2D::new(vec![Point2D::new(min, min), Point2D::new(min + size, min), Point2D::new(min + size, min + size), Point2D::new(min, min + size)]);
+/// let frame = PolygonWithHoles::new(square(0.0, 4.0), vec![square(1.0, 2.0)]);
+/// assert_eq!(frame.area(), 12.0);
+/// assert_eq!(frame.centroid(), Some(Point2D::new(2.0, 2.0)));
+/// assert!(!frame.contains_point(&Point2D::new(2.0, 2.0)));
+/// assert_eq!(frame.triangulate().len(), 8);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolygonWithHoles<T: Num + Copy + Debug> {
+    exterior: Polygon2D<T>,
+    holes: Vec<Polygon2D<T>>,
+}
+
+impl<T: Num + Copy + Debug> PolygonWithHoles<T> {
+    pub fn new(exterior: Polygon2D<T>, holes: Vec<Polygon2D<T>>) -> Self {
+        PolygonWithHoles { exterior, holes }
+    }
+    pub fn exterior(&self) -> &Polygon2D<T> {
+        &self.exterior
+    }
+    pub fn holes(&self) -> &[Polygon2D<T>] {
+        &self.holes
+    }
+    /// The exterior followed by the holes.
+    pub fn rings(&self) -> impl Iterator<Item = &Polygon2D<T>> + '_ {
+        core::iter::once(&self.exterior).chain(&self.holes)
+    }
+    pub fn into_parts(self) -> (Polygon2D<T>, Vec<Polygon2D<T>>) {
+        (self.exterior, self.holes)
+    }
+}
+
+impl<T: Num + Copy + Debug> From<Polygon2D<T>> for PolygonWithHoles<T> {
+    fn from(exterior: Polygon2D<T>) -> Self {
+        PolygonWithHoles::new(exterior, Vec::new())
+    }
+}
+
+impl<T: Float + Debug> PolygonWithHoles<T> {
+    /// Area of the exterior less the areas of the holes.
+    pub fn area(&self) -> T {
+        self.holes.iter().fold(self.exterior.signed_area().abs(), |area, hole| area - hole.signed_area().abs())
+    }
+    /// Returns true if the point lies inside the exterior and outside every hole.
+    ///
+    /// Points exactly on a ring may be reported either way.
+    pub fn contains_point(&self, point: &Point2D<T>) -> bool {
+        self.exterior.contains_point(point) && !self.holes.iter().any(|hole| ring::contains_point(hole.vertices(), point))
+    }
+    /// Counterclockwise triangles covering the polygon but not its holes, by
+    /// [`triangulate_2d`].
+    pub fn triangulate(&self) -> Vec<Triangle2D<T>> {
+        let points: Vec<Point2D<T>> = self.rings().flat_map(|r| r.vertices()).copied().collect();
+        triangulate_2d(&self.exterior, &self.holes)
+            .into_iter()
+            .map(|[a, b, c]| Triangle2D::new(points[a], points[b], points[c]))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shape::{Area, BoundingBox, Centroid, Contains, Perimeter};
+    use approx::assert_relative_eq;
+
+    fn rect(x0: f64, y0: f64, x1: f64, y1: f64) -> Polygon2D<f64> {
+        Polygon2D::new(vec![Point2D::new(x0, y0), Point2D::new(x1, y0), Point2D::new(x1, y1), Point2D::new(x0, y1)])
+    }
+
+    #[test]
+    fn test_polygon_with_holes_measures() {
+        // a clockwise exterior and one hole of each orientation
+        let exterior = Polygon2D::new(rect(0.0, 0.0, 10.0, 4.0).vertices().iter().rev().copied().collect());
+        let left = rect(1.0, 1.0, 3.0, 3.0);
+        let right = Polygon2D::new(rect(6.0, 1.0, 9.0, 2.0).vertices().iter().rev().copied().collect());
+        let polygon = PolygonWithHoles::new(exterior, vec![left, right]);
+
+        assert_eq!(polygon.area(), 33.0);
+        assert_eq!(Area::area(&polygon), 33.0);
+        assert_eq!(polygon.perimeter(), 28.0 + 8.0 + 8.0);
+        let centroid = polygon.centroid().unwrap();
+        assert_relative_eq!(centroid.x, (40.0 * 5.0 - 4.0 * 2.0 - 3.0 * 7.5) / 33.0, epsilon = 1e-12);
+        assert_relative_eq!(centroid.y, (40.0 * 2.0 - 4.0 * 2.0 - 3.0 * 1.5) / 33.0, epsilon = 1e-12);
+        assert_eq!(polygon.bounding_box(), Some(crate::shape::Aabb2D::new(Point2D::new(0.0, 0.0), Point2D::new(10.0, 4.0))));
+
+        assert!(polygon.contains(&Point2D::new(4.5, 2.0)));
+        assert!(!polygon.contains(&Point2D::new(2.0, 2.0)));
+        assert!(!polygon.contains(&Point2D::new(7.0, 1.5)));
+        assert!(!polygon.contains(&Point2D::new(11.0, 1.5)));
+
+        let triangles = polygon.triangulate();
+        assert_eq!(triangles.len(), 12 + 2 * 2 - 2);
+        assert_relative_eq!(triangles.iter().map(|t| t.signed_area()).sum::<f64>(), 33.0, epsilon = 1e-12);
+        assert!(triangles.iter().all(|t| t.signed_area() > 0.0 && polygon.contains_point(&Triangle2D::centroid(t))));
+    }
+
+    #[test]
+    fn test_polygon_without_holes_matches_polygon() {
+        let square = rect(0.0, 0.0, 2.0, 2.0);
+        let polygon = PolygonWithHoles::from(square.clone());
+        assert_eq!(polygon.area(), square.area());
+        assert_eq!(polygon.centroid(), square.centroid());
+        assert_eq!(polygon.triangulate().len(), 2);
+        assert_eq!(polygon.rings().count(), 1);
+        assert_eq!(polygon.into_parts(), (square, vec![]));
+    }
+}
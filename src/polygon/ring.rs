@@ -0,0 +1,66 @@
+// Helpers shared by everything that stores a closed ring as a vertex slice
+// (the closing edge from the last vertex back to the first is implicit).
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Point2D;
+
+/// Shoelace signed area: positive for counterclockwise rings.
+pub(crate) fn signed_area<T: Float + Debug>(ring: &[Point2D<T>]) -> T {
+    if ring.len() < 3 {
+        return T::zero();
+    }
+    let mut sum = T::zero();
+    for i in 0..ring.len() {
+        let a = ring[i];
+        let b = ring[(i + 1) % ring.len()];
+        sum = sum + a.x * b.y - b.x * a.y;
+    }
+    sum / (T::one() + T::one())
+}
+
+/// Even-odd point-in-ring test. Points exactly on an edge may go either way.
+pub(crate) fn contains_point<T: Float + Debug>(ring: &[Point2D<T>], point: &Point2D<T>) -> bool {
+    let mut inside = false;
+    let mut j = ring.len().wrapping_sub(1);
+    for i in 0..ring.len() {
+        let (a, b) = (ring[i], ring[j]);
+        if (a.y > point.y) != (b.y > point.y) {
+            let x = a.x + (point.y - a.y) * (b.x - a.x) / (b.y - a.y);
+            if point.x < x {
+                inside = !inside;
+            }
+        }
+        j = i;
+    }
+    inside
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square() -> Vec<Point2D<f64>> {
+        vec![
+            Point2D::new(0.0, 0.0),
+            Point2D::new(2.0, 0.0),
+            Point2D::new(2.0, 2.0),
+            Point2D::new(0.0, 2.0),
+        ]
+    }
+
+    #[test]
+    fn test_signed_area_orientation() {
+        let mut ring = square();
+        assert_eq!(signed_area(&ring), 4.0);
+        ring.reverse();
+        assert_eq!(signed_area(&ring), -4.0);
+    }
+
+    #[test]
+    fn test_contains_point() {
+        let ring = square();
+        assert!(contains_point(&ring, &Point2D::new(1.0, 1.0)));
+        assert!(!contains_point(&ring, &Point2D::new(3.0, 1.0)));
+        assert!(!contains_point(&ring, &Point2D::new(1.0, -0.5)));
+    }
+}
@@ -0,0 +1,4 @@
+// module exports
+mod strategies;
+
+pub use strategies::{any_point2d, any_segment, finite, finite_coordinate, has_finite_coords, point2d_in, simple_polygon};
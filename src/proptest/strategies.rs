@@ -0,0 +1,126 @@
+use std::fmt::Debug;
+use ::proptest::num::f64 as float;
+use ::proptest::prelude::*;
+use crate::point::Point2D;
+use crate::line::Line2D;
+use crate::polygon::Polygon2D;
+use crate::shape::{Aabb2D, CoordsIter};
+use crate::algorithms::sampling::XorShift;
+use crate::rand::random_simple_polygon;
+
+/// Half the side of the square centered on the origin that [`simple_polygon`] draws its vertices from.
+const POLYGON_SPAN: f64 = 1000.0;
+
+/// Any finite `f64`: zero, subnormal or normal, of either sign. Never NaN or infinite.
+pub fn finite_coordinate() -> impl Strategy<Value = f64> {
+    float::POSITIVE | float::NEGATIVE | float::ZERO | float::SUBNORMAL | float::NORMAL
+}
+
+/// A point with [`finite_coordinate`]s.
+///
+/// The coordinates span the whole `f64` range, so products of them can still
+/// overflow; use [`point2d_in`] where that matters.
+pub fn any_point2d() -> impl Strategy<Value = Point2D<f64>> {
+    (finite_coordinate(), finite_coordinate()).prop_map(|(x, y)| Point2D::new(x, y))
+}
+
+/// A point inside `bounds`, boundary included.
+pub fn point2d_in(bounds: Aabb2D<f64>) -> impl Strategy<Value = Point2D<f64>> {
+    (bounds.min.x..=bounds.max.x, bounds.min.y..=bounds.max.y).prop_map(|(x, y)| Point2D::new(x, y))
+}
+
+/// A segment between two distinct [`any_point2d`]s.
+pub fn any_segment() -> impl Strategy<Value = Line2D<f64>> {
+    (any_point2d(), any_point2d())
+        .prop_filter("degenerate segment", |(p1, p2)| p1 != p2)
+        .prop_map(|(p1, p2)| Line2D::new(p1, p2))
+}
+
+/// A counterclockwise simple polygon with 3 to `max_vertices` vertices in the
+/// square from -1000 to 1000 on both axes.
+///
+/// The polygons come from [`random_simple_polygon`], so they shrink towards
+/// fewer vertices and a different seed rather than towards smaller coordinates.
+/// Its 2-opt untangling makes polygons beyond a few hundred vertices slow to draw.
+///
+/// # Panics
+/// If `max_vertices` is less than 3.
+///
+/// # Example
+/// ```
+/// use geomengine::proptest::simple_polygon;
+/// use proptest::prelude::*;
+///
+/// proptest! {
+///     fn area_is_positive(polygon in simple_polygon(12)) {
+///         prop_assert!(polygon.signed_area() > 0.0);
+///     }
+/// }
+/// # area_is_positive();
+/// ```
+pub fn simple_polygon(max_vertices: usize) -> impl Strategy<Value = Polygon2D<f64>> {
+    assert!(max_vertices >= 3, "a polygon needs at least 3 vertices");
+    let bounds = Aabb2D::new(Point2D::new(-POLYGON_SPAN, -POLYGON_SPAN), Point2D::new(POLYGON_SPAN, POLYGON_SPAN));
+    (3..=max_vertices, any::<u64>()).prop_map(move |(vertices, seed)| random_simple_polygon(&bounds, vertices, &mut XorShift::new(seed)))
+}
+
+/// Returns `true` if every coordinate of `geometry` is finite.
+pub fn has_finite_coords<G: CoordsIter<f64>>(geometry: &G) -> bool {
+    geometry.coords_iter().all(|p| p.x.is_finite() && p.y.is_finite())
+}
+
+/// Rejects the geometries drawn from `strategy` that have a NaN or infinite coordinate.
+///
+/// # Example
+/// ```
+/// use geomengine::point::Point2D;
+/// use geomengine::proptest::finite;
+/// use proptest::prelude::*;
+///
+/// let points = finite((any::<f64>(), any::<f64>()).prop_map(|(x, y)| Point2D::new(x, y)));
+/// ```
+pub fn finite<G, S>(strategy: S) -> impl Strategy<Value = G>
+where
+    G: CoordsIter<f64> + Debug,
+    S: Strategy<Value = G>,
+{
+    strategy.prop_filter("non-finite coordinate", has_finite_coords)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::validation::validate_collection;
+    use crate::shape::{Contains, Geometry2D};
+
+    fn square() -> Aabb2D<f64> {
+        Aabb2D::new(Point2D::new(-2.0, 3.0), Point2D::new(5.0, 4.0))
+    }
+
+    proptest! {
+        #[test]
+        fn test_points_and_segments_are_finite(x in finite_coordinate(), p in any_point2d(), s in any_segment()) {
+            prop_assert!(x.is_finite());
+            prop_assert!(has_finite_coords(&p));
+            prop_assert!(has_finite_coords(&s) && s.p1 != s.p2);
+        }
+
+        #[test]
+        fn test_point2d_in_stays_in_bounds(p in point2d_in(square())) {
+            prop_assert!(square().contains(&p));
+        }
+
+        #[test]
+        fn test_simple_polygon_is_valid(polygon in simple_polygon(10)) {
+            prop_assert!((3..=10).contains(&polygon.len()));
+            prop_assert!(polygon.signed_area() > 0.0);
+            let geometries: [Geometry2D<f64>; 1] = [polygon.into()];
+            prop_assert!(validate_collection(&geometries).is_valid());
+        }
+
+        #[test]
+        fn test_finite_filters_out_nan(p in finite((any::<f64>(), any::<f64>()).prop_map(|(x, y)| Point2D::new(x, y)))) {
+            prop_assert!(p.x.is_finite() && p.y.is_finite());
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::line::Line2D;
+use crate::polygon::{Polygon2D, ring};
+use crate::shape::Aabb2D;
+use crate::algorithms::sampling::{Rng, sample_aabb, shuffle};
+use alloc::vec::Vec;
+
+/// A uniformly random point in `bounds`.
+pub fn random_point<T: Float + Debug>(bounds: &Aabb2D<T>, rng: &mut impl Rng) -> Point2D<T> {
+    sample_aabb(bounds, rng)
+}
+
+/// `count` uniformly random points in `bounds`.
+pub fn random_points<T: Float + Debug>(bounds: &Aabb2D<T>, count: usize, rng: &mut impl Rng) -> Vec<Point2D<T>> {
+    (0..count).map(|_| sample_aabb(bounds, rng)).collect()
+}
+
+/// A segment between two uniformly random points in `bounds`.
+pub fn random_segment<T: Float + Debug>(bounds: &Aabb2D<T>, rng: &mut impl Rng) -> Line2D<T> {
+    Line2D::new(sample_aabb(bounds, rng), sample_aabb(bounds, rng))
+}
+
+/// `count` segments, each between two uniformly random points in `bounds`.
+pub fn random_segments<T: Float + Debug>(bounds: &Aabb2D<T>, count: usize, rng: &mut impl Rng) -> Vec<Line2D<T>> {
+    (0..count).map(|_| random_segment(bounds, rng)).collect()
+}
+
+/// A random simple polygon through `vertices` random points in `bounds`.
+///
+/// # Returns
+/// * A counterclockwise polygon without self-intersections.
+///
+/// # Algorithm Explanation
+/// The points are joined in random order, then untangled by 2-opt moves: while
+/// two edges cross, the part of the ring between them is reversed, which
+/// replaces the crossing pair by two edges that are shorter in total. The
+/// perimeter shrinks with every move, so the untangling ends.
+///
+/// # Time Complexity
+/// **O(n²)** per pass over the edge pairs; the number of moves grows roughly
+/// cubically in practice, so this is meant for polygons of up to a few hundred vertices.
+///
+/// # Panics
+/// If `vertices` is less than 3.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::sampling::XorShift;
+/// use geomengine::point::Point2D;
+/// use geomengine::rand::random_simple_polygon;
+/// use geomengine::shape::Aabb2D;
+///
+/// let bounds = Aabb2D::new(Point2D::new(0.0, 0.0), Point2D::new(100.0, 100.0));
+/// let polygon = random_simple_polygon(&bounds, 20, &mut XorShift::new(3));
+/// assert_eq!(polygon.len(), 20);
+/// ```
+pub fn random_simple_polygon<T: Float + Debug>(bounds: &Aabb2D<T>, vertices: usize, rng: &mut impl Rng) -> Polygon2D<T> {
+    assert!(vertices >= 3, "a polygon needs at least 3 vertices");
+    let mut ring = random_points(bounds, vertices, rng);
+    let n = ring.len();
+    let mut untangled = false;
+    while !untangled {
+        untangled = true;
+        for i in 0..n {
+            // edges sharing a vertex cannot cross properly
+            for j in i + 2..n - usize::from(i == 0) {
+                if crosses(ring[i], ring[i + 1], ring[j], ring[(j + 1) % n]) {
+                    ring[i + 1..=j].reverse();
+                    untangled = false;
+                }
+            }
+        }
+    }
+    if ring::signed_area(&ring) < T::zero() {
+        ring.reverse();
+    }
+    Polygon2D::new(ring)
+}
+
+/// A random convex polygon with `vertices` vertices inside `bounds`.
+///
+/// # Algorithm Explanation
+/// Valtr's algorithm: random x coordinates, sorted, are split at random into a
+/// lower and an upper chain from the smallest to the largest, and the steps
+/// along both chains give edge vectors whose x components sum to zero. The same
+/// is done for y. Pairing the x and y components in random order and sorting
+/// the resulting vectors by angle lays them out as a convex ring, which is then
+/// moved back to where the coordinates were drawn.
+///
+/// # Time Complexity
+/// **O(n log n)**.
+///
+/// # Panics
+/// If `vertices` is less than 3.
+///
+/// # Example
+/// ```
+/// use geomengine::algorithms::sampling::XorShift;
+/// use geomengine::point::Point2D;
+/// use geomengine::rand::random_convex_polygon;
+/// use geomengine::shape::{Aabb2D, Contains};
+///
+/// let bounds = Aabb2D::new(Point2D::new(0.0, 0.0), Point2D::new(10.0, 10.0));
+/// let polygon = random_convex_polygon(&bounds, 12, &mut XorShift::new(8));
+/// assert_eq!(polygon.len(), 12);
+/// assert!(polygon.vertices().iter().all(|p| bounds.contains(p)));
+/// ```
+pub fn random_convex_polygon<T: Float + Debug>(bounds: &Aabb2D<T>, vertices: usize, rng: &mut impl Rng) -> Polygon2D<T> {
+    assert!(vertices >= 3, "a polygon needs at least 3 vertices");
+    let (x_steps, x_min) = chain_steps(vertices, bounds.min.x, bounds.width(), rng);
+    let (mut y_steps, y_min) = chain_steps(vertices, bounds.min.y, bounds.height(), rng);
+    shuffle(&mut y_steps, rng);
+
+    let mut edges: Vec<Point2D<T>> = x_steps.into_iter().zip(y_steps).map(|(x, y)| Point2D::new(x, y)).collect();
+    edges.sort_by(|a, b| a.y.atan2(a.x).partial_cmp(&b.y.atan2(b.x)).unwrap());
+    let mut ring = Vec::with_capacity(vertices);
+    let mut corner = Point2D::new(T::zero(), T::zero());
+    for edge in edges {
+        ring.push(corner);
+        corner = corner + edge;
+    }
+    // the ring spans no more than the coordinates drawn, so this keeps it in bounds
+    let low = ring.iter().fold(Point2D::new(T::infinity(), T::infinity()), |low, p| Point2D::new(low.x.min(p.x), low.y.min(p.y)));
+    let shift = Point2D::new(x_min, y_min) - low;
+    Polygon2D::new(ring.into_iter().map(|p| p + shift).collect())
+}
+
+/// Steps along two random chains between the smallest and largest of `n`
+/// random values in `min..min + extent`, together with the smallest value.
+fn chain_steps<T: Float + Debug>(n: usize, min: T, extent: T, rng: &mut impl Rng) -> (Vec<T>, T) {
+    let mut values: Vec<T> = (0..n).map(|_| min + rng.unit::<T>() * extent).collect();
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let (first, last) = (values[0], values[n - 1]);
+    let mut steps = Vec::with_capacity(n);
+    let (mut upper, mut lower) = (first, first);
+    for &value in &values[1..n - 1] {
+        if rng.next_u64() & 1 == 0 {
+            steps.push(value - upper);
+            upper = value;
+        } else {
+            steps.push(lower - value);
+            lower = value;
+        }
+    }
+    steps.push(last - upper);
+    steps.push(lower - last);
+    (steps, first)
+}
+
+/// Do segments `ab` and `cd` cross at a point interior to both?
+fn crosses<T: Float + Debug>(a: Point2D<T>, b: Point2D<T>, c: Point2D<T>, d: Point2D<T>) -> bool {
+    let side = |p: Point2D<T>, q: Point2D<T>, r: Point2D<T>| (q - p).cross_product(&(r - p));
+    side(a, b, c) * side(a, b, d) < T::zero() && side(c, d, a) * side(c, d, b) < T::zero()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::sampling::XorShift;
+    use crate::shape::Contains;
+
+    fn bounds() -> Aabb2D<f64> {
+        Aabb2D::new(Point2D::new(-5.0, 0.0), Point2D::new(5.0, 20.0))
+    }
+
+    #[test]
+    fn test_random_points_and_segments_in_bounds() {
+        let mut rng = XorShift::new(4);
+        let points = random_points(&bounds(), 500, &mut rng);
+        assert_eq!(points.len(), 500);
+        assert!(points.iter().all(|p| bounds().contains(p)));
+        let segments = random_segments(&bounds(), 100, &mut rng);
+        assert!(segments.iter().all(|s| bounds().contains(s)));
+        assert_ne!(random_point(&bounds(), &mut rng), random_point(&bounds(), &mut rng));
+    }
+
+    #[test]
+    fn test_random_simple_polygons_do_not_cross() {
+        for (seed, n) in [(1, 3), (2, 10), (3, 40), (4, 80)] {
+            let polygon = random_simple_polygon(&bounds(), n, &mut XorShift::new(seed));
+            let v = polygon.vertices();
+            assert_eq!(v.len(), n);
+            assert!(ring::signed_area(v) > 0.0);
+            for i in 0..n {
+                for j in i + 1..n {
+                    let (a, b, c, d) = (v[i], v[(i + 1) % n], v[j], v[(j + 1) % n]);
+                    assert!(!crosses(a, b, c, d), "edges {i} and {j} of {n} cross");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_random_convex_polygons_turn_left() {
+        for (seed, n) in [(5, 3), (6, 8), (7, 50)] {
+            let polygon = random_convex_polygon(&bounds(), n, &mut XorShift::new(seed));
+            let v = polygon.vertices();
+            assert_eq!(v.len(), n);
+            assert!(v.iter().all(|p| bounds().contains(p)));
+            for i in 0..n {
+                let (a, b, c) = (v[i], v[(i + 1) % n], v[(i + 2) % n]);
+                assert!((b - a).cross_product(&(c - b)) > 0.0);
+            }
+        }
+    }
+}
@@ -0,0 +1,4 @@
+// module exports
+mod generators;
+
+pub use generators::{random_convex_polygon, random_point, random_points, random_segment, random_segments, random_simple_polygon};
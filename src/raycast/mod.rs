@@ -0,0 +1,6 @@
+// module exports
+mod ray2d;
+mod raycaster;
+
+pub use ray2d::{Ray2D, RayCast};
+pub use raycaster::{RayHit, Raycaster};
@@ -0,0 +1,222 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::{Point2D, Vector2D};
+use crate::line::{Line2D, Polyline2D};
+use crate::polygon::Polygon2D;
+use crate::shape::{Aabb2D, Circle2D, Geometry2D};
+
+/// Half-line starting at `origin` and running along the unit vector `direction`.
+///
+/// # Example
+/// ```
+/// use geomengine::line::Line2D;
+/// use geomengine::point::{Point2D, Vector2D};
+/// use geomengine::raycast::Ray2D;
+/// use geomengine::shape::Aabb2D;
+///
+/// let ray = Ray2D::new(Point2D::new(0.0, 1.0), Vector2D::new(2.0, 0.0)).unwrap();
+/// assert_eq!(ray.point_at(3.0), Point2D::new(3.0, 1.0));
+/// let wall = Line2D::new(Point2D::new(5.0, 0.0), Point2D::new(5.0, 4.0));
+/// assert_eq!(ray.intersect_segment(&wall), Some((5.0, Point2D::new(5.0, 1.0))));
+/// let aabb = Aabb2D::new(Point2D::new(2.0, -1.0), Point2D::new(4.0, 3.0));
+/// assert_eq!(ray.intersect_aabb(&aabb), Some((2.0, Point2D::new(2.0, 1.0))));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ray2D<T: Float + Debug> {
+    pub origin: Point2D<T>,
+    pub direction: Vector2D<T>,
+}
+
+impl<T: Float + Debug> Ray2D<T> {
+    /// Ray from `origin` towards `direction`, which is normalized; `None` if it is zero.
+    pub fn new(origin: Point2D<T>, direction: Vector2D<T>) -> Option<Self> {
+        Some(Ray2D { origin, direction: direction.normalize()? })
+    }
+    /// Point at distance `t` along the ray.
+    pub fn point_at(&self, t: T) -> Point2D<T> {
+        self.origin + self.direction * t
+    }
+    /// Distance along the ray and point where it first meets `segment`.
+    pub fn intersect_segment(&self, segment: &Line2D<T>) -> Option<(T, Point2D<T>)> {
+        self.intersect(segment)
+    }
+    /// Distance along the ray and point where it first crosses the outline of
+    /// `circle`; from inside, that is where it leaves.
+    pub fn intersect_circle(&self, circle: &Circle2D<T>) -> Option<(T, Point2D<T>)> {
+        self.intersect(circle)
+    }
+    /// Distance along the ray and point where it first crosses the outline of
+    /// `aabb`; from inside, that is where it leaves.
+    pub fn intersect_aabb(&self, aabb: &Aabb2D<T>) -> Option<(T, Point2D<T>)> {
+        self.intersect(aabb)
+    }
+    fn intersect(&self, shape: &impl RayCast<T>) -> Option<(T, Point2D<T>)> {
+        shape.cast_ray(self).map(|(t, _)| (t, self.point_at(t)))
+    }
+}
+
+/// First crossing of a ray with the boundary of a shape.
+///
+/// [`cast_ray`](Self::cast_ray) returns the distance along the ray and the unit
+/// surface normal there, turned to face the ray. Polygons, circles and boxes are
+/// hit where the ray crosses their outline, so a ray starting inside one hits it
+/// on the way out. Points have no extent and are never hit.
+///
+/// # Example
+/// ```
+/// use geomengine::point::{Point2D, Vector2D};
+/// use geomengine::raycast::{Ray2D, RayCast};
+/// use geomengine::shape::Circle2D;
+///
+/// let ray = Ray2D::new(Point2D::new(0.0, 0.0), Vector2D::new(1.0, 0.0)).unwrap();
+/// let circle = Circle2D::new(Point2D::new(5.0, 0.0), 1.0);
+/// assert_eq!(circle.cast_ray(&ray), Some((4.0, Vector2D::new(-1.0, 0.0))));
+/// ```
+pub trait RayCast<T: Float + Debug> {
+    fn cast_ray(&self, ray: &Ray2D<T>) -> Option<(T, Vector2D<T>)>;
+}
+
+/// The nearer of two hits.
+fn nearest<T: Float + Debug>(a: Option<(T, Vector2D<T>)>, b: Option<(T, Vector2D<T>)>) -> Option<(T, Vector2D<T>)> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(if b.0 < a.0 { b } else { a }),
+        (a, b) => a.or(b),
+    }
+}
+
+/// `normal` scaled to unit length and turned against the ray.
+fn facing<T: Float + Debug>(normal: Vector2D<T>, ray: &Ray2D<T>) -> Option<Vector2D<T>> {
+    let normal = normal.normalize()?;
+    Some(if normal.dot_product(&ray.direction) > T::zero() { -normal } else { normal })
+}
+
+fn cast_segments<T: Float + Debug>(segments: impl Iterator<Item = Line2D<T>>, ray: &Ray2D<T>) -> Option<(T, Vector2D<T>)> {
+    segments.map(|segment| segment.cast_ray(ray)).fold(None, nearest)
+}
+
+impl<T: Float + Debug> RayCast<T> for Line2D<T> {
+    fn cast_ray(&self, ray: &Ray2D<T>) -> Option<(T, Vector2D<T>)> {
+        let edge = self.p2 - self.p1;
+        let offset = self.p1 - ray.origin;
+        let denominator = ray.direction.cross_product(&edge);
+        if denominator == T::zero() {
+            // parallel: only a collinear segment is hit, at its nearest point ahead
+            if offset.cross_product(&ray.direction) != T::zero() {
+                return None;
+            }
+            let (t1, t2) = (offset.dot_product(&ray.direction), (self.p2 - ray.origin).dot_product(&ray.direction));
+            let (near, far) = (t1.min(t2), t1.max(t2));
+            return (far >= T::zero()).then(|| (near.max(T::zero()), -ray.direction));
+        }
+        let t = offset.cross_product(&edge) / denominator;
+        let u = offset.cross_product(&ray.direction) / denominator;
+        if t < T::zero() || u < T::zero() || u > T::one() {
+            return None;
+        }
+        Some((t, facing(edge.perpendicular(), ray)?))
+    }
+}
+
+impl<T: Float + Debug> RayCast<T> for Polyline2D<T> {
+    fn cast_ray(&self, ray: &Ray2D<T>) -> Option<(T, Vector2D<T>)> {
+        cast_segments(self.edges(), ray)
+    }
+}
+
+impl<T: Float + Debug> RayCast<T> for Polygon2D<T> {
+    fn cast_ray(&self, ray: &Ray2D<T>) -> Option<(T, Vector2D<T>)> {
+        cast_segments(self.edges(), ray)
+    }
+}
+
+impl<T: Float + Debug> RayCast<T> for Aabb2D<T> {
+    fn cast_ray(&self, ray: &Ray2D<T>) -> Option<(T, Vector2D<T>)> {
+        cast_segments(self.edges().into_iter(), ray)
+    }
+}
+
+impl<T: Float + Debug> RayCast<T> for Circle2D<T> {
+    fn cast_ray(&self, ray: &Ray2D<T>) -> Option<(T, Vector2D<T>)> {
+        let offset = ray.origin - self.center;
+        let b = offset.dot_product(&ray.direction);
+        let discriminant = b * b - (offset.dot_product(&offset) - self.radius * self.radius);
+        if discriminant < T::zero() {
+            return None;
+        }
+        let root = discriminant.sqrt();
+        let t = if -b - root >= T::zero() { -b - root } else { -b + root };
+        if t < T::zero() {
+            return None;
+        }
+        Some((t, facing(ray.point_at(t) - self.center, ray)?))
+    }
+}
+
+impl<T: Float + Debug> RayCast<T> for Geometry2D<T> {
+    fn cast_ray(&self, ray: &Ray2D<T>) -> Option<(T, Vector2D<T>)> {
+        match self {
+            Geometry2D::Point(_) | Geometry2D::MultiPoint(_) => None,
+            Geometry2D::Line(line) => line.cast_ray(ray),
+            Geometry2D::Polyline(polyline) => polyline.cast_ray(ray),
+            Geometry2D::Polygon(polygon) => polygon.cast_ray(ray),
+            Geometry2D::Circle(circle) => circle.cast_ray(ray),
+            Geometry2D::MultiPolyline(polylines) => polylines.iter().map(|p| p.cast_ray(ray)).fold(None, nearest),
+            Geometry2D::MultiPolygon(polygons) => polygons.iter().map(|p| p.cast_ray(ray)).fold(None, nearest),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num::Zero;
+
+    fn ray(ox: f64, oy: f64, dx: f64, dy: f64) -> Ray2D<f64> {
+        Ray2D::new(Point2D::new(ox, oy), Vector2D::new(dx, dy)).unwrap()
+    }
+
+    #[test]
+    fn test_cast_ray_segments() {
+        let wall = Line2D::new(Point2D::new(2.0, -1.0), Point2D::new(2.0, 1.0));
+        assert_eq!(wall.cast_ray(&ray(0.0, 0.0, 1.0, 0.0)), Some((2.0, Vector2D::new(-1.0, 0.0))));
+        // from behind, the normal flips towards the ray
+        assert_eq!(wall.cast_ray(&ray(5.0, 0.0, -1.0, 0.0)), Some((3.0, Vector2D::new(1.0, 0.0))));
+        assert_eq!(wall.cast_ray(&ray(0.0, 0.0, -1.0, 0.0)), None);
+        assert_eq!(wall.cast_ray(&ray(0.0, 2.0, 1.0, 0.0)), None);
+
+        let floor = Line2D::new(Point2D::new(3.0, 0.0), Point2D::new(6.0, 0.0));
+        assert_eq!(floor.cast_ray(&ray(0.0, 0.0, 1.0, 0.0)), Some((3.0, Vector2D::new(-1.0, 0.0))));
+        assert_eq!(Ray2D::new(Point2D::new(0.0, 0.0), Vector2D::zero()), None);
+    }
+
+    #[test]
+    fn test_cast_ray_regions_from_outside_and_inside() {
+        let square = Polygon2D::new(vec![Point2D::new(1.0, -1.0), Point2D::new(3.0, -1.0), Point2D::new(3.0, 1.0), Point2D::new(1.0, 1.0)]);
+        assert_eq!(square.cast_ray(&ray(0.0, 0.0, 1.0, 0.0)), Some((1.0, Vector2D::new(-1.0, 0.0))));
+        assert_eq!(square.cast_ray(&ray(2.0, 0.0, 1.0, 0.0)), Some((1.0, Vector2D::new(-1.0, 0.0))));
+
+        let circle = Circle2D::new(Point2D::new(0.0, 0.0), 2.0);
+        assert_eq!(circle.cast_ray(&ray(0.0, 0.0, 0.0, 1.0)), Some((2.0, Vector2D::new(0.0, -1.0))));
+        assert_eq!(circle.cast_ray(&ray(3.0, 3.0, 1.0, 0.0)), None);
+        assert_eq!(Geometry2D::from(Point2D::new(1.0, 0.0)).cast_ray(&ray(0.0, 0.0, 1.0, 0.0)), None);
+    }
+
+    #[test]
+    fn test_ray_intersection_points() {
+        let r = ray(-3.0, 1.0, 1.0, 0.0);
+        let circle = Circle2D::new(Point2D::new(0.0, 1.0), 1.0);
+        assert_eq!(r.intersect_circle(&circle), Some((2.0, Point2D::new(-1.0, 1.0))));
+        assert_eq!(ray(0.0, 1.0, 1.0, 0.0).intersect_circle(&circle), Some((1.0, Point2D::new(1.0, 1.0))));
+        assert_eq!(ray(-3.0, 1.0, -1.0, 0.0).intersect_circle(&circle), None);
+
+        let aabb = Aabb2D::new(Point2D::new(1.0, 0.0), Point2D::new(2.0, 5.0));
+        assert_eq!(r.intersect_aabb(&aabb), Some((4.0, Point2D::new(1.0, 1.0))));
+        assert_eq!(ray(1.5, 1.0, 0.0, 1.0).intersect_aabb(&aabb), Some((4.0, Point2D::new(1.5, 5.0))));
+
+        let diagonal = Line2D::new(Point2D::new(0.0, 0.0), Point2D::new(4.0, 4.0));
+        let (t, point) = ray(0.0, 4.0, 1.0, -1.0).intersect_segment(&diagonal).unwrap();
+        assert!((t - 8f64.sqrt()).abs() < 1e-12);
+        assert!(point.distance(&Point2D::new(2.0, 2.0)) < 1e-12);
+        assert_eq!(r.intersect_segment(&diagonal), Some((4.0, Point2D::new(1.0, 1.0))));
+    }
+}
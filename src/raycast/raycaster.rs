@@ -0,0 +1,141 @@
+use num::Float;
+use core::cmp::Ordering;
+use core::fmt::Debug;
+use crate::point::{Point2D, Vector2D};
+use crate::shape::{Aabb2D, BoundingBox, Geometry2D};
+use crate::spatial::RTree;
+use super::{Ray2D, RayCast};
+use alloc::vec::Vec;
+
+/// Where a ray hit a shape of a [`Raycaster`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayHit<T: Float + Debug> {
+    pub point: Point2D<T>,
+    /// Distance from the ray origin.
+    pub distance: T,
+    /// Unit surface normal at `point`, facing the ray.
+    pub normal: Vector2D<T>,
+    /// Index of the shape that was hit.
+    pub shape: usize,
+}
+
+/// Casts rays against a fixed collection of shapes.
+///
+/// Shapes are hit as described by [`RayCast`]. Built with [`with_bvh`](Self::with_bvh),
+/// the shapes' bounding boxes are indexed in an [`RTree`] and only shapes whose box
+/// the ray touches are tested; [`new`](Self::new) tests every shape, which is
+/// faster for a handful of them.
+///
+/// # Example
+/// ```
+/// use geomengine::line::Line2D;
+/// use geomengine::point::{Point2D, Vector2D};
+/// use geomengine::raycast::{Ray2D, Raycaster};
+/// use geomengine::shape::Circle2D;
+///
+/// let raycaster = Raycaster::with_bvh(vec![
+///     Circle2D::new(Point2D::new(10.0, 0.0), 1.0).into(),
+///     Line2D::new(Point2D::new(4.0, -1.0), Point2D::new(4.0, 1.0)).into(),
+/// ]);
+/// let ray = Ray2D::new(Point2D::new(0.0, 0.0), Vector2D::new(1.0, 0.0)).unwrap();
+///
+/// let hit = raycaster.cast(&ray).unwrap();
+/// assert_eq!((hit.shape, hit.distance, hit.point), (1, 4.0, Point2D::new(4.0, 0.0)));
+/// assert_eq!(raycaster.cast_all(&ray).iter().map(|h| h.shape).collect::<Vec<_>>(), [1, 0]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Raycaster<T: Float + Debug> {
+    shapes: Vec<Geometry2D<T>>,
+    bvh: Option<RTree<T>>,
+}
+
+impl<T: Float + Debug> Raycaster<T> {
+    /// Raycaster testing every shape on each cast.
+    pub fn new(shapes: Vec<Geometry2D<T>>) -> Self {
+        Raycaster { shapes, bvh: None }
+    }
+
+    /// Raycaster pruning the shapes with an R-tree over their bounding boxes.
+    pub fn with_bvh(shapes: Vec<Geometry2D<T>>) -> Self {
+        // empty shapes get an inverted box no ray can touch
+        let nowhere = Aabb2D::new(Point2D::new(T::infinity(), T::infinity()), Point2D::new(T::neg_infinity(), T::neg_infinity()));
+        let boxes: Vec<_> = shapes.iter().map(|s| s.bounding_box().unwrap_or(nowhere)).collect();
+        Raycaster { bvh: Some(RTree::bulk_load(&boxes)), shapes }
+    }
+
+    pub fn shapes(&self) -> &[Geometry2D<T>] {
+        &self.shapes
+    }
+
+    /// The nearest hit along `ray`, if any; ties go to the lower shape index.
+    pub fn cast(&self, ray: &Ray2D<T>) -> Option<RayHit<T>> {
+        self.hits(ray).min_by(by_distance)
+    }
+
+    /// The nearest hit on every shape the ray meets, sorted by distance.
+    pub fn cast_all(&self, ray: &Ray2D<T>) -> Vec<RayHit<T>> {
+        let mut hits: Vec<RayHit<T>> = self.hits(ray).collect();
+        hits.sort_by(by_distance);
+        hits
+    }
+
+    fn hits<'a>(&'a self, ray: &'a Ray2D<T>) -> impl Iterator<Item = RayHit<T>> + 'a {
+        let candidates: Vec<usize> = match &self.bvh {
+            Some(bvh) => {
+                let mut candidates = bvh.query_ray(ray.origin, ray.direction, T::infinity());
+                candidates.sort_unstable();
+                candidates
+            }
+            None => (0..self.shapes.len()).collect(),
+        };
+        candidates.into_iter().filter_map(move |shape| {
+            let (distance, normal) = self.shapes[shape].cast_ray(ray)?;
+            Some(RayHit { point: ray.point_at(distance), distance, normal, shape })
+        })
+    }
+}
+
+fn by_distance<T: Float + Debug>(a: &RayHit<T>, b: &RayHit<T>) -> Ordering {
+    a.distance.partial_cmp(&b.distance).unwrap_or(Ordering::Equal).then(a.shape.cmp(&b.shape))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polygon::Polygon2D;
+
+    fn boxes(n: usize) -> Vec<Geometry2D<f64>> {
+        (0..n)
+            .map(|i| {
+                let (x, y) = ((i % 20) as f64 * 3.0, (i / 20) as f64 * 3.0);
+                Polygon2D::new(vec![Point2D::new(x, y), Point2D::new(x + 1.0, y), Point2D::new(x + 1.0, y + 1.0), Point2D::new(x, y + 1.0)]).into()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_raycaster_bvh_matches_linear_scan() {
+        let shapes = boxes(400);
+        let (linear, indexed) = (Raycaster::new(shapes.clone()), Raycaster::with_bvh(shapes));
+        for (origin, direction) in [((-1.0, 0.5), (1.0, 0.0)), ((-1.0, -1.0), (1.0, 1.0)), ((30.5, 100.0), (0.0, -1.0)), ((0.5, 0.5), (-1.0, 0.2))] {
+            let ray = Ray2D::new(Point2D::new(origin.0, origin.1), Vector2D::new(direction.0, direction.1)).unwrap();
+            assert_eq!(linear.cast_all(&ray), indexed.cast_all(&ray));
+            assert_eq!(linear.cast(&ray), indexed.cast(&ray));
+        }
+
+        let ray = Ray2D::new(Point2D::new(-1.0, 0.5), Vector2D::new(1.0, 0.0)).unwrap();
+        let hits = indexed.cast_all(&ray);
+        assert_eq!(hits.len(), 20);
+        assert!(hits.windows(2).all(|w| w[0].distance < w[1].distance));
+        assert_eq!(hits[0], RayHit { point: Point2D::new(0.0, 0.5), distance: 1.0, normal: Vector2D::new(-1.0, 0.0), shape: 0 });
+    }
+
+    #[test]
+    fn test_raycaster_skips_empty_and_missed_shapes() {
+        let raycaster = Raycaster::with_bvh(vec![Geometry2D::MultiPolygon(vec![]), boxes(1).remove(0)]);
+        let up = Ray2D::new(Point2D::new(0.5, -5.0), Vector2D::new(0.0, 1.0)).unwrap();
+        assert_eq!(raycaster.cast(&up).map(|h| h.shape), Some(1));
+        let away = Ray2D::new(Point2D::new(0.5, -5.0), Vector2D::new(0.0, -1.0)).unwrap();
+        assert_eq!(raycaster.cast(&away), None);
+    }
+}
@@ -0,0 +1,4 @@
+// module exports
+mod scene_node;
+
+pub use scene_node::{SceneHit, SceneNode};
@@ -0,0 +1,192 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::shape::{Aabb2D, BoundingBox, Distance, Geometry2D};
+use crate::transform::{Transform2D, Transformable};
+use alloc::vec::Vec;
+
+/// A geometry found by [`SceneNode::hit_test`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SceneHit {
+    /// Child indices leading from the tested node to the node holding the geometry.
+    pub path: Vec<usize>,
+    /// Index of the geometry in that node's [`geometries`](SceneNode::geometries).
+    pub geometry: usize,
+}
+
+/// Node of a scene graph: geometries and child nodes sharing a local transform.
+///
+/// A node's `transform` maps its local coordinates into its parent's, so the
+/// world position of a geometry is found by applying the transforms from its node
+/// up to the root. World-space queries on a node ([`world_geometries`](Self::world_geometries),
+/// [`bounding_box`](Self::bounding_box), [`hit_test`](Self::hit_test)) include the
+/// node's own transform. Geometries are visited depth-first, a node's own before
+/// its children's, which is also the drawing order.
+///
+/// # Example
+/// ```
+/// use geomengine::point::Point2D;
+/// use geomengine::scene::SceneNode;
+/// use geomengine::shape::{Aabb2D, BoundingBox, Circle2D};
+/// use geomengine::transform::Transform2D;
+///
+/// // a wheel assembly: two wheels placed on an axle, the axle moved as a whole
+/// let mut wheel = SceneNode::new(Transform2D::translation(-2.0, 0.0));
+/// wheel.add_geometry(Circle2D::new(Point2D::new(0.0, 0.0), 1.0));
+/// let mut axle = SceneNode::new(Transform2D::translation(10.0, 5.0));
+/// axle.add_child(wheel.clone());
+/// axle.add_child(SceneNode { transform: Transform2D::translation(2.0, 0.0), ..wheel });
+///
+/// assert_eq!(axle.bounding_box(), Some(Aabb2D::new(Point2D::new(7.0, 4.0), Point2D::new(13.0, 6.0))));
+/// let hits = axle.hit_test(&Point2D::new(12.5, 5.0), 0.0);
+/// assert_eq!(hits[0].path, [1]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SceneNode<T: Float + Debug> {
+    pub transform: Transform2D<T>,
+    pub geometries: Vec<Geometry2D<T>>,
+    pub children: Vec<SceneNode<T>>,
+}
+
+impl<T: Float + Debug> Default for SceneNode<T> {
+    fn default() -> Self {
+        Self::new(Transform2D::identity())
+    }
+}
+
+impl<T: Float + Debug> SceneNode<T> {
+    pub fn new(transform: Transform2D<T>) -> Self {
+        SceneNode { transform, geometries: Vec::new(), children: Vec::new() }
+    }
+    pub fn add_geometry(&mut self, geometry: impl Into<Geometry2D<T>>) -> &mut Self {
+        self.geometries.push(geometry.into());
+        self
+    }
+    pub fn add_child(&mut self, child: SceneNode<T>) -> &mut Self {
+        self.children.push(child);
+        self
+    }
+
+    /// The descendant reached by following the child indices in `path`.
+    pub fn node(&self, path: &[usize]) -> Option<&SceneNode<T>> {
+        path.iter().try_fold(self, |node, &child| node.children.get(child))
+    }
+    pub fn node_mut(&mut self, path: &[usize]) -> Option<&mut SceneNode<T>> {
+        path.iter().try_fold(self, |node, &child| node.children.get_mut(child))
+    }
+
+    /// Transform from the local coordinates of the node at `path` to this node's parent.
+    pub fn world_transform(&self, path: &[usize]) -> Option<Transform2D<T>> {
+        let mut node = self;
+        let mut transform = self.transform;
+        for &child in path {
+            node = node.children.get(child)?;
+            transform = node.transform.then(&transform);
+        }
+        Some(transform)
+    }
+
+    /// Every geometry of the subtree in world space, in drawing order.
+    pub fn world_geometries(&self) -> Vec<Geometry2D<T>> {
+        let mut geometries = Vec::new();
+        self.visit(&Transform2D::identity(), &mut Vec::new(), &mut |_, _, geometry| geometries.push(geometry));
+        geometries
+    }
+
+    /// World-space bounding box of the subtree, `None` if it holds no points.
+    ///
+    /// The box of each geometry is taken after transforming it, so it stays tight
+    /// under rotation.
+    pub fn bounding_box(&self) -> Option<Aabb2D<T>> {
+        let mut bounds: Option<Aabb2D<T>> = None;
+        self.visit(&Transform2D::identity(), &mut Vec::new(), &mut |_, _, geometry| {
+            if let Some(b) = geometry.bounding_box() {
+                bounds = Some(bounds.map_or(b, |a| a.union(&b)));
+            }
+        });
+        bounds
+    }
+
+    /// Geometries of the subtree within `tolerance` of the world-space `point`.
+    ///
+    /// Areas are hit anywhere inside; points and curves only within the
+    /// tolerance, which acts as a pick radius. Hits come in drawing order, so the
+    /// last one is the topmost.
+    pub fn hit_test(&self, point: &Point2D<T>, tolerance: T) -> Vec<SceneHit> {
+        let mut hits = Vec::new();
+        self.visit(&Transform2D::identity(), &mut Vec::new(), &mut |path, index, geometry| {
+            let near = geometry.bounding_box().is_some_and(|b| {
+                point.x >= b.min.x - tolerance && point.x <= b.max.x + tolerance && point.y >= b.min.y - tolerance && point.y <= b.max.y + tolerance
+            });
+            if near && geometry.distance_to(point) <= tolerance {
+                hits.push(SceneHit { path: path.to_vec(), geometry: index });
+            }
+        });
+        hits
+    }
+
+    /// Calls `f` with the path, index and world-space copy of every geometry below `parent`.
+    fn visit(&self, parent: &Transform2D<T>, path: &mut Vec<usize>, f: &mut impl FnMut(&[usize], usize, Geometry2D<T>)) {
+        let transform = self.transform.then(parent);
+        for (index, geometry) in self.geometries.iter().enumerate() {
+            f(path, index, geometry.apply(&transform));
+        }
+        for (k, child) in self.children.iter().enumerate() {
+            path.push(k);
+            child.visit(&transform, path, f);
+            path.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::line::Line2D;
+    use crate::polygon::Polygon2D;
+    use crate::transform::Angle;
+
+    fn unit_square() -> Polygon2D<f64> {
+        Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(1.0, 0.0), Point2D::new(1.0, 1.0), Point2D::new(0.0, 1.0)])
+    }
+
+    #[test]
+    fn test_scene_transforms_compose_down_the_tree() {
+        let mut leaf = SceneNode::new(Transform2D::translation(1.0, 0.0));
+        leaf.add_geometry(Point2D::new(0.0, 0.0));
+        let mut middle = SceneNode::new(Transform2D::scaling(2.0, 2.0));
+        middle.add_child(leaf);
+        let mut root = SceneNode::new(Transform2D::translation(0.0, 10.0));
+        root.add_geometry(unit_square()).add_child(middle);
+
+        // the leaf is shifted first, then scaled, then shifted again
+        let world = root.world_geometries();
+        assert_eq!(world.len(), 2);
+        assert_eq!(world[1], Geometry2D::Point(Point2D::new(2.0, 10.0)));
+        assert_eq!(root.world_transform(&[0, 0]).unwrap().transform_point(&Point2D::new(0.0, 0.0)), Point2D::new(2.0, 10.0));
+        assert_eq!(root.world_transform(&[1]), None);
+
+        root.node_mut(&[0]).unwrap().transform = Transform2D::identity();
+        assert_eq!(root.world_geometries()[1], Geometry2D::Point(Point2D::new(1.0, 10.0)));
+    }
+
+    #[test]
+    fn test_scene_bounding_box_and_hit_test() {
+        let mut rotated = SceneNode::new(Transform2D::rotation(Angle::from_degrees(90.0)));
+        rotated.add_geometry(unit_square()).add_geometry(Line2D::new(Point2D::new(0.0, 0.0), Point2D::new(3.0, 0.0)));
+        let mut root = SceneNode::default();
+        root.add_geometry(unit_square()).add_child(rotated);
+
+        let bounds = root.bounding_box().unwrap();
+        assert!((bounds.min.x + 1.0).abs() < 1e-12 && (bounds.max.y - 3.0).abs() < 1e-12);
+
+        // both squares and the rotated line share the edge x = 0
+        let hits = root.hit_test(&Point2D::new(0.0, 0.5), 1e-9);
+        assert_eq!(hits.len(), 3);
+        assert_eq!(hits[1], SceneHit { path: vec![0], geometry: 0 });
+        // the rotated line is only hit within the pick radius
+        assert!(root.hit_test(&Point2D::new(0.05, 2.5), 0.01).is_empty());
+        assert_eq!(root.hit_test(&Point2D::new(0.05, 2.5), 0.1), [SceneHit { path: vec![0], geometry: 1 }]);
+        assert_eq!(SceneNode::<f64>::default().bounding_box(), None);
+    }
+}
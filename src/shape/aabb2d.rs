@@ -0,0 +1,109 @@
+use num::{Num, Float};
+use core::fmt::Debug;
+use crate::point::{Point2D, Vector2D};
+use crate::line::Line2D;
+
+/// Axis aligned bounding box spanning `min`..`max` (both corners included).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aabb2D<T: Num + Copy + Debug> {
+    pub min: Point2D<T>,
+    pub max: Point2D<T>,
+}
+
+impl<T: Num + Copy + Debug> Aabb2D<T> {
+    pub fn new(min: Point2D<T>, max: Point2D<T>) -> Self {
+        Aabb2D { min, max }
+    }
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x
+    }
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y
+    }
+    pub fn area(&self) -> T {
+        self.width() * self.height()
+    }
+    pub fn center(&self) -> Point2D<T> {
+        (self.min + self.max) / (T::one() + T::one())
+    }
+    /// The four corners, counterclockwise from `min`.
+    pub fn corners(&self) -> [Point2D<T>; 4] {
+        [self.min, Point2D::new(self.max.x, self.min.y), self.max, Point2D::new(self.min.x, self.max.y)]
+    }
+    /// The four edges, counterclockwise from `min`.
+    pub fn edges(&self) -> [Line2D<T>; 4] {
+        let [a, b, c, d] = self.corners();
+        [Line2D::new(a, b), Line2D::new(b, c), Line2D::new(c, d), Line2D::new(d, a)]
+    }
+}
+
+impl<T: Float + Debug> Aabb2D<T> {
+    /// Smallest box containing all `points`, or `None` if there are none.
+    pub fn from_points(points: &[Point2D<T>]) -> Option<Self> {
+        crate::algorithms::point_algorithms::bounding_box_2d(points)
+    }
+    /// Smallest box containing both boxes.
+    pub fn union(&self, other: &Self) -> Self {
+        Aabb2D::new(
+            Point2D::new(self.min.x.min(other.min.x), self.min.y.min(other.min.y)),
+            Point2D::new(self.max.x.max(other.max.x), self.max.y.max(other.max.y)),
+        )
+    }
+    /// The box both boxes cover, or `None` if they are disjoint. Boxes that
+    /// only touch meet in a flat box.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let min = Point2D::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y));
+        let max = Point2D::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y));
+        (min.x <= max.x && min.y <= max.y).then_some(Aabb2D::new(min, max))
+    }
+    /// Returns true if the point lies in the box, boundary included.
+    pub fn contains_point(&self, point: &Point2D<T>) -> bool {
+        self.min.x <= point.x && point.x <= self.max.x && self.min.y <= point.y && point.y <= self.max.y
+    }
+    /// The box grown by `margin` on every side (shrunk if it is negative).
+    pub fn expand(&self, margin: T) -> Self {
+        let margin = Vector2D::new(margin, margin);
+        Aabb2D::new(self.min - margin, self.max + margin)
+    }
+    /// Smallest box containing this one and `point`.
+    pub fn expand_to_include(&self, point: &Point2D<T>) -> Self {
+        self.union(&Aabb2D::new(*point, *point))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shape::Intersects;
+
+    #[test]
+    fn test_aabb2d_from_points_and_union() {
+        let points = [Point2D::new(1.0, 5.0), Point2D::new(-2.0, 3.0), Point2D::new(4.0, 4.0)];
+        let aabb = Aabb2D::from_points(&points).unwrap();
+        assert_eq!(aabb, Aabb2D::new(Point2D::new(-2.0, 3.0), Point2D::new(4.0, 5.0)));
+        assert_eq!((aabb.width(), aabb.height()), (6.0, 2.0));
+        assert_eq!(aabb.center(), Point2D::new(1.0, 4.0));
+        assert_eq!(Aabb2D::<f64>::from_points(&[]), None);
+
+        let other = Aabb2D::new(Point2D::new(4.0, 0.0), Point2D::new(6.0, 3.0));
+        assert!(aabb.intersects(&other));
+        assert_eq!(aabb.union(&other), Aabb2D::new(Point2D::new(-2.0, 0.0), Point2D::new(6.0, 5.0)));
+    }
+
+    #[test]
+    fn test_aabb2d_intersection_and_expand() {
+        let aabb = Aabb2D::new(Point2D::new(0.0, 0.0), Point2D::new(4.0, 2.0));
+        assert_eq!(aabb.area(), 8.0);
+        let other = Aabb2D::new(Point2D::new(3.0, 1.0), Point2D::new(5.0, 5.0));
+        assert_eq!(aabb.intersection(&other), Some(Aabb2D::new(Point2D::new(3.0, 1.0), Point2D::new(4.0, 2.0))));
+        let touching = Aabb2D::new(Point2D::new(4.0, 0.0), Point2D::new(6.0, 1.0));
+        assert_eq!(aabb.intersection(&touching).map(|i| i.area()), Some(0.0));
+        assert_eq!(aabb.intersection(&touching.expand(-0.5)), None);
+
+        assert!(aabb.contains_point(&Point2D::new(4.0, 0.0)));
+        assert!(!aabb.contains_point(&Point2D::new(4.0, -0.1)));
+        assert_eq!(aabb.expand(1.0), Aabb2D::new(Point2D::new(-1.0, -1.0), Point2D::new(5.0, 3.0)));
+        assert_eq!(aabb.expand_to_include(&Point2D::new(-2.0, 1.0)).min, Point2D::new(-2.0, 0.0));
+        assert_eq!(aabb.corners().iter().filter(|c| aabb.contains_point(c)).count(), 4);
+    }
+}
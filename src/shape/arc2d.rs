@@ -0,0 +1,154 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::line::Polyline2D;
+use crate::transform::Angle;
+use super::{Aabb2D, Winding};
+use alloc::vec::Vec;
+
+/// A circular arc around `center`, from the direction `start_angle` to
+/// `end_angle` (measured from the x axis), turning the way `winding` says.
+///
+/// The arc sweeps less than a full turn: equal angles, or angles a whole
+/// number of turns apart, give a zero length arc.
+///
+/// # Example
+/// ```
+/// use geomengine::point::Point2D;
+/// use geomengine::shape::{Arc2D, Winding};
+/// use geomengine::transform::Angle;
+/// use std::f64::consts::PI;
+///
+/// let arc = Arc2D::new(Point2D::new(0.0, 0.0), 2.0, Angle::zero(), Angle::from_degrees(90.0), Winding::Clockwise);
+/// assert_eq!(arc.sweep().radians(), 1.5 * PI);
+/// assert_eq!(arc.length(), 3.0 * PI);
+/// assert!(arc.contains_angle(Angle::from_degrees(180.0)));
+/// assert!(!arc.contains_angle(Angle::from_degrees(45.0)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Arc2D<T: Float + Debug> {
+    pub center: Point2D<T>,
+    pub radius: T,
+    pub start_angle: Angle<T>,
+    pub end_angle: Angle<T>,
+    pub winding: Winding,
+}
+
+impl<T: Float + Debug> Arc2D<T> {
+    pub fn new(center: Point2D<T>, radius: T, start_angle: Angle<T>, end_angle: Angle<T>, winding: Winding) -> Self {
+        Arc2D { center, radius, start_angle, end_angle, winding }
+    }
+    /// Angle swept from start to end, in `[0, 2π)`.
+    pub fn sweep(&self) -> Angle<T> {
+        match self.winding {
+            Winding::CounterClockwise => (self.end_angle - self.start_angle).normalized(),
+            Winding::Clockwise => (self.start_angle - self.end_angle).normalized(),
+        }
+    }
+    pub fn length(&self) -> T {
+        self.radius * self.sweep().radians()
+    }
+    /// The point of the circle in the direction `angle`, whether or not the arc reaches it.
+    pub fn point_at_angle(&self, angle: Angle<T>) -> Point2D<T> {
+        let (sin, cos) = angle.sin_cos();
+        self.center + Point2D::new(cos, sin) * self.radius
+    }
+    /// The direction `fraction` of the way along the arc, `0` at the start and `1` at the end.
+    pub fn angle_at(&self, fraction: T) -> Angle<T> {
+        match self.winding {
+            Winding::CounterClockwise => self.start_angle + self.sweep() * fraction,
+            Winding::Clockwise => self.start_angle - self.sweep() * fraction,
+        }
+    }
+    pub fn start(&self) -> Point2D<T> {
+        self.point_at_angle(self.start_angle)
+    }
+    pub fn end(&self) -> Point2D<T> {
+        self.point_at_angle(self.end_angle)
+    }
+    /// The point halfway along the arc.
+    pub fn midpoint(&self) -> Point2D<T> {
+        self.point_at_angle(self.angle_at(T::from(0.5).unwrap()))
+    }
+    /// Does the arc pass the direction `angle`? Both ends count.
+    pub fn contains_angle(&self, angle: Angle<T>) -> bool {
+        let from_start = match self.winding {
+            Winding::CounterClockwise => (angle - self.start_angle).normalized(),
+            Winding::Clockwise => (self.start_angle - angle).normalized(),
+        };
+        from_start <= self.sweep()
+    }
+    /// Smallest box holding the arc: its ends, and the extreme points of the
+    /// circle along the axes the arc passes.
+    pub fn bounding_box(&self) -> Aabb2D<T> {
+        let quarter = Angle::from_radians(T::from(core::f64::consts::FRAC_PI_2).unwrap());
+        let mut points = Vec::with_capacity(6);
+        points.extend([self.start(), self.end()]);
+        points.extend((0..4).map(|i| quarter * T::from(i).unwrap()).filter(|&a| self.contains_angle(a)).map(|a| self.point_at_angle(a)));
+        Aabb2D::from_points(&points).unwrap()
+    }
+    /// Approximates the arc by `num_segments` equal chords (at least one).
+    ///
+    /// Chords of an arc sweeping `θ` each stray at most `r (1 - cos(θ / 2n))`
+    /// from it.
+    pub fn to_polyline(&self, num_segments: usize) -> Polyline2D<T> {
+        let n = num_segments.max(1);
+        let vertices = (0..=n).map(|i| self.point_at_angle(self.angle_at(T::from(i).unwrap() / T::from(n).unwrap()))).collect();
+        Polyline2D::new(vertices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use core::f64::consts::{FRAC_PI_2, PI};
+
+    fn radians(angle: f64) -> Angle<f64> {
+        Angle::from_radians(angle)
+    }
+
+    #[test]
+    fn test_arc_points_and_length() {
+        let arc = Arc2D::new(Point2D::new(1.0, 1.0), 2.0, Angle::zero(), radians(FRAC_PI_2), Winding::CounterClockwise);
+        assert_relative_eq!(arc.length(), PI, epsilon = 1e-12);
+        assert_eq!(arc.start(), Point2D::new(3.0, 1.0));
+        assert_relative_eq!(arc.end().x, 1.0, epsilon = 1e-12);
+        let middle = arc.midpoint();
+        assert_relative_eq!(middle.x, 1.0 + 2f64.sqrt(), epsilon = 1e-12);
+        assert_relative_eq!(middle.y, 1.0 + 2f64.sqrt(), epsilon = 1e-12);
+
+        // the same ends the other way round the circle
+        let long_way = Arc2D { winding: Winding::Clockwise, ..arc };
+        assert_relative_eq!(long_way.length(), 3.0 * PI, epsilon = 1e-12);
+        assert_relative_eq!(long_way.midpoint().x, 1.0 - 2f64.sqrt(), epsilon = 1e-12);
+        assert_eq!(Arc2D::new(Point2D::new(0.0, 0.0), 1.0, radians(1.0), radians(1.0 + 2.0 * PI), Winding::CounterClockwise).sweep(), Angle::zero());
+    }
+
+    #[test]
+    fn test_arc_bounding_box() {
+        let arc = Arc2D::new(Point2D::new(0.0, 0.0), 1.0, radians(-FRAC_PI_2 / 2.0), radians(PI - FRAC_PI_2 / 2.0), Winding::CounterClockwise);
+        let bounds = arc.bounding_box();
+        let half = 0.5f64.sqrt();
+        assert_relative_eq!(bounds.min.x, -half, epsilon = 1e-12);
+        assert_relative_eq!(bounds.min.y, -half, epsilon = 1e-12);
+        assert_eq!(bounds.max, Point2D::new(1.0, 1.0));
+
+        let flipped = Arc2D { winding: Winding::Clockwise, ..arc };
+        let bounds = flipped.bounding_box();
+        assert_eq!(bounds.min, Point2D::new(-1.0, -1.0));
+        assert_relative_eq!(bounds.max.x, half, epsilon = 1e-12);
+        assert_relative_eq!(bounds.max.y, half, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_arc_to_polyline() {
+        let arc = Arc2D::new(Point2D::new(0.0, 0.0), 3.0, radians(PI), Angle::zero(), Winding::Clockwise);
+        let polyline = arc.to_polyline(64);
+        assert_eq!(polyline.len(), 65);
+        assert_eq!(polyline.vertices()[0], arc.start());
+        assert!(polyline.vertices().iter().all(|v| v.y >= -1e-12 && (v.distance(&arc.center) - 3.0).abs() < 1e-12));
+        assert_relative_eq!(polyline.length(), arc.length(), epsilon = 1e-2);
+        assert_eq!(arc.to_polyline(0).len(), 2);
+    }
+}
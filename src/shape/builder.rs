@@ -0,0 +1,248 @@
+use num::Float;
+use core::fmt::{self, Debug, Display};
+use crate::point::Point2D;
+use crate::line::Polyline2D;
+use crate::polygon::{Polygon2D, ring};
+use super::Intersects;
+use alloc::vec::Vec;
+
+/// Why a builder rejected its input, or what
+/// [`validate_collection`](crate::algorithms::validation::validate_collection) found wrong.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidityError {
+    /// Fewer distinct vertices than the shape needs.
+    TooFewVertices { found: usize, required: usize },
+    /// The pushed vertex at `index` has a NaN or infinite coordinate.
+    NonFiniteCoordinate { index: usize },
+    /// Consecutive vertices `first` and `second` coincide. Builders drop such
+    /// duplicates instead of reporting them.
+    DuplicateVertex { first: usize, second: usize },
+    /// All vertices of the polygon are collinear.
+    ZeroArea,
+    /// Two non-adjacent edges of the polygon touch or cross; edge `i` runs from
+    /// vertex `i` of the built polygon to the next one.
+    SelfIntersection { first: usize, second: usize },
+}
+
+impl Display for ValidityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidityError::TooFewVertices { found, required } => {
+                write!(f, "{found} distinct vertices, at least {required} required")
+            }
+            ValidityError::NonFiniteCoordinate { index } => write!(f, "vertex {index} has a non-finite coordinate"),
+            ValidityError::DuplicateVertex { first, second } => write!(f, "vertices {first} and {second} coincide"),
+            ValidityError::ZeroArea => write!(f, "polygon has zero area"),
+            ValidityError::SelfIntersection { first, second } => write!(f, "edges {first} and {second} intersect"),
+        }
+    }
+}
+
+impl core::error::Error for ValidityError {}
+
+/// Vertex order of a built polygon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Winding {
+    CounterClockwise,
+    Clockwise,
+}
+
+/// Collects vertices one at a time and builds a cleaned, validated [`Polygon2D`].
+///
+/// [`build`](Self::build):
+/// - drops consecutive duplicate vertices (within the tolerance), including a
+///   closing vertex repeating the first one,
+/// - reverses the vertices if needed to match the requested [`Winding`]
+///   (counterclockwise unless set otherwise),
+/// - rejects non-finite coordinates, fewer than 3 vertices, zero area and
+///   self-intersecting rings.
+///
+/// # Example
+/// ```
+/// use geomengine::point::Point2D;
+/// use geomengine::polygon::Polygon2D;
+///
+/// let mut builder = Polygon2D::builder();
+/// for (x, y) in [(0.0, 0.0), (0.0, 1.0), (0.0, 1.0), (1.0, 0.0), (0.0, 0.0)] {
+///     builder.push_vertex(Point2D::new(x, y));
+/// }
+/// let triangle = builder.build()?;
+/// assert_eq!(triangle.vertices(), &[Point2D::new(0.0, 0.0), Point2D::new(1.0, 0.0), Point2D::new(0.0, 1.0)]);
+/// # Ok::<(), geomengine::shape::ValidityError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct PolygonBuilder<T: Float + Debug> {
+    vertices: Vec<Point2D<T>>,
+    tolerance: T,
+    winding: Winding,
+}
+
+impl<T: Float + Debug> Default for PolygonBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Float + Debug> PolygonBuilder<T> {
+    pub fn new() -> Self {
+        PolygonBuilder { vertices: Vec::new(), tolerance: T::zero(), winding: Winding::CounterClockwise }
+    }
+    pub fn push_vertex(&mut self, vertex: Point2D<T>) -> &mut Self {
+        self.vertices.push(vertex);
+        self
+    }
+    /// Vertices closer than `tolerance` to the previous one are dropped (default: exact duplicates only).
+    pub fn tolerance(&mut self, tolerance: T) -> &mut Self {
+        self.tolerance = tolerance;
+        self
+    }
+    pub fn winding(&mut self, winding: Winding) -> &mut Self {
+        self.winding = winding;
+        self
+    }
+
+    pub fn build(&self) -> Result<Polygon2D<T>, ValidityError> {
+        let mut vertices = clean_vertices(&self.vertices, self.tolerance)?;
+        // automatic closure: a last vertex repeating the first one is dropped
+        while vertices.len() > 1 && vertices[0].distance(&vertices[vertices.len() - 1]) <= self.tolerance {
+            vertices.pop();
+        }
+        if vertices.len() < 3 {
+            return Err(ValidityError::TooFewVertices { found: vertices.len(), required: 3 });
+        }
+
+        let area = ring::signed_area(&vertices);
+        if area == T::zero() {
+            return Err(ValidityError::ZeroArea);
+        }
+        if (area < T::zero()) == (self.winding == Winding::CounterClockwise) {
+            // keep the starting vertex in place
+            vertices[1..].reverse();
+        }
+
+        let polygon = Polygon2D::new(vertices);
+        let edges: Vec<_> = polygon.edges().collect();
+        let n = edges.len();
+        for i in 0..n {
+            // neighbouring edges share a vertex; skip them (edge 0 and n - 1 are neighbours too)
+            for j in i + 2..n - usize::from(i == 0) {
+                if edges[i].intersects(&edges[j]) {
+                    return Err(ValidityError::SelfIntersection { first: i, second: j });
+                }
+            }
+        }
+        Ok(polygon)
+    }
+}
+
+impl<T: Float + Debug> Extend<Point2D<T>> for PolygonBuilder<T> {
+    fn extend<I: IntoIterator<Item = Point2D<T>>>(&mut self, vertices: I) {
+        self.vertices.extend(vertices);
+    }
+}
+
+/// Collects vertices one at a time and builds a cleaned, validated [`Polyline2D`].
+///
+/// [`build`](Self::build) drops consecutive duplicate vertices (within the
+/// tolerance) and rejects non-finite coordinates and fewer than 2 vertices.
+#[derive(Debug, Clone)]
+pub struct PolylineBuilder<T: Float + Debug> {
+    vertices: Vec<Point2D<T>>,
+    tolerance: T,
+}
+
+impl<T: Float + Debug> Default for PolylineBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Float + Debug> PolylineBuilder<T> {
+    pub fn new() -> Self {
+        PolylineBuilder { vertices: Vec::new(), tolerance: T::zero() }
+    }
+    pub fn push_vertex(&mut self, vertex: Point2D<T>) -> &mut Self {
+        self.vertices.push(vertex);
+        self
+    }
+    /// Vertices closer than `tolerance` to the previous one are dropped (default: exact duplicates only).
+    pub fn tolerance(&mut self, tolerance: T) -> &mut Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    pub fn build(&self) -> Result<Polyline2D<T>, ValidityError> {
+        let vertices = clean_vertices(&self.vertices, self.tolerance)?;
+        if vertices.len() < 2 {
+            return Err(ValidityError::TooFewVertices { found: vertices.len(), required: 2 });
+        }
+        Ok(Polyline2D::new(vertices))
+    }
+}
+
+impl<T: Float + Debug> Extend<Point2D<T>> for PolylineBuilder<T> {
+    fn extend<I: IntoIterator<Item = Point2D<T>>>(&mut self, vertices: I) {
+        self.vertices.extend(vertices);
+    }
+}
+
+/// `vertices` without consecutive duplicates, or the index of the first non-finite vertex.
+fn clean_vertices<T: Float + Debug>(vertices: &[Point2D<T>], tolerance: T) -> Result<Vec<Point2D<T>>, ValidityError> {
+    let mut cleaned: Vec<Point2D<T>> = Vec::with_capacity(vertices.len());
+    for (index, &vertex) in vertices.iter().enumerate() {
+        if !(vertex.x.is_finite() && vertex.y.is_finite()) {
+            return Err(ValidityError::NonFiniteCoordinate { index });
+        }
+        if cleaned.last().is_none_or(|last| last.distance(&vertex) > tolerance) {
+            cleaned.push(vertex);
+        }
+    }
+    Ok(cleaned)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn polygon_from(points: &[(f64, f64)]) -> Result<Polygon2D<f64>, ValidityError> {
+        let mut builder = Polygon2D::builder();
+        builder.extend(points.iter().map(|&(x, y)| Point2D::new(x, y)));
+        builder.build()
+    }
+
+    #[test]
+    fn test_polygon_builder_cleans_and_orients() {
+        let square = polygon_from(&[(0.0, 0.0), (0.0, 2.0), (2.0, 2.0), (2.0, 2.0), (2.0, 0.0), (0.0, 0.0)]).unwrap();
+        assert_eq!(square.len(), 4);
+        assert!(ring::signed_area(square.vertices()) > 0.0);
+
+        let mut builder = Polygon2D::builder();
+        builder.extend(square.vertices().iter().copied());
+        let clockwise = builder.winding(Winding::Clockwise).build().unwrap();
+        assert!(ring::signed_area(clockwise.vertices()) < 0.0);
+    }
+
+    #[test]
+    fn test_polygon_builder_rejects_invalid_rings() {
+        assert_eq!(polygon_from(&[(0.0, 0.0), (1.0, 1.0), (1.0, 1.0)]), Err(ValidityError::TooFewVertices { found: 2, required: 3 }));
+        assert_eq!(polygon_from(&[(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)]), Err(ValidityError::ZeroArea));
+        assert_eq!(polygon_from(&[(0.0, 0.0), (f64::NAN, 1.0), (2.0, 0.0)]), Err(ValidityError::NonFiniteCoordinate { index: 1 }));
+        // a bow tie: the first and third edges cross
+        assert_eq!(
+            polygon_from(&[(0.0, 0.0), (2.0, 2.0), (2.0, 0.0), (0.0, 2.0), (-1.0, 1.0)]),
+            Err(ValidityError::SelfIntersection { first: 0, second: 2 })
+        );
+    }
+
+    #[test]
+    fn test_polyline_builder() {
+        let mut builder = Polyline2D::builder();
+        builder.tolerance(0.1);
+        builder.push_vertex(Point2D::new(0.0, 0.0)).push_vertex(Point2D::new(0.05, 0.0)).push_vertex(Point2D::new(1.0, 0.0));
+        assert_eq!(builder.build().unwrap().len(), 2);
+        assert_eq!(
+            PolylineBuilder::<f64>::new().push_vertex(Point2D::new(1.0, 1.0)).build(),
+            Err(ValidityError::TooFewVertices { found: 1, required: 2 })
+        );
+    }
+}
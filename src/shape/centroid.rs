@@ -0,0 +1,192 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::line::{Line2D, Polyline2D};
+use crate::polygon::{Polygon2D, PolygonWithHoles, ring};
+use crate::algorithms::point_algorithms::centroid_2d;
+use super::{Aabb2D, Area, Circle2D, Geometry2D, Perimeter, Triangle2D};
+use alloc::vec::Vec;
+
+/// Center of mass of a shape, or `None` if it has no points.
+///
+/// Each shape is weighted by its own dimension: point sets by count, curves by
+/// length and polygons by area. Degenerate shapes fall back to the next lower
+/// dimension, e.g. a zero-area polygon to the length-weighted centroid of its
+/// boundary and a zero-length polyline to the mean of its vertices.
+///
+/// # Example
+/// ```
+/// use geomengine::line::Polyline2D;
+/// use geomengine::point::Point2D;
+/// use geomengine::shape::Centroid;
+///
+/// // the long segment dominates, unlike the plain vertex mean (2.0, 0.33...)
+/// let polyline = Polyline2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(3.0, 0.0), Point2D::new(3.0, 1.0)]);
+/// assert_eq!(polyline.centroid(), Some(Point2D::new(1.875, 0.125)));
+/// ```
+pub trait Centroid<T: Float + Debug> {
+    fn centroid(&self) -> Option<Point2D<T>>;
+}
+
+/// Mean of `(point, weight)` pairs, or `None` if the weights sum to zero.
+fn weighted_mean<T: Float + Debug>(items: impl Iterator<Item = (Point2D<T>, T)>) -> Option<Point2D<T>> {
+    let (sum, total) = items.fold((Point2D::origin(), T::zero()), |(sum, total), (point, weight)| {
+        (sum + point * weight, total + weight)
+    });
+    (total > T::zero()).then(|| sum / total)
+}
+
+fn vertex_mean<T: Float + Debug>(points: &[Point2D<T>]) -> Option<Point2D<T>> {
+    (!points.is_empty()).then(|| centroid_2d(points))
+}
+
+/// Centroid of a ring's area, `None` if the area is zero.
+fn ring_centroid<T: Float + Debug>(vertices: &[Point2D<T>]) -> Option<Point2D<T>> {
+    let area = ring::signed_area(vertices);
+    if area == T::zero() {
+        return None;
+    }
+    // shift to the first vertex so large coordinates do not cancel
+    let origin = vertices[0];
+    let mut sum = Point2D::origin();
+    for i in 0..vertices.len() {
+        let (a, b) = (vertices[i] - origin, vertices[(i + 1) % vertices.len()] - origin);
+        sum = sum + (a + b) * a.cross_product(&b);
+    }
+    let six = T::from(6.0).unwrap();
+    Some(origin + sum / (six * area))
+}
+
+impl<T: Float + Debug> Centroid<T> for Point2D<T> {
+    fn centroid(&self) -> Option<Point2D<T>> {
+        Some(*self)
+    }
+}
+
+impl<T: Float + Debug> Centroid<T> for Vec<Point2D<T>> {
+    fn centroid(&self) -> Option<Point2D<T>> {
+        vertex_mean(self)
+    }
+}
+
+impl<T: Float + Debug> Centroid<T> for Line2D<T> {
+    fn centroid(&self) -> Option<Point2D<T>> {
+        Some(self.midpoint())
+    }
+}
+
+impl<T: Float + Debug> Centroid<T> for Polyline2D<T> {
+    fn centroid(&self) -> Option<Point2D<T>> {
+        weighted_mean(self.edges().map(|segment| (segment.midpoint(), segment.length())))
+            .or_else(|| vertex_mean(self.vertices()))
+    }
+}
+
+impl<T: Float + Debug> Centroid<T> for Polygon2D<T> {
+    fn centroid(&self) -> Option<Point2D<T>> {
+        ring_centroid(self.vertices())
+            .or_else(|| weighted_mean(self.edges().map(|edge| (edge.midpoint(), edge.length()))))
+            .or_else(|| vertex_mean(self.vertices()))
+    }
+}
+
+impl<T: Float + Debug> Centroid<T> for PolygonWithHoles<T> {
+    fn centroid(&self) -> Option<Point2D<T>> {
+        // the holes as negative weights, whichever way their rings run
+        let parts = core::iter::once((self.exterior(), T::one()))
+            .chain(self.holes().iter().map(|hole| (hole, -T::one())))
+            .filter_map(|(polygon, sign)| ring_centroid(polygon.vertices()).map(|c| (c, sign * polygon.signed_area().abs())));
+        weighted_mean(parts).or_else(|| self.exterior().centroid())
+    }
+}
+
+impl<T: Float + Debug> Centroid<T> for Triangle2D<T> {
+    fn centroid(&self) -> Option<Point2D<T>> {
+        if self.signed_area() != T::zero() {
+            return Some(Triangle2D::centroid(self));
+        }
+        Centroid::centroid(&Polygon2D::from(*self))
+    }
+}
+
+impl<T: Float + Debug> Centroid<T> for Circle2D<T> {
+    fn centroid(&self) -> Option<Point2D<T>> {
+        Some(self.center)
+    }
+}
+
+impl<T: Float + Debug> Centroid<T> for Aabb2D<T> {
+    fn centroid(&self) -> Option<Point2D<T>> {
+        Some(self.center())
+    }
+}
+
+impl<T: Float + Debug> Centroid<T> for Geometry2D<T> {
+    fn centroid(&self) -> Option<Point2D<T>> {
+        match self {
+            Geometry2D::Point(point) => point.centroid(),
+            Geometry2D::Line(line) => line.centroid(),
+            Geometry2D::Polyline(polyline) => polyline.centroid(),
+            Geometry2D::Polygon(polygon) => polygon.centroid(),
+            Geometry2D::Circle(circle) => circle.centroid(),
+            Geometry2D::MultiPoint(points) => points.centroid(),
+            Geometry2D::MultiPolyline(polylines) => {
+                weighted_mean(polylines.iter().filter_map(|p| Some((p.centroid()?, p.length()))))
+                    .or_else(|| vertex_mean(&polylines.iter().flat_map(|p| p.vertices().iter().copied()).collect::<Vec<_>>()))
+            }
+            Geometry2D::MultiPolygon(polygons) => {
+                weighted_mean(polygons.iter().filter_map(|p| Some((p.centroid()?, p.area()))))
+                    .or_else(|| weighted_mean(polygons.iter().filter_map(|p| Some((p.centroid()?, p.perimeter())))))
+                    .or_else(|| vertex_mean(&polygons.iter().flat_map(|p| p.vertices().iter().copied()).collect::<Vec<_>>()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn rectangle(x: f64, y: f64, w: f64, h: f64) -> Polygon2D<f64> {
+        Polygon2D::new(vec![Point2D::new(x, y), Point2D::new(x + w, y), Point2D::new(x + w, y + h), Point2D::new(x, y + h)])
+    }
+
+    #[test]
+    fn test_polygon_centroid_is_area_weighted() {
+        // an L shape: the vertex mean is pulled towards the many corner vertices
+        let l_shape = Polygon2D::new(vec![
+            Point2D::new(0.0, 0.0),
+            Point2D::new(4.0, 0.0),
+            Point2D::new(4.0, 1.0),
+            Point2D::new(1.0, 1.0),
+            Point2D::new(1.0, 4.0),
+            Point2D::new(0.0, 4.0),
+        ]);
+        let c = l_shape.centroid().unwrap();
+        assert_relative_eq!(c.x, 19.0 / 14.0, epsilon = 1e-12);
+        assert_relative_eq!(c.y, 19.0 / 14.0, epsilon = 1e-12);
+
+        let far = rectangle(1e7, 1e7, 2.0, 2.0).centroid().unwrap();
+        assert_eq!(far, Point2D::new(1e7 + 1.0, 1e7 + 1.0));
+    }
+
+    #[test]
+    fn test_degenerate_centroids_fall_back() {
+        let flat = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(4.0, 0.0), Point2D::new(1.0, 0.0)]);
+        assert_eq!(flat.centroid(), Some(Point2D::new(2.0, 0.0)));
+        let stacked = Polyline2D::new(vec![Point2D::new(1.0, 1.0), Point2D::new(1.0, 1.0)]);
+        assert_eq!(stacked.centroid(), Some(Point2D::new(1.0, 1.0)));
+        assert_eq!(Polygon2D::<f64>::new(vec![]).centroid(), None);
+    }
+
+    #[test]
+    fn test_multi_geometry_centroid() {
+        let polygons = Geometry2D::from(vec![rectangle(0.0, 0.0, 1.0, 1.0), rectangle(4.0, 0.0, 3.0, 1.0)]);
+        assert_eq!(polygons.centroid(), Some(Point2D::new(4.25, 0.5)));
+
+        let points = Geometry2D::from(vec![Point2D::new(0.0, 0.0), Point2D::new(2.0, 4.0)]);
+        assert_eq!(points.centroid(), Some(Point2D::new(1.0, 2.0)));
+        assert_eq!(Geometry2D::<f64>::MultiPolyline(vec![]).centroid(), None);
+    }
+}
@@ -0,0 +1,128 @@
+use num::{Num, Float};
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::line::Line2D;
+use alloc::vec::Vec;
+use alloc::vec;
+
+/// A circle (disk) given by its center and radius.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Circle2D<T: Num + Copy + Debug> {
+    pub center: Point2D<T>,
+    pub radius: T,
+}
+
+impl<T: Num + Copy + Debug> Circle2D<T> {
+    pub fn new(center: Point2D<T>, radius: T) -> Self {
+        Circle2D { center, radius }
+    }
+}
+
+impl<T: Float + Debug> Circle2D<T> {
+    pub fn area(&self) -> T {
+        T::from(core::f64::consts::PI).unwrap() * self.radius * self.radius
+    }
+    pub fn circumference(&self) -> T {
+        T::from(core::f64::consts::TAU).unwrap() * self.radius
+    }
+    /// Returns true if the point lies in the disk, boundary included.
+    pub fn contains_point(&self, point: &Point2D<T>) -> bool {
+        self.center.distance(point) <= self.radius
+    }
+    /// Points where the segment `line` crosses or touches the circle, ordered
+    /// from `line.p1` towards `line.p2`.
+    ///
+    /// # Returns
+    /// * No points if the segment stays inside or outside, one if it ends
+    ///   inside or is tangent, and two if it passes through.
+    ///
+    /// # Example
+    /// ```
+    /// use geomengine::line::Line2D;
+    /// use geomengine::point::Point2D;
+    /// use geomengine::shape::Circle2D;
+    ///
+    /// let circle = Circle2D::new(Point2D::new(0.0, 0.0), 5.0);
+    /// let line = Line2D::new(Point2D::new(-10.0, 3.0), Point2D::new(10.0, 3.0));
+    /// assert_eq!(circle.line_intersections(&line), vec![Point2D::new(-4.0, 3.0), Point2D::new(4.0, 3.0)]);
+    /// ```
+    pub fn line_intersections(&self, line: &Line2D<T>) -> Vec<Point2D<T>> {
+        // solve |p1 + t d - center|² = r² for t in [0, 1]
+        let d = line.p2 - line.p1;
+        let f = line.p1 - self.center;
+        let a = d.dot_product(&d);
+        if a == T::zero() {
+            return Vec::new();
+        }
+        let half_b = f.dot_product(&d);
+        let c = f.dot_product(&f) - self.radius * self.radius;
+        let discriminant = half_b * half_b - a * c;
+        if discriminant < T::zero() {
+            return Vec::new();
+        }
+        let root = discriminant.sqrt();
+        let ts = if root == T::zero() { vec![-half_b / a] } else { vec![(-half_b - root) / a, (-half_b + root) / a] };
+        ts.into_iter()
+            .filter(|t| (T::zero()..=T::one()).contains(t))
+            .map(|t| line.p1 + d * t)
+            .collect()
+    }
+    /// Points where the boundaries of `self` and `other` meet, the one right of
+    /// the line from `self.center` to `other.center` first.
+    ///
+    /// # Returns
+    /// * Two points for crossing circles, one if they touch, and none if they
+    ///   are apart, one lies inside the other, or they coincide.
+    pub fn circle_intersections(&self, other: &Circle2D<T>) -> Vec<Point2D<T>> {
+        let offset = other.center - self.center;
+        let distance = offset.dot_product(&offset).sqrt();
+        if distance == T::zero() || distance > self.radius + other.radius || distance < (self.radius - other.radius).abs() {
+            return Vec::new();
+        }
+        // along the center line to the chord, then half the chord either way
+        let along = (distance * distance + self.radius * self.radius - other.radius * other.radius) / (distance + distance);
+        let half_chord = (self.radius * self.radius - along * along).max(T::zero()).sqrt();
+        let unit = offset / distance;
+        let foot = self.center + unit * along;
+        if half_chord == T::zero() {
+            return vec![foot];
+        }
+        let normal = unit.perpendicular() * half_chord;
+        vec![foot - normal, foot + normal]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_circle2d_measures_and_line_intersections() {
+        let circle = Circle2D::new(Point2D::new(1.0, 1.0), 2.0);
+        assert_relative_eq!(circle.area(), 4.0 * core::f64::consts::PI);
+        assert_relative_eq!(circle.circumference(), 4.0 * core::f64::consts::PI);
+        assert!(circle.contains_point(&Point2D::new(3.0, 1.0)));
+        assert!(!circle.contains_point(&Point2D::new(3.0, 1.1)));
+
+        // reversed segment, reversed order; ending inside gives one point
+        let through = Line2D::new(Point2D::new(5.0, 1.0), Point2D::new(-5.0, 1.0));
+        assert_eq!(circle.line_intersections(&through), vec![Point2D::new(3.0, 1.0), Point2D::new(-1.0, 1.0)]);
+        assert_eq!(circle.line_intersections(&Line2D::new(Point2D::new(1.0, 1.0), Point2D::new(1.0, 9.0))), vec![Point2D::new(1.0, 3.0)]);
+        let tangent = Line2D::new(Point2D::new(-5.0, 3.0), Point2D::new(5.0, 3.0));
+        assert_eq!(circle.line_intersections(&tangent), vec![Point2D::new(1.0, 3.0)]);
+        assert!(circle.line_intersections(&Line2D::new(Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0))).is_empty());
+    }
+
+    #[test]
+    fn test_circle2d_circle_intersections() {
+        let a = Circle2D::new(Point2D::new(0.0, 0.0), 5.0);
+        let b = Circle2D::new(Point2D::new(8.0, 0.0), 5.0);
+        assert_eq!(a.circle_intersections(&b), vec![Point2D::new(4.0, -3.0), Point2D::new(4.0, 3.0)]);
+        let touching = Circle2D::new(Point2D::new(0.0, 7.0), 2.0);
+        assert_eq!(a.circle_intersections(&touching), vec![Point2D::new(0.0, 5.0)]);
+        assert!(a.circle_intersections(&Circle2D::new(Point2D::new(1.0, 0.0), 1.0)).is_empty());
+        assert!(a.circle_intersections(&a).is_empty());
+        assert!(a.circle_intersections(&Circle2D::new(Point2D::new(20.0, 0.0), 1.0)).is_empty());
+    }
+}
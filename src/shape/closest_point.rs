@@ -0,0 +1,126 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::line::{InfiniteLine2D, Line2D, Polyline2D};
+use crate::polygon::Polygon2D;
+use super::{Aabb2D, Circle2D, Contains};
+
+/// The point of a shape nearest to a query point.
+///
+/// Closed shapes (polygons, circles, boxes) are regions: a query point inside
+/// them is its own closest point, otherwise the result lies on the boundary.
+/// `None` for an empty polyline or polygon.
+///
+/// # Example
+/// ```
+/// use geomengine::point::Point2D;
+/// use geomengine::shape::{Circle2D, ClosestPoint};
+///
+/// let circle = Circle2D::new(Point2D::new(0.0, 0.0), 2.0);
+/// assert_eq!(circle.closest_point(&Point2D::new(0.0, 5.0)), Some(Point2D::new(0.0, 2.0)));
+/// ```
+pub trait ClosestPoint<T: Float + Debug> {
+    fn closest_point(&self, point: &Point2D<T>) -> Option<Point2D<T>>;
+}
+
+/// The candidate nearest to `point`.
+fn nearest<T: Float + Debug>(point: &Point2D<T>, candidates: impl Iterator<Item = Point2D<T>>) -> Option<Point2D<T>> {
+    candidates
+        .map(|candidate| (candidate.distance(point), candidate))
+        .fold(None, |best: Option<(T, Point2D<T>)>, current| match best {
+            Some(best) if best.0 <= current.0 => Some(best),
+            _ => Some(current),
+        })
+        .map(|(_, candidate)| candidate)
+}
+
+impl<T: Float + Debug> ClosestPoint<T> for Point2D<T> {
+    fn closest_point(&self, _: &Point2D<T>) -> Option<Point2D<T>> {
+        Some(*self)
+    }
+}
+
+impl<T: Float + Debug> ClosestPoint<T> for Line2D<T> {
+    fn closest_point(&self, point: &Point2D<T>) -> Option<Point2D<T>> {
+        Some(self.closest_point_to(point))
+    }
+}
+
+impl<T: Float + Debug> ClosestPoint<T> for InfiniteLine2D<T> {
+    fn closest_point(&self, point: &Point2D<T>) -> Option<Point2D<T>> {
+        Some(self.project(point))
+    }
+}
+
+impl<T: Float + Debug> ClosestPoint<T> for Polyline2D<T> {
+    fn closest_point(&self, point: &Point2D<T>) -> Option<Point2D<T>> {
+        match self.vertices() {
+            [single] => Some(*single),
+            _ => nearest(point, self.edges().map(|segment| segment.closest_point_to(point))),
+        }
+    }
+}
+
+impl<T: Float + Debug> ClosestPoint<T> for Polygon2D<T> {
+    fn closest_point(&self, point: &Point2D<T>) -> Option<Point2D<T>> {
+        match self.vertices() {
+            [single] => Some(*single),
+            _ if self.contains(point) => Some(*point),
+            _ => nearest(point, self.edges().map(|edge| edge.closest_point_to(point))),
+        }
+    }
+}
+
+impl<T: Float + Debug> ClosestPoint<T> for Circle2D<T> {
+    fn closest_point(&self, point: &Point2D<T>) -> Option<Point2D<T>> {
+        let d = self.center.distance(point);
+        if d <= self.radius {
+            return Some(*point);
+        }
+        Some(self.center + (*point - self.center) * (self.radius / d))
+    }
+}
+
+impl<T: Float + Debug> ClosestPoint<T> for Aabb2D<T> {
+    fn closest_point(&self, point: &Point2D<T>) -> Option<Point2D<T>> {
+        Some(Point2D::new(
+            point.x.max(self.min.x).min(self.max.x),
+            point.y.max(self.min.y).min(self.max.y),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_closest_point_on_curves() {
+        let segment = Line2D::new(Point2D::new(0.0, 0.0), Point2D::new(4.0, 0.0));
+        assert_eq!(segment.closest_point(&Point2D::new(1.0, 3.0)), Some(Point2D::new(1.0, 0.0)));
+        assert_eq!(segment.closest_point(&Point2D::new(9.0, 1.0)), Some(Point2D::new(4.0, 0.0)));
+
+        let polyline = Polyline2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(4.0, 0.0), Point2D::new(4.0, 4.0)]);
+        assert_eq!(polyline.closest_point(&Point2D::new(3.0, 2.0)), Some(Point2D::new(4.0, 2.0)));
+        assert_eq!(Polyline2D::<f64>::new(vec![]).closest_point(&Point2D::new(0.0, 0.0)), None);
+    }
+
+    #[test]
+    fn test_closest_point_on_regions() {
+        let square = Polygon2D::new(vec![
+            Point2D::new(0.0, 0.0),
+            Point2D::new(2.0, 0.0),
+            Point2D::new(2.0, 2.0),
+            Point2D::new(0.0, 2.0),
+        ]);
+        assert_eq!(square.closest_point(&Point2D::new(1.0, 1.0)), Some(Point2D::new(1.0, 1.0)));
+        assert_eq!(square.closest_point(&Point2D::new(5.0, 1.0)), Some(Point2D::new(2.0, 1.0)));
+
+        let aabb = Aabb2D::new(Point2D::new(0.0, 0.0), Point2D::new(2.0, 2.0));
+        assert_eq!(aabb.closest_point(&Point2D::new(-1.0, 3.0)), Some(Point2D::new(0.0, 2.0)));
+
+        let circle = Circle2D::new(Point2D::new(1.0, 1.0), 1.0);
+        assert_eq!(circle.closest_point(&Point2D::new(1.0, 1.5)), Some(Point2D::new(1.0, 1.5)));
+        assert_eq!(circle.closest_point(&Point2D::new(4.0, 1.0)), Some(Point2D::new(2.0, 1.0)));
+    }
+}
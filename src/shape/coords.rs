@@ -0,0 +1,141 @@
+use num::Num;
+use core::fmt::Debug;
+use core::iter;
+use crate::point::Point2D;
+use crate::line::{Line2D, Polyline2D};
+use crate::polygon::Polygon2D;
+use crate::algorithms::overlay::OverlayPiece;
+use super::{Aabb2D, Circle2D, Geometry2D};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+/// Access to the coordinates of a geometry, in storage order.
+///
+/// Circles yield their center and boxes their `min` and `max` corners. For
+/// shapes with holes ([`OverlayPiece`]), [`exterior_coords_iter`](Self::exterior_coords_iter)
+/// skips the holes; for every other shape it yields the same coordinates as
+/// [`coords_iter`](Self::coords_iter).
+///
+/// # Example
+/// ```
+/// use geomengine::point::Point2D;
+/// use geomengine::shape::{CoordsIter, Geometry2D};
+///
+/// let shapes: Vec<Geometry2D<i32>> = vec![Point2D::new(1, 2).into(), vec![Point2D::new(3, 4), Point2D::new(5, 6)].into()];
+/// let xs: Vec<i32> = shapes.iter().flat_map(|g| g.coords_iter()).map(|p| p.x).collect();
+/// assert_eq!(xs, [1, 3, 5]);
+/// ```
+pub trait CoordsIter<T: Num + Copy + Debug> {
+    fn coords_iter(&self) -> impl Iterator<Item = Point2D<T>> + '_;
+
+    fn exterior_coords_iter(&self) -> impl Iterator<Item = Point2D<T>> + '_ {
+        self.coords_iter()
+    }
+
+    /// Calls `f` on every coordinate, stopping at the first error.
+    fn try_for_each_coord<E>(&self, f: impl FnMut(Point2D<T>) -> Result<(), E>) -> Result<(), E> {
+        self.coords_iter().try_for_each(f)
+    }
+}
+
+impl<T: Num + Copy + Debug> CoordsIter<T> for Point2D<T> {
+    fn coords_iter(&self) -> impl Iterator<Item = Point2D<T>> + '_ {
+        iter::once(*self)
+    }
+}
+
+impl<T: Num + Copy + Debug> CoordsIter<T> for Vec<Point2D<T>> {
+    fn coords_iter(&self) -> impl Iterator<Item = Point2D<T>> + '_ {
+        self.iter().copied()
+    }
+}
+
+impl<T: Num + Copy + Debug> CoordsIter<T> for Line2D<T> {
+    fn coords_iter(&self) -> impl Iterator<Item = Point2D<T>> + '_ {
+        [self.p1, self.p2].into_iter()
+    }
+}
+
+impl<T: Num + Copy + Debug> CoordsIter<T> for Polyline2D<T> {
+    fn coords_iter(&self) -> impl Iterator<Item = Point2D<T>> + '_ {
+        self.vertices().iter().copied()
+    }
+}
+
+impl<T: Num + Copy + Debug> CoordsIter<T> for Polygon2D<T> {
+    fn coords_iter(&self) -> impl Iterator<Item = Point2D<T>> + '_ {
+        self.vertices().iter().copied()
+    }
+}
+
+impl<T: Num + Copy + Debug> CoordsIter<T> for Circle2D<T> {
+    fn coords_iter(&self) -> impl Iterator<Item = Point2D<T>> + '_ {
+        iter::once(self.center)
+    }
+}
+
+impl<T: Num + Copy + Debug> CoordsIter<T> for Aabb2D<T> {
+    fn coords_iter(&self) -> impl Iterator<Item = Point2D<T>> + '_ {
+        [self.min, self.max].into_iter()
+    }
+}
+
+impl<T: Num + Copy + Debug> CoordsIter<T> for OverlayPiece<T> {
+    fn coords_iter(&self) -> impl Iterator<Item = Point2D<T>> + '_ {
+        self.exterior.coords_iter().chain(self.holes.iter().flat_map(|hole| hole.coords_iter()))
+    }
+
+    fn exterior_coords_iter(&self) -> impl Iterator<Item = Point2D<T>> + '_ {
+        self.exterior.coords_iter()
+    }
+}
+
+impl<T: Num + Copy + Debug> CoordsIter<T> for Geometry2D<T> {
+    fn coords_iter(&self) -> impl Iterator<Item = Point2D<T>> + '_ {
+        let coords: Box<dyn Iterator<Item = Point2D<T>> + '_> = match self {
+            Geometry2D::Point(point) => Box::new(point.coords_iter()),
+            Geometry2D::Line(line) => Box::new(line.coords_iter()),
+            Geometry2D::Polyline(polyline) => Box::new(polyline.coords_iter()),
+            Geometry2D::Polygon(polygon) => Box::new(polygon.coords_iter()),
+            Geometry2D::Circle(circle) => Box::new(circle.coords_iter()),
+            Geometry2D::MultiPoint(points) => Box::new(points.coords_iter()),
+            Geometry2D::MultiPolyline(polylines) => Box::new(polylines.iter().flat_map(|p| p.coords_iter())),
+            Geometry2D::MultiPolygon(polygons) => Box::new(polygons.iter().flat_map(|p| p.coords_iter())),
+        };
+        coords
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coords_iter_per_shape() {
+        let line = Line2D::new(Point2D::new(0.0, 1.0), Point2D::new(2.0, 3.0));
+        assert_eq!(line.coords_iter().collect::<Vec<_>>(), [line.p1, line.p2]);
+
+        let polygon = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(1.0, 0.0), Point2D::new(0.0, 1.0)]);
+        assert_eq!(polygon.coords_iter().count(), 3);
+        assert_eq!(Circle2D::new(Point2D::new(5.0, 5.0), 1.0).coords_iter().collect::<Vec<_>>(), [Point2D::new(5.0, 5.0)]);
+
+        let piece = OverlayPiece { exterior: polygon.clone(), holes: vec![polygon.clone()], a: None, b: None };
+        assert_eq!(piece.coords_iter().count(), 6);
+        assert_eq!(piece.exterior_coords_iter().count(), 3);
+    }
+
+    #[test]
+    fn test_try_for_each_coord_stops_at_first_error() {
+        let geometry = Geometry2D::from(vec![
+            Polyline2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(1.0, f64::NAN)]),
+            Polyline2D::new(vec![Point2D::new(2.0, 2.0)]),
+        ]);
+        let mut visited = 0;
+        let result = geometry.try_for_each_coord(|p| {
+            visited += 1;
+            if p.y.is_nan() { Err(visited) } else { Ok(()) }
+        });
+        assert_eq!(result, Err(2));
+        assert_eq!(geometry.exterior_coords_iter().count(), 3);
+    }
+}
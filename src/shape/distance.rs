@@ -0,0 +1,258 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::line::{InfiniteLine2D, Line2D, Polyline2D};
+use crate::polygon::Polygon2D;
+use super::{Aabb2D, Circle2D, Contains, Intersects};
+
+/// Minimum Euclidean distance between two shapes.
+///
+/// Closed shapes (polygons, circles, boxes) are regions, so the distance is zero
+/// for anything touching or inside them. Distances to an empty polyline or polygon
+/// are infinite. The relation is symmetric.
+///
+/// Named `distance_to` so it does not clash with [`Point2D::distance`].
+///
+/// # Example
+/// ```
+/// use geomengine::line::Line2D;
+/// use geomengine::point::Point2D;
+/// use geomengine::shape::{Circle2D, Distance};
+///
+/// let segment = Line2D::new(Point2D::new(0.0, 0.0), Point2D::new(4.0, 0.0));
+/// let circle = Circle2D::new(Point2D::new(2.0, 5.0), 1.0);
+/// assert_eq!(segment.distance_to(&circle), 4.0);
+/// ```
+pub trait Distance<T, Rhs: ?Sized = Self> {
+    fn distance_to(&self, other: &Rhs) -> T;
+}
+
+/// Smallest of `distances`, infinite if there are none.
+fn min_distance<T: Float + Debug>(distances: impl Iterator<Item = T>) -> T {
+    distances.fold(T::infinity(), T::min)
+}
+
+/// Implements `Distance<$b> for $a` by swapping the arguments of `$b: Distance<$a>`.
+macro_rules! impl_swapped {
+    ($(($a:ident, $b:ident)),*) => {
+        $(
+            impl<T: Float + Debug> Distance<T, $b<T>> for $a<T> {
+                fn distance_to(&self, other: &$b<T>) -> T {
+                    other.distance_to(self)
+                }
+            }
+        )*
+    };
+}
+
+// Point2D
+
+impl<T: Float + Debug> Distance<T> for Point2D<T> {
+    fn distance_to(&self, other: &Point2D<T>) -> T {
+        self.distance(other)
+    }
+}
+impl<T: Float + Debug> Distance<T, Point2D<T>> for Line2D<T> {
+    fn distance_to(&self, point: &Point2D<T>) -> T {
+        self.closest_point_to(point).distance(point)
+    }
+}
+impl<T: Float + Debug> Distance<T, Point2D<T>> for Polyline2D<T> {
+    fn distance_to(&self, point: &Point2D<T>) -> T {
+        match self.vertices() {
+            [single] => single.distance(point),
+            _ => min_distance(self.edges().map(|segment| segment.distance_to(point))),
+        }
+    }
+}
+impl<T: Float + Debug> Distance<T, Point2D<T>> for Polygon2D<T> {
+    fn distance_to(&self, point: &Point2D<T>) -> T {
+        match self.vertices() {
+            [single] => single.distance(point),
+            _ if self.contains(point) => T::zero(),
+            _ => min_distance(self.edges().map(|edge| edge.distance_to(point))),
+        }
+    }
+}
+impl<T: Float + Debug> Distance<T, Point2D<T>> for Circle2D<T> {
+    fn distance_to(&self, point: &Point2D<T>) -> T {
+        (self.center.distance(point) - self.radius).max(T::zero())
+    }
+}
+impl<T: Float + Debug> Distance<T, Point2D<T>> for Aabb2D<T> {
+    fn distance_to(&self, point: &Point2D<T>) -> T {
+        let dx = (self.min.x - point.x).max(point.x - self.max.x).max(T::zero());
+        let dy = (self.min.y - point.y).max(point.y - self.max.y).max(T::zero());
+        dx.hypot(dy)
+    }
+}
+impl<T: Float + Debug> Distance<T, Point2D<T>> for InfiniteLine2D<T> {
+    fn distance_to(&self, point: &Point2D<T>) -> T {
+        self.signed_distance(point).abs()
+    }
+}
+impl_swapped!((Point2D, Line2D), (Point2D, Polyline2D), (Point2D, Polygon2D), (Point2D, Circle2D), (Point2D, Aabb2D), (Point2D, InfiniteLine2D));
+
+// Line2D
+
+impl<T: Float + Debug> Distance<T> for Line2D<T> {
+    fn distance_to(&self, other: &Line2D<T>) -> T {
+        if self.intersects(other) {
+            return T::zero();
+        }
+        // without a crossing, the closest pair involves an endpoint
+        self.distance_to(&other.p1)
+            .min(self.distance_to(&other.p2))
+            .min(other.distance_to(&self.p1))
+            .min(other.distance_to(&self.p2))
+    }
+}
+impl<T: Float + Debug> Distance<T, Polygon2D<T>> for Line2D<T> {
+    fn distance_to(&self, polygon: &Polygon2D<T>) -> T {
+        match polygon.vertices() {
+            [single] => self.distance_to(single),
+            _ if self.intersects(polygon) => T::zero(),
+            _ => min_distance(polygon.edges().map(|edge| self.distance_to(&edge))),
+        }
+    }
+}
+impl<T: Float + Debug> Distance<T, Circle2D<T>> for Line2D<T> {
+    fn distance_to(&self, circle: &Circle2D<T>) -> T {
+        (self.distance_to(&circle.center) - circle.radius).max(T::zero())
+    }
+}
+impl<T: Float + Debug> Distance<T, Aabb2D<T>> for Line2D<T> {
+    fn distance_to(&self, aabb: &Aabb2D<T>) -> T {
+        if self.intersects(aabb) {
+            return T::zero();
+        }
+        min_distance(aabb.edges().iter().map(|edge| self.distance_to(edge)))
+    }
+}
+
+// Polyline2D: a single vertex behaves like a point, otherwise the closest segment counts
+
+macro_rules! impl_polyline_distance {
+    ($($shape:ident),*) => {
+        $(
+            impl<T: Float + Debug> Distance<T, $shape<T>> for Polyline2D<T> {
+                fn distance_to(&self, other: &$shape<T>) -> T {
+                    match self.vertices() {
+                        [single] => single.distance_to(other),
+                        _ => min_distance(self.edges().map(|segment| segment.distance_to(other))),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_polyline_distance!(Line2D, Polyline2D, Polygon2D, Circle2D, Aabb2D);
+impl_swapped!((Line2D, Polyline2D));
+
+// Polygon2D
+
+impl<T: Float + Debug> Distance<T> for Polygon2D<T> {
+    fn distance_to(&self, other: &Polygon2D<T>) -> T {
+        match self.vertices() {
+            [single] => other.distance_to(single),
+            _ if self.intersects(other) => T::zero(),
+            _ => min_distance(self.edges().map(|edge| edge.distance_to(other))),
+        }
+    }
+}
+impl<T: Float + Debug> Distance<T, Circle2D<T>> for Polygon2D<T> {
+    fn distance_to(&self, circle: &Circle2D<T>) -> T {
+        (self.distance_to(&circle.center) - circle.radius).max(T::zero())
+    }
+}
+impl<T: Float + Debug> Distance<T, Aabb2D<T>> for Polygon2D<T> {
+    fn distance_to(&self, aabb: &Aabb2D<T>) -> T {
+        match self.vertices() {
+            [single] => aabb.distance_to(single),
+            _ if self.intersects(aabb) => T::zero(),
+            _ => min_distance(self.edges().map(|edge| edge.distance_to(aabb))),
+        }
+    }
+}
+impl_swapped!((Polygon2D, Line2D), (Polygon2D, Polyline2D));
+
+// Circle2D
+
+impl<T: Float + Debug> Distance<T> for Circle2D<T> {
+    fn distance_to(&self, other: &Circle2D<T>) -> T {
+        (self.center.distance(&other.center) - self.radius - other.radius).max(T::zero())
+    }
+}
+impl<T: Float + Debug> Distance<T, Aabb2D<T>> for Circle2D<T> {
+    fn distance_to(&self, aabb: &Aabb2D<T>) -> T {
+        (aabb.distance_to(&self.center) - self.radius).max(T::zero())
+    }
+}
+impl_swapped!((Circle2D, Line2D), (Circle2D, Polyline2D), (Circle2D, Polygon2D));
+
+// Aabb2D
+
+impl<T: Float + Debug> Distance<T> for Aabb2D<T> {
+    fn distance_to(&self, other: &Aabb2D<T>) -> T {
+        let dx = (self.min.x - other.max.x).max(other.min.x - self.max.x).max(T::zero());
+        let dy = (self.min.y - other.max.y).max(other.min.y - self.max.y).max(T::zero());
+        dx.hypot(dy)
+    }
+}
+impl_swapped!((Aabb2D, Line2D), (Aabb2D, Polyline2D), (Aabb2D, Polygon2D), (Aabb2D, Circle2D));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(x1: f64, y1: f64, x2: f64, y2: f64) -> Line2D<f64> {
+        Line2D::new(Point2D::new(x1, y1), Point2D::new(x2, y2))
+    }
+
+    fn square(min: f64, max: f64) -> Polygon2D<f64> {
+        Polygon2D::new(vec![
+            Point2D::new(min, min),
+            Point2D::new(max, min),
+            Point2D::new(max, max),
+            Point2D::new(min, max),
+        ])
+    }
+
+    #[test]
+    fn test_point_distances() {
+        let p = Point2D::new(3.0, 4.0);
+        assert_eq!(p.distance_to(&Point2D::new(0.0, 0.0)), 5.0);
+        assert_eq!(p.distance_to(&line(0.0, 0.0, 10.0, 0.0)), 4.0);
+        assert_eq!(p.distance_to(&line(-5.0, 4.0, 0.0, 4.0)), 3.0);
+        assert_eq!(p.distance_to(&square(0.0, 10.0)), 0.0);
+        assert_eq!(square(0.0, 1.0).distance_to(&p), 2f64.hypot(3.0));
+        assert_eq!(Circle2D::new(Point2D::new(0.0, 0.0), 2.0).distance_to(&p), 3.0);
+        assert_eq!(Aabb2D::new(Point2D::new(0.0, 0.0), Point2D::new(3.0, 1.0)).distance_to(&p), 3.0);
+        assert_eq!(Polygon2D::new(vec![]).distance_to(&p), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_segment_and_polygon_distances() {
+        assert_eq!(line(0.0, 0.0, 2.0, 2.0).distance_to(&line(0.0, 2.0, 2.0, 0.0)), 0.0);
+        assert_eq!(line(0.0, 0.0, 4.0, 0.0).distance_to(&line(1.0, 1.0, 3.0, 2.0)), 1.0);
+        assert_eq!(line(5.0, 0.0, 5.0, 9.0).distance_to(&square(0.0, 2.0)), 3.0);
+        assert_eq!(square(0.0, 1.0).distance_to(&square(4.0, 5.0)), 3f64.hypot(3.0));
+        assert_eq!(square(0.0, 10.0).distance_to(&square(4.0, 5.0)), 0.0);
+
+        let polyline = Polyline2D::new(vec![Point2D::new(0.0, 5.0), Point2D::new(5.0, 5.0), Point2D::new(5.0, 0.0)]);
+        assert_eq!(polyline.distance_to(&square(0.0, 2.0)), 3.0);
+        assert_eq!(square(0.0, 2.0).distance_to(&polyline), 3.0);
+    }
+
+    #[test]
+    fn test_circle_and_aabb_distances() {
+        let circle = Circle2D::new(Point2D::new(0.0, 0.0), 1.0);
+        let aabb = Aabb2D::new(Point2D::new(4.0, 5.0), Point2D::new(6.0, 6.0));
+        assert_eq!(circle.distance_to(&aabb), 4f64.hypot(5.0) - 1.0);
+        assert_eq!(aabb.distance_to(&circle), circle.distance_to(&aabb));
+        assert_eq!(circle.distance_to(&Circle2D::new(Point2D::new(5.0, 0.0), 2.0)), 2.0);
+        assert_eq!(aabb.distance_to(&Aabb2D::new(Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0))), 3f64.hypot(4.0));
+        assert_eq!(line(-3.0, 2.0, 3.0, 2.0).distance_to(&circle), 1.0);
+    }
+}
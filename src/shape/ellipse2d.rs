@@ -0,0 +1,116 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::{Point2D, Vector2D};
+use crate::transform::Angle;
+use super::Aabb2D;
+use alloc::vec::Vec;
+
+/// An ellipse (filled) around `center` with semi-axes `radius_x` and
+/// `radius_y`, turned counterclockwise by `rotation`, so `radius_x`
+/// runs along the direction `rotation` and `radius_y` across it.
+///
+/// # Example
+/// ```
+/// use geomengine::point::Point2D;
+/// use geomengine::shape::Ellipse2D;
+/// use geomengine::transform::Angle;
+/// use std::f64::consts::PI;
+///
+/// let ellipse = Ellipse2D::new(Point2D::new(0.0, 0.0), 3.0, 1.0, Angle::from_degrees(90.0));
+/// assert_eq!(ellipse.area(), 3.0 * PI);
+/// assert!(ellipse.contains_point(&Point2D::new(0.0, 2.5)));
+/// assert!(!ellipse.contains_point(&Point2D::new(2.5, 0.0)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ellipse2D<T: Float + Debug> {
+    pub center: Point2D<T>,
+    pub radius_x: T,
+    pub radius_y: T,
+    pub rotation: Angle<T>,
+}
+
+impl<T: Float + Debug> Ellipse2D<T> {
+    pub fn new(center: Point2D<T>, radius_x: T, radius_y: T, rotation: Angle<T>) -> Self {
+        Ellipse2D { center, radius_x, radius_y, rotation }
+    }
+    pub fn area(&self) -> T {
+        T::from(core::f64::consts::PI).unwrap() * self.radius_x * self.radius_y
+    }
+    /// Ramanujan's second approximation of the circumference, exact for
+    /// circles and within 0.4% even for a flat ellipse.
+    pub fn perimeter(&self) -> T {
+        let (a, b) = (self.radius_x, self.radius_y);
+        if a + b == T::zero() {
+            return T::zero();
+        }
+        let three = T::from(3.0).unwrap();
+        let h = ((a - b) / (a + b)).powi(2);
+        T::from(core::f64::consts::PI).unwrap() * (a + b) * (T::one() + three * h / (T::from(10.0).unwrap() + (T::from(4.0).unwrap() - three * h).sqrt()))
+    }
+    /// Returns true if the point lies in the ellipse, boundary included.
+    pub fn contains_point(&self, point: &Point2D<T>) -> bool {
+        let (sin, cos) = self.rotation.sin_cos();
+        let offset = *point - self.center;
+        // the offset in the ellipse's own axes
+        let (x, y) = (offset.x * cos + offset.y * sin, offset.y * cos - offset.x * sin);
+        (x / self.radius_x).powi(2) + (y / self.radius_y).powi(2) <= T::one()
+    }
+    /// Point of the boundary at the parametric angle `t`: `(radius_x cos t, radius_y sin t)`
+    /// in the ellipse's axes. Only for circles is `t` the direction from the center.
+    pub fn point_at(&self, t: T) -> Point2D<T> {
+        let (sin, cos) = self.rotation.sin_cos();
+        let (x, y) = (self.radius_x * t.cos(), self.radius_y * t.sin());
+        self.center + Point2D::new(x * cos - y * sin, x * sin + y * cos)
+    }
+    /// `count` boundary points at evenly spaced parametric angles, counterclockwise
+    /// from the end of the `radius_x` axis.
+    pub fn boundary_points(&self, count: usize) -> Vec<Point2D<T>> {
+        let step = T::from(core::f64::consts::TAU).unwrap() / T::from(count).unwrap();
+        (0..count).map(|i| self.point_at(step * T::from(i).unwrap())).collect()
+    }
+    /// Smallest axis aligned box holding the ellipse.
+    pub fn bounding_box(&self) -> Aabb2D<T> {
+        let (sin, cos) = self.rotation.sin_cos();
+        let (a, b) = (self.radius_x, self.radius_y);
+        let half = Vector2D::new((a * a * cos * cos + b * b * sin * sin).sqrt(), (a * a * sin * sin + b * b * cos * cos).sqrt());
+        Aabb2D::new(self.center - half, self.center + half)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polygon::Polygon2D;
+    use crate::shape::Area;
+    use approx::assert_relative_eq;
+    use core::f64::consts::PI;
+
+    #[test]
+    fn test_ellipse_perimeter() {
+        let circle = Ellipse2D::new(Point2D::new(1.0, 1.0), 2.0, 2.0, Angle::from_radians(0.3));
+        assert_relative_eq!(circle.perimeter(), 4.0 * PI, epsilon = 1e-12);
+        // circumference of the 5 x 3 ellipse to 12 digits
+        assert_relative_eq!(Ellipse2D::new(Point2D::new(0.0, 0.0), 5.0, 3.0, Angle::zero()).perimeter(), 25.526998863398, epsilon = 1e-9);
+        assert_eq!(Ellipse2D::new(Point2D::new(0.0, 0.0), 0.0, 0.0, Angle::zero()).perimeter(), 0.0);
+    }
+
+    #[test]
+    fn test_ellipse_boundary_and_containment() {
+        let ellipse = Ellipse2D::new(Point2D::new(2.0, -1.0), 4.0, 1.0, Angle::from_degrees(45.0));
+        let points = ellipse.boundary_points(400);
+        assert_eq!(points.len(), 400);
+        assert_relative_eq!(Polygon2D::new(points.clone()).area(), ellipse.area(), epsilon = 1e-2);
+        for p in &points {
+            let outward = ellipse.center + (*p - ellipse.center) * 1.01;
+            let inward = ellipse.center + (*p - ellipse.center) * 0.99;
+            assert!(ellipse.contains_point(&inward) && !ellipse.contains_point(&outward));
+        }
+
+        let bounds = ellipse.bounding_box();
+        let half = (8.5f64).sqrt();
+        assert_relative_eq!(bounds.max.x, 2.0 + half, epsilon = 1e-12);
+        assert_relative_eq!(bounds.min.y, -1.0 - half, epsilon = 1e-12);
+        let reach = points.iter().map(|p| p.x).fold(f64::MIN, f64::max);
+        assert!(reach <= bounds.max.x && reach > bounds.max.x - 1e-3);
+    }
+}
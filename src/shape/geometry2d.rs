@@ -0,0 +1,151 @@
+use num::{Num, Float};
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::line::{Line2D, Polyline2D};
+use crate::polygon::Polygon2D;
+use super::{Aabb2D, Area, BoundingBox, Circle2D, Contains, Distance, Perimeter};
+use alloc::vec::Vec;
+
+/// Any supported geometry, for storing mixed shapes in one collection.
+///
+/// The shape traits dispatch to the wrapped shape; `Multi*` variants sum areas and
+/// perimeters, merge bounding boxes, contain a point if any member does and are
+/// as far from a point as their nearest member.
+///
+/// # Example
+/// ```
+/// use geomengine::point::Point2D;
+/// use geomengine::shape::{Area, Circle2D, Geometry2D};
+///
+/// let shapes: Vec<Geometry2D<f64>> = vec![
+///     Point2D::new(1.0, 1.0).into(),
+///     Circle2D::new(Point2D::new(0.0, 0.0), 1.0).into(),
+/// ];
+/// let total: f64 = shapes.iter().map(|g| g.area()).sum();
+/// assert_eq!(total, std::f64::consts::PI);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Geometry2D<T: Num + Copy + Debug> {
+    Point(Point2D<T>),
+    Line(Line2D<T>),
+    Polyline(Polyline2D<T>),
+    Polygon(Polygon2D<T>),
+    Circle(Circle2D<T>),
+    MultiPoint(Vec<Point2D<T>>),
+    MultiPolyline(Vec<Polyline2D<T>>),
+    MultiPolygon(Vec<Polygon2D<T>>),
+}
+
+/// Calls `$f` with the shape wrapped by `$geometry`, or folds `$combine` over the
+/// results of the members of a `Multi*` variant, starting from `$empty`.
+macro_rules! dispatch {
+    ($geometry:expr, $shape:ident => $f:expr, $empty:expr, $combine:expr) => {
+        match $geometry {
+            Geometry2D::Point($shape) => $f,
+            Geometry2D::Line($shape) => $f,
+            Geometry2D::Polyline($shape) => $f,
+            Geometry2D::Polygon($shape) => $f,
+            Geometry2D::Circle($shape) => $f,
+            Geometry2D::MultiPoint(shapes) => shapes.iter().map(|$shape| $f).fold($empty, $combine),
+            Geometry2D::MultiPolyline(shapes) => shapes.iter().map(|$shape| $f).fold($empty, $combine),
+            Geometry2D::MultiPolygon(shapes) => shapes.iter().map(|$shape| $f).fold($empty, $combine),
+        }
+    };
+}
+
+impl<T: Float + Debug> Area<T> for Geometry2D<T> {
+    fn area(&self) -> T {
+        dispatch!(self, shape => shape.area(), T::zero(), |a, b| a + b)
+    }
+}
+
+impl<T: Float + Debug> Perimeter<T> for Geometry2D<T> {
+    fn perimeter(&self) -> T {
+        dispatch!(self, shape => shape.perimeter(), T::zero(), |a, b| a + b)
+    }
+}
+
+impl<T: Float + Debug> Contains<Point2D<T>> for Geometry2D<T> {
+    fn contains(&self, point: &Point2D<T>) -> bool {
+        dispatch!(self, shape => shape.contains(point), false, |a, b| a || b)
+    }
+}
+
+impl<T: Float + Debug> Distance<T, Point2D<T>> for Geometry2D<T> {
+    fn distance_to(&self, point: &Point2D<T>) -> T {
+        dispatch!(self, shape => shape.distance_to(point), T::infinity(), T::min)
+    }
+}
+
+impl<T: Float + Debug> BoundingBox<T> for Geometry2D<T> {
+    fn bounding_box(&self) -> Option<Aabb2D<T>> {
+        dispatch!(self, shape => shape.bounding_box(), None, |a: Option<Aabb2D<T>>, b| match (a, b) {
+            (Some(a), Some(b)) => Some(a.union(&b)),
+            (a, b) => a.or(b),
+        })
+    }
+}
+
+macro_rules! impl_from {
+    ($($variant:ident($shape:ty)),*) => {
+        $(
+            impl<T: Num + Copy + Debug> From<$shape> for Geometry2D<T> {
+                fn from(shape: $shape) -> Self {
+                    Geometry2D::$variant(shape)
+                }
+            }
+        )*
+    };
+}
+
+impl_from!(
+    Point(Point2D<T>),
+    Line(Line2D<T>),
+    Polyline(Polyline2D<T>),
+    Polygon(Polygon2D<T>),
+    Circle(Circle2D<T>),
+    MultiPoint(Vec<Point2D<T>>),
+    MultiPolyline(Vec<Polyline2D<T>>),
+    MultiPolygon(Vec<Polygon2D<T>>)
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(x: f64, size: f64) -> Polygon2D<f64> {
+        Polygon2D::new(vec![
+            Point2D::new(x, 0.0),
+            Point2D::new(x + size, 0.0),
+            Point2D::new(x + size, size),
+            Point2D::new(x, size),
+        ])
+    }
+
+    #[test]
+    fn test_geometry2d_dispatches_to_shape() {
+        let line = Line2D::new(Point2D::new(0.0, 0.0), Point2D::new(3.0, 4.0));
+        let geometry = Geometry2D::from(line);
+        assert_eq!(geometry.perimeter(), line.perimeter());
+        assert_eq!(geometry.bounding_box(), line.bounding_box());
+        assert!(geometry.contains(&Point2D::new(1.5, 2.0)));
+
+        let polygon = Geometry2D::from(square(0.0, 2.0));
+        assert_eq!((polygon.area(), polygon.perimeter()), (4.0, 8.0));
+    }
+
+    #[test]
+    fn test_geometry2d_multi_variants() {
+        let squares = Geometry2D::from(vec![square(0.0, 1.0), square(5.0, 2.0)]);
+        assert_eq!(squares.area(), 5.0);
+        assert_eq!(squares.bounding_box(), Some(Aabb2D::new(Point2D::new(0.0, 0.0), Point2D::new(7.0, 2.0))));
+        assert!(squares.contains(&Point2D::new(6.0, 1.0)));
+        assert!(!squares.contains(&Point2D::new(3.0, 0.5)));
+        assert_eq!(squares.distance_to(&Point2D::new(3.0, 0.5)), 2.0);
+
+        let empty: Geometry2D<f64> = Geometry2D::MultiPoint(vec![]);
+        assert_eq!(empty.bounding_box(), None);
+        assert_eq!(empty.area(), 0.0);
+        assert_eq!(empty.distance_to(&Point2D::new(0.0, 0.0)), f64::INFINITY);
+    }
+}
@@ -0,0 +1,252 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::line::{Line2D, Polyline2D};
+use crate::polygon::{ConvexPolygon2D, Polygon2D};
+use super::{Aabb2D, Circle2D, Contains, Rect2D};
+
+/// Whether two shapes share at least one point, boundaries included.
+///
+/// Shapes are treated as closed regions (polygons, circles, boxes) or as curves
+/// (lines, polylines), so a segment lying inside a polygon intersects it. The
+/// relation is symmetric: `a.intersects(&b) == b.intersects(&a)`.
+///
+/// Segment tests are exact; polygon interiors use the even-odd rule.
+pub trait Intersects<Rhs: ?Sized = Self> {
+    fn intersects(&self, other: &Rhs) -> bool;
+}
+
+/// Sign of the turn `a -> b -> c`: positive for counterclockwise, zero for collinear.
+fn orientation<T: Float + Debug>(a: &Point2D<T>, b: &Point2D<T>, c: &Point2D<T>) -> T {
+    (*b - *a).cross_product(&(*c - *a))
+}
+
+// Point2D against anything: containment, in both directions
+
+macro_rules! impl_point_intersects {
+    ($($shape:ident),*) => {
+        $(
+            impl<T: Float + Debug> Intersects<$shape<T>> for Point2D<T> {
+                fn intersects(&self, other: &$shape<T>) -> bool {
+                    other.contains(self)
+                }
+            }
+            impl<T: Float + Debug> Intersects<Point2D<T>> for $shape<T> {
+                fn intersects(&self, point: &Point2D<T>) -> bool {
+                    self.contains(point)
+                }
+            }
+        )*
+    };
+}
+
+impl_point_intersects!(Line2D, Polyline2D, Polygon2D, Circle2D, Aabb2D);
+
+impl<T: Float + Debug> Intersects for Point2D<T> {
+    fn intersects(&self, other: &Point2D<T>) -> bool {
+        self == other
+    }
+}
+
+/// Implements `Intersects<$b> for $a` by swapping the arguments of `$b: Intersects<$a>`.
+macro_rules! impl_swapped {
+    ($(($a:ident, $b:ident)),*) => {
+        $(
+            impl<T: Float + Debug> Intersects<$b<T>> for $a<T> {
+                fn intersects(&self, other: &$b<T>) -> bool {
+                    other.intersects(self)
+                }
+            }
+        )*
+    };
+}
+
+// Line2D
+
+impl<T: Float + Debug> Intersects for Line2D<T> {
+    fn intersects(&self, other: &Line2D<T>) -> bool {
+        let (a, b, c, d) = (&self.p1, &self.p2, &other.p1, &other.p2);
+        let (o1, o2) = (orientation(a, b, c), orientation(a, b, d));
+        let (o3, o4) = (orientation(c, d, a), orientation(c, d, b));
+        let zero = T::zero();
+        if ((o1 > zero && o2 < zero) || (o1 < zero && o2 > zero)) && ((o3 > zero && o4 < zero) || (o3 < zero && o4 > zero)) {
+            return true;
+        }
+        // touching or collinear overlap
+        self.contains(c) || self.contains(d) || other.contains(a) || other.contains(b)
+    }
+}
+impl<T: Float + Debug> Intersects<Circle2D<T>> for Line2D<T> {
+    fn intersects(&self, circle: &Circle2D<T>) -> bool {
+        circle.contains(&self.closest_point_to(&circle.center))
+    }
+}
+impl<T: Float + Debug> Intersects<Aabb2D<T>> for Line2D<T> {
+    fn intersects(&self, aabb: &Aabb2D<T>) -> bool {
+        aabb.contains(&self.p1) || aabb.edges().iter().any(|edge| self.intersects(edge))
+    }
+}
+impl<T: Float + Debug> Intersects<Polygon2D<T>> for Line2D<T> {
+    fn intersects(&self, polygon: &Polygon2D<T>) -> bool {
+        polygon.contains(&self.p1) || polygon.edges().any(|edge| self.intersects(&edge))
+    }
+}
+
+// Polyline2D: a single vertex behaves like a point, otherwise any segment counts
+
+macro_rules! impl_polyline_intersects {
+    ($($shape:ident),*) => {
+        $(
+            impl<T: Float + Debug> Intersects<$shape<T>> for Polyline2D<T> {
+                fn intersects(&self, other: &$shape<T>) -> bool {
+                    match self.vertices() {
+                        [single] => single.intersects(other),
+                        _ => self.edges().any(|segment| segment.intersects(other)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_polyline_intersects!(Line2D, Polyline2D, Polygon2D, Circle2D, Aabb2D);
+impl_swapped!((Line2D, Polyline2D));
+
+// Polygon2D
+
+impl<T: Float + Debug> Intersects for Polygon2D<T> {
+    fn intersects(&self, other: &Polygon2D<T>) -> bool {
+        // either the boundaries cross, or one polygon lies entirely inside the other
+        self.edges().any(|edge| other.edges().any(|e| edge.intersects(&e)))
+            || self.vertices().first().is_some_and(|v| other.contains(v))
+            || other.vertices().first().is_some_and(|v| self.contains(v))
+    }
+}
+impl<T: Float + Debug> Intersects<Circle2D<T>> for Polygon2D<T> {
+    fn intersects(&self, circle: &Circle2D<T>) -> bool {
+        self.contains(&circle.center) || self.edges().any(|edge| edge.intersects(circle))
+    }
+}
+impl<T: Float + Debug> Intersects<Aabb2D<T>> for Polygon2D<T> {
+    fn intersects(&self, aabb: &Aabb2D<T>) -> bool {
+        self.contains(&aabb.min)
+            || self.vertices().first().is_some_and(|v| aabb.contains(v))
+            || self.edges().any(|edge| edge.intersects(aabb))
+    }
+}
+impl_swapped!((Polygon2D, Line2D), (Polygon2D, Polyline2D));
+
+// Rect2D
+
+impl<T: Float + Debug> Intersects for Rect2D<T> {
+    fn intersects(&self, other: &Rect2D<T>) -> bool {
+        self.intersects_rect(other)
+    }
+}
+
+// ConvexPolygon2D
+
+impl<T: Float + Debug> Intersects for ConvexPolygon2D<T> {
+    fn intersects(&self, other: &ConvexPolygon2D<T>) -> bool {
+        // separating axis: apart exactly when some edge has the whole other polygon strictly outside
+        let separated = |p: &[Point2D<T>], q: &[Point2D<T>]| {
+            (0..p.len()).any(|i| q.iter().all(|v| orientation(&p[i], &p[(i + 1) % p.len()], v) < T::zero()))
+        };
+        !separated(self.vertices(), other.vertices()) && !separated(other.vertices(), self.vertices())
+    }
+}
+impl<T: Float + Debug> Intersects<Polygon2D<T>> for ConvexPolygon2D<T> {
+    fn intersects(&self, polygon: &Polygon2D<T>) -> bool {
+        self.polygon().intersects(polygon)
+    }
+}
+impl_swapped!((Polygon2D, ConvexPolygon2D));
+
+// Circle2D
+
+impl<T: Float + Debug> Intersects for Circle2D<T> {
+    fn intersects(&self, other: &Circle2D<T>) -> bool {
+        self.center.distance(&other.center) <= self.radius + other.radius
+    }
+}
+impl<T: Float + Debug> Intersects<Aabb2D<T>> for Circle2D<T> {
+    fn intersects(&self, aabb: &Aabb2D<T>) -> bool {
+        let clamped = Point2D::new(
+            self.center.x.max(aabb.min.x).min(aabb.max.x),
+            self.center.y.max(aabb.min.y).min(aabb.max.y),
+        );
+        self.contains(&clamped)
+    }
+}
+impl_swapped!((Circle2D, Line2D), (Circle2D, Polyline2D), (Circle2D, Polygon2D));
+
+// Aabb2D
+
+impl<T: Float + Debug> Intersects for Aabb2D<T> {
+    fn intersects(&self, other: &Aabb2D<T>) -> bool {
+        self.min.x <= other.max.x && other.min.x <= self.max.x && self.min.y <= other.max.y && other.min.y <= self.max.y
+    }
+}
+impl_swapped!((Aabb2D, Line2D), (Aabb2D, Polyline2D), (Aabb2D, Polygon2D), (Aabb2D, Circle2D));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(x1: f64, y1: f64, x2: f64, y2: f64) -> Line2D<f64> {
+        Line2D::new(Point2D::new(x1, y1), Point2D::new(x2, y2))
+    }
+
+    fn square(min: f64, max: f64) -> Polygon2D<f64> {
+        Polygon2D::new(vec![
+            Point2D::new(min, min),
+            Point2D::new(max, min),
+            Point2D::new(max, max),
+            Point2D::new(min, max),
+        ])
+    }
+
+    #[test]
+    fn test_segment_intersects_segment() {
+        assert!(line(0.0, 0.0, 2.0, 2.0).intersects(&line(0.0, 2.0, 2.0, 0.0)));
+        assert!(line(0.0, 0.0, 2.0, 0.0).intersects(&line(1.0, 0.0, 1.0, 5.0)), "T junction");
+        assert!(line(0.0, 0.0, 2.0, 0.0).intersects(&line(1.0, 0.0, 3.0, 0.0)), "collinear overlap");
+        assert!(!line(0.0, 0.0, 2.0, 0.0).intersects(&line(2.5, 0.0, 3.0, 0.0)));
+        assert!(!line(0.0, 0.0, 2.0, 2.0).intersects(&line(0.0, 1.0, 1.0, 2.0)));
+    }
+
+    #[test]
+    fn test_closed_shapes_intersect_contained_shapes() {
+        let outer = square(0.0, 10.0);
+        assert!(outer.intersects(&square(4.0, 5.0)));
+        assert!(square(4.0, 5.0).intersects(&outer));
+        assert!(!outer.intersects(&square(11.0, 12.0)));
+        assert!(outer.intersects(&line(2.0, 2.0, 3.0, 3.0)));
+        assert!(outer.intersects(&Circle2D::new(Point2D::new(5.0, 5.0), 1.0)));
+        assert!(Circle2D::new(Point2D::new(5.0, 5.0), 20.0).intersects(&outer));
+        assert!(outer.intersects(&Aabb2D::new(Point2D::new(-1.0, -1.0), Point2D::new(11.0, 11.0))));
+    }
+
+    #[test]
+    fn test_circle_and_aabb_pairs() {
+        let circle = Circle2D::new(Point2D::new(0.0, 0.0), 1.0);
+        let aabb = Aabb2D::new(Point2D::new(0.8, 0.8), Point2D::new(2.0, 2.0));
+        assert!(!circle.intersects(&aabb), "the box corner is outside the circle");
+        assert!(circle.intersects(&Aabb2D::new(Point2D::new(0.5, 0.5), Point2D::new(2.0, 2.0))));
+        assert!(circle.intersects(&Circle2D::new(Point2D::new(2.0, 0.0), 1.0)));
+        assert!(!circle.intersects(&line(-2.0, 1.5, 2.0, 1.5)));
+        assert!(line(-2.0, 1.0, 2.0, 1.0).intersects(&circle));
+        assert!(aabb.intersects(&line(0.0, 3.0, 3.0, 0.0)));
+    }
+
+    #[test]
+    fn test_point_and_polyline_pairs() {
+        let polyline = Polyline2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(2.0, 0.0), Point2D::new(2.0, 2.0)]);
+        assert!(Point2D::new(2.0, 1.0).intersects(&polyline));
+        assert!(!polyline.intersects(&Point2D::new(1.0, 1.0)));
+        assert!(polyline.intersects(&line(1.0, 1.0, 3.0, 1.0)));
+        assert!(square(1.5, 2.5).intersects(&polyline));
+        assert!(!square(0.5, 1.5).intersects(&polyline));
+        assert!(!Point2D::new(1.0, 1.0).intersects(&Point2D::new(1.0, 1.5)));
+    }
+}
@@ -0,0 +1,154 @@
+use num::Num;
+use core::convert::Infallible;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::line::{Line2D, Polyline2D};
+use crate::polygon::Polygon2D;
+use crate::algorithms::overlay::OverlayPiece;
+use super::{Aabb2D, Circle2D, Geometry2D};
+use alloc::vec::Vec;
+
+/// Applies a function to every coordinate of a geometry, keeping its structure.
+///
+/// The hook for reprojection, unit conversion and jittering. Circles map their
+/// center and keep their radius; boxes map both corners and re-sort them so
+/// `min` stays below `max`.
+///
+/// # Example
+/// ```
+/// use geomengine::line::Line2D;
+/// use geomengine::point::Point2D;
+/// use geomengine::shape::MapCoords;
+///
+/// let line = Line2D::new(Point2D::new(1.0, 2.0), Point2D::new(3.0, 4.0));
+/// let km = line.map_coords(|p| p / 1000.0);
+/// assert_eq!(km.p2, Point2D::new(0.003, 0.004));
+///
+/// let checked = line.try_map_coords(|p| if p.x < 2.0 { Ok(p) } else { Err("out of range") });
+/// assert_eq!(checked, Err("out of range"));
+/// ```
+pub trait MapCoords<T: Num + Copy + Debug>: Sized {
+    /// Maps every coordinate with `f`, stopping at the first error.
+    fn try_map_coords<E>(&self, f: impl FnMut(Point2D<T>) -> Result<Point2D<T>, E>) -> Result<Self, E>;
+
+    fn map_coords(&self, mut f: impl FnMut(Point2D<T>) -> Point2D<T>) -> Self {
+        match self.try_map_coords(|p| Ok::<_, Infallible>(f(p))) {
+            Ok(mapped) => mapped,
+            Err(never) => match never {},
+        }
+    }
+}
+
+fn try_map_all<T: Num + Copy + Debug, E>(points: &[Point2D<T>], f: impl FnMut(Point2D<T>) -> Result<Point2D<T>, E>) -> Result<Vec<Point2D<T>>, E> {
+    points.iter().copied().map(f).collect()
+}
+
+impl<T: Num + Copy + Debug> MapCoords<T> for Point2D<T> {
+    fn try_map_coords<E>(&self, mut f: impl FnMut(Point2D<T>) -> Result<Point2D<T>, E>) -> Result<Self, E> {
+        f(*self)
+    }
+}
+
+impl<T: Num + Copy + Debug> MapCoords<T> for Vec<Point2D<T>> {
+    fn try_map_coords<E>(&self, f: impl FnMut(Point2D<T>) -> Result<Point2D<T>, E>) -> Result<Self, E> {
+        try_map_all(self, f)
+    }
+}
+
+impl<T: Num + Copy + Debug> MapCoords<T> for Line2D<T> {
+    fn try_map_coords<E>(&self, mut f: impl FnMut(Point2D<T>) -> Result<Point2D<T>, E>) -> Result<Self, E> {
+        Ok(Line2D::new(f(self.p1)?, f(self.p2)?))
+    }
+}
+
+impl<T: Num + Copy + Debug> MapCoords<T> for Polyline2D<T> {
+    fn try_map_coords<E>(&self, f: impl FnMut(Point2D<T>) -> Result<Point2D<T>, E>) -> Result<Self, E> {
+        Ok(Polyline2D::new(try_map_all(self.vertices(), f)?))
+    }
+}
+
+impl<T: Num + Copy + Debug> MapCoords<T> for Polygon2D<T> {
+    fn try_map_coords<E>(&self, f: impl FnMut(Point2D<T>) -> Result<Point2D<T>, E>) -> Result<Self, E> {
+        Ok(Polygon2D::new(try_map_all(self.vertices(), f)?))
+    }
+}
+
+impl<T: Num + Copy + Debug> MapCoords<T> for Circle2D<T> {
+    fn try_map_coords<E>(&self, mut f: impl FnMut(Point2D<T>) -> Result<Point2D<T>, E>) -> Result<Self, E> {
+        Ok(Circle2D::new(f(self.center)?, self.radius))
+    }
+}
+
+impl<T: Num + Copy + Debug + PartialOrd> MapCoords<T> for Aabb2D<T> {
+    fn try_map_coords<E>(&self, mut f: impl FnMut(Point2D<T>) -> Result<Point2D<T>, E>) -> Result<Self, E> {
+        let (a, b) = (f(self.min)?, f(self.max)?);
+        let sorted = |u: T, v: T| if v < u { (v, u) } else { (u, v) };
+        let ((min_x, max_x), (min_y, max_y)) = (sorted(a.x, b.x), sorted(a.y, b.y));
+        Ok(Aabb2D::new(Point2D::new(min_x, min_y), Point2D::new(max_x, max_y)))
+    }
+}
+
+impl<T: Num + Copy + Debug> MapCoords<T> for OverlayPiece<T> {
+    fn try_map_coords<E>(&self, mut f: impl FnMut(Point2D<T>) -> Result<Point2D<T>, E>) -> Result<Self, E> {
+        Ok(OverlayPiece {
+            exterior: self.exterior.try_map_coords(&mut f)?,
+            holes: self.holes.iter().map(|hole| hole.try_map_coords(&mut f)).collect::<Result<_, _>>()?,
+            a: self.a,
+            b: self.b,
+        })
+    }
+}
+
+impl<T: Num + Copy + Debug> MapCoords<T> for Geometry2D<T> {
+    fn try_map_coords<E>(&self, mut f: impl FnMut(Point2D<T>) -> Result<Point2D<T>, E>) -> Result<Self, E> {
+        Ok(match self {
+            Geometry2D::Point(point) => Geometry2D::Point(point.try_map_coords(f)?),
+            Geometry2D::Line(line) => Geometry2D::Line(line.try_map_coords(f)?),
+            Geometry2D::Polyline(polyline) => Geometry2D::Polyline(polyline.try_map_coords(f)?),
+            Geometry2D::Polygon(polygon) => Geometry2D::Polygon(polygon.try_map_coords(f)?),
+            Geometry2D::Circle(circle) => Geometry2D::Circle(circle.try_map_coords(f)?),
+            Geometry2D::MultiPoint(points) => Geometry2D::MultiPoint(points.try_map_coords(f)?),
+            Geometry2D::MultiPolyline(polylines) => Geometry2D::MultiPolyline(
+                polylines.iter().map(|p| p.try_map_coords(&mut f)).collect::<Result<_, _>>()?,
+            ),
+            Geometry2D::MultiPolygon(polygons) => Geometry2D::MultiPolygon(
+                polygons.iter().map(|p| p.try_map_coords(&mut f)).collect::<Result<_, _>>()?,
+            ),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_coords_preserves_structure() {
+        let polygons = Geometry2D::from(vec![
+            Polygon2D::new(vec![Point2D::new(0, 0), Point2D::new(2, 0), Point2D::new(0, 2)]),
+            Polygon2D::new(vec![Point2D::new(5, 5), Point2D::new(6, 5), Point2D::new(5, 6)]),
+        ]);
+        let shifted = polygons.map_coords(|p| p.translate((10, 0)));
+        let Geometry2D::MultiPolygon(shifted) = shifted else { panic!("variant changed") };
+        assert_eq!(shifted.len(), 2);
+        assert_eq!(shifted[1].vertices()[2], Point2D::new(15, 6));
+
+        let mirrored = Aabb2D::new(Point2D::new(1.0, 2.0), Point2D::new(3.0, 5.0)).map_coords(|p| Point2D::new(-p.x, p.y));
+        assert_eq!(mirrored, Aabb2D::new(Point2D::new(-3.0, 2.0), Point2D::new(-1.0, 5.0)));
+
+        let circle = Circle2D::new(Point2D::new(1.0, 1.0), 2.0).map_coords(|p| p * 3.0);
+        assert_eq!(circle, Circle2D::new(Point2D::new(3.0, 3.0), 2.0));
+    }
+
+    #[test]
+    fn test_try_map_coords_stops_at_first_error() {
+        let polyline = Polyline2D::new(vec![Point2D::new(1.0, 0.0), Point2D::new(-1.0, 0.0), Point2D::new(-2.0, 0.0)]);
+        let mut calls = 0;
+        let result = polyline.try_map_coords(|p| {
+            calls += 1;
+            if p.x < 0.0 { Err(p) } else { Ok(p) }
+        });
+        assert_eq!(result, Err(Point2D::new(-1.0, 0.0)));
+        assert_eq!(calls, 2);
+    }
+}
@@ -0,0 +1,32 @@
+// module exports
+mod traits;
+mod circle2d;
+mod arc2d;
+mod ellipse2d;
+mod triangle2d;
+mod aabb2d;
+mod rect2d;
+mod geometry2d;
+mod intersects;
+mod distance;
+mod closest_point;
+mod centroid;
+mod coords;
+mod map_coords;
+mod builder;
+
+pub use traits::{Area, Perimeter, Contains, BoundingBox};
+pub use circle2d::Circle2D;
+pub use arc2d::Arc2D;
+pub use ellipse2d::Ellipse2D;
+pub use triangle2d::Triangle2D;
+pub use aabb2d::Aabb2D;
+pub use rect2d::Rect2D;
+pub use geometry2d::Geometry2D;
+pub use intersects::Intersects;
+pub use distance::Distance;
+pub use closest_point::ClosestPoint;
+pub use centroid::Centroid;
+pub use coords::CoordsIter;
+pub use map_coords::MapCoords;
+pub use builder::{PolygonBuilder, PolylineBuilder, ValidityError, Winding};
@@ -0,0 +1,154 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::{Point2D, Vector2D};
+use crate::polygon::Polygon2D;
+use crate::transform::Angle;
+use super::Aabb2D;
+
+/// An oriented rectangle: `center`, the half width and half height along its
+/// own axes, and `rotation` counterclockwise from the x axis.
+///
+/// Unlike [`Aabb2D`] it can lie at any angle; with zero rotation the two agree.
+///
+/// # Example
+/// ```
+/// use geomengine::point::Point2D;
+/// use geomengine::shape::Rect2D;
+/// use geomengine::transform::Angle;
+///
+/// let diamond = Rect2D::new(Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0), Angle::from_degrees(45.0));
+/// assert!(diamond.contains_point(&Point2D::new(0.0, 1.4)));
+/// assert!(!diamond.contains_point(&Point2D::new(0.9, 0.9)));
+/// let beside = Rect2D::new(Point2D::new(2.3, 0.0), Point2D::new(1.0, 1.0), Angle::zero());
+/// assert!(diamond.intersects_rect(&beside));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rect2D<T: Float + Debug> {
+    pub center: Point2D<T>,
+    pub half_extents: Point2D<T>,
+    pub rotation: Angle<T>,
+}
+
+impl<T: Float + Debug> Rect2D<T> {
+    pub fn new(center: Point2D<T>, half_extents: Point2D<T>, rotation: Angle<T>) -> Self {
+        Rect2D { center, half_extents, rotation }
+    }
+    pub fn width(&self) -> T {
+        self.half_extents.x + self.half_extents.x
+    }
+    pub fn height(&self) -> T {
+        self.half_extents.y + self.half_extents.y
+    }
+    pub fn area(&self) -> T {
+        self.width() * self.height()
+    }
+    /// Unit vectors along the rectangle's width and height.
+    pub fn axes(&self) -> [Vector2D<T>; 2] {
+        let (sin, cos) = self.rotation.sin_cos();
+        [Vector2D::new(cos, sin), Vector2D::new(-sin, cos)]
+    }
+    /// The four corners, counterclockwise from the one at `-half_extents` in
+    /// the rectangle's axes.
+    pub fn corners(&self) -> [Point2D<T>; 4] {
+        let [u, v] = self.axes();
+        let (u, v) = (u * self.half_extents.x, v * self.half_extents.y);
+        let c = self.center;
+        [c - u - v, c + u - v, c + u + v, c - u + v]
+    }
+    /// Returns true if the point lies in the rectangle, boundary included.
+    pub fn contains_point(&self, point: &Point2D<T>) -> bool {
+        let [u, v] = self.axes();
+        let offset = *point - self.center;
+        offset.dot_product(&u).abs() <= self.half_extents.x && offset.dot_product(&v).abs() <= self.half_extents.y
+    }
+    /// Do the rectangles share any point? Touching counts.
+    ///
+    /// # Algorithm Explanation
+    /// Separating axis test: two rectangles are apart exactly when their
+    /// projections onto one of the four edge directions do not overlap.
+    ///
+    /// # Time Complexity
+    /// **O(1)**.
+    pub fn intersects_rect(&self, other: &Rect2D<T>) -> bool {
+        let offset = other.center - self.center;
+        // half the width of a rectangle's projection onto `axis`
+        let reach = |rect: &Rect2D<T>, axis: Vector2D<T>| {
+            let [u, v] = rect.axes();
+            rect.half_extents.x * u.dot_product(&axis).abs() + rect.half_extents.y * v.dot_product(&axis).abs()
+        };
+        self.axes()
+            .into_iter()
+            .chain(other.axes())
+            .all(|axis| offset.dot_product(&axis).abs() <= reach(self, axis) + reach(other, axis))
+    }
+    /// Smallest axis aligned box holding the rectangle.
+    pub fn bounding_box(&self) -> Aabb2D<T> {
+        let [u, v] = self.axes();
+        let half = Vector2D::new(
+            self.half_extents.x * u.x.abs() + self.half_extents.y * v.x.abs(),
+            self.half_extents.x * u.y.abs() + self.half_extents.y * v.y.abs(),
+        );
+        Aabb2D::new(self.center - half, self.center + half)
+    }
+    /// The rectangle as a counterclockwise polygon of its [corners](Self::corners).
+    pub fn to_polygon(&self) -> Polygon2D<T> {
+        Polygon2D::new(self.corners().to_vec())
+    }
+}
+
+impl<T: Float + Debug> From<Aabb2D<T>> for Rect2D<T> {
+    fn from(aabb: Aabb2D<T>) -> Self {
+        let two = T::one() + T::one();
+        Rect2D::new(aabb.center(), Point2D::new(aabb.width() / two, aabb.height() / two), Angle::zero())
+    }
+}
+
+impl<T: Float + Debug> From<Rect2D<T>> for Polygon2D<T> {
+    fn from(rect: Rect2D<T>) -> Self {
+        rect.to_polygon()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::sampling::{XorShift, Rng, sample_aabb};
+    use crate::shape::Intersects;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_rect_corners_and_bounds() {
+        let rect = Rect2D::new(Point2D::new(1.0, 2.0), Point2D::new(2.0, 1.0), Angle::from_degrees(90.0));
+        let corners = rect.corners();
+        let expected = [Point2D::new(2.0, 0.0), Point2D::new(2.0, 4.0), Point2D::new(0.0, 4.0), Point2D::new(0.0, 0.0)];
+        for (corner, expected) in corners.iter().zip(expected) {
+            assert_relative_eq!(corner.x, expected.x, epsilon = 1e-12);
+            assert_relative_eq!(corner.y, expected.y, epsilon = 1e-12);
+        }
+        assert_relative_eq!(rect.to_polygon().signed_area(), rect.area(), epsilon = 1e-12);
+        let bounds = rect.bounding_box();
+        assert_relative_eq!(bounds.min.x, 0.0, epsilon = 1e-12);
+        assert_relative_eq!(bounds.max.y, 4.0, epsilon = 1e-12);
+
+        let aabb = Aabb2D::new(Point2D::new(-1.0, 0.0), Point2D::new(3.0, 1.0));
+        assert_eq!(Rect2D::from(aabb).corners(), aabb.corners());
+        assert_eq!(Rect2D::from(aabb).bounding_box(), aabb);
+    }
+
+    #[test]
+    fn test_rect_queries_match_polygon() {
+        let mut rng = XorShift::new(5);
+        let bounds = Aabb2D::new(Point2D::new(-4.0, -4.0), Point2D::new(4.0, 4.0));
+        let random_rect = |rng: &mut XorShift| {
+            let center = sample_aabb(&bounds, rng);
+            let (width, height, rotation): (f64, f64, f64) = (rng.unit(), rng.unit(), rng.unit());
+            Rect2D::new(center, Point2D::new(0.2 + 2.0 * width, 0.2 + height), Angle::from_radians(6.3 * rotation))
+        };
+        for _ in 0..300 {
+            let (a, b) = (random_rect(&mut rng), random_rect(&mut rng));
+            assert_eq!(a.intersects_rect(&b), a.to_polygon().intersects(&b.to_polygon()));
+            let point = sample_aabb(&bounds, &mut rng);
+            assert_eq!(a.contains_point(&point), a.to_polygon().contains_point(&point));
+        }
+    }
+}
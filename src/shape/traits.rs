@@ -0,0 +1,386 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::{Point2D, Vector2D};
+use crate::line::{Line2D, Polyline2D};
+use crate::polygon::{ConvexPolygon2D, Polygon2D, PolygonWithHoles, ring};
+use super::{Aabb2D, Arc2D, Circle2D, Ellipse2D, Rect2D, Triangle2D};
+
+/// Area enclosed by a shape; zero for points and lines.
+pub trait Area<T> {
+    fn area(&self) -> T;
+}
+
+/// Length of a shape's boundary; the length itself for lines, zero for points.
+pub trait Perimeter<T> {
+    fn perimeter(&self) -> T;
+}
+
+/// Containment of `Rhs` in a shape, boundary included.
+///
+/// Polygons use the even-odd rule, so points exactly on a polygon edge may be
+/// reported either way.
+pub trait Contains<Rhs: ?Sized = Self> {
+    fn contains(&self, other: &Rhs) -> bool;
+}
+
+/// Axis aligned bounding box of a shape, or `None` if it has no vertices.
+pub trait BoundingBox<T: Float + Debug> {
+    fn bounding_box(&self) -> Option<Aabb2D<T>>;
+}
+
+// Point2D
+
+impl<T: Float + Debug> Area<T> for Point2D<T> {
+    fn area(&self) -> T {
+        T::zero()
+    }
+}
+impl<T: Float + Debug> Perimeter<T> for Point2D<T> {
+    fn perimeter(&self) -> T {
+        T::zero()
+    }
+}
+impl<T: Float + Debug> Contains<Point2D<T>> for Point2D<T> {
+    fn contains(&self, other: &Point2D<T>) -> bool {
+        self == other
+    }
+}
+impl<T: Float + Debug> BoundingBox<T> for Point2D<T> {
+    fn bounding_box(&self) -> Option<Aabb2D<T>> {
+        Some(Aabb2D::new(*self, *self))
+    }
+}
+
+// Line2D
+
+impl<T: Float + Debug> Area<T> for Line2D<T> {
+    fn area(&self) -> T {
+        T::zero()
+    }
+}
+impl<T: Float + Debug> Perimeter<T> for Line2D<T> {
+    fn perimeter(&self) -> T {
+        self.length()
+    }
+}
+impl<T: Float + Debug> Contains<Point2D<T>> for Line2D<T> {
+    /// Exact test: the point must be collinear with the segment and between its endpoints.
+    fn contains(&self, point: &Point2D<T>) -> bool {
+        (self.p2 - self.p1).cross_product(&(*point - self.p1)) == T::zero()
+            && self.bounding_box().is_some_and(|aabb| aabb.contains(point))
+    }
+}
+impl<T: Float + Debug> BoundingBox<T> for Line2D<T> {
+    fn bounding_box(&self) -> Option<Aabb2D<T>> {
+        Aabb2D::from_points(&[self.p1, self.p2])
+    }
+}
+
+// Polyline2D
+
+impl<T: Float + Debug> Area<T> for Polyline2D<T> {
+    fn area(&self) -> T {
+        T::zero()
+    }
+}
+impl<T: Float + Debug> Perimeter<T> for Polyline2D<T> {
+    fn perimeter(&self) -> T {
+        self.length()
+    }
+}
+impl<T: Float + Debug> Contains<Point2D<T>> for Polyline2D<T> {
+    fn contains(&self, point: &Point2D<T>) -> bool {
+        match self.vertices() {
+            [single] => single == point,
+            _ => self.edges().any(|segment| segment.contains(point)),
+        }
+    }
+}
+impl<T: Float + Debug> BoundingBox<T> for Polyline2D<T> {
+    fn bounding_box(&self) -> Option<Aabb2D<T>> {
+        Aabb2D::from_points(self.vertices())
+    }
+}
+
+// Circle2D
+
+impl<T: Float + Debug> Area<T> for Circle2D<T> {
+    fn area(&self) -> T {
+        Circle2D::area(self)
+    }
+}
+impl<T: Float + Debug> Perimeter<T> for Circle2D<T> {
+    fn perimeter(&self) -> T {
+        self.circumference()
+    }
+}
+impl<T: Float + Debug> Contains<Point2D<T>> for Circle2D<T> {
+    fn contains(&self, point: &Point2D<T>) -> bool {
+        self.contains_point(point)
+    }
+}
+impl<T: Float + Debug> Contains<Line2D<T>> for Circle2D<T> {
+    fn contains(&self, line: &Line2D<T>) -> bool {
+        self.contains(&line.p1) && self.contains(&line.p2)
+    }
+}
+impl<T: Float + Debug> Contains for Circle2D<T> {
+    fn contains(&self, other: &Circle2D<T>) -> bool {
+        self.center.distance(&other.center) + other.radius <= self.radius
+    }
+}
+impl<T: Float + Debug> BoundingBox<T> for Circle2D<T> {
+    fn bounding_box(&self) -> Option<Aabb2D<T>> {
+        let r = Vector2D::new(self.radius, self.radius);
+        Some(Aabb2D::new(self.center - r, self.center + r))
+    }
+}
+
+// Triangle2D
+
+impl<T: Float + Debug> Area<T> for Triangle2D<T> {
+    fn area(&self) -> T {
+        Triangle2D::area(self)
+    }
+}
+impl<T: Float + Debug> Perimeter<T> for Triangle2D<T> {
+    fn perimeter(&self) -> T {
+        Triangle2D::perimeter(self)
+    }
+}
+impl<T: Float + Debug> Contains<Point2D<T>> for Triangle2D<T> {
+    fn contains(&self, point: &Point2D<T>) -> bool {
+        self.contains_point(point)
+    }
+}
+impl<T: Float + Debug> BoundingBox<T> for Triangle2D<T> {
+    fn bounding_box(&self) -> Option<Aabb2D<T>> {
+        Aabb2D::from_points(&self.vertices())
+    }
+}
+
+// Polygon2D
+
+impl<T: Float + Debug> Area<T> for Polygon2D<T> {
+    fn area(&self) -> T {
+        ring::signed_area(self.vertices()).abs()
+    }
+}
+impl<T: Float + Debug> Perimeter<T> for Polygon2D<T> {
+    fn perimeter(&self) -> T {
+        self.edges().fold(T::zero(), |sum, edge| sum + edge.length())
+    }
+}
+impl<T: Float + Debug> Contains<Point2D<T>> for Polygon2D<T> {
+    fn contains(&self, point: &Point2D<T>) -> bool {
+        self.contains_point(point)
+    }
+}
+impl<T: Float + Debug> BoundingBox<T> for Polygon2D<T> {
+    fn bounding_box(&self) -> Option<Aabb2D<T>> {
+        Aabb2D::from_points(self.vertices())
+    }
+}
+
+// PolygonWithHoles
+
+impl<T: Float + Debug> Area<T> for PolygonWithHoles<T> {
+    fn area(&self) -> T {
+        PolygonWithHoles::area(self)
+    }
+}
+impl<T: Float + Debug> Perimeter<T> for PolygonWithHoles<T> {
+    fn perimeter(&self) -> T {
+        self.rings().fold(T::zero(), |sum, ring| sum + ring.perimeter())
+    }
+}
+impl<T: Float + Debug> Contains<Point2D<T>> for PolygonWithHoles<T> {
+    fn contains(&self, point: &Point2D<T>) -> bool {
+        self.contains_point(point)
+    }
+}
+impl<T: Float + Debug> BoundingBox<T> for PolygonWithHoles<T> {
+    fn bounding_box(&self) -> Option<Aabb2D<T>> {
+        self.exterior().bounding_box()
+    }
+}
+
+// ConvexPolygon2D
+
+impl<T: Float + Debug> Area<T> for ConvexPolygon2D<T> {
+    fn area(&self) -> T {
+        self.polygon().signed_area()
+    }
+}
+impl<T: Float + Debug> Perimeter<T> for ConvexPolygon2D<T> {
+    fn perimeter(&self) -> T {
+        self.polygon().perimeter()
+    }
+}
+impl<T: Float + Debug> Contains<Point2D<T>> for ConvexPolygon2D<T> {
+    fn contains(&self, point: &Point2D<T>) -> bool {
+        self.contains_point(point)
+    }
+}
+impl<T: Float + Debug> BoundingBox<T> for ConvexPolygon2D<T> {
+    fn bounding_box(&self) -> Option<Aabb2D<T>> {
+        Aabb2D::from_points(self.vertices())
+    }
+}
+
+// Ellipse2D
+
+impl<T: Float + Debug> Area<T> for Ellipse2D<T> {
+    fn area(&self) -> T {
+        Ellipse2D::area(self)
+    }
+}
+impl<T: Float + Debug> Perimeter<T> for Ellipse2D<T> {
+    fn perimeter(&self) -> T {
+        Ellipse2D::perimeter(self)
+    }
+}
+impl<T: Float + Debug> Contains<Point2D<T>> for Ellipse2D<T> {
+    fn contains(&self, point: &Point2D<T>) -> bool {
+        self.contains_point(point)
+    }
+}
+impl<T: Float + Debug> BoundingBox<T> for Ellipse2D<T> {
+    fn bounding_box(&self) -> Option<Aabb2D<T>> {
+        Some(Ellipse2D::bounding_box(self))
+    }
+}
+
+// Rect2D
+
+impl<T: Float + Debug> Area<T> for Rect2D<T> {
+    fn area(&self) -> T {
+        Rect2D::area(self)
+    }
+}
+impl<T: Float + Debug> Perimeter<T> for Rect2D<T> {
+    fn perimeter(&self) -> T {
+        (self.width() + self.height()) * (T::one() + T::one())
+    }
+}
+impl<T: Float + Debug> Contains<Point2D<T>> for Rect2D<T> {
+    fn contains(&self, point: &Point2D<T>) -> bool {
+        self.contains_point(point)
+    }
+}
+impl<T: Float + Debug> BoundingBox<T> for Rect2D<T> {
+    fn bounding_box(&self) -> Option<Aabb2D<T>> {
+        Some(Rect2D::bounding_box(self))
+    }
+}
+
+// Arc2D
+
+impl<T: Float + Debug> Area<T> for Arc2D<T> {
+    fn area(&self) -> T {
+        T::zero()
+    }
+}
+impl<T: Float + Debug> Perimeter<T> for Arc2D<T> {
+    fn perimeter(&self) -> T {
+        self.length()
+    }
+}
+impl<T: Float + Debug> BoundingBox<T> for Arc2D<T> {
+    fn bounding_box(&self) -> Option<Aabb2D<T>> {
+        Some(Arc2D::bounding_box(self))
+    }
+}
+
+// Aabb2D
+
+impl<T: Float + Debug> Area<T> for Aabb2D<T> {
+    fn area(&self) -> T {
+        Aabb2D::area(self)
+    }
+}
+impl<T: Float + Debug> Perimeter<T> for Aabb2D<T> {
+    fn perimeter(&self) -> T {
+        (self.width() + self.height()) * (T::one() + T::one())
+    }
+}
+impl<T: Float + Debug> Contains<Point2D<T>> for Aabb2D<T> {
+    fn contains(&self, point: &Point2D<T>) -> bool {
+        self.contains_point(point)
+    }
+}
+impl<T: Float + Debug> Contains<Line2D<T>> for Aabb2D<T> {
+    fn contains(&self, line: &Line2D<T>) -> bool {
+        self.contains(&line.p1) && self.contains(&line.p2)
+    }
+}
+impl<T: Float + Debug> Contains for Aabb2D<T> {
+    fn contains(&self, other: &Aabb2D<T>) -> bool {
+        self.contains(&other.min) && self.contains(&other.max)
+    }
+}
+impl<T: Float + Debug> BoundingBox<T> for Aabb2D<T> {
+    fn bounding_box(&self) -> Option<Aabb2D<T>> {
+        Some(*self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn total_area<T: Float + Debug, S: Area<T>>(shapes: &[S]) -> T {
+        shapes.iter().fold(T::zero(), |sum, shape| sum + shape.area())
+    }
+
+    #[test]
+    fn test_area_and_perimeter() {
+        let square = Polygon2D::new(vec![
+            Point2D::new(0.0, 0.0),
+            Point2D::new(2.0, 0.0),
+            Point2D::new(2.0, 2.0),
+            Point2D::new(0.0, 2.0),
+        ]);
+        assert_eq!((square.area(), square.perimeter()), (4.0, 8.0));
+
+        let aabb = square.bounding_box().unwrap();
+        assert_eq!((aabb.area(), aabb.perimeter()), (4.0, 8.0));
+
+        let circle = Circle2D::new(Point2D::new(1.0, 1.0), 2.0);
+        assert_relative_eq!(circle.area(), 4.0 * core::f64::consts::PI);
+        assert_relative_eq!(circle.perimeter(), 4.0 * core::f64::consts::PI);
+
+        let line = Line2D::new(Point2D::new(0.0, 0.0), Point2D::new(3.0, 4.0));
+        assert_eq!((line.area(), line.perimeter()), (0.0, 5.0));
+        assert_eq!(total_area(&[aabb, Aabb2D::new(Point2D::new(0.0, 0.0), Point2D::new(1.0, 3.0))]), 7.0);
+    }
+
+    #[test]
+    fn test_contains() {
+        let circle = Circle2D::new(Point2D::new(0.0, 0.0), 5.0);
+        assert!(circle.contains(&Point2D::new(3.0, 4.0)));
+        assert!(!circle.contains(&Point2D::new(3.0, 4.1)));
+        assert!(circle.contains(&Circle2D::new(Point2D::new(1.0, 0.0), 4.0)));
+        assert!(!circle.contains(&Circle2D::new(Point2D::new(1.0, 0.0), 4.5)));
+
+        let aabb = circle.bounding_box().unwrap();
+        assert_eq!(aabb, Aabb2D::new(Point2D::new(-5.0, -5.0), Point2D::new(5.0, 5.0)));
+        assert!(aabb.contains(&Line2D::new(Point2D::new(-5.0, 0.0), Point2D::new(4.0, 5.0))));
+        assert!(!aabb.contains(&Aabb2D::new(Point2D::new(0.0, 0.0), Point2D::new(6.0, 1.0))));
+
+        let line = Line2D::new(Point2D::new(0.0, 0.0), Point2D::new(4.0, 2.0));
+        assert!(line.contains(&Point2D::new(2.0, 1.0)));
+        assert!(!line.contains(&Point2D::new(6.0, 3.0)));
+        assert!(!line.contains(&Point2D::new(2.0, 1.5)));
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        assert_eq!(Polygon2D::<f64>::new(vec![]).bounding_box(), None);
+        let p = Point2D::new(1.0, -1.0);
+        assert_eq!(p.bounding_box(), Some(Aabb2D::new(p, p)));
+        let line = Line2D::new(Point2D::new(3.0, 0.0), Point2D::new(1.0, 2.0));
+        assert_eq!(line.bounding_box(), Some(Aabb2D::new(Point2D::new(1.0, 0.0), Point2D::new(3.0, 2.0))));
+    }
+}
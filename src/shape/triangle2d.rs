@@ -0,0 +1,173 @@
+use num::{Num, Float};
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::line::Line2D;
+use crate::polygon::Polygon2D;
+use super::Circle2D;
+use alloc::vec;
+
+/// A triangle given by its three corners, in either winding.
+///
+/// The circumcircle, incircle and barycentric coordinates are undefined for a
+/// degenerate (zero area) triangle, so those queries return `None` for one.
+///
+/// # Example
+/// ```
+/// use geomengine::point::Point2D;
+/// use geomengine::shape::Triangle2D;
+///
+/// let triangle = Triangle2D::new(Point2D::new(0.0, 0.0), Point2D::new(4.0, 0.0), Point2D::new(0.0, 3.0));
+/// assert_eq!(triangle.area(), 6.0);
+/// assert_eq!(triangle.circumcenter(), Some(Point2D::new(2.0, 1.5)));
+/// assert_eq!(triangle.inradius(), Some(1.0));
+/// assert_eq!(triangle.barycentric(&Point2D::new(2.0, 0.0)), Some([0.5, 0.5, 0.0]));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Triangle2D<T: Num + Copy + Debug> {
+    pub a: Point2D<T>,
+    pub b: Point2D<T>,
+    pub c: Point2D<T>,
+}
+
+impl<T: Num + Copy + Debug> Triangle2D<T> {
+    pub fn new(a: Point2D<T>, b: Point2D<T>, c: Point2D<T>) -> Self {
+        Triangle2D { a, b, c }
+    }
+    pub fn vertices(&self) -> [Point2D<T>; 3] {
+        [self.a, self.b, self.c]
+    }
+    /// The edges `ab`, `bc` and `ca`.
+    pub fn edges(&self) -> [Line2D<T>; 3] {
+        [Line2D::new(self.a, self.b), Line2D::new(self.b, self.c), Line2D::new(self.c, self.a)]
+    }
+}
+
+impl<T: Float + Debug> Triangle2D<T> {
+    /// Area, positive if `a`, `b`, `c` run counterclockwise.
+    pub fn signed_area(&self) -> T {
+        (self.b - self.a).cross_product(&(self.c - self.a)) / (T::one() + T::one())
+    }
+    pub fn area(&self) -> T {
+        self.signed_area().abs()
+    }
+    pub fn perimeter(&self) -> T {
+        self.edges().iter().fold(T::zero(), |sum, edge| sum + edge.length())
+    }
+    /// The mean of the corners, where the medians meet.
+    pub fn centroid(&self) -> Point2D<T> {
+        (self.a + self.b + self.c) / T::from(3.0).unwrap()
+    }
+    /// The point equidistant from all three corners.
+    pub fn circumcenter(&self) -> Option<Point2D<T>> {
+        // relative to `a`, so large coordinates do not cancel
+        let (b, c) = (self.b - self.a, self.c - self.a);
+        let d = (T::one() + T::one()) * b.cross_product(&c);
+        if d == T::zero() {
+            return None;
+        }
+        let (b_sq, c_sq) = (b.dot_product(&b), c.dot_product(&c));
+        Some(self.a + Point2D::new(c.y * b_sq - b.y * c_sq, b.x * c_sq - c.x * b_sq) / d)
+    }
+    pub fn circumradius(&self) -> Option<T> {
+        self.circumcenter().map(|center| center.distance(&self.a))
+    }
+    /// The circle through all three corners.
+    pub fn circumcircle(&self) -> Option<Circle2D<T>> {
+        let center = self.circumcenter()?;
+        Some(Circle2D::new(center, center.distance(&self.a)))
+    }
+    /// The center of the largest circle inside the triangle, where the angle
+    /// bisectors meet: the corners weighted by the lengths of the opposite sides.
+    pub fn incenter(&self) -> Option<Point2D<T>> {
+        let (la, lb, lc) = (self.b.distance(&self.c), self.c.distance(&self.a), self.a.distance(&self.b));
+        if self.signed_area() == T::zero() {
+            return None;
+        }
+        Some((self.a * la + self.b * lb + self.c * lc) / (la + lb + lc))
+    }
+    /// Radius of the incircle, `2 · area / perimeter`.
+    pub fn inradius(&self) -> Option<T> {
+        let area = self.area();
+        (area != T::zero()).then(|| (area + area) / self.perimeter())
+    }
+    /// Weights `[u, v, w]` with `u + v + w = 1` and `u·a + v·b + w·c = point`.
+    ///
+    /// All three are in `[0, 1]` exactly for points in the triangle; a negative
+    /// weight means the point is beyond the edge opposite that corner.
+    pub fn barycentric(&self, point: &Point2D<T>) -> Option<[T; 3]> {
+        let twice_area = (self.b - self.a).cross_product(&(self.c - self.a));
+        if twice_area == T::zero() {
+            return None;
+        }
+        let u = (self.b - *point).cross_product(&(self.c - *point)) / twice_area;
+        let v = (self.c - *point).cross_product(&(self.a - *point)) / twice_area;
+        Some([u, v, T::one() - u - v])
+    }
+    /// Returns true if the point lies in the triangle, boundary included.
+    ///
+    /// A degenerate triangle contains the points of its longest edge.
+    pub fn contains_point(&self, point: &Point2D<T>) -> bool {
+        let sides = [
+            (self.b - self.a).cross_product(&(*point - self.a)),
+            (self.c - self.b).cross_product(&(*point - self.b)),
+            (self.a - self.c).cross_product(&(*point - self.c)),
+        ];
+        let (negative, positive) = (sides.iter().any(|&s| s < T::zero()), sides.iter().any(|&s| s > T::zero()));
+        if negative && positive {
+            return false;
+        }
+        // all on one side, or collinear with every edge
+        negative || positive || self.edges().iter().any(|edge| edge.closest_point_to(point) == *point)
+    }
+}
+
+impl<T: Num + Copy + Debug> From<Triangle2D<T>> for Polygon2D<T> {
+    fn from(triangle: Triangle2D<T>) -> Self {
+        Polygon2D::new(vec![triangle.a, triangle.b, triangle.c])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_triangle2d_centers() {
+        // an equilateral triangle far from the origin: all centers coincide
+        let offset = Point2D::new(1e6, -1e6);
+        let h = 3f64.sqrt();
+        let triangle = Triangle2D::new(offset + Point2D::new(-1.0, 0.0), offset + Point2D::new(1.0, 0.0), offset + Point2D::new(0.0, h));
+        let center = offset + Point2D::new(0.0, h / 3.0);
+        for point in [triangle.centroid(), triangle.circumcenter().unwrap(), triangle.incenter().unwrap()] {
+            assert_relative_eq!(point.x, center.x, epsilon = 1e-9);
+            assert_relative_eq!(point.y, center.y, epsilon = 1e-9);
+        }
+        assert_relative_eq!(triangle.circumradius().unwrap(), 2.0 * h / 3.0, epsilon = 1e-9);
+        assert_relative_eq!(triangle.inradius().unwrap(), h / 3.0, epsilon = 1e-9);
+        assert_relative_eq!(triangle.perimeter(), 6.0, epsilon = 1e-9);
+
+        let flat = Triangle2D::new(Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0), Point2D::new(3.0, 3.0));
+        assert_eq!((flat.circumcenter(), flat.incenter(), flat.inradius()), (None, None, None));
+        assert_eq!(flat.barycentric(&Point2D::new(0.0, 0.0)), None);
+    }
+
+    #[test]
+    fn test_triangle2d_barycentric_and_containment() {
+        let clockwise = Triangle2D::new(Point2D::new(0.0, 0.0), Point2D::new(0.0, 4.0), Point2D::new(4.0, 0.0));
+        assert_eq!(clockwise.signed_area(), -8.0);
+        let [u, v, w] = clockwise.barycentric(&Point2D::new(1.0, 1.0)).unwrap();
+        assert_relative_eq!(u, 0.5);
+        assert_relative_eq!(v, 0.25);
+        assert_relative_eq!(w, 0.25);
+        assert!(clockwise.barycentric(&Point2D::new(3.0, 3.0)).unwrap()[0] < 0.0);
+
+        assert!(clockwise.contains_point(&Point2D::new(1.0, 1.0)));
+        assert!(clockwise.contains_point(&Point2D::new(2.0, 2.0)));
+        assert!(!clockwise.contains_point(&Point2D::new(2.0, 2.1)));
+        let flat = Triangle2D::new(Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0), Point2D::new(3.0, 3.0));
+        assert!(flat.contains_point(&Point2D::new(2.0, 2.0)));
+        assert!(!flat.contains_point(&Point2D::new(4.0, 4.0)));
+        assert_eq!(Polygon2D::from(clockwise).len(), 3);
+    }
+}
@@ -0,0 +1,173 @@
+use num::Float;
+use core::cmp::Ordering;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Static 2D KD-tree over points, built balanced by median partitioning.
+///
+/// # Algorithm Explanation
+/// - The median point along the split axis (x on even depths, y on odd ones) is
+///   moved to the middle of its range with a linear-time selection; smaller
+///   points end up on its left, larger ones on its right.
+/// - Both halves are split the same way along the other axis.
+/// - The tree is implicit: the node of a range is its middle element, so it is
+///   stored as a single array and is perfectly balanced.
+///
+/// # Time Complexity
+/// - Construction: O(n log n).
+/// - Nearest neighbour: O(log n) on well distributed data.
+///
+/// # Example
+/// ```
+/// use geomengine::point::Point2D;
+/// use geomengine::spatial::KdTree;
+///
+/// let points = [Point2D::new(0.0, 0.0), Point2D::new(4.0, 1.0), Point2D::new(1.0, 3.0)];
+/// let tree = KdTree::new(&points);
+/// assert_eq!(tree.nearest(&Point2D::new(3.0, 0.0)), Some((1, 2f64.sqrt())));
+/// ```
+#[derive(Debug, Clone)]
+pub struct KdTree<T: Float + Debug> {
+    // points with their input index, in implicit tree order
+    nodes: Vec<(Point2D<T>, usize)>,
+}
+
+impl<T: Float + Debug> KdTree<T> {
+    /// Builds the tree; results are indices into `points`.
+    pub fn new(points: &[Point2D<T>]) -> Self {
+        let mut nodes: Vec<_> = points.iter().copied().zip(0..).collect();
+        let mut ranges = vec![(0, nodes.len(), 0)];
+        while let Some((lo, hi, axis)) = ranges.pop() {
+            if hi - lo <= 1 {
+                continue;
+            }
+            let mid = (lo + hi) / 2;
+            nodes[lo..hi].select_nth_unstable_by(mid - lo, |a, b| {
+                coordinate(&a.0, axis).partial_cmp(&coordinate(&b.0, axis)).unwrap_or(Ordering::Equal)
+            });
+            ranges.push((lo, mid, 1 - axis));
+            ranges.push((mid + 1, hi, 1 - axis));
+        }
+        KdTree { nodes }
+    }
+
+    /// Number of indexed points.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if the tree indexes nothing.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Index of the point closest to `target` and its distance, or `None` if the tree is empty.
+    pub fn nearest(&self, target: &Point2D<T>) -> Option<(usize, T)> {
+        let mut best = None;
+        self.nearest_in(0, self.nodes.len(), 0, target, &mut best);
+        best.map(|(index, distance_sq): (usize, T)| (index, distance_sq.sqrt()))
+    }
+
+    /// Indices of all points within `radius` of `center` (boundary included), in no particular order.
+    pub fn within_radius(&self, center: &Point2D<T>, radius: T) -> Vec<usize> {
+        let radius_sq = radius * radius;
+        let mut found = Vec::new();
+        let mut ranges = vec![(0, self.nodes.len(), 0)];
+        while let Some((lo, hi, axis)) = ranges.pop() {
+            if lo >= hi {
+                continue;
+            }
+            let mid = (lo + hi) / 2;
+            let (point, index) = self.nodes[mid];
+            if distance_sq(&point, center) <= radius_sq {
+                found.push(index);
+            }
+            let diff = coordinate(center, axis) - coordinate(&point, axis);
+            if diff <= radius {
+                ranges.push((lo, mid, 1 - axis));
+            }
+            if diff >= -radius {
+                ranges.push((mid + 1, hi, 1 - axis));
+            }
+        }
+        found
+    }
+
+    // `best` holds the index and squared distance of the closest point so far
+    fn nearest_in(&self, lo: usize, hi: usize, axis: usize, target: &Point2D<T>, best: &mut Option<(usize, T)>) {
+        if lo >= hi {
+            return;
+        }
+        let mid = (lo + hi) / 2;
+        let (point, index) = self.nodes[mid];
+        let d = distance_sq(&point, target);
+        if best.is_none_or(|(_, best_d)| d < best_d) {
+            *best = Some((index, d));
+        }
+
+        // search the side containing the target first; the other side only if the splitting line is closer than the best
+        let diff = coordinate(target, axis) - coordinate(&point, axis);
+        let (near, far) = if diff < T::zero() { ((lo, mid), (mid + 1, hi)) } else { ((mid + 1, hi), (lo, mid)) };
+        self.nearest_in(near.0, near.1, 1 - axis, target, best);
+        if best.is_none_or(|(_, best_d)| diff * diff < best_d) {
+            self.nearest_in(far.0, far.1, 1 - axis, target, best);
+        }
+    }
+}
+
+fn coordinate<T: Float + Debug>(point: &Point2D<T>, axis: usize) -> T {
+    if axis == 0 { point.x } else { point.y }
+}
+
+fn distance_sq<T: Float + Debug>(a: &Point2D<T>, b: &Point2D<T>) -> T {
+    let (dx, dy) = (a.x - b.x, a.y - b.y);
+    dx * dx + dy * dy
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scattered(n: usize) -> Vec<Point2D<f64>> {
+        (0..n).map(|i| Point2D::new(((i * 7919) % 1000) as f64 / 10.0, ((i * 104_729) % 997) as f64 / 10.0)).collect()
+    }
+
+    #[test]
+    fn test_kdtree_nearest_matches_brute_force() {
+        let points = scattered(3000);
+        let tree = KdTree::new(&points);
+        assert_eq!(tree.len(), 3000);
+
+        for target in [Point2D::new(50.0, 50.0), Point2D::new(-10.0, 3.3), Point2D::new(12.34, 98.7)] {
+            let (index, distance) = tree.nearest(&target).unwrap();
+            let expected = points.iter().map(|p| distance_sq(p, &target)).fold(f64::INFINITY, f64::min);
+            assert_eq!(distance_sq(&points[index], &target), expected);
+            assert_eq!(distance, expected.sqrt());
+        }
+    }
+
+    #[test]
+    fn test_kdtree_within_radius_matches_brute_force() {
+        let points = scattered(3000);
+        let tree = KdTree::new(&points);
+        let center = Point2D::new(40.0, 60.0);
+
+        let mut found = tree.within_radius(&center, 7.5);
+        found.sort();
+        let expected: Vec<usize> = (0..points.len()).filter(|&i| distance_sq(&points[i], &center) <= 56.25).collect();
+        assert!(!expected.is_empty());
+        assert_eq!(found, expected);
+    }
+
+    #[test]
+    fn test_kdtree_empty_and_duplicates() {
+        assert_eq!(KdTree::<f64>::new(&[]).nearest(&Point2D::new(0.0, 0.0)), None);
+
+        let tree = KdTree::new(&[Point2D::new(1.0, 1.0); 5]);
+        let mut found = tree.within_radius(&Point2D::new(1.0, 1.0), 0.0);
+        found.sort();
+        assert_eq!(found, vec![0, 1, 2, 3, 4]);
+    }
+}
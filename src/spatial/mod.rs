@@ -0,0 +1,10 @@
+// module exports
+mod str_pack;
+mod rtree;
+mod kdtree;
+mod trapezoidal_map;
+
+pub(crate) use str_pack::str_groups;
+pub use rtree::RTree;
+pub use kdtree::KdTree;
+pub use trapezoidal_map::{Location, PolygonLocator, Trapezoid, TrapezoidalMap};
@@ -0,0 +1,214 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::{Point2D, Vector2D};
+use crate::shape::{Aabb2D, Intersects};
+use super::str_pack::str_order;
+use alloc::vec::Vec;
+
+/// Maximum number of children of a node.
+const NODE_CAPACITY: usize = 16;
+
+/// Static R-tree over axis aligned boxes, built in one pass by sort-tile-recursive packing.
+///
+/// # Algorithm Explanation
+/// - The boxes are ordered with STR packing so every run of 16 entries (the
+///   node capacity) holds spatial neighbours; each run becomes a leaf node.
+/// - The leaf bounds are packed the same way into the next level, and so on
+///   until a single root remains.
+/// - Nodes are full except the last one of each level, so the tree is stored as
+///   flat arrays without per-node allocations.
+///
+/// # Time Complexity
+/// - Construction: O(n log n).
+/// - Query: O(log n + k) for k results on well distributed data.
+///
+/// # Example
+/// ```
+/// use geomengine::point::Point2D;
+/// use geomengine::shape::Aabb2D;
+/// use geomengine::spatial::RTree;
+///
+/// let points: Vec<_> = (0..100).map(|i| Point2D::new((i % 10) as f64, (i / 10) as f64)).collect();
+/// let tree = RTree::from_points(&points);
+/// let mut hits = tree.query(&Aabb2D::new(Point2D::new(1.5, 1.5), Point2D::new(3.0, 3.0)));
+/// hits.sort();
+/// assert_eq!(hits, vec![22, 23, 32, 33]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RTree<T: Float + Debug> {
+    items: Vec<Aabb2D<T>>,
+    // levels[0] groups the items, levels[k] groups the nodes of levels[k - 1]; the last level is the root
+    levels: Vec<Level<T>>,
+}
+
+#[derive(Debug, Clone)]
+struct Level<T: Float + Debug> {
+    /// Bounds of every node on this level.
+    bounds: Vec<Aabb2D<T>>,
+    /// Children of node `i` are `children[i * NODE_CAPACITY..]`, at most `NODE_CAPACITY` of them.
+    children: Vec<usize>,
+}
+
+impl<T: Float + Debug> RTree<T> {
+    /// Builds the tree over `boxes`; query results are indices into `boxes`.
+    pub fn bulk_load(boxes: &[Aabb2D<T>]) -> Self {
+        let items = boxes.to_vec();
+        let mut levels: Vec<Level<T>> = Vec::new();
+        loop {
+            let current = levels.last().map_or(&items[..], |level| &level.bounds[..]);
+            // stop once a level holds the single root (or there is nothing to index)
+            if current.is_empty() || (current.len() == 1 && !levels.is_empty()) {
+                break;
+            }
+            let children = str_order(current, NODE_CAPACITY);
+            let bounds = children.chunks(NODE_CAPACITY).map(|group| enclosing(current, group)).collect();
+            levels.push(Level { bounds, children });
+        }
+        RTree { items, levels }
+    }
+
+    /// Builds the tree over points; query results are indices into `points`.
+    pub fn from_points(points: &[Point2D<T>]) -> Self {
+        let boxes: Vec<_> = points.iter().map(|&p| Aabb2D::new(p, p)).collect();
+        Self::bulk_load(&boxes)
+    }
+
+    /// Number of indexed boxes.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if the tree indexes nothing.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Indices of all boxes intersecting `window` (boundaries included), in no particular order.
+    pub fn query(&self, window: &Aabb2D<T>) -> Vec<usize> {
+        let mut found = Vec::new();
+        let Some(root) = self.levels.len().checked_sub(1) else {
+            return found;
+        };
+        let mut stack: Vec<(usize, usize)> = (0..self.levels[root].bounds.len())
+            .filter(|&node| self.levels[root].bounds[node].intersects(window))
+            .map(|node| (root, node))
+            .collect();
+
+        while let Some((level, node)) = stack.pop() {
+            let children = &self.levels[level].children;
+            let start = node * NODE_CAPACITY;
+            for &child in &children[start..(start + NODE_CAPACITY).min(children.len())] {
+                if level == 0 {
+                    if self.items[child].intersects(window) {
+                        found.push(child);
+                    }
+                } else if self.levels[level - 1].bounds[child].intersects(window) {
+                    stack.push((level - 1, child));
+                }
+            }
+        }
+        found
+    }
+
+    /// Indices of all boxes hit by the ray `origin + t * direction` for `t` in
+    /// `0..=max_t` (infinite for an unbounded ray), in no particular order.
+    pub fn query_ray(&self, origin: Point2D<T>, direction: Vector2D<T>, max_t: T) -> Vec<usize> {
+        let mut found = Vec::new();
+        let Some(root) = self.levels.len().checked_sub(1) else {
+            return found;
+        };
+        let hit = |b: &Aabb2D<T>| ray_hits_box(origin, direction, max_t, b);
+        let mut stack: Vec<(usize, usize)> = (0..self.levels[root].bounds.len())
+            .filter(|&node| hit(&self.levels[root].bounds[node]))
+            .map(|node| (root, node))
+            .collect();
+
+        while let Some((level, node)) = stack.pop() {
+            let children = &self.levels[level].children;
+            let start = node * NODE_CAPACITY;
+            for &child in &children[start..(start + NODE_CAPACITY).min(children.len())] {
+                if level == 0 {
+                    if hit(&self.items[child]) {
+                        found.push(child);
+                    }
+                } else if hit(&self.levels[level - 1].bounds[child]) {
+                    stack.push((level - 1, child));
+                }
+            }
+        }
+        found
+    }
+}
+
+/// Slab test: does the ray segment from `t = 0` to `max_t` touch box `b`?
+fn ray_hits_box<T: Float + Debug>(origin: Point2D<T>, direction: Vector2D<T>, max_t: T, b: &Aabb2D<T>) -> bool {
+    let (mut enter, mut exit) = (T::zero(), max_t);
+    for (o, d, low, high) in [(origin.x, direction.x, b.min.x, b.max.x), (origin.y, direction.y, b.min.y, b.max.y)] {
+        if d == T::zero() {
+            if o < low || o > high {
+                return false;
+            }
+            continue;
+        }
+        let (t1, t2) = ((low - o) / d, (high - o) / d);
+        enter = enter.max(t1.min(t2));
+        exit = exit.min(t1.max(t2));
+    }
+    enter <= exit
+}
+
+/// Bounding box of `boxes[i]` for every `i` in `group`.
+fn enclosing<T: Float + Debug>(boxes: &[Aabb2D<T>], group: &[usize]) -> Aabb2D<T> {
+    group.iter().skip(1).fold(boxes[group[0]], |bounds, &i| bounds.union(&boxes[i]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scattered(n: usize) -> Vec<Point2D<f64>> {
+        (0..n).map(|i| Point2D::new(((i * 7919) % 1000) as f64 / 10.0, ((i * 104_729) % 997) as f64 / 10.0)).collect()
+    }
+
+    #[test]
+    fn test_rtree_query_matches_brute_force() {
+        let points = scattered(5000);
+        let tree = RTree::from_points(&points);
+        assert_eq!(tree.len(), 5000);
+
+        for window in [
+            Aabb2D::new(Point2D::new(10.0, 10.0), Point2D::new(20.0, 35.0)),
+            Aabb2D::new(Point2D::new(-5.0, -5.0), Point2D::new(0.5, 200.0)),
+            Aabb2D::new(Point2D::new(99.0, 99.0), Point2D::new(100.0, 100.0)),
+        ] {
+            let mut hits = tree.query(&window);
+            hits.sort();
+            let expected: Vec<usize> = (0..points.len()).filter(|&i| window.contains_point(&points[i])).collect();
+            assert_eq!(hits, expected);
+        }
+    }
+
+    #[test]
+    fn test_rtree_boxes_and_empty() {
+        let boxes = [
+            Aabb2D::new(Point2D::new(0.0, 0.0), Point2D::new(2.0, 2.0)),
+            Aabb2D::new(Point2D::new(5.0, 5.0), Point2D::new(6.0, 6.0)),
+            Aabb2D::new(Point2D::new(1.0, 1.0), Point2D::new(5.0, 5.0)),
+        ];
+        let tree = RTree::bulk_load(&boxes);
+        let mut hits = tree.query(&Aabb2D::new(Point2D::new(1.5, 1.5), Point2D::new(1.5, 1.5)));
+        hits.sort();
+        assert_eq!(hits, vec![0, 2]);
+        assert_eq!(tree.query(&Aabb2D::new(Point2D::new(6.0, 6.0), Point2D::new(7.0, 7.0))), vec![1]);
+
+        let mut hits = tree.query_ray(Point2D::new(-1.0, 0.0), Vector2D::new(1.0, 1.0), f64::INFINITY);
+        hits.sort();
+        assert_eq!(hits, vec![0, 1, 2]);
+        assert_eq!(tree.query_ray(Point2D::new(-1.0, 0.0), Vector2D::new(1.0, 1.0), 1.0), vec![0]);
+        assert!(tree.query_ray(Point2D::new(3.0, 0.0), Vector2D::new(0.0, -1.0), f64::INFINITY).is_empty());
+
+        let empty = RTree::<f64>::bulk_load(&[]);
+        assert!(empty.is_empty());
+        assert!(empty.query(&Aabb2D::new(Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0))).is_empty());
+    }
+}
@@ -0,0 +1,54 @@
+use num::Float;
+use core::cmp::Ordering;
+use core::fmt::Debug;
+use crate::shape::Aabb2D;
+use alloc::vec::Vec;
+
+/// Sort-tile-recursive packing: orders boxes so that every run of `capacity`
+/// consecutive entries holds spatial neighbours.
+///
+/// Boxes are sorted by center x and cut into `ceil(sqrt(n / capacity))` vertical
+/// slices of a whole number of runs, then each slice is sorted by center y. Only
+/// the very last run can be shorter than `capacity`.
+pub(crate) fn str_order<T: Float + Debug>(bounds: &[Aabb2D<T>], capacity: usize) -> Vec<usize> {
+    let center = |i: usize| bounds[i].center();
+    let mut order: Vec<usize> = (0..bounds.len()).collect();
+    order.sort_by(|&a, &b| center(a).x.partial_cmp(&center(b).x).unwrap_or(Ordering::Equal));
+
+    let group_count = bounds.len().div_ceil(capacity);
+    let slice_count = (group_count as f64).sqrt().ceil() as usize;
+    let slice_size = group_count.div_ceil(slice_count.max(1)).max(1) * capacity;
+
+    for slice in order.chunks_mut(slice_size) {
+        slice.sort_by(|&a, &b| center(a).y.partial_cmp(&center(b).y).unwrap_or(Ordering::Equal));
+    }
+    order
+}
+
+/// [`str_order`] cut into its groups of (at most) `capacity` spatial neighbours.
+pub(crate) fn str_groups<T: Float + Debug>(bounds: &[Aabb2D<T>], capacity: usize) -> Vec<Vec<usize>> {
+    str_order(bounds, capacity).chunks(capacity).map(|group| group.to_vec()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::point::Point2D;
+
+    #[test]
+    fn test_str_groups_cover_all_inputs_once() {
+        let bounds: Vec<_> = (0..50)
+            .map(|i| {
+                let p = Point2D::new((i % 7) as f64, (i / 7) as f64);
+                Aabb2D::new(p, p)
+            })
+            .collect();
+        let groups = str_groups(&bounds, 4);
+
+        let mut seen: Vec<usize> = groups.iter().flatten().cloned().collect();
+        seen.sort();
+        assert_eq!(seen, (0..50).collect::<Vec<_>>());
+        assert!(groups.iter().all(|g| g.len() <= 4));
+        assert!(groups[..groups.len() - 1].iter().all(|g| g.len() == 4));
+    }
+}
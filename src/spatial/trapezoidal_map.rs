@@ -0,0 +1,510 @@
+use num::Float;
+use core::cmp::Ordering;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::line::Line2D;
+use crate::polygon::{Polygon2D, ring};
+use crate::shape::Aabb2D;
+use crate::algorithms::point_algorithms::bounding_box_iter_2d;
+use crate::algorithms::sampling::{Rng, XorShift, shuffle};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Seed of the insertion order used by [`TrapezoidalMap::new`].
+const DEFAULT_SEED: u64 = 0x7a2e_21d5;
+
+/// Trapezoidal decomposition of a set of non-crossing segments with a search
+/// structure for point location.
+///
+/// # Algorithm Explanation
+/// - The segments are inserted in random order into a decomposition of their
+///   bounding box. Every trapezoid is bounded by a segment above, a segment
+///   below and the vertical lines through two segment endpoints.
+/// - A new segment walks from the trapezoid holding its left endpoint through
+///   the trapezoids it crosses; those are split into the pieces left of it,
+///   above it, below it and right of it, and pieces above (or below) it that
+///   are no longer separated by a vertical line are merged.
+/// - The replaced trapezoids become nodes of a search DAG that tests a point
+///   against an endpoint (left or right) or a segment (above or below).
+/// - Points are compared by `(x, y)`, which shears the plane infinitesimally so
+///   vertical segments and endpoints sharing an x coordinate need no special cases.
+///
+/// Segments may share endpoints but must not cross or overlap; zero-length
+/// segments are ignored.
+///
+/// # Time Complexity
+/// - Construction: O(n log n) expected.
+/// - Query: O(log n) expected.
+///
+/// # Example
+/// ```
+/// use geomengine::line::Line2D;
+/// use geomengine::point::Point2D;
+/// use geomengine::spatial::TrapezoidalMap;
+///
+/// let segments = [
+///     Line2D::new(Point2D::new(0.0, 0.0), Point2D::new(4.0, 0.0)),
+///     Line2D::new(Point2D::new(0.0, 2.0), Point2D::new(4.0, 3.0)),
+/// ];
+/// let map = TrapezoidalMap::new(&segments);
+/// let location = map.locate(Point2D::new(2.0, 1.0));
+/// assert_eq!((location.above, location.below), (Some(1), Some(0)));
+/// assert_eq!(map.locate(Point2D::new(5.0, 1.0)).below, None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TrapezoidalMap<T: Float + Debug> {
+    segments: Vec<Line2D<T>>,
+    // segments with their endpoints in (x, y) order, followed by the top and bottom of the bounding box
+    edges: Vec<(Point2D<T>, Point2D<T>)>,
+    trapezoids: Vec<Trap<T>>,
+    // search DAG rooted at node 0
+    nodes: Vec<Node<T>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Node<T: Float + Debug> {
+    /// Endpoint test: points before it in `(x, y)` order go to the first child.
+    Endpoint(Point2D<T>, usize, usize),
+    /// Edge test: points above the edge go to the first child.
+    Edge(usize, usize, usize),
+    Leaf(usize),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Trap<T: Float + Debug> {
+    top: usize,
+    bottom: usize,
+    left: Point2D<T>,
+    right: Point2D<T>,
+    // neighbours across the vertical sides, sharing the top or the bottom edge
+    upper_left: Option<usize>,
+    lower_left: Option<usize>,
+    upper_right: Option<usize>,
+    lower_right: Option<usize>,
+    /// Leaf node of the trapezoid in the search DAG.
+    node: usize,
+}
+
+/// Segments directly above and below a located point; `None` where a vertical
+/// ray from the point leaves the map without hitting a segment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+    pub above: Option<usize>,
+    pub below: Option<usize>,
+}
+
+/// A face of a [`TrapezoidalMap`]: the region between two segments (or the
+/// bounding box) and two vertical lines.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Trapezoid<T: Float + Debug> {
+    pub top: Option<usize>,
+    pub bottom: Option<usize>,
+    /// x coordinate of the left side.
+    pub left: T,
+    /// x coordinate of the right side.
+    pub right: T,
+}
+
+impl<T: Float + Debug> TrapezoidalMap<T> {
+    /// Builds the map with a fixed insertion order; results are indices into `segments`.
+    pub fn new(segments: &[Line2D<T>]) -> Self {
+        Self::with_rng(segments, &mut XorShift::new(DEFAULT_SEED))
+    }
+
+    /// Builds the map, drawing the insertion order from `rng`.
+    pub fn with_rng(segments: &[Line2D<T>], rng: &mut impl Rng) -> Self {
+        let n = segments.len();
+        let mut edges: Vec<_> = segments
+            .iter()
+            .map(|s| if s.p1.cmp_xy(&s.p2) == Ordering::Greater { (s.p2, s.p1) } else { (s.p1, s.p2) })
+            .collect();
+        let origin = Point2D::new(T::zero(), T::zero());
+        let bounds = bounding_box_iter_2d(edges.iter().flat_map(|&(p, q)| [p, q])).unwrap_or(Aabb2D::new(origin, origin));
+        // strictly around every endpoint, so only the sentinels touch the box
+        let bounds = bounds.expand((bounds.width() + bounds.height()) / T::from(10.0).unwrap() + T::one());
+        let (min, max) = (bounds.min, bounds.max);
+        edges.push((Point2D::new(min.x, max.y), max));
+        edges.push((min, Point2D::new(max.x, min.y)));
+
+        let mut map = TrapezoidalMap { segments: segments.to_vec(), edges, trapezoids: Vec::new(), nodes: Vec::new() };
+        map.add_trapezoid(n, n + 1, min, max);
+        let mut order: Vec<usize> = (0..n).filter(|&i| map.edges[i].0 != map.edges[i].1).collect();
+        shuffle(&mut order, rng);
+        for s in order {
+            map.insert(s);
+        }
+        map
+    }
+
+    /// The segments the map was built from.
+    pub fn segments(&self) -> &[Line2D<T>] {
+        &self.segments
+    }
+
+    /// Number of segments.
+    pub fn len(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// Returns `true` if the map holds no segments.
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// Segments directly above and below `point`. A point on a segment is
+    /// placed below it.
+    pub fn locate(&self, point: Point2D<T>) -> Location {
+        let trap = &self.trapezoids[self.find(point, point)];
+        Location { above: self.segment_index(trap.top), below: self.segment_index(trap.bottom) }
+    }
+
+    /// All trapezoids of the decomposition, in no particular order.
+    pub fn trapezoids(&self) -> impl Iterator<Item = Trapezoid<T>> + '_ {
+        // every live trapezoid has exactly one leaf, replaced trapezoids have none
+        self.nodes.iter().filter_map(move |node| match *node {
+            Node::Leaf(t) => {
+                let trap = &self.trapezoids[t];
+                Some(Trapezoid {
+                    top: self.segment_index(trap.top),
+                    bottom: self.segment_index(trap.bottom),
+                    left: trap.left.x,
+                    right: trap.right.x,
+                })
+            }
+            _ => None,
+        })
+    }
+
+    fn segment_index(&self, edge: usize) -> Option<usize> {
+        (edge < self.segments.len()).then_some(edge)
+    }
+
+    /// Cross product telling on which side of `edge` the point `r` is; positive above.
+    fn side(&self, edge: usize, r: Point2D<T>) -> T {
+        let (p, q) = self.edges[edge];
+        (q - p).cross_product(&(r - p))
+    }
+
+    /// Trapezoid holding `point`; a point on an edge is resolved by the side of `towards`.
+    fn find(&self, point: Point2D<T>, towards: Point2D<T>) -> usize {
+        let mut node = 0;
+        loop {
+            match self.nodes[node] {
+                Node::Leaf(t) => return t,
+                Node::Endpoint(x, before, after) => {
+                    node = if point.cmp_xy(&x) == Ordering::Less { before } else { after };
+                }
+                Node::Edge(edge, above, below) => {
+                    let mut side = self.side(edge, point);
+                    if side == T::zero() {
+                        side = self.side(edge, towards);
+                    }
+                    node = if side > T::zero() { above } else { below };
+                }
+            }
+        }
+    }
+
+    fn add_trapezoid(&mut self, top: usize, bottom: usize, left: Point2D<T>, right: Point2D<T>) -> usize {
+        let t = self.trapezoids.len();
+        self.trapezoids.push(Trap {
+            top,
+            bottom,
+            left,
+            right,
+            upper_left: None,
+            lower_left: None,
+            upper_right: None,
+            lower_right: None,
+            node: self.nodes.len(),
+        });
+        self.nodes.push(Node::Leaf(t));
+        t
+    }
+
+    fn add_node(&mut self, node: Node<T>) -> usize {
+        self.nodes.push(node);
+        self.nodes.len() - 1
+    }
+
+    fn insert(&mut self, s: usize) {
+        let (p, q) = self.edges[s];
+        let mut crossed = vec![self.find(p, q)];
+        loop {
+            let trap = self.trapezoids[crossed[crossed.len() - 1]];
+            if q.cmp_xy(&trap.right) != Ordering::Greater {
+                break;
+            }
+            let next = if self.side(s, trap.right) > T::zero() { trap.lower_right } else { trap.upper_right };
+            crossed.push(next.expect("segments of a trapezoidal map must not cross"));
+        }
+        let first = self.trapezoids[crossed[0]];
+        let last = self.trapezoids[crossed[crossed.len() - 1]];
+
+        // pieces left and right of the segment, if its endpoints are not on the old boundaries
+        let left = (p != first.left).then(|| self.add_trapezoid(first.top, first.bottom, first.left, p));
+        let right = (q != last.right).then(|| self.add_trapezoid(last.top, last.bottom, q, last.right));
+        let mut upper = self.add_trapezoid(first.top, s, p, q);
+        let mut lower = self.add_trapezoid(s, first.bottom, p, q);
+        match left {
+            Some(a) => {
+                let trap = &mut self.trapezoids[a];
+                (trap.upper_left, trap.lower_left) = (first.upper_left, first.lower_left);
+                (trap.upper_right, trap.lower_right) = (Some(upper), Some(lower));
+                self.trapezoids[upper].upper_left = Some(a);
+                self.trapezoids[lower].lower_left = Some(a);
+                self.link_left(first, a, a);
+            }
+            None => self.link_left(first, upper, lower),
+        }
+
+        // pieces above and below the segment, split only where an endpoint's vertical line still reaches them
+        let mut pieces = Vec::with_capacity(crossed.len());
+        for pair in crossed.windows(2) {
+            pieces.push((upper, lower));
+            let (current, next) = (self.trapezoids[pair[0]], self.trapezoids[pair[1]]);
+            let r = current.right;
+            if self.side(s, r) > T::zero() {
+                let piece = self.add_trapezoid(next.top, s, r, q);
+                let trap = &mut self.trapezoids[upper];
+                (trap.right, trap.upper_right, trap.lower_right) = (r, current.upper_right, Some(piece));
+                let trap = &mut self.trapezoids[piece];
+                (trap.upper_left, trap.lower_left) = (next.upper_left, Some(upper));
+                if let Some(t) = current.upper_right {
+                    self.trapezoids[t].upper_left = Some(upper);
+                }
+                if let Some(t) = next.upper_left {
+                    self.trapezoids[t].upper_right = Some(piece);
+                }
+                upper = piece;
+            } else {
+                let piece = self.add_trapezoid(s, next.bottom, r, q);
+                let trap = &mut self.trapezoids[lower];
+                (trap.right, trap.lower_right, trap.upper_right) = (r, current.lower_right, Some(piece));
+                let trap = &mut self.trapezoids[piece];
+                (trap.lower_left, trap.upper_left) = (next.lower_left, Some(lower));
+                if let Some(t) = current.lower_right {
+                    self.trapezoids[t].lower_left = Some(lower);
+                }
+                if let Some(t) = next.lower_left {
+                    self.trapezoids[t].lower_right = Some(piece);
+                }
+                lower = piece;
+            }
+        }
+        pieces.push((upper, lower));
+
+        match right {
+            Some(b) => {
+                let trap = &mut self.trapezoids[b];
+                (trap.upper_left, trap.lower_left) = (Some(upper), Some(lower));
+                (trap.upper_right, trap.lower_right) = (last.upper_right, last.lower_right);
+                self.trapezoids[upper].upper_right = Some(b);
+                self.trapezoids[lower].lower_right = Some(b);
+                self.link_right(last, b, b);
+            }
+            None => self.link_right(last, upper, lower),
+        }
+
+        // the leaves of the crossed trapezoids become the search nodes of their pieces
+        let end = crossed.len() - 1;
+        for (j, (&t, &(up, down))) in crossed.iter().zip(&pieces).enumerate() {
+            let mut node = Node::Edge(s, self.trapezoids[up].node, self.trapezoids[down].node);
+            if let (true, Some(b)) = (j == end, right) {
+                let inner = self.add_node(node);
+                node = Node::Endpoint(q, inner, self.trapezoids[b].node);
+            }
+            if let (true, Some(a)) = (j == 0, left) {
+                let inner = self.add_node(node);
+                node = Node::Endpoint(p, self.trapezoids[a].node, inner);
+            }
+            let leaf = self.trapezoids[t].node;
+            self.nodes[leaf] = node;
+        }
+    }
+
+    /// Points the left neighbours of the replaced `old` at `upper` and `lower`.
+    fn link_left(&mut self, old: Trap<T>, upper: usize, lower: usize) {
+        self.trapezoids[upper].upper_left = old.upper_left;
+        self.trapezoids[lower].lower_left = old.lower_left;
+        if let Some(t) = old.upper_left {
+            self.trapezoids[t].upper_right = Some(upper);
+        }
+        if let Some(t) = old.lower_left {
+            self.trapezoids[t].lower_right = Some(lower);
+        }
+    }
+
+    /// Points the right neighbours of the replaced `old` at `upper` and `lower`.
+    fn link_right(&mut self, old: Trap<T>, upper: usize, lower: usize) {
+        self.trapezoids[upper].upper_right = old.upper_right;
+        self.trapezoids[lower].lower_right = old.lower_right;
+        if let Some(t) = old.upper_right {
+            self.trapezoids[t].upper_left = Some(upper);
+        }
+        if let Some(t) = old.lower_right {
+            self.trapezoids[t].lower_left = Some(lower);
+        }
+    }
+}
+
+/// Repeated point-in-polygon tests against a fixed polygon with holes, backed
+/// by a [`TrapezoidalMap`] of its edges.
+///
+/// The rings are oriented so the interior lies left of every edge; a point is
+/// then inside exactly when the edge directly above it runs from right to left.
+///
+/// # Time Complexity
+/// - Construction: O(n log n) expected for n edges.
+/// - Query: O(log n) expected, against O(n) for a direct ring test.
+///
+/// # Example
+/// ```
+/// use geomengine::point::Point2D;
+/// use geomengine::polygon::Polygon2D;
+/// use geomengine::spatial::PolygonLocator;
+///
+/// let square = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(4.0, 0.0), Point2D::new(4.0, 4.0), Point2D::new(0.0, 4.0)]);
+/// let hole = Polygon2D::new(vec![Point2D::new(1.0, 1.0), Point2D::new(3.0, 1.0), Point2D::new(3.0, 3.0), Point2D::new(1.0, 3.0)]);
+/// let locator = PolygonLocator::new(&square, &[hole]);
+/// assert!(locator.contains(Point2D::new(0.5, 2.0)));
+/// assert!(!locator.contains(Point2D::new(2.0, 2.0)));
+/// assert!(!locator.contains(Point2D::new(5.0, 2.0)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct PolygonLocator<T: Float + Debug> {
+    map: TrapezoidalMap<T>,
+    // whether each oriented edge runs from right to left in (x, y) order
+    leftward: Vec<bool>,
+}
+
+impl<T: Float + Debug> PolygonLocator<T> {
+    /// Builds the locator; the holes must lie inside `exterior` and not touch each other.
+    pub fn new(exterior: &Polygon2D<T>, holes: &[Polygon2D<T>]) -> Self {
+        let mut edges = Vec::new();
+        let rings = core::iter::once((exterior, true)).chain(holes.iter().map(|h| (h, false)));
+        for (polygon, counterclockwise) in rings {
+            let mut vertices = polygon.vertices().to_vec();
+            if (ring::signed_area(&vertices) > T::zero()) != counterclockwise {
+                vertices.reverse();
+            }
+            let n = vertices.len();
+            edges.extend((0..n).map(|i| Line2D::new(vertices[i], vertices[(i + 1) % n])));
+        }
+        let leftward = edges.iter().map(|e| e.p1.cmp_xy(&e.p2) == Ordering::Greater).collect();
+        PolygonLocator { map: TrapezoidalMap::new(&edges), leftward }
+    }
+
+    /// Is `point` inside the polygon? Points exactly on an edge may go either way.
+    pub fn contains(&self, point: Point2D<T>) -> bool {
+        self.map.locate(point).above.is_some_and(|edge| self.leftward[edge])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::sampling::sample_aabb;
+
+    /// Star-shaped polygon with `n` vertices at jittered angles and random radii around `center`.
+    fn star(n: usize, center: Point2D<f64>, radius: f64, rng: &mut XorShift) -> Polygon2D<f64> {
+        let vertices = (0..n)
+            .map(|i| {
+                let angle = (i as f64 + 0.8 * rng.unit::<f64>()) * core::f64::consts::TAU / n as f64;
+                let r = radius * (0.5 + 0.5 * rng.unit::<f64>());
+                Point2D::new(center.x + r * angle.cos(), center.y + r * angle.sin())
+            })
+            .collect();
+        Polygon2D::new(vertices)
+    }
+
+    fn edges(polygon: &Polygon2D<f64>) -> Vec<Line2D<f64>> {
+        let v = polygon.vertices();
+        (0..v.len()).map(|i| Line2D::new(v[i], v[(i + 1) % v.len()])).collect()
+    }
+
+    /// Nearest segments above and below `point` along a vertical line.
+    fn brute_force(segments: &[Line2D<f64>], point: Point2D<f64>) -> Location {
+        let (mut above, mut below) = ((None, f64::INFINITY), (None, f64::NEG_INFINITY));
+        for (i, s) in segments.iter().enumerate() {
+            let (lo, hi) = if s.p1.x < s.p2.x { (s.p1, s.p2) } else { (s.p2, s.p1) };
+            if lo.x < point.x && point.x < hi.x {
+                let y = lo.y + (point.x - lo.x) * (hi.y - lo.y) / (hi.x - lo.x);
+                if y > point.y && y < above.1 {
+                    above = (Some(i), y);
+                } else if y < point.y && y > below.1 {
+                    below = (Some(i), y);
+                }
+            }
+        }
+        Location { above: above.0, below: below.0 }
+    }
+
+    #[test]
+    fn test_trapezoidal_map_matches_brute_force() {
+        let mut rng = XorShift::new(11);
+        let mut segments = Vec::new();
+        for (i, n) in [40, 25, 10].into_iter().enumerate() {
+            let center = Point2D::new(10.0 * i as f64, 3.0 * i as f64);
+            segments.extend(edges(&star(n, center, 4.0, &mut rng)));
+        }
+        // a few vertical segments and one sharing an endpoint with them
+        segments.push(Line2D::new(Point2D::new(40.0, -5.0), Point2D::new(40.0, 5.0)));
+        segments.push(Line2D::new(Point2D::new(42.0, 5.0), Point2D::new(42.0, -5.0)));
+        segments.push(Line2D::new(Point2D::new(40.0, 5.0), Point2D::new(42.0, 5.0)));
+
+        let bounds = Aabb2D::new(Point2D::new(-6.0, -8.0), Point2D::new(45.0, 14.0));
+        for seed in [1, 2, 3] {
+            let map = TrapezoidalMap::with_rng(&segments, &mut XorShift::new(seed));
+            assert_eq!(map.len(), segments.len());
+            // Euler: every segment adds at most three trapezoids
+            assert!(map.trapezoids().count() <= 3 * segments.len() + 1);
+            for _ in 0..2000 {
+                let point = sample_aabb(&bounds, &mut rng);
+                assert_eq!(map.locate(point), brute_force(&segments, point), "at {point:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_polygon_locator_matches_ring_test() {
+        let mut rng = XorShift::new(5);
+        let exterior = star(60, Point2D::new(0.0, 0.0), 10.0, &mut rng);
+        // reversed on purpose, the locator orients the rings itself
+        let mut hole = star(12, Point2D::new(0.0, 0.0), 2.0, &mut rng).vertices().to_vec();
+        hole.reverse();
+        let hole = Polygon2D::new(hole);
+        let locator = PolygonLocator::new(&exterior, core::slice::from_ref(&hole));
+
+        let bounds = Aabb2D::new(Point2D::new(-12.0, -12.0), Point2D::new(12.0, 12.0));
+        for _ in 0..5000 {
+            let point = sample_aabb(&bounds, &mut rng);
+            let expected = ring::contains_point(exterior.vertices(), &point) && !ring::contains_point(hole.vertices(), &point);
+            assert_eq!(locator.contains(point), expected, "at {point:?}");
+        }
+    }
+
+    #[test]
+    fn test_axis_aligned_polygon_and_empty_map() {
+        // L shape: vertical edges and endpoints sharing x coordinates
+        let l_shape = Polygon2D::new(vec![
+            Point2D::new(0.0, 0.0),
+            Point2D::new(3.0, 0.0),
+            Point2D::new(3.0, 1.0),
+            Point2D::new(1.0, 1.0),
+            Point2D::new(1.0, 3.0),
+            Point2D::new(0.0, 3.0),
+        ]);
+        let locator = PolygonLocator::new(&l_shape, &[]);
+        assert!(locator.contains(Point2D::new(0.5, 2.5)));
+        assert!(locator.contains(Point2D::new(2.5, 0.5)));
+        assert!(!locator.contains(Point2D::new(2.0, 2.0)));
+        assert!(!locator.contains(Point2D::new(-1.0, 0.5)));
+        assert!(!locator.contains(Point2D::new(1.0, 4.0)));
+
+        let empty = TrapezoidalMap::<f64>::new(&[]);
+        assert!(empty.is_empty());
+        assert_eq!(empty.locate(Point2D::new(1.0, 1.0)), Location { above: None, below: None });
+        assert_eq!(empty.trapezoids().count(), 1);
+    }
+}
@@ -0,0 +1,132 @@
+use num::Float;
+use core::fmt::Debug;
+use core::ops::{Add, Sub, Neg, Mul, Div};
+
+/// An angle, stored in radians, so APIs taking one cannot mix up units.
+///
+/// Build one with [`from_radians`](Self::from_radians) or
+/// [`from_degrees`](Self::from_degrees). Positive angles turn counterclockwise.
+///
+/// # Example
+/// ```
+/// use geomengine::transform::Angle;
+///
+/// let angle: Angle<f64> = Angle::from_degrees(270.0) + Angle::from_degrees(180.0);
+/// assert!((angle.normalized().degrees() - 90.0).abs() < 1e-12);
+/// assert!((Angle::from_degrees(270.0_f64).normalized_signed().degrees() + 90.0).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Angle<T: Float + Debug> {
+    radians: T,
+}
+
+impl<T: Float + Debug> Angle<T> {
+    pub fn from_radians(radians: T) -> Self {
+        Angle { radians }
+    }
+    pub fn from_degrees(degrees: T) -> Self {
+        Angle { radians: degrees.to_radians() }
+    }
+    pub fn zero() -> Self {
+        Angle { radians: T::zero() }
+    }
+    /// The angle whose tangent is `y / x`, in `(-π, π]`, as for the direction of a vector.
+    pub fn atan2(y: T, x: T) -> Self {
+        Angle { radians: y.atan2(x) }
+    }
+
+    pub fn radians(&self) -> T {
+        self.radians
+    }
+    pub fn degrees(&self) -> T {
+        self.radians.to_degrees()
+    }
+    pub fn sin(&self) -> T {
+        self.radians.sin()
+    }
+    pub fn cos(&self) -> T {
+        self.radians.cos()
+    }
+    pub fn sin_cos(&self) -> (T, T) {
+        self.radians.sin_cos()
+    }
+
+    /// The same direction in `[0, 2π)`.
+    pub fn normalized(&self) -> Self {
+        let tau = T::from(core::f64::consts::TAU).unwrap();
+        let wrapped = self.radians - (self.radians / tau).floor() * tau;
+        // rounding can land a tiny negative angle on 2π itself
+        Angle { radians: if wrapped >= tau { T::zero() } else { wrapped } }
+    }
+
+    /// The same direction in `(-π, π]`.
+    pub fn normalized_signed(&self) -> Self {
+        let (pi, tau) = (T::from(core::f64::consts::PI).unwrap(), T::from(core::f64::consts::TAU).unwrap());
+        let wrapped = self.normalized().radians;
+        Angle { radians: if wrapped > pi { wrapped - tau } else { wrapped } }
+    }
+}
+
+impl<T: Float + Debug> Add for Angle<T> {
+    type Output = Self;
+    fn add(self, other: Self) -> Self::Output {
+        Angle { radians: self.radians + other.radians }
+    }
+}
+
+impl<T: Float + Debug> Sub for Angle<T> {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self::Output {
+        Angle { radians: self.radians - other.radians }
+    }
+}
+
+impl<T: Float + Debug> Neg for Angle<T> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Angle { radians: -self.radians }
+    }
+}
+
+impl<T: Float + Debug> Mul<T> for Angle<T> {
+    type Output = Self;
+    fn mul(self, factor: T) -> Self::Output {
+        Angle { radians: self.radians * factor }
+    }
+}
+
+impl<T: Float + Debug> Div<T> for Angle<T> {
+    type Output = Self;
+    fn div(self, divisor: T) -> Self::Output {
+        Angle { radians: self.radians / divisor }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use core::f64::consts::PI;
+
+    #[test]
+    fn test_angle_units_and_arithmetic() {
+        assert_relative_eq!(Angle::from_degrees(180.0).radians(), PI);
+        assert_relative_eq!(Angle::from_radians(PI / 2.0).degrees(), 90.0);
+        let angle = (Angle::from_degrees(30.0) - Angle::from_degrees(90.0)) * 2.0;
+        assert_relative_eq!(angle.degrees(), -120.0, epsilon = 1e-12);
+        assert_relative_eq!((-angle / 4.0).degrees(), 30.0, epsilon = 1e-12);
+        assert_relative_eq!(Angle::atan2(1.0, -1.0).degrees(), 135.0, epsilon = 1e-12);
+        assert!(Angle::from_degrees(10.0) < Angle::from_degrees(20.0));
+    }
+
+    #[test]
+    fn test_angle_normalization() {
+        for (degrees, unsigned, signed) in [(0.0, 0.0, 0.0), (-90.0, 270.0, -90.0), (540.0, 180.0, 180.0), (-180.0, 180.0, 180.0), (725.0, 5.0, 5.0)] {
+            let angle = Angle::from_degrees(degrees);
+            assert_relative_eq!(angle.normalized().degrees(), unsigned, epsilon = 1e-9);
+            assert_relative_eq!(angle.normalized_signed().degrees(), signed, epsilon = 1e-9);
+        }
+        let tiny = Angle::from_radians(-1e-18).normalized().radians();
+        assert!((0.0..2.0 * PI).contains(&tiny));
+    }
+}
@@ -0,0 +1,8 @@
+// module exports
+mod angle;
+mod transform2d;
+mod transformable;
+
+pub use angle::Angle;
+pub use transform2d::Transform2D;
+pub use transformable::Transformable;
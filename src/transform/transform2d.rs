@@ -0,0 +1,128 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use super::Angle;
+
+/// A 2D affine transformation.
+///
+/// Maps `(x, y)` to `(a·x + b·y + tx, c·x + d·y + ty)`. Transforms are combined
+/// with [`then`](Self::then), which applies `self` first.
+///
+/// # Example
+/// ```
+/// use geomengine::point::Point2D;
+/// use geomengine::transform::Transform2D;
+///
+/// let t = Transform2D::scaling(2.0, 2.0).then(&Transform2D::translation(1.0, 0.0));
+/// assert_eq!(t.transform_point(&Point2D::new(1.0, 1.0)), Point2D::new(3.0, 2.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform2D<T: Float + Debug> {
+    pub a: T,
+    pub b: T,
+    pub c: T,
+    pub d: T,
+    pub tx: T,
+    pub ty: T,
+}
+
+impl<T: Float + Debug> Default for Transform2D<T> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<T: Float + Debug> Transform2D<T> {
+    pub fn new(a: T, b: T, c: T, d: T, tx: T, ty: T) -> Self {
+        Transform2D { a, b, c, d, tx, ty }
+    }
+    pub fn identity() -> Self {
+        Self::scaling(T::one(), T::one())
+    }
+    pub fn translation(dx: T, dy: T) -> Self {
+        Transform2D::new(T::one(), T::zero(), T::zero(), T::one(), dx, dy)
+    }
+    /// Counterclockwise rotation around the origin (like [`Point2D::rotate`]).
+    pub fn rotation(angle: Angle<T>) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Transform2D::new(cos, -sin, sin, cos, T::zero(), T::zero())
+    }
+    /// Counterclockwise rotation around `center`.
+    pub fn rotation_about(angle: Angle<T>, center: Point2D<T>) -> Self {
+        Self::about(Self::rotation(angle), center)
+    }
+    pub fn scaling(sx: T, sy: T) -> Self {
+        Transform2D::new(sx, T::zero(), T::zero(), sy, T::zero(), T::zero())
+    }
+    /// Scaling that keeps `center` fixed.
+    pub fn scaling_about(sx: T, sy: T, center: Point2D<T>) -> Self {
+        Self::about(Self::scaling(sx, sy), center)
+    }
+
+    /// `linear` conjugated so that it keeps `center` fixed.
+    fn about(linear: Self, center: Point2D<T>) -> Self {
+        Self::translation(-center.x, -center.y).then(&linear).then(&Self::translation(center.x, center.y))
+    }
+
+    /// The transform applying `self` first and `next` second.
+    pub fn then(&self, next: &Self) -> Self {
+        Transform2D::new(
+            next.a * self.a + next.b * self.c,
+            next.a * self.b + next.b * self.d,
+            next.c * self.a + next.d * self.c,
+            next.c * self.b + next.d * self.d,
+            next.a * self.tx + next.b * self.ty + next.tx,
+            next.c * self.tx + next.d * self.ty + next.ty,
+        )
+    }
+
+    /// Determinant of the linear part: the factor by which areas are scaled
+    /// (negative if the transform mirrors).
+    pub fn determinant(&self) -> T {
+        self.a * self.d - self.b * self.c
+    }
+
+    /// The inverse transform, or `None` if the transform is singular.
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det == T::zero() {
+            return None;
+        }
+        let (a, b, c, d) = (self.d / det, -self.b / det, -self.c / det, self.a / det);
+        Some(Transform2D::new(a, b, c, d, -(a * self.tx + b * self.ty), -(c * self.tx + d * self.ty)))
+    }
+
+    pub fn transform_point(&self, point: &Point2D<T>) -> Point2D<T> {
+        Point2D::new(
+            self.a * point.x + self.b * point.y + self.tx,
+            self.c * point.x + self.d * point.y + self.ty,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_transform2d_rotation_about_matches_point_rotate() {
+        let (p, center) = (Point2D::new(3.0, 1.0), Point2D::new(1.0, -2.0));
+        let rotated = Transform2D::rotation_about(Angle::from_degrees(30.0), center).transform_point(&p);
+        let expected = p.rotate(Angle::from_degrees(30.0), Some(center));
+        assert_relative_eq!(rotated.x, expected.x, epsilon = 1e-12);
+        assert_relative_eq!(rotated.y, expected.y, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_transform2d_then_and_inverse() {
+        let t = Transform2D::scaling_about(2.0, 3.0, Point2D::new(1.0, 1.0)).then(&Transform2D::rotation(Angle::from_degrees(90.0)));
+        let p = Point2D::new(2.0, 5.0);
+        let back = t.inverse().unwrap().transform_point(&t.transform_point(&p));
+        assert_relative_eq!(back.x, p.x, epsilon = 1e-12);
+        assert_relative_eq!(back.y, p.y, epsilon = 1e-12);
+        assert_relative_eq!(t.determinant(), 6.0, epsilon = 1e-12);
+        assert_eq!(Transform2D::scaling(0.0, 1.0).inverse(), None);
+        assert_eq!(Transform2D::identity().then(&t), t);
+    }
+}
@@ -0,0 +1,126 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::point::Point2D;
+use crate::line::{Line2D, Polyline2D};
+use crate::polygon::Polygon2D;
+use crate::shape::{Circle2D, Geometry2D};
+use super::{Angle, Transform2D};
+use alloc::vec::Vec;
+
+/// Geometry that can be moved by an affine [`Transform2D`].
+///
+/// Only [`apply`](Self::apply) has to be implemented; the other methods build the
+/// corresponding transform.
+///
+/// # Example
+/// ```
+/// use geomengine::line::Line2D;
+/// use geomengine::point::Point2D;
+/// use geomengine::transform::Transformable;
+///
+/// let line = Line2D::new(Point2D::new(0.0, 0.0), Point2D::new(1.0, 0.0));
+/// let scaled = line.scale_about(3.0, 3.0, Point2D::new(0.0, 0.0)).translate((0.0, 1.0));
+/// assert_eq!(scaled, Line2D::new(Point2D::new(0.0, 1.0), Point2D::new(3.0, 1.0)));
+/// ```
+pub trait Transformable<T: Float + Debug>: Sized {
+    fn apply(&self, transform: &Transform2D<T>) -> Self;
+
+    /// Moves by `offset` (same signature as [`Point2D::translate`]).
+    fn translate(&self, offset: (T, T)) -> Self {
+        self.apply(&Transform2D::translation(offset.0, offset.1))
+    }
+    fn rotate_about(&self, angle: Angle<T>, center: Point2D<T>) -> Self {
+        self.apply(&Transform2D::rotation_about(angle, center))
+    }
+    fn scale_about(&self, sx: T, sy: T, center: Point2D<T>) -> Self {
+        self.apply(&Transform2D::scaling_about(sx, sy, center))
+    }
+}
+
+fn apply_all<T: Float + Debug>(points: &[Point2D<T>], transform: &Transform2D<T>) -> Vec<Point2D<T>> {
+    points.iter().map(|p| transform.transform_point(p)).collect()
+}
+
+impl<T: Float + Debug> Transformable<T> for Point2D<T> {
+    fn apply(&self, transform: &Transform2D<T>) -> Self {
+        transform.transform_point(self)
+    }
+}
+
+impl<T: Float + Debug> Transformable<T> for Line2D<T> {
+    fn apply(&self, transform: &Transform2D<T>) -> Self {
+        Line2D::new(transform.transform_point(&self.p1), transform.transform_point(&self.p2))
+    }
+}
+
+impl<T: Float + Debug> Transformable<T> for Polyline2D<T> {
+    fn apply(&self, transform: &Transform2D<T>) -> Self {
+        Polyline2D::new(apply_all(self.vertices(), transform))
+    }
+}
+
+impl<T: Float + Debug> Transformable<T> for Polygon2D<T> {
+    fn apply(&self, transform: &Transform2D<T>) -> Self {
+        Polygon2D::new(apply_all(self.vertices(), transform))
+    }
+}
+
+impl<T: Float + Debug> Transformable<T> for Circle2D<T> {
+    /// Moves the center and scales the radius by `sqrt(|determinant|)`, which
+    /// preserves the area. Exact for rigid motions and uniform scaling; other
+    /// transforms would turn the circle into an ellipse.
+    fn apply(&self, transform: &Transform2D<T>) -> Self {
+        Circle2D::new(transform.transform_point(&self.center), self.radius * transform.determinant().abs().sqrt())
+    }
+}
+
+impl<T: Float + Debug> Transformable<T> for Geometry2D<T> {
+    fn apply(&self, transform: &Transform2D<T>) -> Self {
+        match self {
+            Geometry2D::Point(point) => Geometry2D::Point(point.apply(transform)),
+            Geometry2D::Line(line) => Geometry2D::Line(line.apply(transform)),
+            Geometry2D::Polyline(polyline) => Geometry2D::Polyline(polyline.apply(transform)),
+            Geometry2D::Polygon(polygon) => Geometry2D::Polygon(polygon.apply(transform)),
+            Geometry2D::Circle(circle) => Geometry2D::Circle(circle.apply(transform)),
+            Geometry2D::MultiPoint(points) => Geometry2D::MultiPoint(apply_all(points, transform)),
+            Geometry2D::MultiPolyline(polylines) => {
+                Geometry2D::MultiPolyline(polylines.iter().map(|p| p.apply(transform)).collect())
+            }
+            Geometry2D::MultiPolygon(polygons) => {
+                Geometry2D::MultiPolygon(polygons.iter().map(|p| p.apply(transform)).collect())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shape::Area;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_transformable_shapes() {
+        let polygon = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(2.0, 0.0), Point2D::new(0.0, 1.0)]);
+        let moved = polygon.scale_about(2.0, 2.0, Point2D::origin()).translate((1.0, 1.0));
+        assert_eq!(moved.vertices(), &[Point2D::new(1.0, 1.0), Point2D::new(5.0, 1.0), Point2D::new(1.0, 3.0)]);
+        assert_eq!(moved.area(), 4.0);
+
+        let circle = Circle2D::new(Point2D::new(1.0, 0.0), 2.0).scale_about(3.0, 3.0, Point2D::origin());
+        assert_eq!(circle, Circle2D::new(Point2D::new(3.0, 0.0), 6.0));
+
+        let rotated = Point2D::new(1.0, 0.0).rotate_about(Angle::from_degrees(90.0), Point2D::new(0.0, 0.0));
+        assert_relative_eq!(rotated.x, 0.0, epsilon = 1e-12);
+        assert_relative_eq!(rotated.y, 1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_transformable_geometry2d() {
+        let t = Transform2D::translation(1.0, -1.0);
+        let geometry: Geometry2D<f64> = vec![Point2D::new(0.0, 0.0), Point2D::new(2.0, 2.0)].into();
+        assert_eq!(geometry.apply(&t), Geometry2D::MultiPoint(vec![Point2D::new(1.0, -1.0), Point2D::new(3.0, 1.0)]));
+
+        let line = Line2D::new(Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0));
+        assert_eq!(Geometry2D::from(line).apply(&t), Geometry2D::Line(line.apply(&t)));
+    }
+}
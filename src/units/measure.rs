@@ -0,0 +1,72 @@
+use num::Float;
+use core::fmt::Debug;
+use crate::shape::{Distance, Perimeter};
+use super::{Area, Length, LengthUnit};
+
+/// [`Distance::distance_to`] between two shapes whose coordinates are in unit `U`.
+///
+/// # Example
+/// ```
+/// use geomengine::line::Line2D;
+/// use geomengine::point::Point2D;
+/// use geomengine::units::{self, Feet, Length, Meters};
+///
+/// // survey coordinates in feet
+/// let (well, pipe) = (Point2D::new(0.0, 30.0), Line2D::new(Point2D::new(-50.0, 0.0), Point2D::new(50.0, 0.0)));
+/// let clearance: Length<f64, Feet> = units::distance(&well, &pipe);
+/// assert!((clearance.to::<Meters>().value() - 9.144).abs() < 1e-9);
+/// ```
+pub fn distance<U: LengthUnit, T: Float + Debug, B: ?Sized>(a: &impl Distance<T, B>, b: &B) -> Length<T, U> {
+    Length::new(a.distance_to(b))
+}
+
+/// [`Perimeter::perimeter`] of a shape whose coordinates are in unit `U`: the
+/// length of a line or polyline, the boundary length of an area.
+///
+/// # Example
+/// ```
+/// use geomengine::line::Polyline2D;
+/// use geomengine::point::Point2D;
+/// use geomengine::units::{self, Kilometers, Length, Meters};
+///
+/// let route = Polyline2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(300.0, 400.0), Point2D::new(300.0, 1400.0)]);
+/// let travelled: Length<f64, Meters> = units::length(&route);
+/// assert_eq!(travelled.to::<Kilometers>().value(), 1.5);
+/// ```
+pub fn length<U: LengthUnit, T: Float + Debug>(shape: &impl Perimeter<T>) -> Length<T, U> {
+    Length::new(shape.perimeter())
+}
+
+/// [`crate::shape::Area::area`] of a shape whose coordinates are in unit `U`.
+///
+/// # Example
+/// ```
+/// use geomengine::point::Point2D;
+/// use geomengine::polygon::Polygon2D;
+/// use geomengine::units::{self, Area, Feet, Meters};
+///
+/// let lot = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(100.0, 0.0), Point2D::new(100.0, 50.0), Point2D::new(0.0, 50.0)]);
+/// let size: Area<f64, Feet> = units::area(&lot);
+/// assert!((size.to::<Meters>().value() - 464.5152).abs() < 1e-9);
+/// ```
+pub fn area<U: LengthUnit, T: Float + Debug>(shape: &impl crate::shape::Area<T>) -> Area<T, U> {
+    Area::new(shape.area())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::point::Point2D;
+    use crate::shape::Circle2D;
+    use crate::units::{Feet, Meters};
+
+    #[test]
+    fn test_typed_measures_keep_coordinate_values() {
+        let circle = Circle2D::new(Point2D::new(0.0, 0.0), 2.0);
+        let boundary: Length<f64, Meters> = length(&circle);
+        assert_eq!(boundary.value(), circle.perimeter());
+        assert_eq!(area::<Feet, f64>(&circle).value(), crate::shape::Area::area(&circle));
+        let gap: Length<f64, Meters> = distance(&circle, &Point2D::new(5.0, 0.0));
+        assert_eq!(gap, Length::new(3.0));
+    }
+}
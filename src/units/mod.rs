@@ -0,0 +1,6 @@
+// module exports
+mod quantity;
+mod measure;
+
+pub use quantity::{Area, Feet, Inches, Kilometers, Length, LengthUnit, Meters, Miles, Millimeters};
+pub use measure::{area, distance, length};
@@ -0,0 +1,211 @@
+use num::Float;
+use core::fmt::{Debug, Display, self};
+use core::marker::PhantomData;
+use core::ops::{Add, Sub, Mul, Div};
+
+/// A unit of length, as a marker type for [`Length`] and [`Area`].
+pub trait LengthUnit: Copy + Debug + PartialEq + PartialOrd {
+    /// Size of one unit in meters.
+    const METERS: f64;
+    /// Symbol used by `Display`, e.g. `"m"`.
+    const SYMBOL: &'static str;
+}
+
+macro_rules! length_units {
+    ($($(#[$doc:meta])* $unit:ident = $meters:expr, $symbol:expr;)*) => {
+        $(
+            $(#[$doc])*
+            #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+            pub struct $unit;
+
+            impl LengthUnit for $unit {
+                const METERS: f64 = $meters;
+                const SYMBOL: &'static str = $symbol;
+            }
+        )*
+    };
+}
+
+length_units! {
+    Meters = 1.0, "m";
+    Kilometers = 1000.0, "km";
+    Millimeters = 0.001, "mm";
+    /// International foot, exactly 0.3048 m.
+    Feet = 0.3048, "ft";
+    Inches = 0.0254, "in";
+    /// International mile, 5280 feet.
+    Miles = 1609.344, "mi";
+}
+
+/// Factor turning a value in `U` into one in `V`.
+fn factor<T: Float, U: LengthUnit, V: LengthUnit>() -> T {
+    T::from(U::METERS / V::METERS).unwrap()
+}
+
+/// A length in unit `U`.
+///
+/// Lengths only add to and compare with lengths in the same unit, so mixing
+/// units fails to compile; convert with [`to`](Self::to) first.
+///
+/// # Example
+/// ```
+/// use geomengine::units::{Feet, Length, Meters};
+///
+/// let run = Length::<f64, Meters>::new(100.0) + Length::new(20.0);
+/// let in_feet: Length<f64, Feet> = run.to();
+/// assert!((in_feet.value() - 393.700787).abs() < 1e-6);
+/// assert_eq!(format!("{run}"), "120 m");
+/// ```
+///
+/// ```compile_fail
+/// use geomengine::units::{Feet, Length, Meters};
+///
+/// let mixed = Length::<f64, Meters>::new(1.0) + Length::<f64, Feet>::new(1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Length<T: Float + Debug, U: LengthUnit> {
+    value: T,
+    unit: PhantomData<U>,
+}
+
+/// An area in square `U`.
+///
+/// # Example
+/// ```
+/// use geomengine::units::{Area, Kilometers, Length, Meters};
+///
+/// let field = Length::<f64, Meters>::new(200.0) * Length::new(50.0);
+/// assert_eq!(field, Area::new(10_000.0));
+/// let in_km: Area<f64, Kilometers> = field.to();
+/// assert!((in_km.value() - 0.01).abs() < 1e-15);
+/// assert_eq!(format!("{field}"), "10000 m²");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Area<T: Float + Debug, U: LengthUnit> {
+    value: T,
+    unit: PhantomData<U>,
+}
+
+impl<T: Float + Debug, U: LengthUnit> Length<T, U> {
+    pub fn new(value: T) -> Self {
+        Length { value, unit: PhantomData }
+    }
+    /// The number of units.
+    pub fn value(&self) -> T {
+        self.value
+    }
+    /// The same length in unit `V`.
+    pub fn to<V: LengthUnit>(&self) -> Length<T, V> {
+        Length::new(self.value * factor::<T, U, V>())
+    }
+}
+
+impl<T: Float + Debug, U: LengthUnit> Area<T, U> {
+    pub fn new(value: T) -> Self {
+        Area { value, unit: PhantomData }
+    }
+    /// The number of square units.
+    pub fn value(&self) -> T {
+        self.value
+    }
+    /// The same area in square `V`.
+    pub fn to<V: LengthUnit>(&self) -> Area<T, V> {
+        let factor = factor::<T, U, V>();
+        Area::new(self.value * factor * factor)
+    }
+}
+
+/// Addition, subtraction and scaling within one unit, for both quantities.
+macro_rules! impl_quantity_ops {
+    ($($quantity:ident),*) => {
+        $(
+            impl<T: Float + Debug, U: LengthUnit> Add for $quantity<T, U> {
+                type Output = Self;
+                fn add(self, other: Self) -> Self::Output {
+                    $quantity::new(self.value + other.value)
+                }
+            }
+            impl<T: Float + Debug, U: LengthUnit> Sub for $quantity<T, U> {
+                type Output = Self;
+                fn sub(self, other: Self) -> Self::Output {
+                    $quantity::new(self.value - other.value)
+                }
+            }
+            impl<T: Float + Debug, U: LengthUnit> Mul<T> for $quantity<T, U> {
+                type Output = Self;
+                fn mul(self, factor: T) -> Self::Output {
+                    $quantity::new(self.value * factor)
+                }
+            }
+            impl<T: Float + Debug, U: LengthUnit> Div<T> for $quantity<T, U> {
+                type Output = Self;
+                fn div(self, divisor: T) -> Self::Output {
+                    $quantity::new(self.value / divisor)
+                }
+            }
+            /// The ratio of two quantities in the same unit.
+            impl<T: Float + Debug, U: LengthUnit> Div for $quantity<T, U> {
+                type Output = T;
+                fn div(self, other: Self) -> T {
+                    self.value / other.value
+                }
+            }
+        )*
+    };
+}
+
+impl_quantity_ops!(Length, Area);
+
+impl<T: Float + Debug, U: LengthUnit> Mul for Length<T, U> {
+    type Output = Area<T, U>;
+    fn mul(self, other: Self) -> Area<T, U> {
+        Area::new(self.value * other.value)
+    }
+}
+
+impl<T: Float + Debug, U: LengthUnit> Div<Length<T, U>> for Area<T, U> {
+    type Output = Length<T, U>;
+    fn div(self, length: Length<T, U>) -> Length<T, U> {
+        Length::new(self.value / length.value)
+    }
+}
+
+impl<T: Float + Debug + Display, U: LengthUnit> Display for Length<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.value, U::SYMBOL)
+    }
+}
+
+impl<T: Float + Debug + Display, U: LengthUnit> Display for Area<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}²", self.value, U::SYMBOL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_length_conversions_round_trip() {
+        let mile = Length::<f64, Miles>::new(1.0);
+        assert_relative_eq!(mile.to::<Feet>().value(), 5280.0, epsilon = 1e-9);
+        assert_relative_eq!(mile.to::<Kilometers>().value(), 1.609344, epsilon = 1e-12);
+        assert_relative_eq!(Length::<f64, Feet>::new(1.0).to::<Inches>().value(), 12.0, epsilon = 1e-12);
+        assert_relative_eq!(mile.to::<Millimeters>().to::<Miles>().value(), 1.0, epsilon = 1e-12);
+        assert_eq!(Length::<f32, Meters>::new(2.0).to::<Millimeters>(), Length::new(2000.0));
+    }
+
+    #[test]
+    fn test_quantity_arithmetic() {
+        let (a, b) = (Length::<f64, Feet>::new(3.0), Length::<f64, Feet>::new(4.0));
+        assert_eq!((a + b) * 2.0, Length::new(14.0));
+        assert_eq!(b - a, Length::new(1.0));
+        assert_eq!(b / a, 4.0 / 3.0);
+        let square_feet = a * b;
+        assert_eq!(square_feet / a, b);
+        assert_relative_eq!(Area::<f64, Feet>::new(1.0).to::<Inches>().value(), 144.0, epsilon = 1e-9);
+        assert!(a < b);
+    }
+}
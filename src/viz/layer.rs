@@ -0,0 +1,82 @@
+use num::Float;
+use std::fmt::Debug;
+use crate::point::Point2D;
+use crate::line::{Line2D, Polyline2D};
+use crate::polygon::Polygon2D;
+use crate::shape::Aabb2D;
+
+/// One set of geometries drawn by [`debug_plot`](super::debug_plot), in a single color.
+///
+/// Layers are drawn in order, so later layers cover earlier ones. Without an
+/// explicit [`color`](Self::color) each layer takes the next color of a fixed palette.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Layer {
+    pub(crate) points: Vec<(f64, f64)>,
+    pub(crate) paths: Vec<Vec<(f64, f64)>>,
+    pub(crate) rings: Vec<Vec<(f64, f64)>>,
+    pub(super) color: Option<String>,
+}
+
+fn coordinates<T: Float + Debug>(points: &[Point2D<T>]) -> Vec<(f64, f64)> {
+    points.iter().map(|p| (p.x.to_f64().unwrap(), p.y.to_f64().unwrap())).collect()
+}
+
+impl Layer {
+    fn empty() -> Self {
+        Layer { points: Vec::new(), paths: Vec::new(), rings: Vec::new(), color: None }
+    }
+
+    /// Points drawn as dots.
+    pub fn points<T: Float + Debug>(points: &[Point2D<T>]) -> Self {
+        Layer { points: coordinates(points), ..Self::empty() }
+    }
+    /// Segments drawn as separate lines.
+    pub fn segments<T: Float + Debug>(segments: &[Line2D<T>]) -> Self {
+        Layer { paths: segments.iter().map(|s| coordinates(&[s.p1, s.p2])).collect(), ..Self::empty() }
+    }
+    pub fn polyline<T: Float + Debug>(polyline: &Polyline2D<T>) -> Self {
+        Layer { paths: vec![coordinates(polyline.vertices())], ..Self::empty() }
+    }
+    /// A polygon drawn as a translucent fill with its outline.
+    pub fn polygon<T: Float + Debug>(polygon: &Polygon2D<T>) -> Self {
+        Self::polygons(std::slice::from_ref(polygon))
+    }
+    pub fn polygons<T: Float + Debug>(polygons: &[Polygon2D<T>]) -> Self {
+        Layer { rings: polygons.iter().map(|p| coordinates(p.vertices())).collect(), ..Self::empty() }
+    }
+    /// Triangles given as indices into `points`, as returned by the triangulation
+    /// and tessellation routines.
+    ///
+    /// # Panics
+    /// If an index is out of bounds.
+    pub fn triangles<T: Float + Debug>(points: &[Point2D<T>], triangles: &[[usize; 3]]) -> Self {
+        let rings = triangles.iter().map(|t| coordinates(&[points[t[0]], points[t[1]], points[t[2]]])).collect();
+        Layer { rings, ..Self::empty() }
+    }
+    /// Box outlines, e.g. the nodes of a spatial index or a grid's cells.
+    pub fn boxes<T: Float + Debug>(boxes: &[Aabb2D<T>]) -> Self {
+        let paths = boxes
+            .iter()
+            .map(|b| {
+                let mut outline = coordinates(&b.corners());
+                outline.push(outline[0]);
+                outline
+            })
+            .collect();
+        Layer { paths, ..Self::empty() }
+    }
+
+    /// Draws the layer in `color`, any SVG color such as `"red"` or `"#1f77b4"`.
+    ///
+    /// PNG plots only understand `#rgb`, `#rrggbb` and the basic named colors
+    /// (`black`, `white`, `gray`, `red`, `green`, `blue`, `yellow`, `orange`,
+    /// `purple`, `brown`, `cyan`, `magenta`).
+    pub fn color(mut self, color: &str) -> Self {
+        self.color = Some(color.to_string());
+        self
+    }
+
+    pub(super) fn coordinates(&self) -> impl Iterator<Item = (f64, f64)> + '_ {
+        self.points.iter().chain(self.paths.iter().flatten()).chain(self.rings.iter().flatten()).copied()
+    }
+}
@@ -0,0 +1,11 @@
+//! Debug plots of geometries, written as SVG or, drawn with `plotters`, as PNG.
+
+// module exports
+mod layer;
+mod plot;
+mod png;
+mod svg;
+
+pub use layer::Layer;
+pub use plot::debug_plot;
+pub use svg::render_svg;
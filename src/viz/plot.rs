@@ -0,0 +1,104 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use super::{Layer, render_svg};
+use super::png::write_png;
+
+/// Colors given to layers without an explicit one, in order.
+pub(super) const PALETTE: [&str; 6] = ["#1f77b4", "#d62728", "#2ca02c", "#ff7f0e", "#9467bd", "#8c564b"];
+/// Empty space around the drawing, in pixels.
+const MARGIN: f64 = 20.0;
+
+/// Draws `layers` into the SVG or PNG file at `path`, scaled to fit a 800 pixel
+/// wide image. The format follows from the extension of `path`.
+///
+/// Meant for looking at intermediate results while debugging, e.g. a hull
+/// over its input points, not for publication-quality plots.
+///
+/// # Errors
+/// Fails with [`io::ErrorKind::InvalidInput`] if `path` ends in neither `.svg`
+/// nor `.png`, or if a PNG layer has a color other than `#rgb`, `#rrggbb` or
+/// one of the basic named colors, and with the error of writing the file otherwise.
+///
+/// # Example
+/// ```no_run
+/// use geomengine::algorithms::point_algorithms::convex_hull_2d;
+/// use geomengine::point::Point2D;
+/// use geomengine::polygon::Polygon2D;
+/// use geomengine::viz::{debug_plot, Layer};
+///
+/// let points = [Point2D::new(0.0, 0.0), Point2D::new(4.0, 1.0), Point2D::new(2.0, 3.0), Point2D::new(2.0, 1.0)];
+/// let hull = Polygon2D::new(convex_hull_2d(&points));
+/// debug_plot("hull.png", &[Layer::polygon(&hull), Layer::points(&points).color("black")])?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn debug_plot(path: impl AsRef<Path>, layers: &[Layer]) -> io::Result<()> {
+    let path = path.as_ref();
+    let extension = path.extension().and_then(|extension| extension.to_str()).map(str::to_ascii_lowercase);
+    match extension.as_deref() {
+        Some("svg") => fs::write(path, render_svg(layers, 800.0)),
+        Some("png") => write_png(path, layers, 800),
+        _ => Err(io::Error::new(io::ErrorKind::InvalidInput, "debug_plot only writes .svg and .png files")),
+    }
+}
+
+/// Maps layer coordinates to image pixels, with the y axis pointing up as in the geometry.
+pub(super) struct Frame {
+    min_x: f64,
+    max_y: f64,
+    scale: f64,
+    pub(super) height: f64,
+}
+
+impl Frame {
+    /// Fits the finite coordinates of `layers` into an image `width` pixels
+    /// wide; the height follows from their extent. Empty layers give a unit square.
+    pub(super) fn fit(layers: &[Layer], width: f64) -> Self {
+        let (mut min_x, mut min_y, mut max_x, mut max_y) = (f64::INFINITY, f64::INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY);
+        for (x, y) in layers.iter().flat_map(Layer::coordinates).filter(|(x, y)| x.is_finite() && y.is_finite()) {
+            (min_x, min_y, max_x, max_y) = (min_x.min(x), min_y.min(y), max_x.max(x), max_y.max(y));
+        }
+        if min_x > max_x {
+            (min_x, min_y, max_x, max_y) = (0.0, 0.0, 1.0, 1.0);
+        }
+        let scale = (width - 2.0 * MARGIN).max(1.0) / (max_x - min_x).max(max_y - min_y);
+        // a single point has no extent to scale to
+        let scale = if scale.is_finite() { scale } else { 1.0 };
+        let height = (max_y - min_y) * scale + 2.0 * MARGIN;
+        Frame { min_x, max_y, scale, height }
+    }
+
+    pub(super) fn to_pixels(&self, (x, y): (f64, f64)) -> (f64, f64) {
+        ((x - self.min_x) * self.scale + MARGIN, (self.max_y - y) * self.scale + MARGIN)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::point::Point2D;
+
+    #[test]
+    fn test_debug_plot_picks_format_from_extension() {
+        let layers = [Layer::points(&[Point2D::new(1.0f32, 2.0)])];
+        let path = std::env::temp_dir().join(format!("geomengine-{}-plot.svg", std::process::id()));
+        debug_plot(&path, &layers).unwrap();
+        assert!(fs::read_to_string(&path).unwrap().contains("<circle"));
+        fs::remove_file(path).unwrap();
+
+        let path = std::env::temp_dir().join(format!("geomengine-{}-plot.PNG", std::process::id()));
+        debug_plot(&path, &layers).unwrap();
+        assert!(fs::read(&path).unwrap().starts_with(b"\x89PNG"));
+        fs::remove_file(path).unwrap();
+
+        let path = std::env::temp_dir().join(format!("geomengine-{}-plot.jpg", std::process::id()));
+        let error = debug_plot(&path, &layers).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(!path.exists());
+        // a bad color is caught before the file is created
+        let path = std::env::temp_dir().join(format!("geomengine-{}-bad-color.png", std::process::id()));
+        let error = debug_plot(&path, &[Layer::points(&[Point2D::new(0.0, 0.0)]).color("chartreuse")]).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,132 @@
+use std::io;
+use std::path::Path;
+use plotters::prelude::*;
+use super::Layer;
+use super::plot::{Frame, PALETTE};
+
+/// Named colors understood in PNG plots, next to `#rgb` and `#rrggbb`.
+const NAMED_COLORS: [(&str, (u8, u8, u8)); 13] = [
+    ("black", (0, 0, 0)),
+    ("white", (255, 255, 255)),
+    ("gray", (128, 128, 128)),
+    ("grey", (128, 128, 128)),
+    ("red", (255, 0, 0)),
+    ("green", (0, 128, 0)),
+    ("blue", (0, 0, 255)),
+    ("yellow", (255, 255, 0)),
+    ("orange", (255, 165, 0)),
+    ("purple", (128, 0, 128)),
+    ("brown", (165, 42, 42)),
+    ("cyan", (0, 255, 255)),
+    ("magenta", (255, 0, 255)),
+];
+
+/// Writes `layers` as a PNG image `width` pixels wide, laid out as [`render_svg`](super::render_svg) does.
+///
+/// Colors are checked before the file is created, so an unsupported color leaves
+/// no file behind.
+pub(super) fn write_png(path: &Path, layers: &[Layer], width: u32) -> io::Result<()> {
+    let colors = layer_colors(layers)?;
+    let frame = Frame::fit(layers, f64::from(width));
+    let area = BitMapBackend::new(path, (width, frame.height.ceil() as u32)).into_drawing_area();
+    draw(&area, &frame, layers, &colors)?;
+    area.present().map_err(drawing_error)
+}
+
+/// The color of every layer, its own or the next one from [`PALETTE`].
+fn layer_colors(layers: &[Layer]) -> io::Result<Vec<RGBColor>> {
+    layers
+        .iter()
+        .enumerate()
+        .map(|(index, layer)| match &layer.color {
+            Some(color) => parse_color(color)
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, format!("unsupported PNG color `{color}`"))),
+            None => Ok(parse_color(PALETTE[index % PALETTE.len()]).unwrap()),
+        })
+        .collect()
+}
+
+/// Draws `layers` onto `area` in `colors`: translucent polygons with outlines, then paths, then dots.
+fn draw<DB: DrawingBackend>(area: &DrawingArea<DB, plotters::coord::Shift>, frame: &Frame, layers: &[Layer], colors: &[RGBColor]) -> io::Result<()> {
+    area.fill(&WHITE).map_err(drawing_error)?;
+    let pixels = |points: &[(f64, f64)]| -> Vec<(i32, i32)> {
+        points
+            .iter()
+            .filter(|(x, y)| x.is_finite() && y.is_finite())
+            .map(|&p| frame.to_pixels(p))
+            .map(|(x, y)| (x.round() as i32, y.round() as i32))
+            .collect()
+    };
+    for (layer, &color) in layers.iter().zip(colors) {
+        let stroke = color.stroke_width(2);
+        for ring in &layer.rings {
+            let mut outline = pixels(ring);
+            area.draw(&Polygon::new(outline.clone(), color.mix(0.2).filled())).map_err(drawing_error)?;
+            if let Some(&first) = outline.first() {
+                outline.push(first);
+            }
+            area.draw(&PathElement::new(outline, stroke)).map_err(drawing_error)?;
+        }
+        for path in &layer.paths {
+            area.draw(&PathElement::new(pixels(path), stroke)).map_err(drawing_error)?;
+        }
+        for point in pixels(&layer.points) {
+            area.draw(&Circle::new(point, 3, color.filled())).map_err(drawing_error)?;
+        }
+    }
+    Ok(())
+}
+
+fn drawing_error(error: impl std::error::Error) -> io::Error {
+    io::Error::other(error.to_string())
+}
+
+/// `#rgb`, `#rrggbb` or one of [`NAMED_COLORS`], case insensitive.
+fn parse_color(text: &str) -> Option<RGBColor> {
+    let text = text.trim().to_ascii_lowercase();
+    if let Some(hex) = text.strip_prefix('#') {
+        let digits: Vec<u8> = hex.chars().map(|c| c.to_digit(16).map(|d| d as u8)).collect::<Option<_>>()?;
+        return match digits[..] {
+            [r, g, b] => Some(RGBColor(r * 17, g * 17, b * 17)),
+            [r1, r0, g1, g0, b1, b0] => Some(RGBColor(r1 * 16 + r0, g1 * 16 + g0, b1 * 16 + b0)),
+            _ => None,
+        };
+    }
+    NAMED_COLORS.iter().find(|(name, _)| *name == text).map(|&(_, (r, g, b))| RGBColor(r, g, b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::point::Point2D;
+    use crate::polygon::Polygon2D;
+
+    #[test]
+    fn test_parse_color() {
+        assert_eq!(parse_color("#1f77b4"), Some(RGBColor(0x1f, 0x77, 0xb4)));
+        assert_eq!(parse_color("#F0a"), Some(RGBColor(255, 0, 170)));
+        assert_eq!(parse_color("Black"), Some(RGBColor(0, 0, 0)));
+        assert_eq!(parse_color("#12345"), None);
+        assert_eq!(parse_color("rebeccapurple"), None);
+    }
+
+    #[test]
+    fn test_draw_fills_polygons_and_dots() {
+        let square = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(10.0, 0.0), Point2D::new(10.0, 10.0), Point2D::new(0.0, 10.0)]);
+        let layers = [Layer::polygon(&square).color("blue"), Layer::points(&[Point2D::new(0.0, 0.0)]).color("red")];
+        // 100 pixels for 10 units inside the 20 pixel margin
+        let frame = Frame::fit(&layers, 140.0);
+        let mut buffer = vec![0; 140 * 140 * 3];
+        {
+            let area = BitMapBackend::with_buffer(&mut buffer, (140, 140)).into_drawing_area();
+            draw(&area, &frame, &layers, &layer_colors(&layers).unwrap()).unwrap();
+            area.present().unwrap();
+        }
+        let pixel = |x: usize, y: usize| &buffer[3 * (140 * y + x)..3 * (140 * y + x) + 3];
+        assert_eq!(pixel(5, 5), [255, 255, 255]);
+        // a fifth of blue over white inside the square
+        assert_eq!(pixel(70, 70), [204, 204, 255]);
+        // the dot at the origin sits at the bottom left corner of the square
+        assert_eq!(pixel(20, 120), [255, 0, 0]);
+    }
+}
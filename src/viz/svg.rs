@@ -0,0 +1,77 @@
+use std::fmt::Write as _;
+use super::Layer;
+use super::plot::{Frame, PALETTE};
+
+/// The SVG document [`debug_plot`](super::debug_plot) writes, `width` pixels wide.
+///
+/// The y axis points up, as in the geometry, and the height follows from the
+/// extent of the layers. Empty layers give an empty image.
+pub fn render_svg(layers: &[Layer], width: f64) -> String {
+    let frame = Frame::fit(layers, width);
+    let height = frame.height;
+    let to_pixels = |point| frame.to_pixels(point);
+    let point_list = |points: &[(f64, f64)]| {
+        points.iter().map(|&p| to_pixels(p)).map(|(x, y)| format!("{x:.2},{y:.2}")).collect::<Vec<_>>().join(" ")
+    };
+
+    let mut svg = String::new();
+    writeln!(svg, r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width:.0}" height="{height:.0}" viewBox="0 0 {width:.2} {height:.2}">"#).unwrap();
+    writeln!(svg, r#"<rect width="100%" height="100%" fill="white"/>"#).unwrap();
+    for (index, layer) in layers.iter().enumerate() {
+        let color = escape_attribute(layer.color.as_deref().unwrap_or(PALETTE[index % PALETTE.len()]));
+        writeln!(svg, r#"<g stroke="{color}" fill="{color}" stroke-width="1.5" stroke-linejoin="round">"#).unwrap();
+        for ring in &layer.rings {
+            writeln!(svg, r#"<polygon points="{}" fill-opacity="0.2"/>"#, point_list(ring)).unwrap();
+        }
+        for path in &layer.paths {
+            writeln!(svg, r#"<polyline points="{}" fill="none"/>"#, point_list(path)).unwrap();
+        }
+        for &point in &layer.points {
+            let (x, y) = to_pixels(point);
+            writeln!(svg, r#"<circle cx="{x:.2}" cy="{y:.2}" r="3" stroke="none"/>"#).unwrap();
+        }
+        svg.push_str("</g>\n");
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// `text` made safe inside a double-quoted XML attribute.
+fn escape_attribute(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::line::Polyline2D;
+    use crate::point::Point2D;
+    use crate::polygon::Polygon2D;
+    use crate::shape::Aabb2D;
+
+    #[test]
+    fn test_render_svg_maps_coordinates_with_y_up() {
+        let square = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(10.0, 0.0), Point2D::new(10.0, 5.0), Point2D::new(0.0, 5.0)]);
+        let layers = [
+            Layer::polygon(&square),
+            Layer::points(&[Point2D::new(0.0, 5.0)]).color("black"),
+            Layer::polyline(&Polyline2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(10.0, 5.0)])),
+            Layer::boxes(&[Aabb2D::new(Point2D::new(1.0, 1.0), Point2D::new(2.0, 2.0))]),
+            Layer::triangles(square.vertices(), &[[0, 1, 2]]),
+        ];
+        let svg = render_svg(&layers, 140.0);
+        // 100 pixels for 10 units, so the image is 50 + 2 × 20 pixels high
+        assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="140" height="90""#));
+        assert!(svg.contains(r#"<polygon points="20.00,70.00 120.00,70.00 120.00,20.00 20.00,20.00""#));
+        // the top left corner is at the top left of the image
+        assert!(svg.contains(r#"<circle cx="20.00" cy="20.00""#));
+        assert!(svg.contains(r#"stroke="black""#) && svg.contains(PALETTE[0]) && svg.contains(PALETTE[2]));
+        assert_eq!(svg.matches("<polyline").count(), 2);
+        assert_eq!(svg.matches("<polygon").count(), 2);
+        assert!(render_svg(&[], 100.0).ends_with("</svg>\n"));
+
+        let sneaky = render_svg(&[Layer::points(&[Point2D::new(0.0, 0.0)]).color(r#"red"/><script>alert(1)</script><g a=""#)], 100.0);
+        assert!(!sneaky.contains("<script>"));
+        assert!(sneaky.contains(r#"stroke="red&quot;/&gt;&lt;script&gt;"#));
+    }
+}